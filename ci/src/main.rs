@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use clap::ArgMatches;
-use dagger_sdk::{Container, HostDirectoryOpts, Query};
+use dagger_sdk::{Container, IgnoreFiles, Query};
 
 #[tokio::main]
 async fn main() -> eyre::Result<()> {
@@ -42,40 +42,47 @@ async fn run_codegen(client: Arc<Query>, _subm: &ArgMatches) -> eyre::Result<()>
     let container = get_dependencies(client).await?;
 
     let generated_image = container
-        .with_exec(vec!["mkdir", "-p", "/mnt/output"])
         .with_mounted_file("/usr/bin/docker", docker_cli.id().await?)
         .with_unix_socket("/var/run/docker.sock", socket.id().await?)
         .with_exec(vec![
             "cargo",
             "run",
             "--",
-            "generate",
+            "export-schema",
             "--output",
-            "crates/dagger-sdk/gen.rs",
+            "/mnt/output/schema.json",
         ])
-        .with_exec(vec!["cargo", "fmt", "--all"])
-        .with_exec(vec!["cargo", "fix", "--workspace", "--allow-dirty"])
-        .with_exec(vec!["cargo", "fmt", "--all"])
-        .with_exec(vec!["mv", "crates/dagger-sdk/gen.rs", "/mnt/output/gen.rs"]);
+        .with_exec(vec![
+            "cargo",
+            "run",
+            "--",
+            "generate",
+            "--modules",
+            "--schema",
+            "/mnt/output/schema.json",
+            "--config",
+            "crates/dagger-sdk/dagger-codegen.toml",
+            "--output",
+            "/mnt/output/gen",
+        ]);
 
-    let _ = generated_image.exit_code().await?;
+    generated_image.sync_ok().await?;
 
+    // checked in together, so `cargo make codegen_check` runs without an engine
+    generated_image
+        .file("/mnt/output/schema.json")
+        .export("crates/dagger-sdk/schema.json")
+        .await?;
     generated_image
-        .file("/mnt/output/gen.rs")
-        .export("crates/dagger-sdk/src/gen.rs")
+        .directory("/mnt/output/gen")
+        .export("crates/dagger-sdk/src/gen")
         .await?;
 
     Ok(())
 }
 
 async fn release(client: Arc<Query>, _subm: &clap::ArgMatches) -> Result<(), color_eyre::Report> {
-    let src_dir = client.host().directory_opts(
-        ".",
-        HostDirectoryOpts {
-            exclude: Some(vec!["target/"]),
-            include: None,
-        },
-    );
+    let src_dir = client.host().directory_ignoring(".", IgnoreFiles::GIT)?;
     let base_image = client
         .container()
         .from("rust:latest")
@@ -93,10 +100,7 @@ async fn release(client: Arc<Query>, _subm: &clap::ArgMatches) -> Result<(), col
             "dagger-rs",
             "dagger-sdk",
         ]);
-    let exit = container.exit_code().await?;
-    if exit != 0 {
-        eyre::bail!("container failed with non-zero exit code");
-    }
+    container.sync_ok().await?;
 
     println!("released pr succeeded!");
 
@@ -104,26 +108,16 @@ async fn release(client: Arc<Query>, _subm: &clap::ArgMatches) -> Result<(), col
 }
 
 async fn get_dependencies(client: Arc<Query>) -> eyre::Result<Container> {
-    let cargo_dir = client.host().directory_opts(
-        ".",
-        HostDirectoryOpts {
-            exclude: None,
-            include: Some(vec![
-                "**/Cargo.lock",
-                "**/Cargo.toml",
-                "**/main.rs",
-                "**/lib.rs",
-            ]),
-        },
-    );
-
-    let src_dir = client.host().directory_opts(
-        ".",
-        HostDirectoryOpts {
-            exclude: Some(vec!["target/"]),
-            include: None,
-        },
-    );
+    let cargo_dir = client.host().directory_with(".", |o| {
+        o.include(vec![
+            "**/Cargo.lock",
+            "**/Cargo.toml",
+            "**/main.rs",
+            "**/lib.rs",
+        ])
+    });
+
+    let src_dir = client.host().directory_ignoring(".", IgnoreFiles::GIT)?;
 
     let cache_cargo_index_dir = client.cache_volume("cargo_index");
     let _cache_cargo_deps = client.cache_volume("cargo_deps");
@@ -148,16 +142,17 @@ async fn get_dependencies(client: Arc<Query>) -> eyre::Result<Container> {
             "sccache-v0.3.3-x86_64-unknown-linux-musl/sccache",
             "/usr/local/bin/sccache",
         ])
-        .with_exec(vec!["chmod", "+x", "/usr/local/bin/sccache"])
+        .with_exec(vec!["chmod", "+x", "/usr/local/bin/sccache"]);
+
+    let mut base_image = base_image;
+    for name in ["AWS_ACCESS_KEY_ID", "AWS_SECRET_ACCESS_KEY"] {
+        if let Ok(secret) = client.host().secret_env_variable(name) {
+            base_image = base_image.with_secret_variable(name, secret.id().await?);
+        }
+    }
+
+    let base_image = base_image
         //.with_env_variable("RUSTC_WRAPPER", "/usr/local/bin/sccache")
-        .with_env_variable(
-            "AWS_ACCESS_KEY_ID",
-            std::env::var("AWS_ACCESS_KEY_ID").unwrap_or("".into()),
-        )
-        .with_env_variable(
-            "AWS_SECRET_ACCESS_KEY",
-            std::env::var("AWS_SECRET_ACCESS_KEY").unwrap_or("".into()),
-        )
         .with_env_variable("SCCACHE_BUCKET", "sccache")
         .with_env_variable("SCCACHE_REGION", "auto")
         .with_env_variable("SCCACHE_ENDPOINT", "https://api-minio.front.kjuulh.io")
@@ -204,10 +199,7 @@ async fn select_base_image(client: Arc<Query>) -> eyre::Result<Container> {
 }
 
 async fn validate_pr(client: Arc<Query>, container: Container) -> eyre::Result<()> {
-    let exit = container.exit_code().await?;
-    if exit != 0 {
-        eyre::bail!("container failed with non-zero exit code");
-    }
+    container.sync_ok().await?;
     let docker_cli = client
         .container()
         .from("docker:cli")
@@ -219,10 +211,7 @@ async fn validate_pr(client: Arc<Query>, container: Container) -> eyre::Result<(
         .with_unix_socket("/var/run/docker.sock", socket.id().await?)
         .with_exec(vec!["cargo", "test", "--all"]);
 
-    let exit = container.exit_code().await?;
-    if exit != 0 {
-        eyre::bail!("container failed with non-zero exit code");
-    }
+    container.sync_ok().await?;
 
     println!("validating pr succeeded!");
 
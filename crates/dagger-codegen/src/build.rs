@@ -0,0 +1,152 @@
+//! Generates bindings for a custom schema, like the one of an extension, from a `build.rs`.
+//!
+//! ```no_run
+//! // build.rs
+//! fn main() -> eyre::Result<()> {
+//!     dagger_codegen::build::Builder::new("schema.json")
+//!         .base_schema("dagger.json")
+//!         .generate()?;
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! The generated `schema.rs` is then included with `dagger_sdk::include_schema!("schema");`.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use dagger_core::introspection::{IntrospectionResponse, Schema};
+use eyre::Context;
+
+use crate::config::Config;
+use crate::generate_with_diagnostics;
+use crate::rust::RustGenerator;
+
+pub struct Builder {
+    schema: PathBuf,
+    base_schema: Option<PathBuf>,
+    config: Option<PathBuf>,
+    out_dir: Option<PathBuf>,
+}
+
+impl Builder {
+    /// Generates code for the introspection schema at `schema`
+    pub fn new(schema: impl Into<PathBuf>) -> Self {
+        Self {
+            schema: schema.into(),
+            base_schema: None,
+            config: None,
+            out_dir: None,
+        }
+    }
+
+    /// Schema dagger-sdk was generated from, its types are used from dagger-sdk instead of being
+    /// generated again
+    pub fn base_schema(mut self, path: impl Into<PathBuf>) -> Self {
+        self.base_schema = Some(path.into());
+        self
+    }
+
+    /// A dagger-codegen.toml customizing the generated code
+    pub fn config(mut self, path: impl Into<PathBuf>) -> Self {
+        self.config = Some(path.into());
+        self
+    }
+
+    /// Where to write the generated code, `OUT_DIR` by default
+    pub fn out_dir(mut self, path: impl Into<PathBuf>) -> Self {
+        self.out_dir = Some(path.into());
+        self
+    }
+
+    /// Writes the code to `<schema file stem>.rs` in the output directory and returns its path
+    pub fn generate(self) -> eyre::Result<PathBuf> {
+        for path in [
+            Some(&self.schema),
+            self.base_schema.as_ref(),
+            self.config.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            println!("cargo:rerun-if-changed={}", path.display());
+        }
+
+        let mut config = match self.config.as_ref() {
+            Some(path) => Config::from_toml(
+                &std::fs::read_to_string(path)
+                    .with_context(|| format!("could not read {}", path.display()))?,
+            )?,
+            None => Config::default(),
+        };
+        if config.sdk.is_none() {
+            config.sdk = Some("dagger_sdk".into());
+        }
+
+        let mut generator = RustGenerator::new(config);
+        if let Some(base_schema) = self.base_schema.as_ref() {
+            generator = generator.extending(load_schema(base_schema)?);
+        }
+
+        let generated = generate_with_diagnostics(load_schema(&self.schema)?, Arc::new(generator))?;
+        for diagnostic in generated.diagnostics.iter() {
+            println!("cargo:warning={}", diagnostic);
+        }
+        generated.check(false)?;
+
+        let out_dir = match self.out_dir {
+            Some(out_dir) => out_dir,
+            None => std::env::var("OUT_DIR")
+                .context("OUT_DIR is not set, generate is meant to be called from a build.rs")?
+                .into(),
+        };
+        let name = self
+            .schema
+            .file_stem()
+            .ok_or(eyre::anyhow!("{} is not a file", self.schema.display()))?;
+        let output = out_dir.join(name).with_extension("rs");
+
+        std::fs::write(&output, generated.code)
+            .with_context(|| format!("could not write {}", output.display()))?;
+
+        Ok(output)
+    }
+}
+
+fn load_schema(path: &Path) -> eyre::Result<Schema> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("could not read {}", path.display()))?;
+    let resp: IntrospectionResponse = serde_json::from_str(&content)
+        .with_context(|| format!("could not parse introspection schema {}", path.display()))?;
+
+    resp.into_schema().schema.ok_or(eyre::anyhow!(
+        "{} does not contain a __schema",
+        path.display()
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use super::Builder;
+
+    #[test]
+    fn generate_writes_to_out_dir() {
+        let fixtures = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+        let out_dir = tempfile::tempdir().unwrap();
+        let out_dir = out_dir.path();
+
+        let output = Builder::new(fixtures.join("extension.json"))
+            .base_schema(fixtures.join("extension_base.json"))
+            .out_dir(out_dir)
+            .generate()
+            .unwrap();
+
+        assert_eq!(output, out_dir.join("extension.rs"));
+        let code = std::fs::read_to_string(output).unwrap();
+        assert!(code.contains("use dagger_sdk::*;"));
+        assert!(code.contains("pub trait QueryExt"));
+    }
+}
@@ -0,0 +1,253 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use dagger_core::introspection::{__TypeKind, Schema};
+use serde::Deserialize;
+
+use crate::diagnostics::Diagnostics;
+
+/// Customizes the generated code, usually loaded from a `dagger-codegen.toml`.
+///
+/// `skip`, `rename` and `scalars` refer to schema names, either a type (`Container`) or a field
+/// of an object or interface (`Container.withExec`). `derives`, `attributes` and `builders`
+/// refer to the name of the generated item (`ContainerWithExecOpts`). `features` puts objects,
+/// interfaces and unions behind a cargo feature of the crate the code is generated into.
+/// `sdk` is set when generating code outside of dagger-sdk, like bindings for an extension.
+/// `blocking` adds a `blocking` module wrapping each object with a synchronous API. `traits` adds
+/// an `api` module with a trait per object and an in-memory fake implementing it, to write code
+/// which can run against fakes.
+///
+/// ```toml
+/// skip = ["Query.defaultPlatform"]
+/// builders = ["ContainerWithExecOpts"]
+///
+/// [rename]
+/// "Container.withExec" = "exec"
+///
+/// [scalars]
+/// DateTime = "chrono::DateTime<chrono::Utc>"
+///
+/// [derives]
+/// NetworkProtocol = ["Hash", "Eq"]
+///
+/// [attributes]
+/// BuildArg = ["non_exhaustive"]
+///
+/// [features]
+/// git = ["GitRepository", "GitRef"]
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Types and fields to leave out of the generated code
+    pub skip: Vec<String>,
+    /// Names to use instead of the ones derived from the schema
+    pub rename: BTreeMap<String, String>,
+    /// Rust types to use for scalars instead of generating a newtype for them
+    pub scalars: BTreeMap<String, String>,
+    /// Extra derives per generated item
+    pub derives: BTreeMap<String, Vec<String>>,
+    /// Extra attributes per generated item, without the surrounding `#[]`
+    pub attributes: BTreeMap<String, Vec<String>>,
+    /// Generated structs deriving `Builder`, every `*Opts` struct when not set
+    pub builders: Option<Vec<String>>,
+    /// Cargo features and the types they enable
+    pub features: BTreeMap<String, Vec<String>>,
+    /// Path of the dagger-sdk crate `Selection` and `graphql_client` are used from, the code is
+    /// generated into dagger-sdk itself when not set
+    pub sdk: Option<String>,
+    /// Also generate blocking wrappers of the objects in a `blocking` module
+    pub blocking: bool,
+    /// Also generate an `*Api` trait and a `Fake*` implementing it per object in an `api` module
+    pub traits: bool,
+}
+
+impl Config {
+    pub fn from_toml(content: &str) -> eyre::Result<Self> {
+        Ok(toml::from_str(content)?)
+    }
+
+    pub fn skips_type(&self, name: &str) -> bool {
+        self.skip.iter().any(|s| s == name)
+    }
+
+    pub fn skips_field(&self, type_name: &str, field_name: &str) -> bool {
+        let path = format!("{}.{}", type_name, field_name);
+
+        self.skip.contains(&path)
+    }
+
+    pub fn renamed_type(&self, name: &str) -> Option<&str> {
+        self.rename.get(name).map(|n| n.as_str())
+    }
+
+    pub fn renamed_field(&self, type_name: &str, field_name: &str) -> Option<&str> {
+        self.rename
+            .get(&format!("{}.{}", type_name, field_name))
+            .map(|n| n.as_str())
+    }
+
+    pub fn scalar(&self, name: &str) -> Option<&str> {
+        self.scalars.get(name).map(|s| s.as_str())
+    }
+
+    pub fn derives(&self, item: &str) -> &[String] {
+        self.derives.get(item).map(|d| d.as_slice()).unwrap_or(&[])
+    }
+
+    pub fn attributes(&self, item: &str) -> &[String] {
+        self.attributes
+            .get(item)
+            .map(|a| a.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Whether `item` derives `Builder`, `default` applies when `builders` isn't set
+    pub fn has_builder(&self, item: &str, default: bool) -> bool {
+        match self.builders.as_ref() {
+            Some(builders) => builders.iter().any(|b| b == item),
+            None => default,
+        }
+    }
+
+    /// The cargo feature enabling the type, if it is behind one
+    pub fn feature_of(&self, type_name: &str) -> Option<&str> {
+        self.features
+            .iter()
+            .find(|(_, types)| types.iter().any(|t| t == type_name))
+            .map(|(feature, _)| feature.as_str())
+    }
+
+    /// Warns about schema names which don't exist in `schema`, as those are most likely typos
+    pub fn validate(&self, schema: &Schema, diagnostics: &Diagnostics) {
+        let mut names = BTreeSet::new();
+        let mut scalars = BTreeSet::new();
+        let mut featurable = BTreeSet::new();
+        for t in schema.types.iter().flatten().flatten() {
+            let t = &t.full_type;
+            let name = match t.name.as_ref() {
+                Some(name) => name,
+                None => continue,
+            };
+
+            names.insert(name.clone());
+            match t.kind {
+                Some(__TypeKind::SCALAR) => {
+                    scalars.insert(name.clone());
+                }
+                Some(__TypeKind::OBJECT | __TypeKind::INTERFACE | __TypeKind::UNION) => {
+                    featurable.insert(name.clone());
+                }
+                _ => {}
+            }
+            for field in t.fields.iter().flatten() {
+                if let Some(field_name) = field.name.as_ref() {
+                    names.insert(format!("{}.{}", name, field_name));
+                }
+            }
+            for field in t.input_fields.iter().flatten() {
+                names.insert(format!("{}.{}", name, field.input_value.name));
+            }
+        }
+
+        let _guard = diagnostics.enter("config");
+        for name in self.skip.iter().chain(self.rename.keys()) {
+            if !names.contains(name) {
+                diagnostics.warn(format!("{} does not exist in the schema", name));
+            }
+        }
+        for name in self.scalars.keys() {
+            if !scalars.contains(name) {
+                diagnostics.warn(format!("{} is not a scalar in the schema", name));
+            }
+        }
+        for name in self.features.values().flatten() {
+            if !featurable.contains(name) {
+                diagnostics.warn(format!(
+                    "{} is not an object, interface or union in the schema",
+                    name
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use dagger_core::introspection::Schema;
+    use pretty_assertions::assert_eq;
+
+    use super::Config;
+    use crate::diagnostics::Diagnostics;
+
+    #[test]
+    fn from_toml() {
+        let config = Config::from_toml(
+            r#"
+            skip = ["Query.defaultPlatform"]
+
+            [rename]
+            "Container.withExec" = "exec"
+
+            [scalars]
+            DateTime = "chrono::DateTime<chrono::Utc>"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.skips_field("Query", "defaultPlatform"), true);
+        assert_eq!(config.skips_type("Query"), false);
+        assert_eq!(config.renamed_field("Container", "withExec"), Some("exec"));
+        assert_eq!(
+            config.scalar("DateTime"),
+            Some("chrono::DateTime<chrono::Utc>")
+        );
+        assert_eq!(config.has_builder("ContainerWithExecOpts", true), true);
+        assert_eq!(config.feature_of("Container"), None);
+    }
+
+    #[test]
+    fn feature_of() {
+        let config = Config::from_toml(
+            r#"
+            [features]
+            git = ["GitRepository", "GitRef"]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.feature_of("GitRef"), Some("git"));
+        assert_eq!(config.feature_of("Container"), None);
+    }
+
+    #[test]
+    fn from_toml_rejects_unknown_keys() {
+        assert!(Config::from_toml("ignore = [\"DateTime\"]").is_err());
+    }
+
+    #[test]
+    fn validate_warns_about_unknown_names() {
+        let schema: Schema =
+            serde_json::from_str(r#"{"types": [{"kind": "SCALAR", "name": "DateTime"}]}"#).unwrap();
+        let config = Config::from_toml(
+            r#"
+            skip = ["Container"]
+
+            [scalars]
+            DateTime = "String"
+            "#,
+        )
+        .unwrap();
+        let diagnostics = Diagnostics::new();
+
+        config.validate(&schema, &diagnostics);
+
+        assert_eq!(
+            diagnostics
+                .all()
+                .iter()
+                .map(|d| d.to_string())
+                .collect::<Vec<_>>(),
+            vec!["warning: config: Container does not exist in the schema"]
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use std::fmt::Display;
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// Path of the type, field or argument being generated, e.g. `Container.withExec.args`
+    pub path: String,
+    pub message: String,
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let severity = match self.severity {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+
+        if self.path.is_empty() {
+            write!(f, "{}: {}", severity, self.message)
+        } else {
+            write!(f, "{}: {}: {}", severity, self.path, self.message)
+        }
+    }
+}
+
+/// Collects diagnostics while generating, tagged with the path currently being generated.
+///
+/// Cloning is cheap and every clone reports into the same collection.
+#[derive(Clone, Debug, Default)]
+pub struct Diagnostics {
+    diagnostics: Arc<Mutex<Vec<Diagnostic>>>,
+    path: Arc<Mutex<Vec<String>>>,
+}
+
+impl Diagnostics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pushes `segment` onto the current path until the returned guard is dropped
+    pub fn enter(&self, segment: &str) -> PathGuard {
+        self.path.lock().unwrap().push(segment.to_string());
+
+        PathGuard {
+            path: self.path.clone(),
+        }
+    }
+
+    pub fn warn(&self, message: impl Into<String>) {
+        self.push(Severity::Warning, message.into());
+    }
+
+    pub fn error(&self, message: impl Into<String>) {
+        self.push(Severity::Error, message.into());
+    }
+
+    fn push(&self, severity: Severity, message: String) {
+        let diagnostic = Diagnostic {
+            severity,
+            path: self.path.lock().unwrap().join("."),
+            message,
+        };
+
+        // functions are rendered once per style, each reporting the same skipped fields
+        let mut diagnostics = self.diagnostics.lock().unwrap();
+        if !diagnostics.contains(&diagnostic) {
+            diagnostics.push(diagnostic);
+        }
+    }
+
+    pub fn all(&self) -> Vec<Diagnostic> {
+        self.diagnostics.lock().unwrap().clone()
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.all().iter().any(|d| d.severity == Severity::Error)
+    }
+
+    pub fn has_warnings(&self) -> bool {
+        self.all().iter().any(|d| d.severity == Severity::Warning)
+    }
+}
+
+pub struct PathGuard {
+    path: Arc<Mutex<Vec<String>>>,
+}
+
+impl Drop for PathGuard {
+    fn drop(&mut self) {
+        self.path.lock().unwrap().pop();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::{Diagnostic, Diagnostics, Severity};
+
+    #[test]
+    fn diagnostics_are_tagged_with_path() {
+        let diagnostics = Diagnostics::new();
+
+        {
+            let _type = diagnostics.enter("Container");
+            let _field = diagnostics.enter("withExec");
+            diagnostics.warn("skipped");
+        }
+        diagnostics.error("failed");
+
+        assert_eq!(
+            diagnostics.all(),
+            vec![
+                Diagnostic {
+                    severity: Severity::Warning,
+                    path: "Container.withExec".into(),
+                    message: "skipped".into(),
+                },
+                Diagnostic {
+                    severity: Severity::Error,
+                    path: "".into(),
+                    message: "failed".into(),
+                },
+            ]
+        );
+        assert_eq!(
+            diagnostics.all()[0].to_string(),
+            "warning: Container.withExec: skipped"
+        );
+    }
+}
@@ -1,9 +1,10 @@
+use std::collections::BTreeSet;
 use std::sync::Arc;
 
-use dagger_core::introspection::{FullType, FullTypeFields, InputValue, TypeRef, __TypeKind};
-use eyre::ContextCompat;
-
+use crate::config::Config;
+use crate::diagnostics::Diagnostics;
 use crate::utility::OptionExt;
+use dagger_core::introspection::{__TypeKind, FullType, FullTypeFields, InputValue, TypeRef};
 
 pub trait FormatTypeFuncs {
     fn format_kind_list(&self, representation: &str, input: bool, immutable: bool) -> String;
@@ -18,6 +19,8 @@ pub trait FormatTypeFuncs {
         input: bool,
     ) -> String;
     fn format_kind_object(&self, representation: &str, ref_name: &str) -> String;
+    fn format_kind_interface(&self, representation: &str, ref_name: &str) -> String;
+    fn format_kind_union(&self, representation: &str, ref_name: &str) -> String;
     fn format_kind_input_object(&self, representation: &str, ref_name: &str) -> String;
     fn format_kind_enum(&self, representation: &str, ref_name: &str) -> String;
 }
@@ -26,12 +29,53 @@ pub type DynFormatTypeFuncs = Arc<dyn FormatTypeFuncs + Send + Sync>;
 
 pub struct CommonFunctions {
     format_type_funcs: DynFormatTypeFuncs,
+    diagnostics: Diagnostics,
+    config: Arc<Config>,
+    objects: BTreeSet<String>,
 }
 
 impl CommonFunctions {
-    pub fn new(funcs: DynFormatTypeFuncs) -> Self {
+    pub fn new(funcs: DynFormatTypeFuncs, diagnostics: Diagnostics, config: Arc<Config>) -> Self {
         Self {
             format_type_funcs: funcs,
+            diagnostics,
+            config,
+            objects: BTreeSet::new(),
+        }
+    }
+
+    /// Names of the objects in scope of the generated code, which docs can link to
+    pub fn with_objects(mut self, objects: BTreeSet<String>) -> Self {
+        self.objects = objects;
+        self
+    }
+
+    /// The object identified by a scalar like `DirectoryID`, unless docs can't always link to it
+    /// as it is behind a feature
+    pub fn identified_object<'a>(&self, scalar: &'a str) -> Option<&'a str> {
+        let object = scalar.strip_suffix("ID")?;
+        if !self.objects.contains(object) || self.config.feature_of(object).is_some() {
+            return None;
+        }
+
+        Some(object)
+    }
+
+    pub fn diagnostics(&self) -> &Diagnostics {
+        &self.diagnostics
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Whether the field was skipped in the config, fields without a name or parent never are
+    pub fn skips_field(&self, field: &FullTypeFields) -> bool {
+        let parent = field.parent_type.as_ref().and_then(|p| p.name.as_ref());
+
+        match (parent, field.name.as_ref()) {
+            (Some(parent), Some(name)) => self.config.skips_field(parent, name),
+            _ => false,
         }
     }
 
@@ -57,51 +101,48 @@ impl CommonFunctions {
                         __TypeKind::SCALAR => match Scalar::from(rf) {
                             Scalar::Int => self
                                 .format_type_funcs
-                                .format_kind_scalar_int(&mut representation),
+                                .format_kind_scalar_int(&representation),
                             Scalar::Float => self
                                 .format_type_funcs
-                                .format_kind_scalar_float(&mut representation),
+                                .format_kind_scalar_float(&representation),
                             Scalar::String => {
                                 if immutable {
                                     "&'a str".into()
                                 } else {
                                     self.format_type_funcs
-                                        .format_kind_scalar_string(&mut representation, input)
+                                        .format_kind_scalar_string(&representation, input)
                                 }
                             }
                             Scalar::Boolean => self
                                 .format_type_funcs
-                                .format_kind_scalar_boolean(&mut representation),
+                                .format_kind_scalar_boolean(&representation),
                             Scalar::Default => self.format_type_funcs.format_kind_scalar_default(
-                                &mut representation,
-                                rf.name.as_ref().unwrap(),
+                                &representation,
+                                self.type_ref_name(rf),
                                 input,
                             ),
                         },
                         __TypeKind::OBJECT => self
                             .format_type_funcs
-                            .format_kind_object(&mut representation, rf.name.as_ref().unwrap()),
+                            .format_kind_object(&representation, self.type_ref_name(rf)),
                         __TypeKind::ENUM => self
                             .format_type_funcs
-                            .format_kind_enum(&mut representation, rf.name.as_ref().unwrap()),
-                        __TypeKind::INPUT_OBJECT => {
-                            self.format_type_funcs.format_kind_input_object(
-                                &mut representation,
-                                &rf.name.as_ref().unwrap(),
-                            )
-                        }
+                            .format_kind_enum(&representation, self.type_ref_name(rf)),
+                        __TypeKind::INPUT_OBJECT => self
+                            .format_type_funcs
+                            .format_kind_input_object(&representation, self.type_ref_name(rf)),
                         __TypeKind::LIST => {
-                            let mut inner_type = rf
-                                .of_type
-                                .as_ref()
-                                .map(|t| t.clone())
-                                .map(|t| *t)
-                                .map(|t| self.format_type(&t, input, immutable))
-                                .context("could not get inner type of list")
-                                .unwrap();
+                            let inner_type = match rf.of_type.as_ref() {
+                                Some(t) => self.format_type(t, input, immutable),
+                                None => {
+                                    self.diagnostics
+                                        .error("list type reference has no inner type");
+                                    return representation;
+                                }
+                            };
 
                             representation = self.format_type_funcs.format_kind_list(
-                                &mut inner_type,
+                                &inner_type,
                                 input,
                                 immutable,
                             );
@@ -109,27 +150,49 @@ impl CommonFunctions {
                             return representation;
                         }
                         __TypeKind::NON_NULL => {
-                            r = rf.of_type.as_ref().map(|t| t.clone()).map(|t| *t);
+                            r = rf.of_type.clone().map(|t| *t);
                             continue;
                         }
-                        __TypeKind::Other(_) => {
-                            r = rf.of_type.as_ref().map(|t| t.clone()).map(|t| *t);
+                        __TypeKind::Other(kind) => {
+                            self.diagnostics.warn(format!(
+                                "unsupported type kind {}, using its inner type",
+                                kind
+                            ));
+                            r = rf.of_type.clone().map(|t| *t);
                             continue;
                         }
-                        __TypeKind::INTERFACE => break,
-                        __TypeKind::UNION => break,
+                        __TypeKind::INTERFACE => self
+                            .format_type_funcs
+                            .format_kind_interface(&representation, self.type_ref_name(rf)),
+                        __TypeKind::UNION => self
+                            .format_type_funcs
+                            .format_kind_union(&representation, self.type_ref_name(rf)),
                     },
-                    None => break,
+                    None => {
+                        self.diagnostics.warn("type reference has no kind");
+                        break;
+                    }
                 },
                 None => break,
             };
         }
 
-        println!("rep: {}", representation);
-        println!("{:?}", t);
+        if representation.is_empty() {
+            self.diagnostics.error("could not resolve type reference");
+        }
 
         representation
     }
+
+    fn type_ref_name<'a>(&self, type_ref: &'a TypeRef) -> &'a str {
+        match type_ref.name.as_deref() {
+            Some(name) => name,
+            None => {
+                self.diagnostics.error("type reference has no name");
+                ""
+            }
+        }
+    }
 }
 
 pub enum Scalar {
@@ -142,7 +205,7 @@ pub enum Scalar {
 
 impl From<&TypeRef> for Scalar {
     fn from(value: &TypeRef) -> Self {
-        match value.name.as_ref().map(|n| n.as_str()) {
+        match value.name.as_deref() {
             Some("Int") => Scalar::Int,
             Some("Float") => Scalar::Float,
             Some("String") => Scalar::String,
@@ -155,9 +218,7 @@ impl From<&TypeRef> for Scalar {
 
 #[allow(dead_code)]
 pub fn get_type_from_name<'t>(types: &'t [FullType], name: &'t str) -> Option<&'t FullType> {
-    types
-        .into_iter()
-        .find(|t| t.name.as_ref().map(|s| s.as_str()) == Some(name))
+    types.iter().find(|t| t.name.as_deref() == Some(name))
 }
 
 pub fn type_ref_is_optional(type_ref: &TypeRef) -> bool {
@@ -172,8 +233,7 @@ pub fn type_field_has_optional(field: &FullTypeFields) -> bool {
         .args
         .pipe(|a| {
             a.iter()
-                .map(|a| a.pipe(|a| &a.input_value))
-                .flatten()
+                .filter_map(|a| a.pipe(|a| &a.input_value))
                 .collect::<Vec<_>>()
         })
         .pipe(|s| input_values_has_optionals(s.as_slice()))
@@ -187,7 +247,10 @@ pub fn type_ref_is_scalar(type_ref: &TypeRef) -> bool {
         .pipe(|k| *k == __TypeKind::NON_NULL)
         .unwrap_or(false)
     {
-        type_ref = *type_ref.of_type.unwrap().clone();
+        type_ref = match type_ref.of_type {
+            Some(t) => *t,
+            None => return false,
+        };
     }
 
     type_ref
@@ -203,7 +266,10 @@ pub fn type_ref_is_enum(type_ref: &TypeRef) -> bool {
         .pipe(|k| *k == __TypeKind::NON_NULL)
         .unwrap_or(false)
     {
-        type_ref = *type_ref.of_type.unwrap().clone();
+        type_ref = match type_ref.of_type {
+            Some(t) => *t,
+            None => return false,
+        };
     }
 
     type_ref
@@ -219,7 +285,10 @@ pub fn type_ref_is_object(type_ref: &TypeRef) -> bool {
         .pipe(|k| *k == __TypeKind::NON_NULL)
         .unwrap_or(false)
     {
-        type_ref = *type_ref.of_type.unwrap().clone();
+        type_ref = match type_ref.of_type {
+            Some(t) => *t,
+            None => return false,
+        };
     }
 
     type_ref
@@ -228,6 +297,44 @@ pub fn type_ref_is_object(type_ref: &TypeRef) -> bool {
         .unwrap_or(false)
 }
 
+pub fn type_ref_is_interface(type_ref: &TypeRef) -> bool {
+    let mut type_ref = type_ref.clone();
+    if type_ref
+        .kind
+        .pipe(|k| *k == __TypeKind::NON_NULL)
+        .unwrap_or(false)
+    {
+        type_ref = match type_ref.of_type {
+            Some(t) => *t,
+            None => return false,
+        };
+    }
+
+    type_ref
+        .kind
+        .pipe(|k| *k == __TypeKind::INTERFACE)
+        .unwrap_or(false)
+}
+
+pub fn type_ref_is_union(type_ref: &TypeRef) -> bool {
+    let mut type_ref = type_ref.clone();
+    if type_ref
+        .kind
+        .pipe(|k| *k == __TypeKind::NON_NULL)
+        .unwrap_or(false)
+    {
+        type_ref = match type_ref.of_type {
+            Some(t) => *t,
+            None => return false,
+        };
+    }
+
+    type_ref
+        .kind
+        .pipe(|k| *k == __TypeKind::UNION)
+        .unwrap_or(false)
+}
+
 pub fn type_ref_is_list(type_ref: &TypeRef) -> bool {
     let mut type_ref = type_ref.clone();
     if type_ref
@@ -235,7 +342,10 @@ pub fn type_ref_is_list(type_ref: &TypeRef) -> bool {
         .pipe(|k| *k == __TypeKind::NON_NULL)
         .unwrap_or(false)
     {
-        type_ref = *type_ref.of_type.unwrap().clone();
+        type_ref = match type_ref.of_type {
+            Some(t) => *t,
+            None => return false,
+        };
     }
 
     type_ref
@@ -251,7 +361,10 @@ pub fn type_ref_is_list_of_objects(type_ref: &TypeRef) -> bool {
         .pipe(|k| *k == __TypeKind::NON_NULL)
         .unwrap_or(false)
     {
-        type_ref = *type_ref.of_type.unwrap().clone();
+        type_ref = match type_ref.of_type {
+            Some(t) => *t,
+            None => return false,
+        };
     }
 
     if type_ref
@@ -259,33 +372,88 @@ pub fn type_ref_is_list_of_objects(type_ref: &TypeRef) -> bool {
         .pipe(|k| *k == __TypeKind::LIST)
         .unwrap_or(false)
     {
-        type_ref = *type_ref.of_type.unwrap().clone();
+        type_ref = match type_ref.of_type {
+            Some(t) => *t,
+            None => return false,
+        };
     }
 
     type_ref_is_object(&type_ref)
 }
 
+pub fn type_ref_is_list_of_interfaces(type_ref: &TypeRef) -> bool {
+    let mut type_ref = type_ref.clone();
+    if type_ref
+        .kind
+        .pipe(|k| *k == __TypeKind::NON_NULL)
+        .unwrap_or(false)
+    {
+        type_ref = match type_ref.of_type {
+            Some(t) => *t,
+            None => return false,
+        };
+    }
+
+    if type_ref
+        .kind
+        .pipe(|k| *k == __TypeKind::LIST)
+        .unwrap_or(false)
+    {
+        type_ref = match type_ref.of_type {
+            Some(t) => *t,
+            None => return false,
+        };
+    }
+
+    type_ref_is_interface(&type_ref)
+}
+
+pub fn type_ref_is_list_of_unions(type_ref: &TypeRef) -> bool {
+    let mut type_ref = type_ref.clone();
+    if type_ref
+        .kind
+        .pipe(|k| *k == __TypeKind::NON_NULL)
+        .unwrap_or(false)
+    {
+        type_ref = match type_ref.of_type {
+            Some(t) => *t,
+            None => return false,
+        };
+    }
+
+    if !type_ref
+        .kind
+        .pipe(|k| *k == __TypeKind::LIST)
+        .unwrap_or(false)
+    {
+        return false;
+    }
+
+    match type_ref.of_type {
+        Some(t) => type_ref_is_union(&t),
+        None => false,
+    }
+}
+
 pub fn input_values_has_optionals(input_values: &[&InputValue]) -> bool {
-    input_values
-        .into_iter()
-        .map(|k| type_ref_is_optional(&k.type_))
-        .filter(|k| *k)
-        .collect::<Vec<_>>()
-        .len()
-        > 0
+    input_values.iter().any(|k| type_ref_is_optional(&k.type_))
 }
 
 #[allow(dead_code)]
 pub fn input_values_is_empty(input_values: &[InputValue]) -> bool {
-    input_values.len() > 0
+    !input_values.is_empty()
 }
 
 #[cfg(test)]
 mod test {
-    use dagger_core::introspection::{FullType, InputValue, TypeRef, __TypeKind};
+    use dagger_core::introspection::{__TypeKind, FullType, InputValue, TypeRef};
     use pretty_assertions::assert_eq;
 
-    use crate::functions::{input_values_has_optionals, type_ref_is_optional};
+    use std::sync::Arc;
+
+    use crate::diagnostics::Diagnostics;
+    use crate::functions::{input_values_has_optionals, type_ref_is_optional, CommonFunctions};
+    use crate::rust::format::FormatTypeFunc;
 
     use super::get_type_from_name;
 
@@ -392,7 +560,7 @@ mod test {
 
     #[test]
     fn input_values_has_optionals_has_optional() {
-        let input = vec![
+        let input = [
             InputValue {
                 name: "some-name".to_string(),
                 description: None,
@@ -422,7 +590,7 @@ mod test {
 
     #[test]
     fn input_values_has_optionals_is_required() {
-        let input = vec![
+        let input = [
             InputValue {
                 name: "some-name".to_string(),
                 description: None,
@@ -449,4 +617,48 @@ mod test {
 
         assert_eq!(output, false);
     }
+
+    #[test]
+    fn format_output_type_interface() {
+        let funcs = CommonFunctions::new(
+            Arc::new(FormatTypeFunc::default()),
+            Diagnostics::new(),
+            Arc::default(),
+        );
+        let input = TypeRef {
+            kind: Some(__TypeKind::NON_NULL),
+            name: None,
+            of_type: Some(Box::new(TypeRef {
+                kind: Some(__TypeKind::INTERFACE),
+                name: Some("Node".into()),
+                of_type: None,
+            })),
+        };
+
+        let output = funcs.format_output_type(&input);
+
+        assert_eq!(output, "AnyNode".to_string());
+    }
+
+    #[test]
+    fn format_output_type_list_of_unions() {
+        let funcs = CommonFunctions::new(
+            Arc::new(FormatTypeFunc::default()),
+            Diagnostics::new(),
+            Arc::default(),
+        );
+        let input = TypeRef {
+            kind: Some(__TypeKind::LIST),
+            name: None,
+            of_type: Some(Box::new(TypeRef {
+                kind: Some(__TypeKind::UNION),
+                name: Some("SearchResult".into()),
+                of_type: None,
+            })),
+        };
+
+        let output = funcs.format_output_type(&input);
+
+        assert_eq!(output, "Vec<SearchResult>".to_string());
+    }
 }
@@ -1,9 +1,19 @@
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use dagger_core::introspection::Schema;
 
+use crate::diagnostics::Diagnostics;
+
 pub trait Generator {
-    fn generate(&self, schema: Schema) -> eyre::Result<String>;
+    fn generate(&self, schema: Schema, diagnostics: &Diagnostics) -> eyre::Result<String>;
+
+    /// Generates a tree of files, keyed by their path relative to the output directory
+    fn generate_modules(
+        &self,
+        schema: Schema,
+        diagnostics: &Diagnostics,
+    ) -> eyre::Result<BTreeMap<String, String>>;
 }
 
 pub type DynGenerator = Arc<dyn Generator + Send + Sync>;
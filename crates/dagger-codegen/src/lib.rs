@@ -1,27 +1,103 @@
 #![deny(warnings)]
 
+pub mod build;
+pub mod config;
+pub mod diagnostics;
 mod functions;
-mod generator;
+pub mod generator;
 pub mod rust;
+pub mod sdl;
 pub mod utility;
 mod visitor;
 
+use std::collections::BTreeMap;
+
 use dagger_core::introspection::Schema;
 
+use self::diagnostics::{Diagnostic, Diagnostics, Severity};
 use self::generator::DynGenerator;
 
 fn set_schema_parents(mut schema: Schema) -> Schema {
     for t in schema.types.as_mut().into_iter().flatten().flatten() {
         let t_parent = t.full_type.clone();
-        for mut field in t.full_type.fields.as_mut().into_iter().flatten() {
+        for field in t.full_type.fields.as_mut().into_iter().flatten() {
             field.parent_type = Some(t_parent.clone());
         }
     }
 
-    return schema;
+    schema
 }
 
-pub fn generate(schema: Schema, generator: DynGenerator) -> eyre::Result<String> {
+pub struct Generated {
+    pub code: String,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl Generated {
+    /// Fails if any errors were reported, or any warnings as well when `strict` is set
+    pub fn check(&self, strict: bool) -> eyre::Result<()> {
+        check_diagnostics(&self.diagnostics, strict)
+    }
+}
+
+pub struct GeneratedModules {
+    /// Generated files keyed by their path relative to the output directory
+    pub files: BTreeMap<String, String>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl GeneratedModules {
+    /// Fails if any errors were reported, or any warnings as well when `strict` is set
+    pub fn check(&self, strict: bool) -> eyre::Result<()> {
+        check_diagnostics(&self.diagnostics, strict)
+    }
+}
+
+fn check_diagnostics(diagnostics: &[Diagnostic], strict: bool) -> eyre::Result<()> {
+    let failures = diagnostics
+        .iter()
+        .filter(|d| strict || d.severity == Severity::Error)
+        .map(|d| d.to_string())
+        .collect::<Vec<_>>();
+
+    if !failures.is_empty() {
+        eyre::bail!("code generation failed:\n{}", failures.join("\n"))
+    }
+
+    Ok(())
+}
+
+pub fn generate_with_diagnostics(
+    schema: Schema,
+    generator: DynGenerator,
+) -> eyre::Result<Generated> {
     let schema = set_schema_parents(schema);
-    generator.generate(schema)
+    let diagnostics = Diagnostics::new();
+    let code = generator.generate(schema, &diagnostics)?;
+
+    Ok(Generated {
+        code,
+        diagnostics: diagnostics.all(),
+    })
+}
+
+pub fn generate_modules_with_diagnostics(
+    schema: Schema,
+    generator: DynGenerator,
+) -> eyre::Result<GeneratedModules> {
+    let schema = set_schema_parents(schema);
+    let diagnostics = Diagnostics::new();
+    let files = generator.generate_modules(schema, &diagnostics)?;
+
+    Ok(GeneratedModules {
+        files,
+        diagnostics: diagnostics.all(),
+    })
+}
+
+pub fn generate(schema: Schema, generator: DynGenerator) -> eyre::Result<String> {
+    let generated = generate_with_diagnostics(schema, generator)?;
+    generated.check(false)?;
+
+    Ok(generated.code)
 }
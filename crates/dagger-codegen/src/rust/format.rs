@@ -1,8 +1,20 @@
+use std::sync::Arc;
+
+use crate::config::Config;
 use crate::functions::FormatTypeFuncs;
 
-use super::functions::format_name;
+use super::functions::{format_interface_object_name, format_type_name};
 
-pub struct FormatTypeFunc;
+#[derive(Default)]
+pub struct FormatTypeFunc {
+    config: Arc<Config>,
+}
+
+impl FormatTypeFunc {
+    pub fn new(config: Arc<Config>) -> Self {
+        Self { config }
+    }
+}
 
 impl FormatTypeFuncs for FormatTypeFunc {
     fn format_kind_list(&self, representation: &str, _input: bool, _immutable: bool) -> String {
@@ -44,25 +56,40 @@ impl FormatTypeFuncs for FormatTypeFunc {
         _input: bool,
     ) -> String {
         let mut rep = representation.to_string();
-        rep.push_str(&format_name(ref_name));
+        match self.config.scalar(ref_name) {
+            Some(scalar) => rep.push_str(scalar),
+            None => rep.push_str(&format_type_name(&self.config, ref_name)),
+        }
         rep
     }
 
     fn format_kind_object(&self, representation: &str, ref_name: &str) -> String {
         let mut rep = representation.to_string();
-        rep.push_str(&format_name(ref_name));
+        rep.push_str(&format_type_name(&self.config, ref_name));
+        rep
+    }
+
+    fn format_kind_interface(&self, representation: &str, ref_name: &str) -> String {
+        let mut rep = representation.to_string();
+        rep.push_str(&format_interface_object_name(&self.config, ref_name));
+        rep
+    }
+
+    fn format_kind_union(&self, representation: &str, ref_name: &str) -> String {
+        let mut rep = representation.to_string();
+        rep.push_str(&format_type_name(&self.config, ref_name));
         rep
     }
 
     fn format_kind_input_object(&self, representation: &str, ref_name: &str) -> String {
         let mut rep = representation.to_string();
-        rep.push_str(&format_name(ref_name));
+        rep.push_str(&format_type_name(&self.config, ref_name));
         rep
     }
 
     fn format_kind_enum(&self, representation: &str, ref_name: &str) -> String {
         let mut rep = representation.to_string();
-        rep.push_str(&format_name(ref_name));
+        rep.push_str(&format_type_name(&self.config, ref_name));
         rep
     }
 }
@@ -1,12 +1,15 @@
 use convert_case::{Case, Casing};
-use dagger_core::introspection::{FullTypeFields, TypeRef};
+use dagger_core::introspection::{__TypeKind, FullTypeFields, InputValue, TypeRef};
 use genco::prelude::rust;
 use genco::quote;
 use genco::tokens::quoted;
 
+use crate::config::Config;
 use crate::functions::{
-    type_field_has_optional, type_ref_is_enum, type_ref_is_list, type_ref_is_list_of_objects,
-    type_ref_is_object, type_ref_is_optional, type_ref_is_scalar, CommonFunctions, Scalar,
+    type_field_has_optional, type_ref_is_enum, type_ref_is_interface, type_ref_is_list,
+    type_ref_is_list_of_interfaces, type_ref_is_list_of_objects, type_ref_is_list_of_unions,
+    type_ref_is_object, type_ref_is_optional, type_ref_is_scalar, type_ref_is_union,
+    CommonFunctions, Scalar,
 };
 use crate::utility::OptionExt;
 
@@ -20,29 +23,217 @@ pub fn format_struct_name(s: &str) -> String {
     s.to_case(Case::Snake)
 }
 
-pub fn field_options_struct_name(field: &FullTypeFields) -> Option<String> {
+/// Name of the item generated for the schema type `name`, unless it was renamed in the config
+pub fn format_type_name(config: &Config, name: &str) -> String {
+    config
+        .renamed_type(name)
+        .map(|n| n.to_string())
+        .unwrap_or_else(|| format_name(name))
+}
+
+/// Name of the function generated for `field`, unless it was renamed in the config
+pub fn format_field_name(config: &Config, field: &FullTypeFields) -> Option<String> {
+    let name = field.name.as_ref()?;
+    let renamed = field
+        .parent_type
+        .as_ref()
+        .and_then(|p| p.name.as_ref())
+        .and_then(|parent| config.renamed_field(parent, name));
+
+    Some(
+        renamed
+            .map(|n| n.to_string())
+            .unwrap_or_else(|| format_struct_name(name)),
+    )
+}
+
+pub fn field_options_struct_name(config: &Config, field: &FullTypeFields) -> Option<String> {
     field
         .parent_type
         .as_ref()
-        .map(|p| p.name.as_ref().map(|n| format_name(n)))
-        .flatten()
-        .zip(field.name.as_ref().map(|n| format_name(n)))
+        .and_then(|p| p.name.as_ref().map(|n| format_type_name(config, n)))
+        .zip(format_field_name(config, field).map(|n| format_name(&n)))
         .map(|(parent_name, field_name)| format!("{parent_name}{field_name}Opts"))
 }
 
+pub fn format_interface_object_name(config: &Config, s: &str) -> String {
+    format!("Any{}", format_type_name(config, s))
+}
+
+pub fn format_extension_trait_name(config: &Config, s: &str) -> String {
+    format!("{}Ext", format_type_name(config, s))
+}
+
+pub fn format_api_trait_name(config: &Config, s: &str) -> String {
+    format!("{}Api", format_type_name(config, s))
+}
+
+pub fn format_fake_name(config: &Config, s: &str) -> String {
+    format!("Fake{}", format_type_name(config, s))
+}
+
+/// `Fake` answering the queries of the fakes of the `api` module, used from the crate set as
+/// `sdk` in the config if any
+pub fn fake_import(config: &Config) -> rust::Import {
+    match config.sdk.as_deref() {
+        Some(sdk) => rust::import(sdk, "Fake"),
+        None => rust::import("crate::fake", "Fake"),
+    }
+}
+
+/// `Selection` of the query builder, used from the crate set as `sdk` in the config if any
+pub fn selection_import(config: &Config) -> rust::Import {
+    match config.sdk.as_deref() {
+        Some(sdk) => rust::import(sdk, "Selection"),
+        None => rust::import("crate::querybuilder", "Selection"),
+    }
+}
+
+/// `graphql_client` executing queries, used from the crate set as `sdk` in the config if any
+pub fn graphql_client_import(config: &Config) -> rust::Import {
+    match config.sdk.as_deref() {
+        Some(sdk) => rust::import(sdk, "graphql_client"),
+        None => rust::import("crate::client", "graphql_client"),
+    }
+}
+
+/// Derives added to `item` in the config, to be appended to its existing derive list
+pub fn render_extra_derives(config: &Config, item: &str) -> Option<rust::Tokens> {
+    let derives = config.derives(item);
+    if derives.is_empty() {
+        return None;
+    }
+
+    Some(quote! {
+        $(for derive in derives => , $derive)
+    })
+}
+
+/// `#[cfg(feature = ..)]` for items referring to `type_name`, unless it is behind the same
+/// feature as `parent` which already gates the whole module
+pub fn render_feature_gate(
+    config: &Config,
+    type_name: &str,
+    parent: Option<&str>,
+) -> Option<rust::Tokens> {
+    let feature = config.feature_of(type_name)?;
+    if parent.and_then(|p| config.feature_of(p)) == Some(feature) {
+        return None;
+    }
+
+    Some(quote! {
+        #[cfg(feature = $(quoted(feature)))]
+    })
+}
+
+/// Feature gate for the items rendered for `field`, based on the type it returns
+pub fn render_field_feature_gate(config: &Config, field: &FullTypeFields) -> Option<rust::Tokens> {
+    let type_name = field
+        .type_
+        .as_ref()
+        .and_then(|t| type_ref_base_name(&t.type_ref))?;
+
+    render_feature_gate(
+        config,
+        type_name,
+        field.parent_type.as_ref().and_then(|p| p.name.as_deref()),
+    )
+}
+
+/// Attributes added to `item` in the config
+pub fn render_attributes(config: &Config, item: &str) -> Option<rust::Tokens> {
+    let attributes = config.attributes(item);
+    if attributes.is_empty() {
+        return None;
+    }
+
+    Some(quote! {
+        $(for attribute in attributes join ($['\r']) => #[$attribute])
+    })
+}
+
+/// Where a rendered function ends up, which decides its visibility and whether it has a body
+#[derive(Clone, Copy, PartialEq)]
+pub enum FunctionStyle {
+    Inherent,
+    TraitDeclaration,
+    TraitImplementation,
+    /// Calls the async function of the same name on `self.inner`, blocking on its result
+    Blocking,
+    /// Function of an `*Api` trait, returning objects as associated types
+    ApiDeclaration,
+    /// Implementation of an `*Api` trait function, calling the inherent function of the same name
+    ApiImplementation,
+    /// Implementation of an `*Api` trait function by a fake, answering the query from its `Fake`
+    Fake,
+}
+
+impl FunctionStyle {
+    fn is_inherent(self) -> bool {
+        matches!(self, FunctionStyle::Inherent | FunctionStyle::Blocking)
+    }
+
+    fn is_api(self) -> bool {
+        matches!(
+            self,
+            FunctionStyle::ApiDeclaration | FunctionStyle::ApiImplementation | FunctionStyle::Fake
+        )
+    }
+}
+
 pub fn format_function(funcs: &CommonFunctions, field: &FullTypeFields) -> Option<rust::Tokens> {
-    let is_async = field.type_.pipe(|t| &t.type_ref).pipe(|t| {
-        if type_ref_is_object(&t) || type_ref_is_list_of_objects(&t) {
+    format_function_with_style(funcs, field, FunctionStyle::Inherent)
+}
+
+pub fn format_function_with_style(
+    funcs: &CommonFunctions,
+    field: &FullTypeFields,
+    style: FunctionStyle,
+) -> Option<rust::Tokens> {
+    let name = match field.name.as_ref() {
+        Some(name) => name,
+        None => {
+            funcs.diagnostics().warn("skipped field without a name");
+            return None;
+        }
+    };
+    let _guard = funcs.diagnostics().enter(name);
+
+    let type_ref = match field.type_.as_ref() {
+        Some(t) => &t.type_ref,
+        None => {
+            funcs.diagnostics().warn("skipped field without a type");
             return None;
+        }
+    };
+    if type_ref_is_list(type_ref) && list_element_type(type_ref).is_none() {
+        funcs
+            .diagnostics()
+            .warn("skipped field returning a list without an element type");
+        return None;
+    }
+    if type_ref_is_list_of_unions(type_ref) {
+        funcs.diagnostics().warn(
+            "skipped field returning a list of unions, as GraphQL can't select a single element of a list",
+        );
+        return None;
+    }
+
+    let is_async = field.type_.pipe(|t| &t.type_ref).pipe(|t| {
+        if type_ref_is_lazy(t) || style == FunctionStyle::Blocking {
+            None
         } else {
-            return Some(quote! {
+            Some(quote! {
                 async
-            });
-        };
+            })
+        }
     });
 
+    let feature_gate = render_field_feature_gate(funcs.config(), field);
+
     let signature = quote! {
-        pub $(is_async) fn $(field.name.pipe(|n | format_struct_name(n)))
+        $(feature_gate)
+        $(if style.is_inherent() => pub) $(is_async.clone().flatten()) fn $(format_field_name(funcs.config(), field))
     };
 
     let lifecycle = format_optional_args(funcs, field)
@@ -57,64 +248,196 @@ pub fn format_function(funcs: &CommonFunctions, field: &FullTypeFields) -> Optio
             }
         });
 
-    let args = format_function_args(funcs, field, lifecycle.as_ref());
+    // fakes implement documented trait functions
+    let args = format_function_args(funcs, field, lifecycle.as_ref(), style).map(
+        |(args, desc, opts_desc)| match style {
+            FunctionStyle::Fake => (args, quote!(), opts_desc.map(|_| quote!())),
+            _ => (args, desc, opts_desc),
+        },
+    );
 
     let output_type = field
         .type_
         .pipe(|t| &t.type_ref)
-        .pipe(|t| render_output_type(funcs, t));
+        .pipe(|t| render_output_type(funcs, t, style));
+
+    // Only required arguments are added to the query in every variant of the function
+    let has_required_args = field
+        .args
+        .iter()
+        .flatten()
+        .flatten()
+        .any(|a| !type_ref_is_optional(&a.input_value.type_));
 
-    if let Some((args, desc, true)) = args {
-        let required_args = format_required_function_args(funcs, field);
+    let function_name = format_field_name(funcs.config(), field);
+    let call_args = |suffix: &str| {
+        let mut args = required_arg_names(field);
+        if suffix == "_opts" {
+            args.push("opts".into());
+        }
+
+        args
+    };
+    let body = |suffix: &str, optional_args: bool| match style {
+        FunctionStyle::Blocking => render_blocking_body(
+            funcs,
+            type_ref,
+            quote!(self.inner.$(function_name.as_ref())$(suffix)($(for a in call_args(suffix) join (, ) => $a))),
+        ),
+        // Inherent functions take precedence over trait functions of the same name
+        FunctionStyle::ApiImplementation => quote! {
+            self.$(function_name.as_ref())$(suffix)($(for a in call_args(suffix) join (, ) => $a))$(if matches!(is_async, Some(Some(_))) => .await)
+        },
+        _ => quote! {
+            let $(if has_required_args || suffix == "_opts" => mut) query = self.selection.select($(quoted(field.name.as_ref())));
+
+            $(render_required_args(funcs, field))
+            $(if optional_args => $(render_optional_args(funcs, field)))
+
+            $(match style {
+                FunctionStyle::Fake => $(render_fake_execution(funcs, type_ref)),
+                _ => $(render_execution(funcs, type_ref)),
+            })
+        },
+    };
+
+    if let Some((args, desc, Some(opts_desc))) = args {
+        let required_args = format_required_function_args(funcs, field, style);
+        let opts_type = field_options_struct_name(funcs.config(), field)
+            .map(|name| quote!($name$(lifecycle.as_ref())));
         Some(quote! {
-            $(field.description.pipe(|d| format_struct_comment(d)))
+            $(render_function_doc(funcs, field, style))
             $(&desc)
             $(&signature)(
                 $(required_args)
-            ) -> $(output_type.as_ref()) {
-                let mut query = self.selection.select($(quoted(field.name.as_ref())));
-
-                $(render_required_args(funcs, field))
-
-                $(render_execution(funcs, field))
-            }
+            ) -> $(output_type.as_ref())$(render_body(style, body("", false)))
 
-            $(field.description.pipe(|d| format_struct_comment(d)))
-            $(&desc)
-            $(&signature)_opts$(lifecycle)(
+            $(render_function_doc(funcs, field, style))
+            $(&opts_desc)
+            $(&signature)_opts$(lifecycle.as_ref())(
                 $args
-            ) -> $(output_type) {
-                let mut query = self.selection.select($(quoted(field.name.as_ref())));
-
-                $(render_required_args(funcs, field))
-                $(render_optional_args(funcs, field))
-
-                $(render_execution(funcs, field))
-            }
+            ) -> $(output_type.as_ref())$(render_body(style, body("_opts", true)))
+
+            $(if style.is_inherent() {
+                $(render_function_doc(funcs, field, style))
+                $(&opts_desc)
+                $(&signature)_with$(lifecycle.as_ref())(
+                    $(format_required_function_args(funcs, field, style))
+                    opts: impl FnOnce($(opts_type.as_ref())) -> $(opts_type.as_ref()),
+                ) -> $(output_type) {
+                    self.$(function_name.as_ref())_opts($(for n in required_arg_names(field) => $n,) opts(Default::default()))$(if matches!(is_async, Some(Some(_))) => .await)
+                }
+            })
         })
     } else {
         Some(quote! {
-            $(field.description.pipe(|d| format_struct_comment(d)))
+            $(render_function_doc(funcs, field, style))
             $(if let Some((_, desc, _)) = &args => $desc)
             $(signature)(
                 $(if let Some((args, _, _)) = &args => $args)
-            ) -> $(output_type) {
-                let mut query = self.selection.select($(quoted(field.name.as_ref())));
+            ) -> $(output_type)$(render_body(style, body("", true)))
+        })
+    }
+}
 
-                $(render_required_args(funcs, field))
-                $(render_optional_args(funcs, field))
+fn format_argument_type(
+    funcs: &CommonFunctions,
+    type_ref: &TypeRef,
+    style: FunctionStyle,
+) -> String {
+    let t = funcs.format_input_type(type_ref);
+
+    // async_trait boxes futures as Send, so everything captured by them has to be Send as well
+    match style {
+        FunctionStyle::Inherent | FunctionStyle::Blocking => t,
+        FunctionStyle::TraitDeclaration
+        | FunctionStyle::TraitImplementation
+        | FunctionStyle::ApiDeclaration
+        | FunctionStyle::ApiImplementation
+        | FunctionStyle::Fake => t.replace("impl Into<String>", "impl Into<String> + Send"),
+    }
+}
 
-                $(render_execution(funcs, field))
+fn render_body(style: FunctionStyle, body: rust::Tokens) -> rust::Tokens {
+    match style {
+        FunctionStyle::TraitDeclaration | FunctionStyle::ApiDeclaration => quote!(;),
+        _ => {
+            quote! {
+                $[' ']{
+                    $body
+                }
             }
-        })
+        }
+    }
+}
+
+/// Wraps what the async function returns: lazy objects in their blocking counterpart, anything
+/// else by blocking on the query
+fn render_blocking_body(
+    funcs: &CommonFunctions,
+    type_ref: &TypeRef,
+    call: rust::Tokens,
+) -> rust::Tokens {
+    if type_ref_is_object(type_ref) {
+        return quote! {
+            $(funcs.format_output_type(type_ref)) {
+                inner: $call,
+                runtime: self.runtime.clone(),
+            }
+        };
     }
+
+    if let Some(object) = list_element_type(type_ref).filter(|t| type_ref_is_object(t)) {
+        return quote! {
+            $call
+                .into_iter()
+                .map(|inner| $(funcs.format_output_type(object)) {
+                    inner,
+                    runtime: self.runtime.clone(),
+                })
+                .collect()
+        };
+    }
+
+    quote! {
+        self.runtime.block_on($call)
+    }
+}
+
+/// Name of the named type at the bottom of `type_ref`, looking through lists and non-null
+pub fn type_ref_base_name(type_ref: &TypeRef) -> Option<&str> {
+    match type_ref.of_type.as_ref() {
+        Some(of_type) => type_ref_base_name(of_type),
+        None => type_ref.name.as_deref(),
+    }
+}
+
+/// The type of the elements of a list, looking through non-null
+fn list_element_type(type_ref: &TypeRef) -> Option<&TypeRef> {
+    let list = match type_ref.kind {
+        Some(__TypeKind::NON_NULL) => type_ref.of_type.as_deref()?,
+        _ => type_ref,
+    };
+
+    match list.kind {
+        Some(__TypeKind::LIST) => list.of_type.as_deref(),
+        _ => None,
+    }
+}
+
+/// Lazy types only extend the query, everything else executes it
+fn type_ref_is_lazy(type_ref: &TypeRef) -> bool {
+    type_ref_is_object(type_ref)
+        || type_ref_is_list_of_objects(type_ref)
+        || type_ref_is_interface(type_ref)
+        || type_ref_is_list_of_interfaces(type_ref)
 }
 
 fn render_required_args(_funcs: &CommonFunctions, field: &FullTypeFields) -> Option<rust::Tokens> {
     if let Some(args) = field.args.as_ref() {
         let args = args
-            .into_iter()
-            .map(|a| {
+            .iter()
+            .filter_map(|a| {
                 a.as_ref().and_then(|s| {
                     if type_ref_is_optional(&s.input_value.type_) {
                         return None;
@@ -124,8 +447,8 @@ fn render_required_args(_funcs: &CommonFunctions, field: &FullTypeFields) -> Opt
                     let name = &s.input_value.name;
 
                     if type_ref_is_scalar(&s.input_value.type_) {
-                        if let Scalar::String =
-                            Scalar::from(&*s.input_value.type_.of_type.as_ref().unwrap().clone())
+                        if let Some(Scalar::String) =
+                            s.input_value.type_.of_type.as_deref().map(Scalar::from)
                         {
                             return Some(quote! {
                                 query = query.arg($(quoted(name)), $(&n).into());
@@ -139,22 +462,9 @@ fn render_required_args(_funcs: &CommonFunctions, field: &FullTypeFields) -> Opt
                         })
                     }
 
-                    if type_ref_is_list(&s.input_value.type_) {
-                        let inner = *s
-                            .input_value
-                            .type_
-                            .of_type
-                            .as_ref()
-                            .unwrap()
-                            .clone()
-                            .of_type
-                            .as_ref()
-                            .unwrap()
-                            .clone();
-                        println!("type: {:?}", inner);
-                        if type_ref_is_scalar(&inner) {
-                            if let Scalar::String =
-                                Scalar::from(&*inner.of_type.as_ref().unwrap().clone())
+                    if let Some(inner) = list_element_type(&s.input_value.type_) {
+                        if type_ref_is_scalar(inner) {
+                            if let Some(Scalar::String) = inner.of_type.as_deref().map(Scalar::from)
                             {
                                 return Some(quote! {
                                     query = query.arg($(quoted(name)), $(&n).into_iter().map(|i| i.into()).collect::<Vec<String>>());
@@ -168,7 +478,6 @@ fn render_required_args(_funcs: &CommonFunctions, field: &FullTypeFields) -> Opt
                     })
                 })
             })
-            .flatten()
             .collect::<Vec<_>>();
         let required_args = quote! {
             $(for arg in args join ($['\r']) => $arg)
@@ -183,8 +492,8 @@ fn render_required_args(_funcs: &CommonFunctions, field: &FullTypeFields) -> Opt
 fn render_optional_args(_funcs: &CommonFunctions, field: &FullTypeFields) -> Option<rust::Tokens> {
     if let Some(args) = field.args.as_ref() {
         let args = args
-            .into_iter()
-            .map(|a| {
+            .iter()
+            .filter_map(|a| {
                 a.as_ref().and_then(|s| {
                     if !type_ref_is_optional(&s.input_value.type_) {
                         return None;
@@ -208,10 +517,9 @@ fn render_optional_args(_funcs: &CommonFunctions, field: &FullTypeFields) -> Opt
                     })
                 })
             })
-            .flatten()
             .collect::<Vec<_>>();
 
-        if args.len() == 0 {
+        if args.is_empty() {
             return None;
         }
 
@@ -225,10 +533,26 @@ fn render_optional_args(_funcs: &CommonFunctions, field: &FullTypeFields) -> Opt
     }
 }
 
-fn render_output_type(funcs: &CommonFunctions, type_ref: &TypeRef) -> rust::Tokens {
+fn render_output_type(
+    funcs: &CommonFunctions,
+    type_ref: &TypeRef,
+    style: FunctionStyle,
+) -> rust::Tokens {
+    if style.is_api() {
+        if type_ref_is_object(type_ref) {
+            return quote!(Self::$(funcs.format_output_type(type_ref)));
+        }
+        if let Some(object) = type_ref_is_list_of_objects(type_ref)
+            .then(|| type_ref_base_name(type_ref))
+            .flatten()
+        {
+            return quote!(Vec<Self::$(format_type_name(funcs.config(), object))>);
+        }
+    }
+
     let output_type = funcs.format_output_type(type_ref);
 
-    if type_ref_is_object(type_ref) || type_ref_is_list_of_objects(type_ref) {
+    if type_ref_is_lazy(type_ref) {
         return quote! {
             $(output_type)
         };
@@ -239,9 +563,9 @@ fn render_output_type(funcs: &CommonFunctions, type_ref: &TypeRef) -> rust::Toke
     }
 }
 
-fn render_execution(funcs: &CommonFunctions, field: &FullTypeFields) -> rust::Tokens {
-    if let Some(true) = field.type_.pipe(|t| type_ref_is_object(&t.type_ref)) {
-        let output_type = funcs.format_output_type(&field.type_.as_ref().unwrap().type_ref);
+fn render_execution(funcs: &CommonFunctions, type_ref: &TypeRef) -> rust::Tokens {
+    if type_ref_is_object(type_ref) || type_ref_is_interface(type_ref) {
+        let output_type = funcs.format_output_type(type_ref);
         return quote! {
             return $(output_type) {
                 proc: self.proc.clone(),
@@ -251,23 +575,10 @@ fn render_execution(funcs: &CommonFunctions, field: &FullTypeFields) -> rust::To
         };
     }
 
-    if let Some(true) = field
-        .type_
-        .pipe(|t| type_ref_is_list_of_objects(&t.type_ref))
+    if let Some(element) =
+        list_element_type(type_ref).filter(|t| type_ref_is_object(t) || type_ref_is_interface(t))
     {
-        let output_type = funcs.format_output_type(
-            &field
-                .type_
-                .as_ref()
-                .unwrap()
-                .type_ref
-                .of_type
-                .as_ref()
-                .unwrap()
-                .of_type
-                .as_ref()
-                .unwrap(),
-        );
+        let output_type = funcs.format_output_type(element);
         return quote! {
             return vec![$(output_type) {
                 proc: self.proc.clone(),
@@ -277,40 +588,88 @@ fn render_execution(funcs: &CommonFunctions, field: &FullTypeFields) -> rust::To
         };
     }
 
-    let graphql_client = rust::import("crate::client", "graphql_client");
+    let graphql_client = graphql_client_import(funcs.config());
+
+    if type_ref_is_union(type_ref) {
+        let output_type = funcs.format_output_type(type_ref);
+        return quote! {
+            let typename: String = query
+                .select("__typename")
+                .execute(&$(&graphql_client)(&self.conn))
+                .await?;
+
+            $(output_type)::from_typename(&typename, self.proc.clone(), query, self.conn.clone())
+        };
+    }
 
     quote! {
         query.execute(&$graphql_client(&self.conn)).await
     }
 }
 
+/// Like [`render_execution`], for the fakes of the `api` module
+fn render_fake_execution(funcs: &CommonFunctions, type_ref: &TypeRef) -> rust::Tokens {
+    let fake = |object: &str| {
+        quote! {
+            $(format_fake_name(funcs.config(), object)) {
+                selection: query,
+                fake: self.fake.clone(),
+            }
+        }
+    };
+
+    if let Some(object) = type_ref_is_object(type_ref)
+        .then(|| type_ref_base_name(type_ref))
+        .flatten()
+    {
+        return fake(object);
+    }
+
+    if let Some(object) = type_ref_is_list_of_objects(type_ref)
+        .then(|| type_ref_base_name(type_ref))
+        .flatten()
+    {
+        return quote!(vec![$(fake(object))]);
+    }
+
+    quote! {
+        self.fake.execute(&query)
+    }
+}
+
 fn format_function_args(
     funcs: &CommonFunctions,
     field: &FullTypeFields,
     lifecycle: Option<&rust::Tokens>,
-) -> Option<(rust::Tokens, rust::Tokens, bool)> {
+    style: FunctionStyle,
+) -> Option<(rust::Tokens, rust::Tokens, Option<rust::Tokens>)> {
     let mut argument_description = Vec::new();
     if let Some(args) = field.args.as_ref() {
         let args = args
-            .into_iter()
-            .map(|a| {
+            .iter()
+            .filter_map(|a| {
                 a.as_ref().and_then(|s| {
                     if type_ref_is_optional(&s.input_value.type_) {
                         return None;
                     }
 
-                    let t = funcs.format_input_type(&s.input_value.type_);
+                    let _guard = funcs.diagnostics().enter(&s.input_value.name);
+                    let t = format_argument_type(funcs, &s.input_value.type_, style);
                     let n = format_struct_name(&s.input_value.name);
 
-                    if let Some(desc) = s.input_value.description.as_ref().and_then(|d| {
-                        if d != "" {
-                            Some(write_comment_line(&format!("* `{n}` - {}", d)))
-                        } else {
-                            None
-                        }
-                    }) {
+                    if let Some(doc) = argument_doc(funcs, &s.input_value) {
+                        // Continuation lines are indented to stay part of the list item
+                        let lines = doc
+                            .lines()
+                            .enumerate()
+                            .map(|(i, l)| match i {
+                                0 => format!("* `{n}` - {}", l),
+                                _ if l.is_empty() => String::new(),
+                                _ => format!("  {}", l),
+                            })
+                            .collect::<Vec<_>>();
                         argument_description.push(quote! {
-                            $(desc)
+                            $(render_doc_lines(&lines))
                         });
                     }
 
@@ -319,7 +678,6 @@ fn format_function_args(
                     })
                 })
             })
-            .flatten()
             .collect::<Vec<_>>();
         let required_args = quote! {
             &self,
@@ -327,15 +685,31 @@ fn format_function_args(
         };
 
         if type_field_has_optional(field) {
-            let field_name = field_options_struct_name(field);
-            argument_description.push(quote! {
-                $(field_name.pipe(|_| write_comment_line(&format!("* `opt` - optional argument, see inner type for documentation, use <func>_opts to use"))))
-            });
+            let field_name = field_options_struct_name(funcs.config(), field);
 
+            // The variant without opts points to the ones taking them
+            let variants = field_name
+                .as_ref()
+                .zip(format_field_name(funcs.config(), field))
+                .map(|(opts, function)| match style {
+                    FunctionStyle::Inherent | FunctionStyle::Blocking => format!(
+                        "Optional arguments, see [`{opts}`], are taken by [`Self::{function}_opts`] and [`Self::{function}_with`]."
+                    ),
+                    _ => format!(
+                        "Optional arguments, see [`{opts}`], are taken by [`Self::{function}_opts`]."
+                    ),
+                });
             let description = quote! {
-                $(if argument_description.len() > 0 => $(format!("/// ")))
-                $(if argument_description.len() > 0 => $(format!("/// # Arguments")))
-                $(if argument_description.len() > 0 => $(format!("/// ")))
+                $(render_doc_lines(&variants.into_iter().flat_map(|l| [String::new(), l]).collect::<Vec<_>>()))
+                $(if !argument_description.is_empty() => $(render_arguments_header()))
+                $(for arg_desc in argument_description.iter() join ($['\r']) => $arg_desc)
+            };
+
+            argument_description.push(quote! {
+                $(field_name.as_ref().and_then(|opts| write_comment_line(&format!("* `opts` - Optional arguments, see [`{opts}`]."))))
+            });
+            let opts_description = quote! {
+                $(render_arguments_header())
                 $(for arg_desc in argument_description join ($['\r']) => $arg_desc)
             };
 
@@ -345,36 +719,48 @@ fn format_function_args(
                     opts: $(field_name)$(lifecycle)
                 },
                 description,
-                true,
+                Some(opts_description),
             ))
         } else {
             let description = quote! {
-                $(if argument_description.len() > 0 => $(format!("/// ")))
-                $(if argument_description.len() > 0 => $(format!("/// # Arguments")))
-                $(if argument_description.len() > 0 => $(format!("/// ")))
+                $(if !argument_description.is_empty() => $(render_arguments_header()))
                 $(for arg_desc in argument_description join ($['\r']) => $arg_desc)
             };
-            Some((required_args, description, false))
+            Some((required_args, description, None))
         }
     } else {
         None
     }
 }
 
+/// Names of the required arguments, in the order the function takes them
+fn required_arg_names(field: &FullTypeFields) -> Vec<String> {
+    field
+        .args
+        .iter()
+        .flatten()
+        .flatten()
+        .filter(|a| !type_ref_is_optional(&a.input_value.type_))
+        .map(|a| format_struct_name(&a.input_value.name))
+        .collect()
+}
+
 fn format_required_function_args(
     funcs: &CommonFunctions,
     field: &FullTypeFields,
+    style: FunctionStyle,
 ) -> Option<rust::Tokens> {
     if let Some(args) = field.args.as_ref() {
         let args = args
-            .into_iter()
-            .map(|a| {
+            .iter()
+            .filter_map(|a| {
                 a.as_ref().and_then(|s| {
                     if type_ref_is_optional(&s.input_value.type_) {
                         return None;
                     }
 
-                    let t = funcs.format_input_type(&s.input_value.type_);
+                    let _guard = funcs.diagnostics().enter(&s.input_value.name);
+                    let t = format_argument_type(funcs, &s.input_value.type_, style);
                     let n = format_struct_name(&s.input_value.name);
 
                     Some(quote! {
@@ -382,7 +768,6 @@ fn format_required_function_args(
                     })
                 })
             })
-            .flatten()
             .collect::<Vec<_>>();
         let required_args = quote! {
             &self,
@@ -399,21 +784,181 @@ pub fn format_optional_args(
     funcs: &CommonFunctions,
     field: &FullTypeFields,
 ) -> Option<(rust::Tokens, bool)> {
+    let builder = field_options_struct_name(funcs.config(), field)
+        .map(|name| funcs.config().has_builder(&name, true))
+        .unwrap_or(true);
+
     field
         .args
-        .pipe(|t| t.into_iter().flatten().collect::<Vec<_>>())
+        .pipe(|t| t.iter().flatten().collect::<Vec<_>>())
         .map(|t| {
             t.into_iter()
                 .filter(|t| type_ref_is_optional(&t.input_value.type_))
                 .collect::<Vec<_>>()
         })
-        .pipe(|t| render_optional_field_args(funcs, t))
+        .pipe(|t| render_optional_field_args(funcs, t, builder))
         .flatten()
 }
 
+fn render_arguments_header() -> rust::Tokens {
+    let mut tokens = rust::Tokens::new();
+
+    for line in ["///", "/// # Arguments", "///"] {
+        tokens.append(line);
+        tokens.push();
+    }
+
+    tokens
+}
+
+/// Renders `lines` as doc comments, keeping empty lines as paragraph breaks
+pub fn render_doc_lines(lines: &[String]) -> Option<rust::Tokens> {
+    if lines.is_empty() {
+        return None;
+    }
+
+    let mut tokens = rust::Tokens::new();
+    for line in lines {
+        if line.is_empty() {
+            tokens.append("///");
+        } else {
+            tokens.append(format!("/// {}", line));
+        }
+        tokens.push();
+    }
+
+    Some(tokens)
+}
+
+pub fn description_lines(description: Option<&str>) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+
+    for line in description.map(|d| d.trim().lines()).into_iter().flatten() {
+        let line = escape_doc(line.trim());
+
+        // A line right after a list would otherwise continue its last item
+        let after_list = lines.last().map(|l| is_list_item(l)).unwrap_or(false);
+        if after_list && !line.is_empty() && !is_list_item(&line) {
+            lines.push(String::new());
+        }
+
+        lines.push(line);
+    }
+
+    lines
+}
+
+fn is_list_item(line: &str) -> bool {
+    line.starts_with("- ") || line.starts_with("* ")
+}
+
+/// Keeps rustdoc from reading brackets in a schema description as links, and turns bare urls
+/// into ones it links
+fn escape_doc(line: &str) -> String {
+    line.split(' ')
+        .map(|word| {
+            let word = word.replace('[', "\\[").replace(']', "\\]");
+            match word.find("http://").or_else(|| word.find("https://")) {
+                Some(start) => {
+                    let end = word
+                        .trim_end_matches(['.', ',', ')', '"', '\''])
+                        .len()
+                        .max(start);
+                    format!("{}<{}>{}", &word[..start], &word[start..end], &word[end..])
+                }
+                None => word,
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The description of a function, and whether it only extends the query or executes it
+fn render_function_doc(
+    funcs: &CommonFunctions,
+    field: &FullTypeFields,
+    style: FunctionStyle,
+) -> Option<rust::Tokens> {
+    if style == FunctionStyle::Fake {
+        return None;
+    }
+
+    let mut lines = description_lines(field.description.as_deref());
+    if !lines.is_empty() {
+        lines.push(String::new());
+    }
+
+    match field.type_.as_ref().map(|t| &t.type_ref) {
+        Some(type_ref) if type_ref_is_lazy(type_ref) => {
+            if let Some(name) = lazy_type_name(funcs, type_ref) {
+                lines.push(format!(
+                    "Lazy, the query only runs once a field of the returned [`{}`] is {}.",
+                    name,
+                    match style {
+                        FunctionStyle::Blocking => "called",
+                        _ => "awaited",
+                    }
+                ));
+            }
+        }
+        Some(_) => lines.push("Executes the query and returns its result.".into()),
+        None => {}
+    }
+
+    render_doc_lines(&lines)
+}
+
+fn lazy_type_name(funcs: &CommonFunctions, type_ref: &TypeRef) -> Option<String> {
+    let name = type_ref_base_name(type_ref)?;
+
+    if type_ref_is_interface(type_ref) || type_ref_is_list_of_interfaces(type_ref) {
+        Some(format_interface_object_name(funcs.config(), name))
+    } else {
+        Some(format_type_name(funcs.config(), name))
+    }
+}
+
+/// Documents an argument or input field with its description, the object it identifies and
+/// its GraphQL default value
+pub fn argument_doc(funcs: &CommonFunctions, input_value: &InputValue) -> Option<String> {
+    let mut parts = Vec::new();
+
+    if let Some(description) = input_value
+        .description
+        .as_deref()
+        .map(|d| d.trim())
+        .filter(|d| !d.is_empty())
+    {
+        parts.push(
+            description
+                .lines()
+                .map(|l| escape_doc(l.trim()))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        );
+    }
+    if let Some(object) =
+        type_ref_base_name(&input_value.type_).and_then(|s| funcs.identified_object(s))
+    {
+        parts.push(format!(
+            "Identifies a [`{}`].",
+            format_type_name(funcs.config(), object)
+        ));
+    }
+    if let Some(default_value) = input_value.default_value.as_ref() {
+        parts.push(format!("Defaults to `{}`.", default_value));
+    }
+
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join("\n"))
+    }
+}
+
 pub fn write_comment_line(content: &str) -> Option<rust::Tokens> {
     let cnt = content.trim();
-    if cnt == "" {
+    if cnt.is_empty() {
         return None;
     }
 
@@ -435,7 +980,7 @@ pub fn format_struct_comment(desc: &str) -> Option<rust::Tokens> {
         .map(write_comment_line)
         .collect::<Vec<_>>();
 
-    if formatted_lines.len() > 0 {
+    if !formatted_lines.is_empty() {
         Some(quote! {
             $(for line in formatted_lines join($['\r']) => $line)
         })
@@ -443,3 +988,34 @@ pub fn format_struct_comment(desc: &str) -> Option<rust::Tokens> {
         None
     }
 }
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::{description_lines, escape_doc};
+
+    #[test]
+    fn description_lines_end_lists() {
+        assert_eq!(
+            description_lines(Some(
+                "Serves:\n- health checks\n- publishing\nExperimental."
+            )),
+            vec![
+                "Serves:",
+                "- health checks",
+                "- publishing",
+                "",
+                "Experimental."
+            ]
+        );
+    }
+
+    #[test]
+    fn escape_doc_brackets_and_urls() {
+        assert_eq!(
+            escape_doc("Formatted as [host]/[user]/[repo]:[tag] (e.g., \"https://docs.dagger.io\")."),
+            "Formatted as \\[host\\]/\\[user\\]/\\[repo\\]:\\[tag\\] (e.g., \"<https://docs.dagger.io>\")."
+        );
+    }
+}
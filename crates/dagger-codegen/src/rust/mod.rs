@@ -2,98 +2,250 @@ pub mod format;
 mod functions;
 pub mod templates;
 
+use std::collections::{BTreeMap, BTreeSet};
 use std::sync::{Arc, Mutex};
 
-use dagger_core::introspection::Schema;
+use dagger_core::introspection::{__TypeKind, FullType, Schema};
 use eyre::Context;
 use genco::prelude::rust;
+use genco::quote;
+use genco::tokens::quoted;
 
+use crate::config::Config;
+use crate::diagnostics::Diagnostics;
 use crate::functions::CommonFunctions;
 use crate::generator::Generator;
 use crate::visitor::{VisitHandlers, Visitor};
 
 use self::format::FormatTypeFunc;
+use self::functions::{
+    format_extension_trait_name, format_interface_object_name, format_struct_name, format_type_name,
+};
+use self::templates::api_tmpl::render_api_trait;
+use self::templates::blocking_tmpl::render_blocking_object;
 use self::templates::enum_tmpl::render_enum;
 use self::templates::input_tmpl::render_input;
-use self::templates::object_tmpl::render_object;
+use self::templates::interface_tmpl::render_interface;
+use self::templates::object_tmpl::{render_object, render_object_extension};
 use self::templates::scalar_tmpl::render_scalar;
+use self::templates::union_tmpl::render_union;
 
-pub struct RustGenerator {}
+#[derive(Default)]
+pub struct RustGenerator {
+    config: Arc<Config>,
+    base: Option<Arc<Schema>>,
+}
 
-impl Generator for RustGenerator {
-    fn generate(&self, schema: Schema) -> eyre::Result<String> {
-        let render = Arc::new(Mutex::new(rust::Tokens::new()));
-        let common_funcs = Arc::new(CommonFunctions::new(Arc::new(FormatTypeFunc {})));
-        println!("generating dagger for rust");
+impl RustGenerator {
+    pub fn new(config: Config) -> Self {
+        Self {
+            config: Arc::new(config),
+            base: None,
+        }
+    }
+
+    /// Generates code on top of the sdk generated from `base`, which requires `sdk` to be set in
+    /// the config. Types of `base` are used from the sdk, and fields added to its objects are
+    /// generated as `*Ext` traits.
+    pub fn extending(mut self, base: Schema) -> Self {
+        self.base = Some(Arc::new(base));
+        self
+    }
+
+    /// The sdk path and the names of its items when extending a base schema
+    fn base_names(&self) -> eyre::Result<Option<(&str, BTreeSet<String>)>> {
+        let base = match self.base.as_ref() {
+            Some(base) => base,
+            None => return Ok(None),
+        };
+        let sdk = self.config.sdk.as_deref().ok_or(eyre::anyhow!(
+            "sdk has to be set in the config to extend a base schema"
+        ))?;
+
+        let mut names = BTreeSet::new();
+        for t in base.types.iter().flatten().flatten() {
+            if let Some(name) = t.full_type.name.as_ref() {
+                names.insert(format_type_name(&self.config, name));
+                if t.full_type.kind == Some(__TypeKind::INTERFACE) {
+                    names.insert(format_interface_object_name(&self.config, name));
+                }
+            }
+        }
+
+        Ok(Some((sdk, names)))
+    }
+}
+
+impl RustGenerator {
+    fn render(&self, schema: Schema, diagnostics: &Diagnostics) -> eyre::Result<Vec<RenderedType>> {
+        self.config.validate(&schema, diagnostics);
+
+        // Objects of a base schema are in scope as well, through the glob import of the sdk
+        let objects = schema
+            .types
+            .iter()
+            .flatten()
+            .flatten()
+            .map(|t| &t.full_type)
+            .filter(|t| t.kind == Some(__TypeKind::OBJECT))
+            .filter_map(|t| t.name.clone())
+            .filter(|name| !self.config.skips_type(name))
+            .collect::<BTreeSet<_>>();
+
+        let (schema, extended) = match self.base.as_ref() {
+            Some(base) => without_base_types(schema, base),
+            None => (schema, BTreeSet::new()),
+        };
+
+        let rendered = Arc::new(Mutex::new(Vec::new()));
+        let common_funcs = Arc::new(
+            CommonFunctions::new(
+                Arc::new(FormatTypeFunc::new(self.config.clone())),
+                diagnostics.clone(),
+                self.config.clone(),
+            )
+            .with_objects(objects),
+        );
+
+        let interfaces = Arc::new(
+            schema
+                .types
+                .iter()
+                .flatten()
+                .flatten()
+                .map(|t| t.full_type.clone())
+                .filter(|t| t.kind == Some(__TypeKind::INTERFACE))
+                .collect::<Vec<_>>(),
+        );
 
         let visitor = Visitor {
             schema,
+            diagnostics: diagnostics.clone(),
+            config: self.config.clone(),
             handlers: VisitHandlers {
                 visit_scalar: Arc::new({
-                    let render = render.clone();
-                    let _common_funcs = common_funcs.clone();
+                    let rendered = rendered.clone();
+                    let common_funcs = common_funcs.clone();
 
                     move |t| {
-                        println!("generating scalar");
-                        let rendered_scalar = render_scalar(t)?;
+                        let tokens = render_scalar(&common_funcs, t)?;
+
+                        rendered.lock().unwrap().push(RenderedType::shared(
+                            "scalars",
+                            &common_funcs,
+                            t,
+                            tokens,
+                        ));
 
-                        let mut render = render.lock().unwrap();
+                        Ok(())
+                    }
+                }),
+                visit_object: Arc::new({
+                    let rendered = rendered.clone();
+                    let common_funcs = common_funcs.clone();
+                    let interfaces = interfaces.clone();
 
-                        render.append(rendered_scalar);
-                        render.push();
+                    move |t| {
+                        if extended.contains(t.name.as_ref().unwrap()) {
+                            let tokens = render_object_extension(&common_funcs, t)?;
 
-                        println!("generated scalar");
+                            rendered.lock().unwrap().push(RenderedType::extension(
+                                &common_funcs,
+                                t,
+                                tokens,
+                            ));
+
+                            return Ok(());
+                        }
+
+                        let tokens = render_object(&common_funcs, t, &interfaces)?;
+
+                        rendered
+                            .lock()
+                            .unwrap()
+                            .push(RenderedType::own(&common_funcs, t, tokens));
+
+                        if common_funcs.config().blocking {
+                            let tokens = render_blocking_object(&common_funcs, t)?;
+
+                            rendered
+                                .lock()
+                                .unwrap()
+                                .push(RenderedType::namespaced("blocking", tokens));
+                        }
+
+                        if common_funcs.config().traits {
+                            let tokens = render_api_trait(&common_funcs, t)?;
+
+                            rendered
+                                .lock()
+                                .unwrap()
+                                .push(RenderedType::namespaced("api", tokens));
+                        }
 
                         Ok(())
                     }
                 }),
-                visit_object: Arc::new({
-                    let render = render.clone();
+                visit_interface: Arc::new({
+                    let rendered = rendered.clone();
                     let common_funcs = common_funcs.clone();
 
                     move |t| {
-                        println!("generating object");
-                        let rendered_scalar = render_object(&common_funcs, t)?;
+                        let tokens = render_interface(&common_funcs, t)?;
+
+                        rendered
+                            .lock()
+                            .unwrap()
+                            .push(RenderedType::own(&common_funcs, t, tokens));
+
+                        Ok(())
+                    }
+                }),
+                visit_union: Arc::new({
+                    let rendered = rendered.clone();
+                    let common_funcs = common_funcs.clone();
 
-                        let mut render = render.lock().unwrap();
+                    move |t| {
+                        let tokens = render_union(&common_funcs, t)?;
 
-                        render.append(rendered_scalar);
-                        render.push();
-                        println!("generated object");
+                        rendered
+                            .lock()
+                            .unwrap()
+                            .push(RenderedType::own(&common_funcs, t, tokens));
 
                         Ok(())
                     }
                 }),
                 visit_input: Arc::new({
-                    let render = render.clone();
+                    let rendered = rendered.clone();
                     let common_funcs = common_funcs.clone();
 
                     move |t| {
-                        println!("generating input");
-                        let rendered_scalar = render_input(&common_funcs, t)?;
+                        let tokens = render_input(&common_funcs, t)?;
 
-                        let mut render = render.lock().unwrap();
-
-                        render.append(rendered_scalar);
-                        render.push();
-                        println!("generated input");
+                        rendered.lock().unwrap().push(RenderedType::shared(
+                            "inputs",
+                            &common_funcs,
+                            t,
+                            tokens,
+                        ));
 
                         Ok(())
                     }
                 }),
                 visit_enum: Arc::new({
-                    let render = render.clone();
-                    let _common_funcs = common_funcs.clone();
+                    let rendered = rendered.clone();
+                    let common_funcs = common_funcs.clone();
 
                     move |t| {
-                        println!("generating enum");
-                        let rendered_scalar = render_enum(t)?;
-
-                        let mut render = render.lock().unwrap();
+                        let tokens = render_enum(&common_funcs, t)?;
 
-                        render.append(rendered_scalar);
-                        render.push();
-                        println!("generated enum");
+                        rendered.lock().unwrap().push(RenderedType::shared(
+                            "enums",
+                            &common_funcs,
+                            t,
+                            tokens,
+                        ));
 
                         Ok(())
                     }
@@ -103,12 +255,293 @@ impl Generator for RustGenerator {
 
         visitor.run()?;
 
-        println!("done generating objects");
+        let rendered = std::mem::take(&mut *rendered.lock().unwrap());
+
+        Ok(rendered)
+    }
+}
+
+impl Generator for RustGenerator {
+    fn generate(&self, schema: Schema, diagnostics: &Diagnostics) -> eyre::Result<String> {
+        let base_names = self.base_names()?;
+        let mut render = rust::Tokens::new();
+        let mut namespaces: BTreeMap<String, rust::Tokens> = BTreeMap::new();
+
+        for rendered in self.render(schema, diagnostics)? {
+            if rendered.namespace {
+                let tokens = namespaces.entry(rendered.module).or_default();
+                tokens.append(rendered.tokens);
+                tokens.push();
+                continue;
+            }
 
-        let rendered = render.lock().unwrap();
+            match rendered.feature {
+                Some(feature) => render.append(quote! {
+                    #[cfg(feature = $(quoted(&feature)))]
+                    mod $(&rendered.module) {
+                        use super::*;
 
-        rendered
+                        $(rendered.tokens)
+                    }
+                    #[cfg(feature = $(quoted(&feature)))]
+                    pub use $(&rendered.module)::*;
+                }),
+                None => render.append(rendered.tokens),
+            }
+            render.push();
+        }
+        for (module, tokens) in namespaces {
+            render.append(quote! {
+                pub mod $(&module) {
+                    use super::*;
+
+                    $(tokens)
+                }
+            });
+            render.push();
+        }
+
+        let code = render
             .to_file_string()
-            .context("could not render to file string")
+            .context("could not render to file string")?;
+
+        match base_names.and_then(|(sdk, names)| use_of(sdk, &code, &names.iter().collect())) {
+            Some(u) => with_uses(&render, &[u]),
+            None => Ok(code),
+        }
+    }
+
+    fn generate_modules(
+        &self,
+        schema: Schema,
+        diagnostics: &Diagnostics,
+    ) -> eyre::Result<BTreeMap<String, String>> {
+        let base_names = self.base_names()?;
+        let mut modules: BTreeMap<String, (Option<String>, bool, Vec<String>, rust::Tokens)> =
+            BTreeMap::new();
+
+        for rendered in self.render(schema, diagnostics)? {
+            let (_, _, names, render) = modules.entry(rendered.module).or_insert_with(|| {
+                (
+                    rendered.feature,
+                    rendered.namespace,
+                    Vec::new(),
+                    rust::Tokens::new(),
+                )
+            });
+
+            names.extend(rendered.names);
+            render.append(rendered.tokens);
+            render.push();
+        }
+
+        let mut files = BTreeMap::new();
+        let mut mod_rs = String::new();
+        for (module, (feature, namespace, _, render)) in modules.iter() {
+            let other_names = modules
+                .iter()
+                .filter(|(m, _)| *m != module)
+                .flat_map(|(_, (_, _, names, _))| names.iter())
+                .collect::<BTreeSet<_>>();
+
+            let code = render
+                .to_file_string()
+                .context("could not render to file string")?;
+
+            let mut uses = Vec::new();
+            uses.extend(use_of("super", &code, &other_names));
+            if let Some((sdk, names)) = base_names.as_ref() {
+                uses.extend(use_of(sdk, &code, &names.iter().collect()));
+            }
+            let code = if uses.is_empty() {
+                code
+            } else {
+                with_uses(render, &uses)?
+            };
+            files.insert(format!("{}.rs", module), code);
+
+            let gate = feature
+                .as_ref()
+                .map(|f| format!("#[cfg(feature = \"{}\")]\n", f))
+                .unwrap_or_default();
+            if *namespace {
+                mod_rs.push_str(&format!("{gate}pub mod {module};\n"));
+            } else {
+                mod_rs.push_str(&format!(
+                    "{gate}mod {module};\n{gate}pub use {module}::*;\n"
+                ));
+            }
+        }
+        files.insert("mod.rs".into(), mod_rs);
+
+        Ok(files)
+    }
+}
+
+/// A rendered type and the module it ends up in when generating modules
+struct RenderedType {
+    module: String,
+    feature: Option<String>,
+    /// Whether the module is used by its path, instead of its items being re-exported
+    namespace: bool,
+    /// Names of the items other modules may refer to
+    names: Vec<String>,
+    tokens: rust::Tokens,
+}
+
+impl RenderedType {
+    /// Types sharing a module with others of the same kind, like scalars
+    fn shared(module: &str, funcs: &CommonFunctions, t: &FullType, tokens: rust::Tokens) -> Self {
+        Self {
+            module: module.into(),
+            feature: None,
+            namespace: false,
+            names: vec![format_type_name(funcs.config(), t.name.as_ref().unwrap())],
+            tokens,
+        }
+    }
+
+    /// Types of a module used by its path, like `blocking`, whose names mirror the ones of the
+    /// parent module
+    fn namespaced(module: &str, tokens: rust::Tokens) -> Self {
+        Self {
+            module: module.into(),
+            feature: None,
+            namespace: true,
+            names: Vec::new(),
+            tokens,
+        }
+    }
+
+    /// Extension traits of objects in the base schema
+    fn extension(funcs: &CommonFunctions, t: &FullType, tokens: rust::Tokens) -> Self {
+        let type_name = t.name.as_ref().unwrap();
+
+        Self {
+            module: format_struct_name(&format_type_name(funcs.config(), type_name)),
+            feature: funcs.config().feature_of(type_name).map(|f| f.to_string()),
+            namespace: false,
+            names: vec![format_extension_trait_name(funcs.config(), type_name)],
+            tokens,
+        }
     }
+
+    /// Types getting a module of their own, which can be put behind a feature
+    fn own(funcs: &CommonFunctions, t: &FullType, tokens: rust::Tokens) -> Self {
+        let type_name = t.name.as_ref().unwrap();
+        let name = format_type_name(funcs.config(), type_name);
+
+        let mut names = vec![name.clone()];
+        if t.kind == Some(__TypeKind::INTERFACE) {
+            names.push(format_interface_object_name(funcs.config(), type_name));
+        }
+
+        Self {
+            module: format_struct_name(&name),
+            feature: funcs.config().feature_of(type_name).map(|f| f.to_string()),
+            namespace: false,
+            names,
+            tokens,
+        }
+    }
+}
+
+struct Use<'a> {
+    module: &'a str,
+    doc_only: bool,
+}
+
+/// Renders `render` after a glob import of each of `uses`
+fn with_uses(render: &rust::Tokens, uses: &[Use]) -> eyre::Result<String> {
+    let mut with_uses = rust::Tokens::new();
+    for u in uses {
+        if u.doc_only {
+            with_uses.append(quote!(#[allow(unused_imports)]));
+            with_uses.push();
+        }
+        with_uses.append(quote!(use $(u.module)::*;));
+        with_uses.push();
+    }
+    with_uses.line();
+    with_uses.append(render.clone());
+
+    with_uses
+        .to_file_string()
+        .context("could not render to file string")
+}
+
+/// Drops the types of `base` from `schema`, except for objects `schema` adds fields to, which
+/// keep only those fields. Returns the names of these objects.
+fn without_base_types(mut schema: Schema, base: &Schema) -> (Schema, BTreeSet<String>) {
+    let base_types = base
+        .types
+        .iter()
+        .flatten()
+        .flatten()
+        .filter_map(|t| Some((t.full_type.name.clone()?, &t.full_type)))
+        .collect::<BTreeMap<_, _>>();
+
+    let mut extended = BTreeSet::new();
+    for types in schema.types.iter_mut() {
+        types.retain_mut(|t| {
+            let t = match t {
+                Some(t) => &mut t.full_type,
+                None => return true,
+            };
+            let base_type = match t.name.as_ref().and_then(|n| base_types.get(n)) {
+                Some(base_type) => base_type,
+                None => return true,
+            };
+            if t.kind != Some(__TypeKind::OBJECT) {
+                return false;
+            }
+
+            let base_fields = base_type
+                .fields
+                .iter()
+                .flatten()
+                .filter_map(|f| f.name.as_ref())
+                .collect::<BTreeSet<_>>();
+            for fields in t.fields.iter_mut() {
+                fields.retain(|f| f.name.as_ref().map(|n| !base_fields.contains(n)) == Some(true));
+            }
+
+            let added = t.fields.iter().flatten().count() > 0;
+            if added {
+                extended.insert(t.name.clone().unwrap());
+            }
+
+            added
+        });
+    }
+
+    (schema, extended)
+}
+
+/// How `module` needs to be imported for the names `code` uses from it, if at all. Names only
+/// linked to in doc comments still need the import, though the compiler doesn't see it used.
+fn use_of<'a>(module: &'a str, code: &str, names: &BTreeSet<&String>) -> Option<Use<'a>> {
+    let (docs, code): (Vec<_>, Vec<_>) =
+        code.lines().partition(|l| l.trim_start().starts_with("//"));
+
+    if references_any(&code, names) {
+        Some(Use {
+            module,
+            doc_only: false,
+        })
+    } else if references_any(&docs, names) {
+        Some(Use {
+            module,
+            doc_only: true,
+        })
+    } else {
+        None
+    }
+}
+
+fn references_any(lines: &[&str], names: &BTreeSet<&String>) -> bool {
+    lines
+        .iter()
+        .flat_map(|l| l.split(|c: char| !c.is_alphanumeric() && c != '_'))
+        .any(|ident| names.iter().any(|n| n.as_str() == ident))
 }
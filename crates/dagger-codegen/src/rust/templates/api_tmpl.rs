@@ -0,0 +1,116 @@
+use std::collections::BTreeSet;
+
+use dagger_core::introspection::{FullType, FullTypeFields};
+use genco::prelude::rust;
+use genco::quote;
+
+use crate::functions::{type_ref_is_list_of_objects, type_ref_is_object, CommonFunctions};
+use crate::rust::functions::{
+    fake_import, format_api_trait_name, format_fake_name, format_function_with_style,
+    format_struct_comment, format_type_name, render_feature_gate, selection_import,
+    type_ref_base_name, FunctionStyle,
+};
+use crate::rust::templates::blocking_tmpl::is_supported_in;
+use crate::utility::OptionExt;
+
+/// The trait of the `api` module for an object, implemented by the object of the parent module
+/// and by a fake answering its queries in memory
+pub fn render_api_trait(funcs: &CommonFunctions, t: &FullType) -> eyre::Result<rust::Tokens> {
+    let async_trait = rust::import("async_trait", "async_trait");
+
+    let type_name = t.name.as_ref().unwrap();
+    let name = format_type_name(funcs.config(), type_name);
+    let trait_name = format_api_trait_name(funcs.config(), type_name);
+    let fake_name = format_fake_name(funcs.config(), type_name);
+    let selection = selection_import(funcs.config());
+    let fake = fake_import(funcs.config());
+    let feature_gate = render_feature_gate(funcs.config(), type_name, None);
+
+    let fields = t
+        .fields
+        .iter()
+        .flatten()
+        .filter(|f| !funcs.skips_field(f))
+        .filter(|f| is_supported_in(funcs, f, "api"))
+        .collect::<Vec<_>>();
+    let objects = returned_objects(&fields);
+
+    let render_functions = |style| {
+        fields
+            .iter()
+            .filter_map(|f| format_function_with_style(funcs, f, style))
+            .collect::<Vec<_>>()
+    };
+    let declarations = render_functions(FunctionStyle::ApiDeclaration);
+    let implementations = render_functions(FunctionStyle::ApiImplementation);
+    let fakes = render_functions(FunctionStyle::Fake);
+
+    // `value` maps the schema name of an object to the type it is
+    let associated_types = |value: &dyn Fn(&str) -> String| {
+        quote! {
+            $(for object in objects.iter() join ($['\r']) =>
+                $(render_feature_gate(funcs.config(), object, Some(type_name)))
+                type $(format_type_name(funcs.config(), object)) = $(value(object));
+            )
+        }
+    };
+
+    Ok(quote! {
+        $(format_struct_comment(&format!("Trait of [`super::{}`]", name)))
+        $(feature_gate.as_ref())
+        #[$(&async_trait)]
+        pub trait $(&trait_name): Send + Sync {
+            $(for object in objects.iter() join ($['\r']) =>
+                $(render_feature_gate(funcs.config(), object, Some(type_name)))
+                type $(format_type_name(funcs.config(), object)): $(format_api_trait_name(funcs.config(), object));
+            )
+
+            $(for function in declarations join ($['\r']) => $function)
+        }
+
+        $(feature_gate.as_ref())
+        #[$(&async_trait)]
+        impl $(&trait_name) for super::$(&name) {
+            $(associated_types(&|object| format!("super::{}", format_type_name(funcs.config(), object))))
+
+            $(for function in implementations join ($['\r']) => $function)
+        }
+
+        $(format_struct_comment(&format!("In-memory fake of [`{}`]", trait_name)))
+        $(feature_gate.as_ref())
+        #[derive(Debug, Clone)]
+        pub struct $(&fake_name) {
+            pub selection: $(&selection),
+            pub fake: $(&fake),
+        }
+
+        $(feature_gate.as_ref())
+        impl $(&fake_name) {
+            $(format_struct_comment("A fake whose queries start at this object, answered by `fake`"))
+            pub fn new(fake: &$(&fake)) -> Self {
+                Self {
+                    selection: $(&selection)::default(),
+                    fake: fake.clone(),
+                }
+            }
+        }
+
+        $(feature_gate.as_ref())
+        #[$(&async_trait)]
+        impl $(&trait_name) for $(&fake_name) {
+            $(associated_types(&|object| format_fake_name(funcs.config(), object)))
+
+            $(for function in fakes join ($['\r']) => $function)
+        }
+    })
+}
+
+/// Schema names of the objects `fields` return, which become associated types of the trait
+fn returned_objects(fields: &[&FullTypeFields]) -> BTreeSet<String> {
+    fields
+        .iter()
+        .filter_map(|f| f.type_.pipe(|t| &t.type_ref))
+        .filter(|t| type_ref_is_object(t) || type_ref_is_list_of_objects(t))
+        .filter_map(|t| type_ref_base_name(t).map(|n| n.to_string()))
+        .collect()
+}
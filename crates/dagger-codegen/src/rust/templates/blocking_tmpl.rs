@@ -0,0 +1,69 @@
+use dagger_core::introspection::{FullType, FullTypeFields};
+use genco::prelude::rust;
+use genco::quote;
+
+use crate::functions::{
+    type_ref_is_interface, type_ref_is_list_of_interfaces, type_ref_is_union, CommonFunctions,
+};
+use crate::rust::functions::{
+    format_function_with_style, format_struct_comment, format_type_name, render_feature_gate,
+    FunctionStyle,
+};
+
+/// An object of the `blocking` module, which wraps the async object of the parent module and
+/// the runtime its queries run on
+pub fn render_blocking_object(funcs: &CommonFunctions, t: &FullType) -> eyre::Result<rust::Tokens> {
+    let arc = rust::import("std::sync", "Arc");
+    let runtime = rust::import("tokio::runtime", "Runtime");
+
+    let type_name = t.name.as_ref().unwrap();
+    let name = format_type_name(funcs.config(), type_name);
+    let feature_gate = render_feature_gate(funcs.config(), type_name, None);
+
+    let functions = t
+        .fields
+        .iter()
+        .flatten()
+        .filter(|f| !funcs.skips_field(f))
+        .filter(|f| is_supported_in(funcs, f, "blocking"))
+        .filter_map(|f| format_function_with_style(funcs, f, FunctionStyle::Blocking))
+        .collect::<Vec<_>>();
+
+    Ok(quote! {
+        $(format_struct_comment(&format!("Blocking version of [`super::{}`]", name)))
+        $(feature_gate.as_ref())
+        #[derive(Debug, Clone)]
+        pub struct $(&name) {
+            pub inner: super::$(&name),
+            pub runtime: $(&arc)<$runtime>,
+        }
+
+        $(feature_gate.as_ref())
+        impl $(&name) {
+            $(for function in functions join ($['\r']) => $function)
+        }
+    })
+}
+
+/// Interfaces and unions have no counterpart in the `blocking` and `api` modules, so fields
+/// returning them are left out of `module`
+pub fn is_supported_in(funcs: &CommonFunctions, field: &FullTypeFields, module: &str) -> bool {
+    let type_ref = match field.type_.as_ref() {
+        Some(t) => &t.type_ref,
+        None => return true,
+    };
+
+    let supported = !(type_ref_is_interface(type_ref)
+        || type_ref_is_list_of_interfaces(type_ref)
+        || type_ref_is_union(type_ref));
+
+    if !supported {
+        let _guard = field.name.as_ref().map(|n| funcs.diagnostics().enter(n));
+        funcs.diagnostics().warn(format!(
+            "skipped in the {} module, it returns an interface or union",
+            module
+        ));
+    }
+
+    supported
+}
@@ -1,37 +1,119 @@
 use dagger_core::introspection::FullType;
 use genco::prelude::rust;
 use genco::quote;
+use genco::tokens::quoted;
 
-fn render_enum_values(values: &FullType) -> Option<rust::Tokens> {
-    let values = values
-        .enum_values
-        .as_ref()
-        .into_iter()
-        .map(|values| {
-            values
-                .into_iter()
-                .map(|val| quote! { $(val.name.as_ref()), })
-        })
+use crate::functions::CommonFunctions;
+use crate::rust::functions::{
+    description_lines, format_type_name, render_attributes, render_doc_lines, render_extra_derives,
+};
+
+/// Variant holding values the schema this was generated from doesn't have
+const UNKNOWN_VARIANT: &str = "Unknown";
+
+fn enum_values<'a>(funcs: &CommonFunctions, t: &'a FullType) -> Vec<(&'a str, Option<&'a str>)> {
+    t.enum_values
+        .iter()
         .flatten()
-        .collect::<Vec<_>>();
+        .filter_map(|val| {
+            let name = val.name.as_deref()?;
+            if name == UNKNOWN_VARIANT {
+                funcs.diagnostics().warn(format!(
+                    "skipped value {}, it collides with the variant of unknown values",
+                    name
+                ));
+                return None;
+            }
+
+            Some((name, val.description.as_deref()))
+        })
+        .collect()
+}
 
+fn render_enum_values(values: &[(&str, Option<&str>)]) -> rust::Tokens {
     let mut tokens = rust::Tokens::new();
-    for val in values {
-        tokens.append(val);
+    for (name, description) in values {
+        tokens.append(quote! {
+            $(render_doc_lines(&description_lines(*description)))
+            $(*name),
+        });
         tokens.push();
     }
 
-    Some(tokens)
+    tokens
 }
 
-pub fn render_enum(t: &FullType) -> eyre::Result<rust::Tokens> {
+pub fn render_enum(funcs: &CommonFunctions, t: &FullType) -> eyre::Result<rust::Tokens> {
     let serialize = rust::import("serde", "Serialize");
+    let serializer = rust::import("serde", "Serializer");
     let deserialize = rust::import("serde", "Deserialize");
+    let deserializer = rust::import("serde", "Deserializer");
+    let display = rust::import("std::fmt", "Display");
+    let formatter = rust::import("std::fmt", "Formatter");
+    let from_str = rust::import("std::str", "FromStr");
+    let infallible = rust::import("std::convert", "Infallible");
+
+    let name = format_type_name(funcs.config(), t.name.as_ref().unwrap());
+    let values = enum_values(funcs, t);
 
     Ok(quote! {
-        #[derive($serialize, $deserialize, Clone, PartialEq, Debug)]
-        pub enum $(t.name.as_ref()) {
-            $(render_enum_values(t))
+        $(render_doc_lines(&description_lines(t.description.as_deref())))
+        $(render_attributes(funcs.config(), &name))
+        #[derive(Clone, PartialEq, Debug$(render_extra_derives(funcs.config(), &name)))]
+        #[non_exhaustive]
+        pub enum $(&name) {
+            $(render_enum_values(&values))
+            $(render_doc_lines(&["A value added to the schema after this was generated".to_string()]))
+            $(UNKNOWN_VARIANT)(String),
+        }
+
+        impl $(&name) {
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(for (value, _) in values.iter() join ($['\r']) => Self::$(*value) => $(quoted(*value)),)
+                    Self::$(UNKNOWN_VARIANT)(value) => value,
+                }
+            }
+        }
+
+        impl AsRef<str> for $(&name) {
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl $display for $(&name) {
+            fn fmt(&self, f: &mut $formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl $from_str for $(&name) {
+            type Err = $infallible;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(match s {
+                    $(for (value, _) in values.iter() join ($['\r']) => $(quoted(*value)) => Self::$(*value),)
+                    _ => Self::$(UNKNOWN_VARIANT)(s.to_string()),
+                })
+            }
+        }
+
+        impl $serialize for $(&name) {
+            fn serialize<S: $serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> $deserialize<'de> for $(&name) {
+            fn deserialize<D: $deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = String::deserialize(deserializer)?;
+
+                Ok(match value.parse() {
+                    Ok(value) => value,
+                    Err(never) => match never {},
+                })
+            }
         }
     })
 }
@@ -1,17 +1,39 @@
 use dagger_core::introspection::{FullType, FullTypeInputFields};
 use genco::prelude::rust;
 use genco::quote;
+use genco::tokens::quoted;
 
-use crate::functions::CommonFunctions;
-use crate::rust::functions::{format_name, format_struct_name};
+use crate::functions::{type_ref_is_optional, CommonFunctions};
+use crate::rust::functions::{
+    argument_doc, description_lines, format_struct_comment, format_struct_name, format_type_name,
+    render_attributes, render_doc_lines, render_extra_derives,
+};
 
 pub fn render_input(funcs: &CommonFunctions, t: &FullType) -> eyre::Result<rust::Tokens> {
     let deserialize = rust::import("serde", "Deserialize");
     let serialize = rust::import("serde", "Serialize");
+    let builder = rust::import("derive_builder", "Builder");
+
+    let type_name = t.name.as_ref().unwrap();
+    let name = format_type_name(funcs.config(), type_name);
+    let fields = t
+        .input_fields
+        .iter()
+        .flatten()
+        .filter(|f| !funcs.config().skips_field(type_name, &f.input_value.name))
+        .cloned()
+        .collect::<Vec<_>>();
+
     Ok(quote! {
-        #[derive($serialize, $deserialize, Debug, PartialEq, Clone)]
-        pub struct $(format_name(t.name.as_ref().unwrap())) {
-            $(render_input_fields(funcs, t.input_fields.as_ref().unwrap_or(&Vec::new())  ))
+        $(render_doc_lines(&description_lines(t.description.as_deref())))
+        $(render_attributes(funcs.config(), &name))
+        $(if funcs.config().has_builder(&name, false) {
+            #[derive($builder, $serialize, $deserialize, Debug, PartialEq, Clone$(render_extra_derives(funcs.config(), &name)))]
+        } else {
+            #[derive($serialize, $deserialize, Debug, PartialEq, Clone$(render_extra_derives(funcs.config(), &name)))]
+        })
+        pub struct $(&name) {
+            $(render_input_fields(funcs, &fields))
         }
     })
 }
@@ -32,7 +54,16 @@ pub fn render_input_fields(
 }
 
 pub fn render_input_field(funcs: &CommonFunctions, field: &FullTypeInputFields) -> rust::Tokens {
+    let _guard = funcs.diagnostics().enter(&field.input_value.name);
+
+    // Serialized under the schema's name, which the Rust name is converted from
     quote! {
-        pub $(format_struct_name(&field.input_value.name)): $(funcs.format_output_type(&field.input_value.type_)),
+        $(argument_doc(funcs, &field.input_value).and_then(|d| format_struct_comment(&d)))
+        #[serde(rename = $(quoted(&field.input_value.name)))]
+        pub $(format_struct_name(&field.input_value.name)): $(if type_ref_is_optional(&field.input_value.type_) {
+            Option<$(funcs.format_output_type(&field.input_value.type_))>
+        } else {
+            $(funcs.format_output_type(&field.input_value.type_))
+        }),
     }
 }
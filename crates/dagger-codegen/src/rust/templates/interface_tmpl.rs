@@ -0,0 +1,111 @@
+use dagger_core::introspection::{FullType, FullTypeFields};
+use genco::prelude::rust;
+use genco::quote;
+use genco::tokens::quoted;
+
+use crate::functions::CommonFunctions;
+use crate::rust::functions::{
+    format_function_with_style, format_interface_object_name, format_struct_comment,
+    format_struct_name, format_type_name, render_attributes, render_extra_derives,
+    render_feature_gate, selection_import, FunctionStyle,
+};
+use crate::rust::templates::object_tmpl::render_optional_args;
+use crate::utility::OptionExt;
+
+pub fn render_interface(funcs: &CommonFunctions, t: &FullType) -> eyre::Result<rust::Tokens> {
+    let selection = selection_import(funcs.config());
+    let child = rust::import("tokio::process", "Child");
+    let conn = rust::import("dagger_core::connect_params", "ConnectParams");
+    let arc = rust::import("std::sync", "Arc");
+    let async_trait = rust::import("async_trait", "async_trait");
+
+    let name = format_type_name(funcs.config(), t.name.as_ref().unwrap());
+    let object_name = format_interface_object_name(funcs.config(), t.name.as_ref().unwrap());
+
+    Ok(quote! {
+        $(t.description.pipe(|d| format_struct_comment(d)))
+        #[$(&async_trait)]
+        pub trait $(&name) {
+            $(t.fields.pipe(|f| render_trait_functions(funcs, f, FunctionStyle::TraitDeclaration)))
+        }
+
+        $(t.fields.pipe(|f| render_optional_args(funcs, f)))
+
+        $(format_struct_comment(&format!("Any object implementing [`{}`], use the `as_*` functions to select a concrete type", name)))
+        $(render_attributes(funcs.config(), &object_name))
+        #[derive(Debug, Clone$(render_extra_derives(funcs.config(), &object_name)))]
+        pub struct $(&object_name) {
+            pub proc: $arc<$child>,
+            pub selection: $selection,
+            pub conn: $conn,
+        }
+
+        impl $(&object_name) {
+            $(for possible_type in possible_type_names(t) join ($['\r']) => $(render_as_function(funcs, t, &possible_type)))
+        }
+
+        $(render_interface_impl(funcs, &object_name, t))
+    })
+}
+
+pub fn render_interface_impl(
+    funcs: &CommonFunctions,
+    implementor: &str,
+    interface: &FullType,
+) -> rust::Tokens {
+    let async_trait = rust::import("async_trait", "async_trait");
+
+    quote! {
+        #[$async_trait]
+        impl $(interface.name.pipe(|n| format_type_name(funcs.config(), n))) for $implementor {
+            $(interface.fields.pipe(|f| render_trait_functions(funcs, f, FunctionStyle::TraitImplementation)))
+        }
+    }
+}
+
+pub fn render_trait_functions(
+    funcs: &CommonFunctions,
+    fields: &[FullTypeFields],
+    style: FunctionStyle,
+) -> Option<rust::Tokens> {
+    let rendered_functions = fields
+        .iter()
+        .filter(|f| !funcs.skips_field(f))
+        .map(|f| format_function_with_style(funcs, f, style))
+        .collect::<Vec<_>>();
+
+    if !rendered_functions.is_empty() {
+        Some(quote! {
+            $(for func in rendered_functions join ($['\r']) => $func)
+        })
+    } else {
+        None
+    }
+}
+
+fn render_as_function(
+    funcs: &CommonFunctions,
+    interface: &FullType,
+    type_name: &str,
+) -> rust::Tokens {
+    let name = format_type_name(funcs.config(), type_name);
+
+    quote! {
+        $(render_feature_gate(funcs.config(), type_name, interface.name.as_deref()))
+        pub fn $(format!("as_{}", format_struct_name(&name)))(&self) -> $(&name) {
+            $(&name) {
+                proc: self.proc.clone(),
+                selection: self.selection.inline_fragment($(quoted(type_name))),
+                conn: self.conn.clone(),
+            }
+        }
+    }
+}
+
+pub fn possible_type_names(t: &FullType) -> Vec<String> {
+    t.possible_types
+        .iter()
+        .flatten()
+        .filter_map(|p| p.type_ref.name.clone())
+        .collect()
+}
@@ -1,4 +1,8 @@
+pub mod api_tmpl;
+pub mod blocking_tmpl;
 pub mod enum_tmpl;
 pub mod input_tmpl;
+pub mod interface_tmpl;
 pub mod object_tmpl;
 pub mod scalar_tmpl;
+pub mod union_tmpl;
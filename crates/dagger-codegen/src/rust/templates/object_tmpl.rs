@@ -1,23 +1,35 @@
-use dagger_core::introspection::{FullType, FullTypeFields, FullTypeFieldsArgs};
+use dagger_core::introspection::{__TypeKind, FullType, FullTypeFields, FullTypeFieldsArgs};
 use genco::prelude::rust;
 use genco::quote;
 
-use crate::functions::CommonFunctions;
+use crate::functions::{type_ref_is_optional, CommonFunctions, Scalar};
 use crate::rust::functions::{
-    field_options_struct_name, format_function, format_name, format_optional_args,
-    format_struct_comment, format_struct_name,
+    argument_doc, description_lines, field_options_struct_name, format_function,
+    format_optional_args, format_struct_comment, format_struct_name, format_type_name,
+    render_attributes, render_doc_lines, render_extra_derives, render_field_feature_gate,
+    selection_import,
 };
+use crate::rust::functions::{format_extension_trait_name, FunctionStyle};
+use crate::rust::templates::interface_tmpl::{render_interface_impl, render_trait_functions};
 use crate::utility::OptionExt;
 
-pub fn render_object(funcs: &CommonFunctions, t: &FullType) -> eyre::Result<rust::Tokens> {
-    let selection = rust::import("crate::querybuilder", "Selection");
+pub fn render_object(
+    funcs: &CommonFunctions,
+    t: &FullType,
+    interfaces: &[FullType],
+) -> eyre::Result<rust::Tokens> {
+    let selection = selection_import(funcs.config());
     let child = rust::import("tokio::process", "Child");
     let conn = rust::import("dagger_core::connect_params", "ConnectParams");
     let arc = rust::import("std::sync", "Arc");
 
+    let name = format_type_name(funcs.config(), t.name.as_ref().unwrap());
+
     Ok(quote! {
-        #[derive(Debug, Clone)]
-        pub struct $(t.name.pipe(|s| format_name(s))) {
+        $(render_doc_lines(&description_lines(t.description.as_deref())))
+        $(render_attributes(funcs.config(), &name))
+        #[derive(Debug, Clone$(render_extra_derives(funcs.config(), &name)))]
+        pub struct $(&name) {
             pub proc: $arc<$child>,
             pub selection: $selection,
             pub conn: $conn,
@@ -25,23 +37,60 @@ pub fn render_object(funcs: &CommonFunctions, t: &FullType) -> eyre::Result<rust
 
         $(t.fields.pipe(|f| render_optional_args(funcs, f)))
 
-        impl $(t.name.pipe(|s| format_name(s))) {
+        impl $(&name) {
             $(t.fields.pipe(|f| render_functions(funcs, f)))
         }
+
+        $(for interface in implemented_interfaces(t, interfaces) join ($['\r']) => $(render_interface_impl(funcs, &name, interface)))
     })
 }
 
-fn render_optional_args(
+/// Fields an extension adds to an object of the sdk, as a trait implemented for that object
+pub fn render_object_extension(
+    funcs: &CommonFunctions,
+    t: &FullType,
+) -> eyre::Result<rust::Tokens> {
+    let async_trait = rust::import("async_trait", "async_trait");
+
+    let name = format_type_name(funcs.config(), t.name.as_ref().unwrap());
+    let trait_name = format_extension_trait_name(funcs.config(), t.name.as_ref().unwrap());
+
+    Ok(quote! {
+        $(t.fields.pipe(|f| render_optional_args(funcs, f)))
+
+        $(format_struct_comment(&format!("Fields added to [`{}`] by the schema", name)))
+        #[$(&async_trait)]
+        pub trait $(&trait_name) {
+            $(t.fields.pipe(|f| render_trait_functions(funcs, f, FunctionStyle::TraitDeclaration)))
+        }
+
+        #[$(&async_trait)]
+        impl $(&trait_name) for $(&name) {
+            $(t.fields.pipe(|f| render_trait_functions(funcs, f, FunctionStyle::TraitImplementation)))
+        }
+    })
+}
+
+fn implemented_interfaces<'a>(t: &FullType, interfaces: &'a [FullType]) -> Vec<&'a FullType> {
+    t.interfaces
+        .iter()
+        .flatten()
+        .filter_map(|i| i.type_ref.name.as_ref())
+        .filter_map(|name| interfaces.iter().find(|i| i.name.as_ref() == Some(name)))
+        .collect()
+}
+
+pub fn render_optional_args(
     funcs: &CommonFunctions,
-    fields: &Vec<FullTypeFields>,
+    fields: &[FullTypeFields],
 ) -> Option<rust::Tokens> {
     let rendered_fields = fields
         .iter()
-        .map(|f| render_optional_arg(funcs, f))
-        .flatten()
+        .filter(|f| !funcs.skips_field(f))
+        .filter_map(|f| render_optional_arg(funcs, f))
         .collect::<Vec<_>>();
 
-    if rendered_fields.len() == 0 {
+    if rendered_fields.is_empty() {
         None
     } else {
         Some(quote! {
@@ -51,7 +100,8 @@ fn render_optional_args(
 }
 
 fn render_optional_arg(funcs: &CommonFunctions, field: &FullTypeFields) -> Option<rust::Tokens> {
-    let output_type = field_options_struct_name(field);
+    let _guard = field.name.as_ref().map(|n| funcs.diagnostics().enter(n));
+    let output_type = field_options_struct_name(funcs.config(), field)?;
     let fields = format_optional_args(funcs, field);
 
     let builder = rust::import("derive_builder", "Builder");
@@ -59,34 +109,92 @@ fn render_optional_arg(funcs: &CommonFunctions, field: &FullTypeFields) -> Optio
 
     if let Some((fields, contains_lifetime)) = fields {
         Some(quote! {
-            #[derive($builder, Debug, PartialEq)]
-            pub struct $output_type$(if contains_lifetime => <'a>) {
+            $(render_field_feature_gate(funcs.config(), field))
+            $(render_attributes(funcs.config(), &output_type))
+            $(if funcs.config().has_builder(&output_type, true) {
+                #[derive($builder, Debug, Default, PartialEq$(render_extra_derives(funcs.config(), &output_type)))]
+            } else {
+                #[derive(Debug, Default, PartialEq$(render_extra_derives(funcs.config(), &output_type)))]
+            })
+            #[non_exhaustive]
+            pub struct $(&output_type)$(if contains_lifetime => <'a>) {
                 //#[builder(default, setter(skip))]
                 //pub marker: $(phantom_data)<&'a ()>,
                 $fields
             }
+
+            $(render_field_feature_gate(funcs.config(), field))
+            impl$(if contains_lifetime => <'a>) $(&output_type)$(if contains_lifetime => <'a>) {
+                $(render_optional_arg_setters(funcs, field))
+            }
         })
     } else {
         None
     }
 }
 
+/// Fluent setters for each optional argument, so opts can be chained from `Default::default()`
+fn render_optional_arg_setters(funcs: &CommonFunctions, field: &FullTypeFields) -> rust::Tokens {
+    let setters = field
+        .args
+        .iter()
+        .flatten()
+        .flatten()
+        .map(|a| &a.input_value)
+        .filter(|a| type_ref_is_optional(&a.type_))
+        .map(|a| {
+            let name = format_struct_name(&a.name);
+            let type_ = funcs.format_immutable_input_type(&a.type_);
+
+            // Scalars generated as newtypes are taken as anything converting into them, like
+            // the required arguments of functions
+            let is_newtype = a.type_.kind == Some(__TypeKind::SCALAR)
+                && matches!(Scalar::from(&a.type_), Scalar::Default)
+                && a.type_
+                    .name
+                    .as_deref()
+                    .and_then(|n| funcs.config().scalar(n))
+                    .is_none();
+
+            quote! {
+                $(argument_doc(funcs, a).and_then(|d| format_struct_comment(&d)))
+                $(if is_newtype {
+                    pub fn $(&name)(mut self, $(&name): impl Into<$(type_)>) -> Self {
+                        self.$(&name) = Some($(&name).into());
+                        self
+                    }
+                } else {
+                    pub fn $(&name)(mut self, $(&name): $(type_)) -> Self {
+                        self.$(&name) = Some($(&name));
+                        self
+                    }
+                })
+            }
+        });
+
+    quote! {
+        $(for setter in setters join ($['\r']) => $setter)
+    }
+}
+
 pub fn render_optional_field_args(
     funcs: &CommonFunctions,
-    args: &Vec<&FullTypeFieldsArgs>,
+    args: &[&FullTypeFieldsArgs],
+    builder: bool,
 ) -> Option<(rust::Tokens, bool)> {
-    if args.len() == 0 {
+    if args.is_empty() {
         return None;
     }
     let mut contains_lifetime = false;
-    let rendered_args = args.into_iter().map(|a| &a.input_value).map(|a| {
+    let rendered_args = args.iter().map(|a| &a.input_value).map(|a| {
+        let _guard = funcs.diagnostics().enter(&a.name);
         let type_ = funcs.format_immutable_input_type(&a.type_);
         if type_.contains("str") {
             contains_lifetime = true;
         }
         quote! {
-            $(a.description.pipe(|d| format_struct_comment(d)))
-            #[builder(setter(into, strip_option), default)]
+            $(argument_doc(funcs, a).and_then(|d| format_struct_comment(&d)))
+            $(if builder => #[builder(setter(into, strip_option), default)])
             pub $(format_struct_name(&a.name)): Option<$(type_)>,
         }
     });
@@ -99,13 +207,17 @@ pub fn render_optional_field_args(
     ))
 }
 
-fn render_functions(funcs: &CommonFunctions, fields: &Vec<FullTypeFields>) -> Option<rust::Tokens> {
+pub fn render_functions(
+    funcs: &CommonFunctions,
+    fields: &[FullTypeFields],
+) -> Option<rust::Tokens> {
     let rendered_functions = fields
         .iter()
+        .filter(|f| !funcs.skips_field(f))
         .map(|f| render_function(funcs, f))
         .collect::<Vec<_>>();
 
-    if rendered_functions.len() > 0 {
+    if !rendered_functions.is_empty() {
         Some(quote! {
             $(for func in rendered_functions join ($['\r']) => $func)
         })
@@ -2,26 +2,43 @@ use dagger_core::introspection::FullType;
 use genco::prelude::rust;
 use genco::quote;
 
-use crate::rust::functions::format_name;
-use crate::utility::OptionExt;
+use crate::functions::CommonFunctions;
+use crate::rust::functions::{
+    description_lines, format_type_name, render_attributes, render_doc_lines, render_extra_derives,
+};
 
-pub fn render_scalar(t: &FullType) -> eyre::Result<rust::Tokens> {
+pub fn render_scalar(funcs: &CommonFunctions, t: &FullType) -> eyre::Result<rust::Tokens> {
     let deserialize = rust::import("serde", "Deserialize");
     let serialize = rust::import("serde", "Serialize");
 
+    let name = format_type_name(funcs.config(), t.name.as_ref().unwrap());
+
+    let mut doc = description_lines(t.description.as_deref());
+    if let Some(object) = funcs.identified_object(t.name.as_ref().unwrap()) {
+        if !doc.is_empty() {
+            doc.push(String::new());
+        }
+        doc.push(format!(
+            "Identifies a [`{}`].",
+            format_type_name(funcs.config(), object)
+        ));
+    }
+
     Ok(quote! {
-        #[derive($serialize, $deserialize, PartialEq, Debug, Clone)]
-        pub struct $(t.name.pipe(|n|format_name(n)))(pub String);
+        $(render_doc_lines(&doc))
+        $(render_attributes(funcs.config(), &name))
+        #[derive($serialize, $deserialize, PartialEq, Debug, Clone$(render_extra_derives(funcs.config(), &name)))]
+        pub struct $(&name)(pub String);
 
-        impl Into<$(t.name.pipe(|n| format_name(n)))> for &str {
-            fn into(self) -> $(t.name.pipe(|n| format_name(n))) {
-                $(t.name.pipe(|n| format_name(n)))(self.to_string())
+        impl Into<$(&name)> for &str {
+            fn into(self) -> $(&name) {
+                $(&name)(self.to_string())
             }
         }
 
-        impl Into<$(t.name.pipe(|n| format_name(n)))> for String {
-            fn into(self) -> $(t.name.pipe(|n| format_name(n))) {
-                $(t.name.pipe(|n| format_name(n)))(self.clone())
+        impl Into<$(&name)> for String {
+            fn into(self) -> $(&name) {
+                $(&name)(self.clone())
             }
         }
     })
@@ -0,0 +1,53 @@
+use dagger_core::introspection::FullType;
+use genco::prelude::rust;
+use genco::quote;
+use genco::tokens::quoted;
+
+use crate::functions::CommonFunctions;
+use crate::rust::functions::{
+    format_struct_comment, format_type_name, render_attributes, render_extra_derives,
+    render_feature_gate, selection_import,
+};
+use crate::rust::templates::interface_tmpl::possible_type_names;
+use crate::utility::OptionExt;
+
+pub fn render_union(funcs: &CommonFunctions, t: &FullType) -> eyre::Result<rust::Tokens> {
+    let selection = selection_import(funcs.config());
+    let child = rust::import("tokio::process", "Child");
+    let conn = rust::import("dagger_core::connect_params", "ConnectParams");
+    let arc = rust::import("std::sync", "Arc");
+
+    let name = format_type_name(funcs.config(), t.name.as_ref().unwrap());
+    let possible_types = possible_type_names(t);
+
+    Ok(quote! {
+        $(t.description.pipe(|d| format_struct_comment(d)))
+        $(render_attributes(funcs.config(), &name))
+        #[derive(Debug, Clone$(render_extra_derives(funcs.config(), &name)))]
+        pub enum $(&name) {
+            $(for possible_type in &possible_types join ($['\r']) =>
+                $(render_feature_gate(funcs.config(), possible_type, t.name.as_deref()))
+                $(format_type_name(funcs.config(), possible_type))($(format_type_name(funcs.config(), possible_type))),
+            )
+        }
+
+        impl $(&name) {
+            $(format_struct_comment("Selects the variant matching the `__typename` the engine returned for `selection`"))
+            pub fn from_typename(
+                typename: &str,
+                proc: $arc<$child>,
+                selection: $selection,
+                conn: $conn,
+            ) -> eyre::Result<Self> {
+                match typename {
+                    $(for possible_type in &possible_types join ($['\r']) => $(render_feature_gate(funcs.config(), possible_type, t.name.as_deref())) $(quoted(possible_type)) => Ok($(&name)::$(format_type_name(funcs.config(), possible_type))($(format_type_name(funcs.config(), possible_type)) {
+                        proc,
+                        selection: selection.inline_fragment($(quoted(possible_type))),
+                        conn,
+                    })),)
+                    _ => eyre::bail!($(quoted(format!("unknown {} type: {{}}", name))), typename),
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,389 @@
+use std::collections::BTreeMap;
+
+use dagger_core::introspection::{
+    __TypeKind, FullType, FullTypeFields, InputValue, Schema, TypeRef,
+};
+
+use crate::diagnostics::Diagnostics;
+use crate::generator::Generator;
+
+const INDENT: &str = "  ";
+
+/// Renders the schema as GraphQL SDL, including descriptions, default values and deprecations
+#[derive(Default)]
+pub struct SdlGenerator;
+
+impl Generator for SdlGenerator {
+    fn generate(&self, schema: Schema, diagnostics: &Diagnostics) -> eyre::Result<String> {
+        let mut definitions = Vec::new();
+
+        if let Some(definition) = render_schema_definition(&schema) {
+            definitions.push(definition);
+        }
+
+        for t in schema.types.iter().flatten().flatten() {
+            let t = &t.full_type;
+            let name = match t.name.as_ref() {
+                Some(name) => name,
+                None => {
+                    diagnostics.warn("skipped type without a name");
+                    continue;
+                }
+            };
+            if is_builtin(name) {
+                continue;
+            }
+
+            let _guard = diagnostics.enter(name);
+            let definition = match t.kind.as_ref() {
+                Some(__TypeKind::SCALAR) => format!("scalar {}", name),
+                Some(__TypeKind::OBJECT) => {
+                    render_fields_definition(format!("type {}{}", name, render_implements(t)), t)
+                }
+                Some(__TypeKind::INTERFACE) => render_fields_definition(
+                    format!("interface {}{}", name, render_implements(t)),
+                    t,
+                ),
+                Some(__TypeKind::UNION) => format!(
+                    "union {} = {}",
+                    name,
+                    t.possible_types
+                        .iter()
+                        .flatten()
+                        .filter_map(|p| p.type_ref.name.as_deref())
+                        .collect::<Vec<_>>()
+                        .join(" | ")
+                ),
+                Some(__TypeKind::ENUM) => render_enum(name, t),
+                Some(__TypeKind::INPUT_OBJECT) => render_input(name, t),
+                kind => {
+                    diagnostics.warn(format!("skipped type of unsupported kind {:?}", kind));
+                    continue;
+                }
+            };
+
+            definitions.push(format!(
+                "{}{}",
+                render_description(t.description.as_deref(), ""),
+                definition
+            ));
+        }
+
+        Ok(definitions.join("\n\n") + "\n")
+    }
+
+    fn generate_modules(
+        &self,
+        schema: Schema,
+        diagnostics: &Diagnostics,
+    ) -> eyre::Result<BTreeMap<String, String>> {
+        Ok(BTreeMap::from([(
+            "schema.graphql".to_string(),
+            self.generate(schema, diagnostics)?,
+        )]))
+    }
+}
+
+/// Built-in scalars and introspection types, which every GraphQL schema has
+fn is_builtin(name: &str) -> bool {
+    matches!(name, "String" | "Int" | "Float" | "Boolean" | "ID") || name.starts_with("__")
+}
+
+/// `schema { .. }`, which is only needed when the root types aren't named by convention
+fn render_schema_definition(schema: &Schema) -> Option<String> {
+    let roots = [
+        (
+            "query",
+            schema.query_type.as_ref().and_then(|t| t.name.as_ref()),
+            "Query",
+        ),
+        (
+            "mutation",
+            schema.mutation_type.as_ref().and_then(|t| t.name.as_ref()),
+            "Mutation",
+        ),
+        (
+            "subscription",
+            schema
+                .subscription_type
+                .as_ref()
+                .and_then(|t| t.name.as_ref()),
+            "Subscription",
+        ),
+    ];
+
+    if roots
+        .iter()
+        .all(|(_, name, conventional)| name.map(|n| n == conventional).unwrap_or(true))
+    {
+        return None;
+    }
+
+    let operations = roots
+        .iter()
+        .filter_map(|(operation, name, _)| Some(format!("{INDENT}{}: {}", operation, (*name)?)))
+        .collect::<Vec<_>>();
+
+    Some(format!("schema {{\n{}\n}}", operations.join("\n")))
+}
+
+fn render_implements(t: &FullType) -> String {
+    let interfaces = t
+        .interfaces
+        .iter()
+        .flatten()
+        .filter_map(|i| i.type_ref.name.as_deref())
+        .collect::<Vec<_>>();
+
+    if interfaces.is_empty() {
+        String::new()
+    } else {
+        format!(" implements {}", interfaces.join(" & "))
+    }
+}
+
+fn render_fields_definition(header: String, t: &FullType) -> String {
+    let fields = t
+        .fields
+        .iter()
+        .flatten()
+        .filter_map(render_field)
+        .collect::<Vec<_>>();
+
+    render_block(header, fields)
+}
+
+fn render_field(field: &FullTypeFields) -> Option<String> {
+    let name = field.name.as_ref()?;
+    let type_ref = &field.type_.as_ref()?.type_ref;
+    let args = field
+        .args
+        .iter()
+        .flatten()
+        .flatten()
+        .map(|a| &a.input_value)
+        .collect::<Vec<_>>();
+
+    let args = if args.is_empty() {
+        String::new()
+    } else if args.iter().any(|a| a.description.is_some()) {
+        // Descriptions of arguments only fit when each argument is on its own line
+        let indent = INDENT.repeat(2);
+        let args = args
+            .iter()
+            .map(|a| {
+                format!(
+                    "{}{indent}{}",
+                    render_description(a.description.as_deref(), &indent),
+                    render_input_value(a)
+                )
+            })
+            .collect::<Vec<_>>();
+
+        format!("(\n{}\n{INDENT})", args.join("\n"))
+    } else {
+        format!(
+            "({})",
+            args.iter()
+                .map(|a| render_input_value(a))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    };
+
+    Some(format!(
+        "{}{INDENT}{}{}: {}{}",
+        render_description(field.description.as_deref(), INDENT),
+        name,
+        args,
+        render_type_ref(type_ref),
+        render_deprecation(field.is_deprecated, field.deprecation_reason.as_deref())
+    ))
+}
+
+fn render_enum(name: &str, t: &FullType) -> String {
+    let values = t
+        .enum_values
+        .iter()
+        .flatten()
+        .filter_map(|v| {
+            Some(format!(
+                "{}{INDENT}{}{}",
+                render_description(v.description.as_deref(), INDENT),
+                v.name.as_ref()?,
+                render_deprecation(v.is_deprecated, v.deprecation_reason.as_deref())
+            ))
+        })
+        .collect::<Vec<_>>();
+
+    render_block(format!("enum {}", name), values)
+}
+
+fn render_input(name: &str, t: &FullType) -> String {
+    let fields = t
+        .input_fields
+        .iter()
+        .flatten()
+        .map(|f| {
+            format!(
+                "{}{INDENT}{}",
+                render_description(f.input_value.description.as_deref(), INDENT),
+                render_input_value(&f.input_value)
+            )
+        })
+        .collect::<Vec<_>>();
+
+    render_block(format!("input {}", name), fields)
+}
+
+fn render_block(header: String, items: Vec<String>) -> String {
+    if items.is_empty() {
+        return header;
+    }
+
+    format!("{} {{\n{}\n}}", header, items.join("\n"))
+}
+
+fn render_input_value(input_value: &InputValue) -> String {
+    let default_value = input_value
+        .default_value
+        .as_ref()
+        .map(|d| format!(" = {}", d))
+        .unwrap_or_default();
+
+    format!(
+        "{}: {}{}",
+        input_value.name,
+        render_type_ref(&input_value.type_),
+        default_value
+    )
+}
+
+fn render_type_ref(type_ref: &TypeRef) -> String {
+    let of_type = || {
+        type_ref
+            .of_type
+            .as_ref()
+            .map(|t| render_type_ref(t))
+            .unwrap_or_default()
+    };
+
+    match type_ref.kind {
+        Some(__TypeKind::NON_NULL) => format!("{}!", of_type()),
+        Some(__TypeKind::LIST) => format!("[{}]", of_type()),
+        _ => type_ref.name.clone().unwrap_or_default(),
+    }
+}
+
+fn render_deprecation(is_deprecated: Option<bool>, reason: Option<&str>) -> String {
+    if is_deprecated != Some(true) {
+        return String::new();
+    }
+
+    match reason {
+        // The reason the spec defaults to is left out, like the engine would have written it
+        Some(reason) if reason != "No longer supported" => {
+            format!(" @deprecated(reason: {})", render_string(reason))
+        }
+        _ => " @deprecated".into(),
+    }
+}
+
+/// The description as lines indented by `indent`, or nothing without one
+fn render_description(description: Option<&str>, indent: &str) -> String {
+    let description = match description.map(|d| d.trim()) {
+        Some(description) if !description.is_empty() => description,
+        _ => return String::new(),
+    };
+
+    if !description.contains('\n') {
+        return format!("{}{}\n", indent, render_string(description));
+    }
+
+    let lines = description
+        .replace("\"\"\"", "\\\"\"\"")
+        .lines()
+        .map(|l| {
+            if l.is_empty() {
+                String::new()
+            } else {
+                format!("{}{}", indent, l)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    format!(
+        "{indent}\"\"\"\n{}\n{indent}\"\"\"\n",
+        lines.join("\n"),
+        indent = indent
+    )
+}
+
+fn render_string(s: &str) -> String {
+    serde_json::to_string(s).unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use dagger_core::introspection::Schema;
+    use pretty_assertions::assert_eq;
+
+    use super::SdlGenerator;
+    use crate::diagnostics::Diagnostics;
+    use crate::generator::Generator;
+
+    #[test]
+    fn renders_descriptions_defaults_and_deprecations() {
+        let schema: Schema = serde_json::from_str(
+            r#"{
+                "queryType": {"name": "Query"},
+                "types": [
+                    {"kind": "SCALAR", "name": "String"},
+                    {"kind": "OBJECT", "name": "Query", "fields": [
+                        {
+                            "name": "greet",
+                            "description": "Greets someone.\n\nThe greeting is \"friendly\".",
+                            "args": [{
+                                "name": "greeting",
+                                "description": "What to say",
+                                "type": {"kind": "SCALAR", "name": "String"},
+                                "defaultValue": "\"hello\""
+                            }],
+                            "type": {"kind": "NON_NULL", "ofType": {"kind": "SCALAR", "name": "String"}},
+                            "isDeprecated": true,
+                            "deprecationReason": "Use `welcome` instead."
+                        }
+                    ]},
+                    {"kind": "ENUM", "name": "Mood", "enumValues": [
+                        {"name": "HAPPY", "isDeprecated": false},
+                        {"name": "GRUMPY", "isDeprecated": true, "deprecationReason": "No longer supported"}
+                    ]}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let sdl = SdlGenerator.generate(schema, &Diagnostics::new()).unwrap();
+
+        assert_eq!(
+            sdl,
+            r#"type Query {
+  """
+  Greets someone.
+
+  The greeting is "friendly".
+  """
+  greet(
+    "What to say"
+    greeting: String = "hello"
+  ): String! @deprecated(reason: "Use `welcome` instead.")
+}
+
+enum Mood {
+  HAPPY
+  GRUMPY @deprecated
+}
+"#
+        );
+    }
+}
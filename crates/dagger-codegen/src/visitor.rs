@@ -1,11 +1,16 @@
 use std::sync::Arc;
 
-use dagger_core::introspection::{FullType, Schema, __TypeKind};
+use dagger_core::introspection::{__TypeKind, FullType, Schema};
 use itertools::Itertools;
 
+use crate::config::Config;
+use crate::diagnostics::Diagnostics;
+
 pub struct Visitor {
     pub schema: Schema,
     pub handlers: VisitHandlers,
+    pub diagnostics: Diagnostics,
+    pub config: Arc<Config>,
 }
 
 pub type VisitFunc = Arc<dyn Fn(&FullType) -> eyre::Result<()>>;
@@ -13,6 +18,8 @@ pub type VisitFunc = Arc<dyn Fn(&FullType) -> eyre::Result<()>>;
 pub struct VisitHandlers {
     pub visit_scalar: VisitFunc,
     pub visit_object: VisitFunc,
+    pub visit_interface: VisitFunc,
+    pub visit_union: VisitFunc,
     pub visit_input: VisitFunc,
     pub visit_enum: VisitFunc,
 }
@@ -48,6 +55,16 @@ impl Visitor {
                 handler: self.handlers.visit_object.clone(),
                 ignore: None,
             },
+            SequenceItem {
+                kind: __TypeKind::INTERFACE,
+                handler: self.handlers.visit_interface.clone(),
+                ignore: None,
+            },
+            SequenceItem {
+                kind: __TypeKind::UNION,
+                handler: self.handlers.visit_union.clone(),
+                ignore: None,
+            },
             SequenceItem {
                 kind: __TypeKind::ENUM,
                 handler: self.handlers.visit_enum.clone(),
@@ -55,6 +72,13 @@ impl Visitor {
             },
         ];
 
+        if self.schema.types.is_none() {
+            self.diagnostics.error("schema contains no types");
+            return Ok(());
+        }
+
+        self.report_unsupported(&sequence);
+
         for item in sequence {
             self.visit(&item)?;
         }
@@ -62,14 +86,44 @@ impl Visitor {
         Ok(())
     }
 
+    fn report_unsupported(&self, sequence: &[SequenceItem]) {
+        for t in self.schema.types.iter().flatten().flatten() {
+            let name = match t.full_type.name.as_ref() {
+                Some(name) => name,
+                None => {
+                    self.diagnostics.warn("skipped type without a name");
+                    continue;
+                }
+            };
+            if name.starts_with("__") {
+                continue;
+            }
+
+            let _guard = self.diagnostics.enter(name);
+            match t.full_type.kind.as_ref() {
+                Some(kind) if sequence.iter().any(|i| &i.kind == kind) => {}
+                Some(__TypeKind::Other(kind)) => self
+                    .diagnostics
+                    .warn(format!("skipped type of unsupported kind {}", kind)),
+                Some(kind) => self
+                    .diagnostics
+                    .warn(format!("skipped type of unsupported kind {:?}", kind)),
+                None => self.diagnostics.warn("skipped type without a kind"),
+            }
+        }
+    }
+
     fn visit(&self, item: &SequenceItem) -> eyre::Result<()> {
         self.schema
             .types
-            .as_ref()
-            .unwrap()
-            .into_iter()
-            .map(|t| t.as_ref().unwrap())
-            .filter(|t| match t.full_type.kind.as_ref().unwrap() == &item.kind {
+            .iter()
+            .flatten()
+            .flatten()
+            .filter(|t| match t.full_type.name.as_ref() {
+                Some(name) => !self.config.skips_type(name) && self.config.scalar(name).is_none(),
+                None => true,
+            })
+            .filter(|t| match t.full_type.kind.as_ref() == Some(&item.kind) {
                 true => match (item.ignore.as_ref(), t.full_type.name.as_ref()) {
                     (Some(ignore), Some(name)) => {
                         if name.starts_with("__") {
@@ -79,26 +133,25 @@ impl Visitor {
                             return false;
                         }
 
-                        return true;
+                        true
                     }
                     (None, Some(name)) => {
                         if name.starts_with("__") {
                             return false;
                         }
-                        return true;
+                        true
                     }
                     _ => false,
                 },
                 false => false,
             })
-            .sorted_by(|a, b| {
-                a.full_type
-                    .name
-                    .as_ref()
-                    .unwrap()
-                    .cmp(&b.full_type.name.as_ref().unwrap())
+            // types without a name were reported by `report_unsupported`
+            .filter_map(|t| t.full_type.name.as_deref().map(|name| (name, t)))
+            .sorted_by(|(a, _), (b, _)| a.cmp(b))
+            .map(|(name, t)| {
+                let _guard = self.diagnostics.enter(name);
+                (*item.handler)(&t.full_type)
             })
-            .map(|t| (*item.handler)(&t.full_type))
             .collect::<eyre::Result<Vec<_>>>()?;
 
         Ok(())
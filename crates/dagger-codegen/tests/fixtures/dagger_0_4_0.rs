@@ -2,7 +2,10 @@ use crate::client::graphql_client;
 use crate::querybuilder::Selection;
 use dagger_core::connect_params::ConnectParams;
 use derive_builder::Builder;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 use std::sync::Arc;
 use tokio::process::Child;
 
@@ -20,6 +23,7 @@ impl Into<CacheId> for String {
         CacheId(self.clone())
     }
 }
+/// Identifies a [`Container`].
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct ContainerId(pub String);
 
@@ -34,6 +38,7 @@ impl Into<ContainerId> for String {
         ContainerId(self.clone())
     }
 }
+/// Identifies a [`Directory`].
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct DirectoryId(pub String);
 
@@ -48,6 +53,7 @@ impl Into<DirectoryId> for String {
         DirectoryId(self.clone())
     }
 }
+/// Identifies a [`File`].
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct FileId(pub String);
 
@@ -76,6 +82,7 @@ impl Into<Platform> for String {
         Platform(self.clone())
     }
 }
+/// Identifies a [`Secret`].
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct SecretId(pub String);
 
@@ -90,6 +97,7 @@ impl Into<SecretId> for String {
         SecretId(self.clone())
     }
 }
+/// Identifies a [`Socket`].
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct SocketId(pub String);
 
@@ -106,12 +114,16 @@ impl Into<SocketId> for String {
 }
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 pub struct BuildArg {
+    #[serde(rename = "name")]
     pub name: String,
+    #[serde(rename = "value")]
     pub value: String,
 }
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 pub struct PipelineLabel {
+    #[serde(rename = "value")]
     pub value: String,
+    #[serde(rename = "name")]
     pub name: String,
 }
 #[derive(Debug, Clone)]
@@ -122,12 +134,16 @@ pub struct CacheVolume {
 }
 
 impl CacheVolume {
-    pub async fn id(&self) -> eyre::Result<CacheId> {
+    /// Executes the query and returns its result.
+    pub async fn id(
+        &self,
+    ) -> eyre::Result<CacheId> {
         let query = self.selection.select("id");
 
         query.execute(&graphql_client(&self.conn)).await
     }
 }
+
 #[derive(Debug, Clone)]
 pub struct Container {
     pub proc: Arc<Child>,
@@ -135,8 +151,10 @@ pub struct Container {
     pub conn: ConnectParams,
 }
 
-#[derive(Builder, Debug, PartialEq)]
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
 pub struct ContainerBuildOpts<'a> {
+
     /// Path to the Dockerfile to use.
     /// Default: './Dockerfile'.
     #[builder(setter(into, strip_option), default)]
@@ -148,18 +166,54 @@ pub struct ContainerBuildOpts<'a> {
     #[builder(setter(into, strip_option), default)]
     pub target: Option<&'a str>,
 }
-#[derive(Builder, Debug, PartialEq)]
+
+impl<'a> ContainerBuildOpts<'a> {
+    /// Path to the Dockerfile to use.
+    /// Default: './Dockerfile'.
+    pub fn dockerfile(mut self, dockerfile: &'a str) -> Self {
+        self.dockerfile = Some(dockerfile);
+        self
+    }
+    /// Additional build arguments.
+    pub fn build_args(mut self, build_args: Vec<BuildArg>) -> Self {
+        self.build_args = Some(build_args);
+        self
+    }
+    /// Target build stage to build.
+    pub fn target(mut self, target: &'a str) -> Self {
+        self.target = Some(target);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
 pub struct ContainerEndpointOpts<'a> {
+
     /// The exposed port number for the endpoint
     #[builder(setter(into, strip_option), default)]
     pub port: Option<isize>,
-    /// Return a URL with the given scheme, eg. http for http://
+    /// Return a URL with the given scheme, eg. http for <http://>
     #[builder(setter(into, strip_option), default)]
     pub scheme: Option<&'a str>,
 }
-#[derive(Builder, Debug, PartialEq)]
+
+impl<'a> ContainerEndpointOpts<'a> {
+    /// The exposed port number for the endpoint
+    pub fn port(mut self, port: isize) -> Self {
+        self.port = Some(port);
+        self
+    }
+    /// Return a URL with the given scheme, eg. http for <http://>
+    pub fn scheme(mut self, scheme: &'a str) -> Self {
+        self.scheme = Some(scheme);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
 pub struct ContainerExecOpts<'a> {
-    /// Command to run instead of the container's default command (e.g., ["run", "main.go"]).
+
+    /// Command to run instead of the container's default command (e.g., \["run", "main.go"\]).
     #[builder(setter(into, strip_option), default)]
     pub args: Option<Vec<&'a str>>,
     /// Content to write to the command's standard input before closing (e.g., "Hello world").
@@ -177,15 +231,60 @@ pub struct ContainerExecOpts<'a> {
     #[builder(setter(into, strip_option), default)]
     pub experimental_privileged_nesting: Option<bool>,
 }
-#[derive(Builder, Debug, PartialEq)]
+
+impl<'a> ContainerExecOpts<'a> {
+    /// Command to run instead of the container's default command (e.g., \["run", "main.go"\]).
+    pub fn args(mut self, args: Vec<&'a str>) -> Self {
+        self.args = Some(args);
+        self
+    }
+    /// Content to write to the command's standard input before closing (e.g., "Hello world").
+    pub fn stdin(mut self, stdin: &'a str) -> Self {
+        self.stdin = Some(stdin);
+        self
+    }
+    /// Redirect the command's standard output to a file in the container (e.g., "/tmp/stdout").
+    pub fn redirect_stdout(mut self, redirect_stdout: &'a str) -> Self {
+        self.redirect_stdout = Some(redirect_stdout);
+        self
+    }
+    /// Redirect the command's standard error to a file in the container (e.g., "/tmp/stderr").
+    pub fn redirect_stderr(mut self, redirect_stderr: &'a str) -> Self {
+        self.redirect_stderr = Some(redirect_stderr);
+        self
+    }
+    /// Provide dagger access to the executed command.
+    /// Do not use this option unless you trust the command being executed.
+    /// The command being executed WILL BE GRANTED FULL ACCESS TO YOUR HOST FILESYSTEM.
+    pub fn experimental_privileged_nesting(mut self, experimental_privileged_nesting: bool) -> Self {
+        self.experimental_privileged_nesting = Some(experimental_privileged_nesting);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
 pub struct ContainerExportOpts {
+
     /// Identifiers for other platform specific containers.
     /// Used for multi-platform image.
+    /// Identifies a [`Container`].
     #[builder(setter(into, strip_option), default)]
     pub platform_variants: Option<Vec<ContainerId>>,
 }
-#[derive(Builder, Debug, PartialEq)]
+
+impl ContainerExportOpts {
+    /// Identifiers for other platform specific containers.
+    /// Used for multi-platform image.
+    /// Identifies a [`Container`].
+    pub fn platform_variants(mut self, platform_variants: Vec<ContainerId>) -> Self {
+        self.platform_variants = Some(platform_variants);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
 pub struct ContainerPipelineOpts<'a> {
+
     /// Pipeline description.
     #[builder(setter(into, strip_option), default)]
     pub description: Option<&'a str>,
@@ -193,30 +292,83 @@ pub struct ContainerPipelineOpts<'a> {
     #[builder(setter(into, strip_option), default)]
     pub labels: Option<Vec<PipelineLabel>>,
 }
-#[derive(Builder, Debug, PartialEq)]
+
+impl<'a> ContainerPipelineOpts<'a> {
+    /// Pipeline description.
+    pub fn description(mut self, description: &'a str) -> Self {
+        self.description = Some(description);
+        self
+    }
+    /// Pipeline labels.
+    pub fn labels(mut self, labels: Vec<PipelineLabel>) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
 pub struct ContainerPublishOpts {
+
     /// Identifiers for other platform specific containers.
     /// Used for multi-platform image.
+    /// Identifies a [`Container`].
     #[builder(setter(into, strip_option), default)]
     pub platform_variants: Option<Vec<ContainerId>>,
 }
-#[derive(Builder, Debug, PartialEq)]
+
+impl ContainerPublishOpts {
+    /// Identifiers for other platform specific containers.
+    /// Used for multi-platform image.
+    /// Identifies a [`Container`].
+    pub fn platform_variants(mut self, platform_variants: Vec<ContainerId>) -> Self {
+        self.platform_variants = Some(platform_variants);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
 pub struct ContainerWithDefaultArgsOpts<'a> {
-    /// Arguments to prepend to future executions (e.g., ["-v", "--no-cache"]).
+
+    /// Arguments to prepend to future executions (e.g., \["-v", "--no-cache"\]).
     #[builder(setter(into, strip_option), default)]
     pub args: Option<Vec<&'a str>>,
 }
-#[derive(Builder, Debug, PartialEq)]
+
+impl<'a> ContainerWithDefaultArgsOpts<'a> {
+    /// Arguments to prepend to future executions (e.g., \["-v", "--no-cache"\]).
+    pub fn args(mut self, args: Vec<&'a str>) -> Self {
+        self.args = Some(args);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
 pub struct ContainerWithDirectoryOpts<'a> {
-    /// Patterns to exclude in the written directory (e.g., ["node_modules/**", ".gitignore", ".git/"]).
+
+    /// Patterns to exclude in the written directory (e.g., \["node_modules/**", ".gitignore", ".git/"\]).
     #[builder(setter(into, strip_option), default)]
     pub exclude: Option<Vec<&'a str>>,
-    /// Patterns to include in the written directory (e.g., ["*.go", "go.mod", "go.sum"]).
+    /// Patterns to include in the written directory (e.g., \["*.go", "go.mod", "go.sum"\]).
     #[builder(setter(into, strip_option), default)]
     pub include: Option<Vec<&'a str>>,
 }
-#[derive(Builder, Debug, PartialEq)]
+
+impl<'a> ContainerWithDirectoryOpts<'a> {
+    /// Patterns to exclude in the written directory (e.g., \["node_modules/**", ".gitignore", ".git/"\]).
+    pub fn exclude(mut self, exclude: Vec<&'a str>) -> Self {
+        self.exclude = Some(exclude);
+        self
+    }
+    /// Patterns to include in the written directory (e.g., \["*.go", "go.mod", "go.sum"\]).
+    pub fn include(mut self, include: Vec<&'a str>) -> Self {
+        self.include = Some(include);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
 pub struct ContainerWithExecOpts<'a> {
+
     /// Content to write to the command's standard input before closing (e.g., "Hello world").
     #[builder(setter(into, strip_option), default)]
     pub stdin: Option<&'a str>,
@@ -238,8 +390,43 @@ pub struct ContainerWithExecOpts<'a> {
     #[builder(setter(into, strip_option), default)]
     pub insecure_root_capabilities: Option<bool>,
 }
-#[derive(Builder, Debug, PartialEq)]
+
+impl<'a> ContainerWithExecOpts<'a> {
+    /// Content to write to the command's standard input before closing (e.g., "Hello world").
+    pub fn stdin(mut self, stdin: &'a str) -> Self {
+        self.stdin = Some(stdin);
+        self
+    }
+    /// Redirect the command's standard output to a file in the container (e.g., "/tmp/stdout").
+    pub fn redirect_stdout(mut self, redirect_stdout: &'a str) -> Self {
+        self.redirect_stdout = Some(redirect_stdout);
+        self
+    }
+    /// Redirect the command's standard error to a file in the container (e.g., "/tmp/stderr").
+    pub fn redirect_stderr(mut self, redirect_stderr: &'a str) -> Self {
+        self.redirect_stderr = Some(redirect_stderr);
+        self
+    }
+    /// Provides dagger access to the executed command.
+    /// Do not use this option unless you trust the command being executed.
+    /// The command being executed WILL BE GRANTED FULL ACCESS TO YOUR HOST FILESYSTEM.
+    pub fn experimental_privileged_nesting(mut self, experimental_privileged_nesting: bool) -> Self {
+        self.experimental_privileged_nesting = Some(experimental_privileged_nesting);
+        self
+    }
+    /// Execute the command with all root capabilities. This is similar to running a command
+    /// with "sudo" or executing `docker run` with the `--privileged` flag. Containerization
+    /// does not provide any security guarantees when using this option. It should only be used
+    /// when absolutely necessary and only with trusted commands.
+    pub fn insecure_root_capabilities(mut self, insecure_root_capabilities: bool) -> Self {
+        self.insecure_root_capabilities = Some(insecure_root_capabilities);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
 pub struct ContainerWithExposedPortOpts<'a> {
+
     /// Transport layer network protocol
     #[builder(setter(into, strip_option), default)]
     pub protocol: Option<NetworkProtocol>,
@@ -247,24 +434,67 @@ pub struct ContainerWithExposedPortOpts<'a> {
     #[builder(setter(into, strip_option), default)]
     pub description: Option<&'a str>,
 }
-#[derive(Builder, Debug, PartialEq)]
+
+impl<'a> ContainerWithExposedPortOpts<'a> {
+    /// Transport layer network protocol
+    pub fn protocol(mut self, protocol: NetworkProtocol) -> Self {
+        self.protocol = Some(protocol);
+        self
+    }
+    /// Optional port description
+    pub fn description(mut self, description: &'a str) -> Self {
+        self.description = Some(description);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
 pub struct ContainerWithFileOpts {
+
     /// Permission given to the copied file (e.g., 0600).
     /// Default: 0644.
     #[builder(setter(into, strip_option), default)]
     pub permissions: Option<isize>,
 }
-#[derive(Builder, Debug, PartialEq)]
+
+impl ContainerWithFileOpts {
+    /// Permission given to the copied file (e.g., 0600).
+    /// Default: 0644.
+    pub fn permissions(mut self, permissions: isize) -> Self {
+        self.permissions = Some(permissions);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
 pub struct ContainerWithMountedCacheOpts {
+
     /// Identifier of the directory to use as the cache volume's root.
+    /// Identifies a [`Directory`].
     #[builder(setter(into, strip_option), default)]
     pub source: Option<DirectoryId>,
     /// Sharing mode of the cache volume.
     #[builder(setter(into, strip_option), default)]
     pub sharing: Option<CacheSharingMode>,
 }
-#[derive(Builder, Debug, PartialEq)]
+
+impl ContainerWithMountedCacheOpts {
+    /// Identifier of the directory to use as the cache volume's root.
+    /// Identifies a [`Directory`].
+    pub fn source(mut self, source: impl Into<DirectoryId>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+    /// Sharing mode of the cache volume.
+    pub fn sharing(mut self, sharing: CacheSharingMode) -> Self {
+        self.sharing = Some(sharing);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
 pub struct ContainerWithNewFileOpts<'a> {
+
     /// Content of the file to write (e.g., "Hello world!").
     #[builder(setter(into, strip_option), default)]
     pub contents: Option<&'a str>,
@@ -273,21 +503,52 @@ pub struct ContainerWithNewFileOpts<'a> {
     #[builder(setter(into, strip_option), default)]
     pub permissions: Option<isize>,
 }
-#[derive(Builder, Debug, PartialEq)]
+
+impl<'a> ContainerWithNewFileOpts<'a> {
+    /// Content of the file to write (e.g., "Hello world!").
+    pub fn contents(mut self, contents: &'a str) -> Self {
+        self.contents = Some(contents);
+        self
+    }
+    /// Permission given to the written file (e.g., 0600).
+    /// Default: 0644.
+    pub fn permissions(mut self, permissions: isize) -> Self {
+        self.permissions = Some(permissions);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
 pub struct ContainerWithoutExposedPortOpts {
+
     /// Port protocol to unexpose
     #[builder(setter(into, strip_option), default)]
     pub protocol: Option<NetworkProtocol>,
 }
 
+impl ContainerWithoutExposedPortOpts {
+    /// Port protocol to unexpose
+    pub fn protocol(mut self, protocol: NetworkProtocol) -> Self {
+        self.protocol = Some(protocol);
+        self
+    }
+}
+
 impl Container {
     /// Initializes this container from a Dockerfile build.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerBuildOpts`], are taken by [`Self::build_opts`] and [`Self::build_with`].
+    ///
     /// # Arguments
     ///
     /// * `context` - Directory context used by the Dockerfile.
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn build(&self, context: DirectoryId) -> Container {
+    ///   Identifies a [`Directory`].
+    pub fn build(
+        &self,
+        context: DirectoryId,
+    ) -> Container {
         let mut query = self.selection.select("build");
 
         query = query.arg("context", context);
@@ -296,16 +557,23 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
 
     /// Initializes this container from a Dockerfile build.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `context` - Directory context used by the Dockerfile.
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn build_opts<'a>(&self, context: DirectoryId, opts: ContainerBuildOpts<'a>) -> Container {
+    ///   Identifies a [`Directory`].
+    /// * `opts` - Optional arguments, see [`ContainerBuildOpts`].
+    pub fn build_opts<'a>(
+        &self,
+        context: DirectoryId,
+        opts: ContainerBuildOpts<'a>
+    ) -> Container {
         let mut query = self.selection.select("build");
 
         query = query.arg("context", context);
@@ -323,10 +591,31 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
+    }
+
+    /// Initializes this container from a Dockerfile build.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - Directory context used by the Dockerfile.
+    ///   Identifies a [`Directory`].
+    /// * `opts` - Optional arguments, see [`ContainerBuildOpts`].
+    pub fn build_with<'a>(
+        &self,
+        context: DirectoryId,
+        opts: impl FnOnce(ContainerBuildOpts<'a>) -> ContainerBuildOpts<'a>,
+    ) -> Container {
+        self.build_opts(context, opts(Default::default()))
     }
     /// Retrieves default arguments for future commands.
-    pub async fn default_args(&self) -> eyre::Result<Vec<String>> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn default_args(
+        &self,
+    ) -> eyre::Result<Vec<String>> {
         let query = self.selection.select("defaultArgs");
 
         query.execute(&graphql_client(&self.conn)).await
@@ -334,10 +623,15 @@ impl Container {
     /// Retrieves a directory at the given path.
     /// Mounts are included.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `path` - The path of the directory to retrieve (e.g., "./src").
-    pub fn directory(&self, path: impl Into<String>) -> Directory {
+    pub fn directory(
+        &self,
+        path: impl Into<String>,
+    ) -> Directory {
         let mut query = self.selection.select("directory");
 
         query = query.arg("path", path.into());
@@ -346,17 +640,19 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Retrieves an endpoint that clients can use to reach this container.
     /// If no port is specified, the first exposed port is used. If none exist an error is returned.
     /// If a scheme is specified, a URL is returned. Otherwise, a host:port pair is returned.
     /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
     ///
-    /// # Arguments
+    /// Executes the query and returns its result.
     ///
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub async fn endpoint(&self) -> eyre::Result<String> {
+    /// Optional arguments, see [`ContainerEndpointOpts`], are taken by [`Self::endpoint_opts`] and [`Self::endpoint_with`].
+    pub async fn endpoint(
+        &self,
+    ) -> eyre::Result<String> {
         let query = self.selection.select("endpoint");
 
         query.execute(&graphql_client(&self.conn)).await
@@ -367,10 +663,15 @@ impl Container {
     /// If a scheme is specified, a URL is returned. Otherwise, a host:port pair is returned.
     /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
     ///
+    /// Executes the query and returns its result.
+    ///
     /// # Arguments
     ///
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub async fn endpoint_opts<'a>(&self, opts: ContainerEndpointOpts<'a>) -> eyre::Result<String> {
+    /// * `opts` - Optional arguments, see [`ContainerEndpointOpts`].
+    pub async fn endpoint_opts<'a>(
+        &self,
+        opts: ContainerEndpointOpts<'a>
+    ) -> eyre::Result<String> {
         let mut query = self.selection.select("endpoint");
 
         if let Some(port) = opts.port {
@@ -382,18 +683,44 @@ impl Container {
 
         query.execute(&graphql_client(&self.conn)).await
     }
+
+    /// Retrieves an endpoint that clients can use to reach this container.
+    /// If no port is specified, the first exposed port is used. If none exist an error is returned.
+    /// If a scheme is specified, a URL is returned. Otherwise, a host:port pair is returned.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerEndpointOpts`].
+    pub async fn endpoint_with<'a>(
+        &self,
+        opts: impl FnOnce(ContainerEndpointOpts<'a>) -> ContainerEndpointOpts<'a>,
+    ) -> eyre::Result<String> {
+        self.endpoint_opts( opts(Default::default())).await
+    }
     /// Retrieves entrypoint to be prepended to the arguments of all commands.
-    pub async fn entrypoint(&self) -> eyre::Result<Vec<String>> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn entrypoint(
+        &self,
+    ) -> eyre::Result<Vec<String>> {
         let query = self.selection.select("entrypoint");
 
         query.execute(&graphql_client(&self.conn)).await
     }
     /// Retrieves the value of the specified environment variable.
     ///
+    /// Executes the query and returns its result.
+    ///
     /// # Arguments
     ///
     /// * `name` - The name of the environment variable to retrieve (e.g., "PATH").
-    pub async fn env_variable(&self, name: impl Into<String>) -> eyre::Result<String> {
+    pub async fn env_variable(
+        &self,
+        name: impl Into<String>,
+    ) -> eyre::Result<String> {
         let mut query = self.selection.select("envVariable");
 
         query = query.arg("name", name.into());
@@ -401,36 +728,47 @@ impl Container {
         query.execute(&graphql_client(&self.conn)).await
     }
     /// Retrieves the list of environment variables passed to commands.
-    pub fn env_variables(&self) -> Vec<EnvVariable> {
+    ///
+    /// Lazy, the query only runs once a field of the returned [`EnvVariable`] is awaited.
+    pub fn env_variables(
+        &self,
+    ) -> Vec<EnvVariable> {
         let query = self.selection.select("envVariables");
 
         return vec![EnvVariable {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        }];
+        }]
     }
     /// Retrieves this container after executing the specified command inside it.
     ///
-    /// # Arguments
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
     ///
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn exec(&self) -> Container {
+    /// Optional arguments, see [`ContainerExecOpts`], are taken by [`Self::exec_opts`] and [`Self::exec_with`].
+    pub fn exec(
+        &self,
+    ) -> Container {
         let query = self.selection.select("exec");
 
         return Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
 
     /// Retrieves this container after executing the specified command inside it.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn exec_opts<'a>(&self, opts: ContainerExecOpts<'a>) -> Container {
+    /// * `opts` - Optional arguments, see [`ContainerExecOpts`].
+    pub fn exec_opts<'a>(
+        &self,
+        opts: ContainerExecOpts<'a>
+    ) -> Container {
         let mut query = self.selection.select("exec");
 
         if let Some(args) = opts.args {
@@ -446,21 +784,36 @@ impl Container {
             query = query.arg("redirectStderr", redirect_stderr);
         }
         if let Some(experimental_privileged_nesting) = opts.experimental_privileged_nesting {
-            query = query.arg(
-                "experimentalPrivilegedNesting",
-                experimental_privileged_nesting,
-            );
+            query = query.arg("experimentalPrivilegedNesting", experimental_privileged_nesting);
         }
 
         return Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
+    }
+
+    /// Retrieves this container after executing the specified command inside it.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerExecOpts`].
+    pub fn exec_with<'a>(
+        &self,
+        opts: impl FnOnce(ContainerExecOpts<'a>) -> ContainerExecOpts<'a>,
+    ) -> Container {
+        self.exec_opts( opts(Default::default()))
     }
     /// Exit code of the last executed command. Zero means success.
     /// Errors if no command has been executed.
-    pub async fn exit_code(&self) -> eyre::Result<isize> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn exit_code(
+        &self,
+    ) -> eyre::Result<isize> {
         let query = self.selection.select("exitCode");
 
         query.execute(&graphql_client(&self.conn)).await
@@ -469,12 +822,18 @@ impl Container {
     /// Return true on success.
     /// It can also publishes platform variants.
     ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`ContainerExportOpts`], are taken by [`Self::export_opts`] and [`Self::export_with`].
+    ///
     /// # Arguments
     ///
     /// * `path` - Host's destination path (e.g., "./tarball").
-    /// Path can be relative to the engine's workdir or absolute.
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub async fn export(&self, path: impl Into<String>) -> eyre::Result<bool> {
+    ///   Path can be relative to the engine's workdir or absolute.
+    pub async fn export(
+        &self,
+        path: impl Into<String>,
+    ) -> eyre::Result<bool> {
         let mut query = self.selection.select("export");
 
         query = query.arg("path", path.into());
@@ -486,15 +845,17 @@ impl Container {
     /// Return true on success.
     /// It can also publishes platform variants.
     ///
+    /// Executes the query and returns its result.
+    ///
     /// # Arguments
     ///
     /// * `path` - Host's destination path (e.g., "./tarball").
-    /// Path can be relative to the engine's workdir or absolute.
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
+    ///   Path can be relative to the engine's workdir or absolute.
+    /// * `opts` - Optional arguments, see [`ContainerExportOpts`].
     pub async fn export_opts(
         &self,
         path: impl Into<String>,
-        opts: ContainerExportOpts,
+        opts: ContainerExportOpts
     ) -> eyre::Result<bool> {
         let mut query = self.selection.select("export");
 
@@ -505,24 +866,52 @@ impl Container {
 
         query.execute(&graphql_client(&self.conn)).await
     }
+
+    /// Writes the container as an OCI tarball to the destination file path on the host for the specified platform variants.
+    /// Return true on success.
+    /// It can also publishes platform variants.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Host's destination path (e.g., "./tarball").
+    ///   Path can be relative to the engine's workdir or absolute.
+    /// * `opts` - Optional arguments, see [`ContainerExportOpts`].
+    pub async fn export_with(
+        &self,
+        path: impl Into<String>,
+        opts: impl FnOnce(ContainerExportOpts) -> ContainerExportOpts,
+    ) -> eyre::Result<bool> {
+        self.export_opts(path, opts(Default::default())).await
+    }
     /// Retrieves the list of exposed ports.
     /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
-    pub fn exposed_ports(&self) -> Vec<Port> {
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Port`] is awaited.
+    pub fn exposed_ports(
+        &self,
+    ) -> Vec<Port> {
         let query = self.selection.select("exposedPorts");
 
         return vec![Port {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        }];
+        }]
     }
     /// Retrieves a file at the given path.
     /// Mounts are included.
     ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `path` - The path of the file to retrieve (e.g., "./README.md").
-    pub fn file(&self, path: impl Into<String>) -> File {
+    pub fn file(
+        &self,
+        path: impl Into<String>,
+    ) -> File {
         let mut query = self.selection.select("file");
 
         query = query.arg("path", path.into());
@@ -531,16 +920,21 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Initializes this container from a pulled base image.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `address` - Image's address from its registry.
     ///
-    /// Formatted as [host]/[user]/[repo]:[tag] (e.g., "docker.io/dagger/dagger:main").
-    pub fn from(&self, address: impl Into<String>) -> Container {
+    ///   Formatted as \[host\]/\[user\]/\[repo\]:\[tag\] (e.g., "docker.io/dagger/dagger:main").
+    pub fn from(
+        &self,
+        address: impl Into<String>,
+    ) -> Container {
         let mut query = self.selection.select("from");
 
         query = query.arg("address", address.into());
@@ -549,39 +943,60 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Retrieves this container's root filesystem. Mounts are not included.
-    pub fn fs(&self) -> Directory {
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    pub fn fs(
+        &self,
+    ) -> Directory {
         let query = self.selection.select("fs");
 
         return Directory {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Retrieves a hostname which can be used by clients to reach this container.
     /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
-    pub async fn hostname(&self) -> eyre::Result<String> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn hostname(
+        &self,
+    ) -> eyre::Result<String> {
         let query = self.selection.select("hostname");
 
         query.execute(&graphql_client(&self.conn)).await
     }
     /// A unique identifier for this container.
-    pub async fn id(&self) -> eyre::Result<ContainerId> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn id(
+        &self,
+    ) -> eyre::Result<ContainerId> {
         let query = self.selection.select("id");
 
         query.execute(&graphql_client(&self.conn)).await
     }
     /// The unique image reference which can only be retrieved immediately after the 'Container.From' call.
-    pub async fn image_ref(&self) -> eyre::Result<String> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn image_ref(
+        &self,
+    ) -> eyre::Result<String> {
         let query = self.selection.select("imageRef");
 
         query.execute(&graphql_client(&self.conn)).await
     }
     /// Retrieves the value of the specified label.
-    pub async fn label(&self, name: impl Into<String>) -> eyre::Result<String> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn label(
+        &self,
+        name: impl Into<String>,
+    ) -> eyre::Result<String> {
         let mut query = self.selection.select("label");
 
         query = query.arg("name", name.into());
@@ -589,28 +1004,42 @@ impl Container {
         query.execute(&graphql_client(&self.conn)).await
     }
     /// Retrieves the list of labels passed to container.
-    pub fn labels(&self) -> Vec<Label> {
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Label`] is awaited.
+    pub fn labels(
+        &self,
+    ) -> Vec<Label> {
         let query = self.selection.select("labels");
 
         return vec![Label {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        }];
+        }]
     }
     /// Retrieves the list of paths where a directory is mounted.
-    pub async fn mounts(&self) -> eyre::Result<Vec<String>> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn mounts(
+        &self,
+    ) -> eyre::Result<Vec<String>> {
         let query = self.selection.select("mounts");
 
         query.execute(&graphql_client(&self.conn)).await
     }
     /// Creates a named sub-pipeline
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerPipelineOpts`], are taken by [`Self::pipeline_opts`] and [`Self::pipeline_with`].
+    ///
     /// # Arguments
     ///
     /// * `name` - Pipeline name.
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn pipeline(&self, name: impl Into<String>) -> Container {
+    pub fn pipeline(
+        &self,
+        name: impl Into<String>,
+    ) -> Container {
         let mut query = self.selection.select("pipeline");
 
         query = query.arg("name", name.into());
@@ -619,19 +1048,21 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
 
     /// Creates a named sub-pipeline
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `name` - Pipeline name.
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
+    /// * `opts` - Optional arguments, see [`ContainerPipelineOpts`].
     pub fn pipeline_opts<'a>(
         &self,
         name: impl Into<String>,
-        opts: ContainerPipelineOpts<'a>,
+        opts: ContainerPipelineOpts<'a>
     ) -> Container {
         let mut query = self.selection.select("pipeline");
 
@@ -647,10 +1078,30 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
+    }
+
+    /// Creates a named sub-pipeline
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    /// * `opts` - Optional arguments, see [`ContainerPipelineOpts`].
+    pub fn pipeline_with<'a>(
+        &self,
+        name: impl Into<String>,
+        opts: impl FnOnce(ContainerPipelineOpts<'a>) -> ContainerPipelineOpts<'a>,
+    ) -> Container {
+        self.pipeline_opts(name, opts(Default::default()))
     }
     /// The platform this container executes and publishes as.
-    pub async fn platform(&self) -> eyre::Result<Platform> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn platform(
+        &self,
+    ) -> eyre::Result<Platform> {
         let query = self.selection.select("platform");
 
         query.execute(&graphql_client(&self.conn)).await
@@ -659,13 +1110,19 @@ impl Container {
     /// Publish returns a fully qualified ref.
     /// It can also publish platform variants.
     ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`ContainerPublishOpts`], are taken by [`Self::publish_opts`] and [`Self::publish_with`].
+    ///
     /// # Arguments
     ///
     /// * `address` - Registry's address to publish the image to.
     ///
-    /// Formatted as [host]/[user]/[repo]:[tag] (e.g. "docker.io/dagger/dagger:main").
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub async fn publish(&self, address: impl Into<String>) -> eyre::Result<String> {
+    ///   Formatted as \[host\]/\[user\]/\[repo\]:\[tag\] (e.g. "docker.io/dagger/dagger:main").
+    pub async fn publish(
+        &self,
+        address: impl Into<String>,
+    ) -> eyre::Result<String> {
         let mut query = self.selection.select("publish");
 
         query = query.arg("address", address.into());
@@ -677,16 +1134,18 @@ impl Container {
     /// Publish returns a fully qualified ref.
     /// It can also publish platform variants.
     ///
+    /// Executes the query and returns its result.
+    ///
     /// # Arguments
     ///
     /// * `address` - Registry's address to publish the image to.
     ///
-    /// Formatted as [host]/[user]/[repo]:[tag] (e.g. "docker.io/dagger/dagger:main").
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
+    ///   Formatted as \[host\]/\[user\]/\[repo\]:\[tag\] (e.g. "docker.io/dagger/dagger:main").
+    /// * `opts` - Optional arguments, see [`ContainerPublishOpts`].
     pub async fn publish_opts(
         &self,
         address: impl Into<String>,
-        opts: ContainerPublishOpts,
+        opts: ContainerPublishOpts
     ) -> eyre::Result<String> {
         let mut query = self.selection.select("publish");
 
@@ -697,57 +1156,100 @@ impl Container {
 
         query.execute(&graphql_client(&self.conn)).await
     }
+
+    /// Publishes this container as a new image to the specified address.
+    /// Publish returns a fully qualified ref.
+    /// It can also publish platform variants.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Registry's address to publish the image to.
+    ///
+    ///   Formatted as \[host\]/\[user\]/\[repo\]:\[tag\] (e.g. "docker.io/dagger/dagger:main").
+    /// * `opts` - Optional arguments, see [`ContainerPublishOpts`].
+    pub async fn publish_with(
+        &self,
+        address: impl Into<String>,
+        opts: impl FnOnce(ContainerPublishOpts) -> ContainerPublishOpts,
+    ) -> eyre::Result<String> {
+        self.publish_opts(address, opts(Default::default())).await
+    }
     /// Retrieves this container's root filesystem. Mounts are not included.
-    pub fn rootfs(&self) -> Directory {
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    pub fn rootfs(
+        &self,
+    ) -> Directory {
         let query = self.selection.select("rootfs");
 
         return Directory {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// The error stream of the last executed command.
     /// Errors if no command has been executed.
-    pub async fn stderr(&self) -> eyre::Result<String> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn stderr(
+        &self,
+    ) -> eyre::Result<String> {
         let query = self.selection.select("stderr");
 
         query.execute(&graphql_client(&self.conn)).await
     }
     /// The output stream of the last executed command.
     /// Errors if no command has been executed.
-    pub async fn stdout(&self) -> eyre::Result<String> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn stdout(
+        &self,
+    ) -> eyre::Result<String> {
         let query = self.selection.select("stdout");
 
         query.execute(&graphql_client(&self.conn)).await
     }
     /// Retrieves the user to be set for all commands.
-    pub async fn user(&self) -> eyre::Result<String> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn user(
+        &self,
+    ) -> eyre::Result<String> {
         let query = self.selection.select("user");
 
         query.execute(&graphql_client(&self.conn)).await
     }
     /// Configures default arguments for future commands.
     ///
-    /// # Arguments
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
     ///
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn with_default_args(&self) -> Container {
+    /// Optional arguments, see [`ContainerWithDefaultArgsOpts`], are taken by [`Self::with_default_args_opts`] and [`Self::with_default_args_with`].
+    pub fn with_default_args(
+        &self,
+    ) -> Container {
         let query = self.selection.select("withDefaultArgs");
 
         return Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
 
     /// Configures default arguments for future commands.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn with_default_args_opts<'a>(&self, opts: ContainerWithDefaultArgsOpts<'a>) -> Container {
+    /// * `opts` - Optional arguments, see [`ContainerWithDefaultArgsOpts`].
+    pub fn with_default_args_opts<'a>(
+        &self,
+        opts: ContainerWithDefaultArgsOpts<'a>
+    ) -> Container {
         let mut query = self.selection.select("withDefaultArgs");
 
         if let Some(args) = opts.args {
@@ -758,16 +1260,38 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
+    }
+
+    /// Configures default arguments for future commands.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerWithDefaultArgsOpts`].
+    pub fn with_default_args_with<'a>(
+        &self,
+        opts: impl FnOnce(ContainerWithDefaultArgsOpts<'a>) -> ContainerWithDefaultArgsOpts<'a>,
+    ) -> Container {
+        self.with_default_args_opts( opts(Default::default()))
     }
     /// Retrieves this container plus a directory written at the given path.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithDirectoryOpts`], are taken by [`Self::with_directory_opts`] and [`Self::with_directory_with`].
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the written directory (e.g., "/tmp/directory").
     /// * `directory` - Identifier of the directory to write
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn with_directory(&self, path: impl Into<String>, directory: DirectoryId) -> Container {
+    ///   Identifies a [`Directory`].
+    pub fn with_directory(
+        &self,
+        path: impl Into<String>,
+        directory: DirectoryId,
+    ) -> Container {
         let mut query = self.selection.select("withDirectory");
 
         query = query.arg("path", path.into());
@@ -777,21 +1301,24 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
 
     /// Retrieves this container plus a directory written at the given path.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the written directory (e.g., "/tmp/directory").
     /// * `directory` - Identifier of the directory to write
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
+    ///   Identifies a [`Directory`].
+    /// * `opts` - Optional arguments, see [`ContainerWithDirectoryOpts`].
     pub fn with_directory_opts<'a>(
         &self,
         path: impl Into<String>,
         directory: DirectoryId,
-        opts: ContainerWithDirectoryOpts<'a>,
+        opts: ContainerWithDirectoryOpts<'a>
     ) -> Container {
         let mut query = self.selection.select("withDirectory");
 
@@ -808,29 +1335,52 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
+    }
+
+    /// Retrieves this container plus a directory written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written directory (e.g., "/tmp/directory").
+    /// * `directory` - Identifier of the directory to write
+    ///   Identifies a [`Directory`].
+    /// * `opts` - Optional arguments, see [`ContainerWithDirectoryOpts`].
+    pub fn with_directory_with<'a>(
+        &self,
+        path: impl Into<String>,
+        directory: DirectoryId,
+        opts: impl FnOnce(ContainerWithDirectoryOpts<'a>) -> ContainerWithDirectoryOpts<'a>,
+    ) -> Container {
+        self.with_directory_opts(path,directory, opts(Default::default()))
     }
     /// Retrieves this container but with a different command entrypoint.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
-    /// * `args` - Entrypoint to use for future executions (e.g., ["go", "run"]).
-    pub fn with_entrypoint(&self, args: Vec<impl Into<String>>) -> Container {
+    /// * `args` - Entrypoint to use for future executions (e.g., \["go", "run"\]).
+    pub fn with_entrypoint(
+        &self,
+        args: Vec<impl Into<String>>,
+    ) -> Container {
         let mut query = self.selection.select("withEntrypoint");
 
-        query = query.arg(
-            "args",
-            args.into_iter().map(|i| i.into()).collect::<Vec<String>>(),
-        );
+        query = query.arg("args", args.into_iter().map(|i| i.into()).collect::<Vec<String>>());
 
         return Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Retrieves this container plus the given environment variable.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `name` - The name of the environment variable (e.g., "HOST").
@@ -849,46 +1399,48 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Retrieves this container after executing the specified command inside it.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithExecOpts`], are taken by [`Self::with_exec_opts`] and [`Self::with_exec_with`].
+    ///
     /// # Arguments
     ///
-    /// * `args` - Command to run instead of the container's default command (e.g., ["run", "main.go"]).
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn with_exec(&self, args: Vec<impl Into<String>>) -> Container {
+    /// * `args` - Command to run instead of the container's default command (e.g., \["run", "main.go"\]).
+    pub fn with_exec(
+        &self,
+        args: Vec<impl Into<String>>,
+    ) -> Container {
         let mut query = self.selection.select("withExec");
 
-        query = query.arg(
-            "args",
-            args.into_iter().map(|i| i.into()).collect::<Vec<String>>(),
-        );
+        query = query.arg("args", args.into_iter().map(|i| i.into()).collect::<Vec<String>>());
 
         return Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
 
     /// Retrieves this container after executing the specified command inside it.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
-    /// * `args` - Command to run instead of the container's default command (e.g., ["run", "main.go"]).
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
+    /// * `args` - Command to run instead of the container's default command (e.g., \["run", "main.go"\]).
+    /// * `opts` - Optional arguments, see [`ContainerWithExecOpts`].
     pub fn with_exec_opts<'a>(
         &self,
         args: Vec<impl Into<String>>,
-        opts: ContainerWithExecOpts<'a>,
+        opts: ContainerWithExecOpts<'a>
     ) -> Container {
         let mut query = self.selection.select("withExec");
 
-        query = query.arg(
-            "args",
-            args.into_iter().map(|i| i.into()).collect::<Vec<String>>(),
-        );
+        query = query.arg("args", args.into_iter().map(|i| i.into()).collect::<Vec<String>>());
         if let Some(stdin) = opts.stdin {
             query = query.arg("stdin", stdin);
         }
@@ -899,10 +1451,7 @@ impl Container {
             query = query.arg("redirectStderr", redirect_stderr);
         }
         if let Some(experimental_privileged_nesting) = opts.experimental_privileged_nesting {
-            query = query.arg(
-                "experimentalPrivilegedNesting",
-                experimental_privileged_nesting,
-            );
+            query = query.arg("experimentalPrivilegedNesting", experimental_privileged_nesting);
         }
         if let Some(insecure_root_capabilities) = opts.insecure_root_capabilities {
             query = query.arg("insecureRootCapabilities", insecure_root_capabilities);
@@ -912,19 +1461,42 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
+    }
+
+    /// Retrieves this container after executing the specified command inside it.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Command to run instead of the container's default command (e.g., \["run", "main.go"\]).
+    /// * `opts` - Optional arguments, see [`ContainerWithExecOpts`].
+    pub fn with_exec_with<'a>(
+        &self,
+        args: Vec<impl Into<String>>,
+        opts: impl FnOnce(ContainerWithExecOpts<'a>) -> ContainerWithExecOpts<'a>,
+    ) -> Container {
+        self.with_exec_opts(args, opts(Default::default()))
     }
     /// Expose a network port.
     /// Exposed ports serve two purposes:
     /// - For health checks and introspection, when running services
     /// - For setting the EXPOSE OCI field when publishing the container
+    ///
     /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithExposedPortOpts`], are taken by [`Self::with_exposed_port_opts`] and [`Self::with_exposed_port_with`].
+    ///
     /// # Arguments
     ///
     /// * `port` - Port number to expose
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn with_exposed_port(&self, port: isize) -> Container {
+    pub fn with_exposed_port(
+        &self,
+        port: isize,
+    ) -> Container {
         let mut query = self.selection.select("withExposedPort");
 
         query = query.arg("port", port);
@@ -933,23 +1505,26 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
 
     /// Expose a network port.
     /// Exposed ports serve two purposes:
     /// - For health checks and introspection, when running services
     /// - For setting the EXPOSE OCI field when publishing the container
+    ///
     /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `port` - Port number to expose
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
+    /// * `opts` - Optional arguments, see [`ContainerWithExposedPortOpts`].
     pub fn with_exposed_port_opts<'a>(
         &self,
         port: isize,
-        opts: ContainerWithExposedPortOpts<'a>,
+        opts: ContainerWithExposedPortOpts<'a>
     ) -> Container {
         let mut query = self.selection.select("withExposedPort");
 
@@ -965,10 +1540,40 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
+    }
+
+    /// Expose a network port.
+    /// Exposed ports serve two purposes:
+    /// - For health checks and introspection, when running services
+    /// - For setting the EXPOSE OCI field when publishing the container
+    ///
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Port number to expose
+    /// * `opts` - Optional arguments, see [`ContainerWithExposedPortOpts`].
+    pub fn with_exposed_port_with<'a>(
+        &self,
+        port: isize,
+        opts: impl FnOnce(ContainerWithExposedPortOpts<'a>) -> ContainerWithExposedPortOpts<'a>,
+    ) -> Container {
+        self.with_exposed_port_opts(port, opts(Default::default()))
     }
     /// Initializes this container from this DirectoryID.
-    pub fn with_fs(&self, id: DirectoryId) -> Container {
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Identifies a [`Directory`].
+    pub fn with_fs(
+        &self,
+        id: DirectoryId,
+    ) -> Container {
         let mut query = self.selection.select("withFS");
 
         query = query.arg("id", id);
@@ -977,16 +1582,24 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Retrieves this container plus the contents of the given file copied to the given path.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithFileOpts`], are taken by [`Self::with_file_opts`] and [`Self::with_file_with`].
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the copied file (e.g., "/tmp/file.txt").
     /// * `source` - Identifier of the file to copy.
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn with_file(&self, path: impl Into<String>, source: FileId) -> Container {
+    ///   Identifies a [`File`].
+    pub fn with_file(
+        &self,
+        path: impl Into<String>,
+        source: FileId,
+    ) -> Container {
         let mut query = self.selection.select("withFile");
 
         query = query.arg("path", path.into());
@@ -996,21 +1609,24 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
 
     /// Retrieves this container plus the contents of the given file copied to the given path.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the copied file (e.g., "/tmp/file.txt").
     /// * `source` - Identifier of the file to copy.
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
+    ///   Identifies a [`File`].
+    /// * `opts` - Optional arguments, see [`ContainerWithFileOpts`].
     pub fn with_file_opts(
         &self,
         path: impl Into<String>,
         source: FileId,
-        opts: ContainerWithFileOpts,
+        opts: ContainerWithFileOpts
     ) -> Container {
         let mut query = self.selection.select("withFile");
 
@@ -1024,15 +1640,40 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
+    }
+
+    /// Retrieves this container plus the contents of the given file copied to the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the copied file (e.g., "/tmp/file.txt").
+    /// * `source` - Identifier of the file to copy.
+    ///   Identifies a [`File`].
+    /// * `opts` - Optional arguments, see [`ContainerWithFileOpts`].
+    pub fn with_file_with(
+        &self,
+        path: impl Into<String>,
+        source: FileId,
+        opts: impl FnOnce(ContainerWithFileOpts) -> ContainerWithFileOpts,
+    ) -> Container {
+        self.with_file_opts(path,source, opts(Default::default()))
     }
     /// Retrieves this container plus the given label.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `name` - The name of the label (e.g., "org.opencontainers.artifact.created").
     /// * `value` - The value of the label (e.g., "2023-01-01T00:00:00Z").
-    pub fn with_label(&self, name: impl Into<String>, value: impl Into<String>) -> Container {
+    pub fn with_label(
+        &self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Container {
         let mut query = self.selection.select("withLabel");
 
         query = query.arg("name", name.into());
@@ -1042,16 +1683,23 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Retrieves this container plus a cache volume mounted at the given path.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithMountedCacheOpts`], are taken by [`Self::with_mounted_cache_opts`] and [`Self::with_mounted_cache_with`].
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the cache directory (e.g., "/cache/node_modules").
     /// * `cache` - Identifier of the cache volume to mount.
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn with_mounted_cache(&self, path: impl Into<String>, cache: CacheId) -> Container {
+    pub fn with_mounted_cache(
+        &self,
+        path: impl Into<String>,
+        cache: CacheId,
+    ) -> Container {
         let mut query = self.selection.select("withMountedCache");
 
         query = query.arg("path", path.into());
@@ -1061,21 +1709,23 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
 
     /// Retrieves this container plus a cache volume mounted at the given path.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the cache directory (e.g., "/cache/node_modules").
     /// * `cache` - Identifier of the cache volume to mount.
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
+    /// * `opts` - Optional arguments, see [`ContainerWithMountedCacheOpts`].
     pub fn with_mounted_cache_opts(
         &self,
         path: impl Into<String>,
         cache: CacheId,
-        opts: ContainerWithMountedCacheOpts,
+        opts: ContainerWithMountedCacheOpts
     ) -> Container {
         let mut query = self.selection.select("withMountedCache");
 
@@ -1092,14 +1742,35 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
+    }
+
+    /// Retrieves this container plus a cache volume mounted at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the cache directory (e.g., "/cache/node_modules").
+    /// * `cache` - Identifier of the cache volume to mount.
+    /// * `opts` - Optional arguments, see [`ContainerWithMountedCacheOpts`].
+    pub fn with_mounted_cache_with(
+        &self,
+        path: impl Into<String>,
+        cache: CacheId,
+        opts: impl FnOnce(ContainerWithMountedCacheOpts) -> ContainerWithMountedCacheOpts,
+    ) -> Container {
+        self.with_mounted_cache_opts(path,cache, opts(Default::default()))
     }
     /// Retrieves this container plus a directory mounted at the given path.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the mounted directory (e.g., "/mnt/directory").
     /// * `source` - Identifier of the mounted directory.
+    ///   Identifies a [`Directory`].
     pub fn with_mounted_directory(
         &self,
         path: impl Into<String>,
@@ -1114,15 +1785,22 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Retrieves this container plus a file mounted at the given path.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the mounted file (e.g., "/tmp/file.txt").
     /// * `source` - Identifier of the mounted file.
-    pub fn with_mounted_file(&self, path: impl Into<String>, source: FileId) -> Container {
+    ///   Identifies a [`File`].
+    pub fn with_mounted_file(
+        &self,
+        path: impl Into<String>,
+        source: FileId,
+    ) -> Container {
         let mut query = self.selection.select("withMountedFile");
 
         query = query.arg("path", path.into());
@@ -1132,15 +1810,22 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Retrieves this container plus a secret mounted into a file at the given path.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the secret file (e.g., "/tmp/secret.txt").
     /// * `source` - Identifier of the secret to mount.
-    pub fn with_mounted_secret(&self, path: impl Into<String>, source: SecretId) -> Container {
+    ///   Identifies a [`Secret`].
+    pub fn with_mounted_secret(
+        &self,
+        path: impl Into<String>,
+        source: SecretId,
+    ) -> Container {
         let mut query = self.selection.select("withMountedSecret");
 
         query = query.arg("path", path.into());
@@ -1150,14 +1835,19 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Retrieves this container plus a temporary directory mounted at the given path.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the temporary directory (e.g., "/tmp/temp_dir").
-    pub fn with_mounted_temp(&self, path: impl Into<String>) -> Container {
+    pub fn with_mounted_temp(
+        &self,
+        path: impl Into<String>,
+    ) -> Container {
         let mut query = self.selection.select("withMountedTemp");
 
         query = query.arg("path", path.into());
@@ -1166,15 +1856,21 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Retrieves this container plus a new file written at the given path.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithNewFileOpts`], are taken by [`Self::with_new_file_opts`] and [`Self::with_new_file_with`].
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the written file (e.g., "/tmp/file.txt").
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn with_new_file(&self, path: impl Into<String>) -> Container {
+    pub fn with_new_file(
+        &self,
+        path: impl Into<String>,
+    ) -> Container {
         let mut query = self.selection.select("withNewFile");
 
         query = query.arg("path", path.into());
@@ -1183,19 +1879,21 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
 
     /// Retrieves this container plus a new file written at the given path.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the written file (e.g., "/tmp/file.txt").
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
+    /// * `opts` - Optional arguments, see [`ContainerWithNewFileOpts`].
     pub fn with_new_file_opts<'a>(
         &self,
         path: impl Into<String>,
-        opts: ContainerWithNewFileOpts<'a>,
+        opts: ContainerWithNewFileOpts<'a>
     ) -> Container {
         let mut query = self.selection.select("withNewFile");
 
@@ -1211,16 +1909,35 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
+    }
+
+    /// Retrieves this container plus a new file written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written file (e.g., "/tmp/file.txt").
+    /// * `opts` - Optional arguments, see [`ContainerWithNewFileOpts`].
+    pub fn with_new_file_with<'a>(
+        &self,
+        path: impl Into<String>,
+        opts: impl FnOnce(ContainerWithNewFileOpts<'a>) -> ContainerWithNewFileOpts<'a>,
+    ) -> Container {
+        self.with_new_file_opts(path, opts(Default::default()))
     }
     /// Retrieves this container with a registry authentication for a given address.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `address` - Registry's address to bind the authentication to.
-    /// Formatted as [host]/[user]/[repo]:[tag] (e.g. docker.io/dagger/dagger:main).
+    ///   Formatted as \[host\]/\[user\]/\[repo\]:\[tag\] (e.g. docker.io/dagger/dagger:main).
     /// * `username` - The username of the registry's account (e.g., "Dagger").
     /// * `secret` - The API key, password or token to authenticate to this registry.
+    ///   Identifies a [`Secret`].
     pub fn with_registry_auth(
         &self,
         address: impl Into<String>,
@@ -1237,10 +1954,19 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Initializes this container from this DirectoryID.
-    pub fn with_rootfs(&self, id: DirectoryId) -> Container {
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Identifies a [`Directory`].
+    pub fn with_rootfs(
+        &self,
+        id: DirectoryId,
+    ) -> Container {
         let mut query = self.selection.select("withRootfs");
 
         query = query.arg("id", id);
@@ -1249,15 +1975,22 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Retrieves this container plus an env variable containing the given secret.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `name` - The name of the secret variable (e.g., "API_SECRET").
     /// * `secret` - The identifier of the secret value.
-    pub fn with_secret_variable(&self, name: impl Into<String>, secret: SecretId) -> Container {
+    ///   Identifies a [`Secret`].
+    pub fn with_secret_variable(
+        &self,
+        name: impl Into<String>,
+        secret: SecretId,
+    ) -> Container {
         let mut query = self.selection.select("withSecretVariable");
 
         query = query.arg("name", name.into());
@@ -1267,17 +2000,20 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Establish a runtime dependency on a service. The service will be started automatically when needed and detached when it is no longer needed.
     /// The service will be reachable from the container via the provided hostname alias.
     /// The service dependency will also convey to any files or directories produced by the container.
     /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `alias` - A name that can be used to reach the service from the container
     /// * `service` - Identifier of the service container
+    ///   Identifies a [`Container`].
     pub fn with_service_binding(
         &self,
         alias: impl Into<String>,
@@ -1292,15 +2028,22 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Retrieves this container plus a socket forwarded to the given Unix socket path.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the forwarded Unix socket (e.g., "/tmp/socket").
     /// * `source` - Identifier of the socket to forward.
-    pub fn with_unix_socket(&self, path: impl Into<String>, source: SocketId) -> Container {
+    ///   Identifies a [`Socket`].
+    pub fn with_unix_socket(
+        &self,
+        path: impl Into<String>,
+        source: SocketId,
+    ) -> Container {
         let mut query = self.selection.select("withUnixSocket");
 
         query = query.arg("path", path.into());
@@ -1310,14 +2053,19 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Retrieves this container with a different command user.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `name` - The user to set (e.g., "root").
-    pub fn with_user(&self, name: impl Into<String>) -> Container {
+    pub fn with_user(
+        &self,
+        name: impl Into<String>,
+    ) -> Container {
         let mut query = self.selection.select("withUser");
 
         query = query.arg("name", name.into());
@@ -1326,14 +2074,19 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Retrieves this container with a different working directory.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `path` - The path to set as the working directory (e.g., "/app").
-    pub fn with_workdir(&self, path: impl Into<String>) -> Container {
+    pub fn with_workdir(
+        &self,
+        path: impl Into<String>,
+    ) -> Container {
         let mut query = self.selection.select("withWorkdir");
 
         query = query.arg("path", path.into());
@@ -1342,14 +2095,19 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Retrieves this container minus the given environment variable.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `name` - The name of the environment variable (e.g., "HOST").
-    pub fn without_env_variable(&self, name: impl Into<String>) -> Container {
+    pub fn without_env_variable(
+        &self,
+        name: impl Into<String>,
+    ) -> Container {
         let mut query = self.selection.select("withoutEnvVariable");
 
         query = query.arg("name", name.into());
@@ -1358,16 +2116,22 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Unexpose a previously exposed port.
     /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithoutExposedPortOpts`], are taken by [`Self::without_exposed_port_opts`] and [`Self::without_exposed_port_with`].
+    ///
     /// # Arguments
     ///
     /// * `port` - Port number to unexpose
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn without_exposed_port(&self, port: isize) -> Container {
+    pub fn without_exposed_port(
+        &self,
+        port: isize,
+    ) -> Container {
         let mut query = self.selection.select("withoutExposedPort");
 
         query = query.arg("port", port);
@@ -1376,20 +2140,22 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
 
     /// Unexpose a previously exposed port.
     /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `port` - Port number to unexpose
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
+    /// * `opts` - Optional arguments, see [`ContainerWithoutExposedPortOpts`].
     pub fn without_exposed_port_opts(
         &self,
         port: isize,
-        opts: ContainerWithoutExposedPortOpts,
+        opts: ContainerWithoutExposedPortOpts
     ) -> Container {
         let mut query = self.selection.select("withoutExposedPort");
 
@@ -1402,14 +2168,36 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
+    }
+
+    /// Unexpose a previously exposed port.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Port number to unexpose
+    /// * `opts` - Optional arguments, see [`ContainerWithoutExposedPortOpts`].
+    pub fn without_exposed_port_with(
+        &self,
+        port: isize,
+        opts: impl FnOnce(ContainerWithoutExposedPortOpts) -> ContainerWithoutExposedPortOpts,
+    ) -> Container {
+        self.without_exposed_port_opts(port, opts(Default::default()))
     }
     /// Retrieves this container minus the given environment label.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `name` - The name of the label to remove (e.g., "org.opencontainers.artifact.created").
-    pub fn without_label(&self, name: impl Into<String>) -> Container {
+    pub fn without_label(
+        &self,
+        name: impl Into<String>,
+    ) -> Container {
         let mut query = self.selection.select("withoutLabel");
 
         query = query.arg("name", name.into());
@@ -1418,14 +2206,19 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Retrieves this container after unmounting everything at the given path.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the cache directory (e.g., "/cache/node_modules").
-    pub fn without_mount(&self, path: impl Into<String>) -> Container {
+    pub fn without_mount(
+        &self,
+        path: impl Into<String>,
+    ) -> Container {
         let mut query = self.selection.select("withoutMount");
 
         query = query.arg("path", path.into());
@@ -1434,15 +2227,20 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Retrieves this container without the registry authentication of a given address.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `address` - Registry's address to remove the authentication from.
-    /// Formatted as [host]/[user]/[repo]:[tag] (e.g. docker.io/dagger/dagger:main).
-    pub fn without_registry_auth(&self, address: impl Into<String>) -> Container {
+    ///   Formatted as \[host\]/\[user\]/\[repo\]:\[tag\] (e.g. docker.io/dagger/dagger:main).
+    pub fn without_registry_auth(
+        &self,
+        address: impl Into<String>,
+    ) -> Container {
         let mut query = self.selection.select("withoutRegistryAuth");
 
         query = query.arg("address", address.into());
@@ -1451,14 +2249,19 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Retrieves this container with a previously added Unix socket removed.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the socket to remove (e.g., "/tmp/socket").
-    pub fn without_unix_socket(&self, path: impl Into<String>) -> Container {
+    pub fn without_unix_socket(
+        &self,
+        path: impl Into<String>,
+    ) -> Container {
         let mut query = self.selection.select("withoutUnixSocket");
 
         query = query.arg("path", path.into());
@@ -1467,15 +2270,20 @@ impl Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Retrieves the working directory for all commands.
-    pub async fn workdir(&self) -> eyre::Result<String> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn workdir(
+        &self,
+    ) -> eyre::Result<String> {
         let query = self.selection.select("workdir");
 
         query.execute(&graphql_client(&self.conn)).await
     }
 }
+
 #[derive(Debug, Clone)]
 pub struct Directory {
     pub proc: Arc<Child>,
@@ -1483,8 +2291,10 @@ pub struct Directory {
     pub conn: ConnectParams,
 }
 
-#[derive(Builder, Debug, PartialEq)]
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
 pub struct DirectoryDockerBuildOpts<'a> {
+
     /// Path to the Dockerfile to use (e.g., "frontend.Dockerfile").
     /// Defaults: './Dockerfile'.
     #[builder(setter(into, strip_option), default)]
@@ -1499,14 +2309,50 @@ pub struct DirectoryDockerBuildOpts<'a> {
     #[builder(setter(into, strip_option), default)]
     pub target: Option<&'a str>,
 }
-#[derive(Builder, Debug, PartialEq)]
+
+impl<'a> DirectoryDockerBuildOpts<'a> {
+    /// Path to the Dockerfile to use (e.g., "frontend.Dockerfile").
+    /// Defaults: './Dockerfile'.
+    pub fn dockerfile(mut self, dockerfile: &'a str) -> Self {
+        self.dockerfile = Some(dockerfile);
+        self
+    }
+    /// The platform to build.
+    pub fn platform(mut self, platform: impl Into<Platform>) -> Self {
+        self.platform = Some(platform.into());
+        self
+    }
+    /// Build arguments to use in the build.
+    pub fn build_args(mut self, build_args: Vec<BuildArg>) -> Self {
+        self.build_args = Some(build_args);
+        self
+    }
+    /// Target build stage to build.
+    pub fn target(mut self, target: &'a str) -> Self {
+        self.target = Some(target);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
 pub struct DirectoryEntriesOpts<'a> {
+
     /// Location of the directory to look at (e.g., "/src").
     #[builder(setter(into, strip_option), default)]
     pub path: Option<&'a str>,
 }
-#[derive(Builder, Debug, PartialEq)]
+
+impl<'a> DirectoryEntriesOpts<'a> {
+    /// Location of the directory to look at (e.g., "/src").
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.path = Some(path);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
 pub struct DirectoryPipelineOpts<'a> {
+
     /// Pipeline description.
     #[builder(setter(into, strip_option), default)]
     pub description: Option<&'a str>,
@@ -1514,44 +2360,111 @@ pub struct DirectoryPipelineOpts<'a> {
     #[builder(setter(into, strip_option), default)]
     pub labels: Option<Vec<PipelineLabel>>,
 }
-#[derive(Builder, Debug, PartialEq)]
+
+impl<'a> DirectoryPipelineOpts<'a> {
+    /// Pipeline description.
+    pub fn description(mut self, description: &'a str) -> Self {
+        self.description = Some(description);
+        self
+    }
+    /// Pipeline labels.
+    pub fn labels(mut self, labels: Vec<PipelineLabel>) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
 pub struct DirectoryWithDirectoryOpts<'a> {
-    /// Exclude artifacts that match the given pattern (e.g., ["node_modules/", ".git*"]).
+
+    /// Exclude artifacts that match the given pattern (e.g., \["node_modules/", ".git*"\]).
     #[builder(setter(into, strip_option), default)]
     pub exclude: Option<Vec<&'a str>>,
-    /// Include only artifacts that match the given pattern (e.g., ["app/", "package.*"]).
+    /// Include only artifacts that match the given pattern (e.g., \["app/", "package.*"\]).
     #[builder(setter(into, strip_option), default)]
     pub include: Option<Vec<&'a str>>,
 }
-#[derive(Builder, Debug, PartialEq)]
+
+impl<'a> DirectoryWithDirectoryOpts<'a> {
+    /// Exclude artifacts that match the given pattern (e.g., \["node_modules/", ".git*"\]).
+    pub fn exclude(mut self, exclude: Vec<&'a str>) -> Self {
+        self.exclude = Some(exclude);
+        self
+    }
+    /// Include only artifacts that match the given pattern (e.g., \["app/", "package.*"\]).
+    pub fn include(mut self, include: Vec<&'a str>) -> Self {
+        self.include = Some(include);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
 pub struct DirectoryWithFileOpts {
+
     /// Permission given to the copied file (e.g., 0600).
     /// Default: 0644.
     #[builder(setter(into, strip_option), default)]
     pub permissions: Option<isize>,
 }
-#[derive(Builder, Debug, PartialEq)]
+
+impl DirectoryWithFileOpts {
+    /// Permission given to the copied file (e.g., 0600).
+    /// Default: 0644.
+    pub fn permissions(mut self, permissions: isize) -> Self {
+        self.permissions = Some(permissions);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
 pub struct DirectoryWithNewDirectoryOpts {
+
     /// Permission granted to the created directory (e.g., 0777).
     /// Default: 0755.
     #[builder(setter(into, strip_option), default)]
     pub permissions: Option<isize>,
 }
-#[derive(Builder, Debug, PartialEq)]
+
+impl DirectoryWithNewDirectoryOpts {
+    /// Permission granted to the created directory (e.g., 0777).
+    /// Default: 0755.
+    pub fn permissions(mut self, permissions: isize) -> Self {
+        self.permissions = Some(permissions);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
 pub struct DirectoryWithNewFileOpts {
+
     /// Permission given to the copied file (e.g., 0600).
     /// Default: 0644.
     #[builder(setter(into, strip_option), default)]
     pub permissions: Option<isize>,
 }
 
+impl DirectoryWithNewFileOpts {
+    /// Permission given to the copied file (e.g., 0600).
+    /// Default: 0644.
+    pub fn permissions(mut self, permissions: isize) -> Self {
+        self.permissions = Some(permissions);
+        self
+    }
+}
+
 impl Directory {
     /// Gets the difference between this directory and an another directory.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `other` - Identifier of the directory to compare.
-    pub fn diff(&self, other: DirectoryId) -> Directory {
+    ///   Identifies a [`Directory`].
+    pub fn diff(
+        &self,
+        other: DirectoryId,
+    ) -> Directory {
         let mut query = self.selection.select("diff");
 
         query = query.arg("other", other);
@@ -1560,14 +2473,19 @@ impl Directory {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Retrieves a directory at the given path.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the directory to retrieve (e.g., "/src").
-    pub fn directory(&self, path: impl Into<String>) -> Directory {
+    pub fn directory(
+        &self,
+        path: impl Into<String>,
+    ) -> Directory {
         let mut query = self.selection.select("directory");
 
         query = query.arg("path", path.into());
@@ -1576,29 +2494,36 @@ impl Directory {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Builds a new Docker container from this directory.
     ///
-    /// # Arguments
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
     ///
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn docker_build(&self) -> Container {
+    /// Optional arguments, see [`DirectoryDockerBuildOpts`], are taken by [`Self::docker_build_opts`] and [`Self::docker_build_with`].
+    pub fn docker_build(
+        &self,
+    ) -> Container {
         let query = self.selection.select("dockerBuild");
 
         return Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
 
     /// Builds a new Docker container from this directory.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn docker_build_opts<'a>(&self, opts: DirectoryDockerBuildOpts<'a>) -> Container {
+    /// * `opts` - Optional arguments, see [`DirectoryDockerBuildOpts`].
+    pub fn docker_build_opts<'a>(
+        &self,
+        opts: DirectoryDockerBuildOpts<'a>
+    ) -> Container {
         let mut query = self.selection.select("dockerBuild");
 
         if let Some(dockerfile) = opts.dockerfile {
@@ -1618,14 +2543,30 @@ impl Directory {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
-    /// Returns a list of files and directories at the given path.
+
+    /// Builds a new Docker container from this directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
     ///
     /// # Arguments
     ///
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub async fn entries(&self) -> eyre::Result<Vec<String>> {
+    /// * `opts` - Optional arguments, see [`DirectoryDockerBuildOpts`].
+    pub fn docker_build_with<'a>(
+        &self,
+        opts: impl FnOnce(DirectoryDockerBuildOpts<'a>) -> DirectoryDockerBuildOpts<'a>,
+    ) -> Container {
+        self.docker_build_opts( opts(Default::default()))
+    }
+    /// Returns a list of files and directories at the given path.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`DirectoryEntriesOpts`], are taken by [`Self::entries_opts`] and [`Self::entries_with`].
+    pub async fn entries(
+        &self,
+    ) -> eyre::Result<Vec<String>> {
         let query = self.selection.select("entries");
 
         query.execute(&graphql_client(&self.conn)).await
@@ -1633,12 +2574,14 @@ impl Directory {
 
     /// Returns a list of files and directories at the given path.
     ///
+    /// Executes the query and returns its result.
+    ///
     /// # Arguments
     ///
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
+    /// * `opts` - Optional arguments, see [`DirectoryEntriesOpts`].
     pub async fn entries_opts<'a>(
         &self,
-        opts: DirectoryEntriesOpts<'a>,
+        opts: DirectoryEntriesOpts<'a>
     ) -> eyre::Result<Vec<String>> {
         let mut query = self.selection.select("entries");
 
@@ -1648,12 +2591,31 @@ impl Directory {
 
         query.execute(&graphql_client(&self.conn)).await
     }
+
+    /// Returns a list of files and directories at the given path.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`DirectoryEntriesOpts`].
+    pub async fn entries_with<'a>(
+        &self,
+        opts: impl FnOnce(DirectoryEntriesOpts<'a>) -> DirectoryEntriesOpts<'a>,
+    ) -> eyre::Result<Vec<String>> {
+        self.entries_opts( opts(Default::default())).await
+    }
     /// Writes the contents of the directory to a path on the host.
     ///
+    /// Executes the query and returns its result.
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the copied directory (e.g., "logs/").
-    pub async fn export(&self, path: impl Into<String>) -> eyre::Result<bool> {
+    pub async fn export(
+        &self,
+        path: impl Into<String>,
+    ) -> eyre::Result<bool> {
         let mut query = self.selection.select("export");
 
         query = query.arg("path", path.into());
@@ -1662,10 +2624,15 @@ impl Directory {
     }
     /// Retrieves a file at the given path.
     ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the file to retrieve (e.g., "README.md").
-    pub fn file(&self, path: impl Into<String>) -> File {
+    pub fn file(
+        &self,
+        path: impl Into<String>,
+    ) -> File {
         let mut query = self.selection.select("file");
 
         query = query.arg("path", path.into());
@@ -1674,16 +2641,25 @@ impl Directory {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// The content-addressed identifier of the directory.
-    pub async fn id(&self) -> eyre::Result<DirectoryId> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn id(
+        &self,
+    ) -> eyre::Result<DirectoryId> {
         let query = self.selection.select("id");
 
         query.execute(&graphql_client(&self.conn)).await
     }
     /// load a project's metadata
-    pub fn load_project(&self, config_path: impl Into<String>) -> Project {
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Project`] is awaited.
+    pub fn load_project(
+        &self,
+        config_path: impl Into<String>,
+    ) -> Project {
         let mut query = self.selection.select("loadProject");
 
         query = query.arg("configPath", config_path.into());
@@ -1692,15 +2668,21 @@ impl Directory {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Creates a named sub-pipeline
     ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`DirectoryPipelineOpts`], are taken by [`Self::pipeline_opts`] and [`Self::pipeline_with`].
+    ///
     /// # Arguments
     ///
     /// * `name` - Pipeline name.
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn pipeline(&self, name: impl Into<String>) -> Directory {
+    pub fn pipeline(
+        &self,
+        name: impl Into<String>,
+    ) -> Directory {
         let mut query = self.selection.select("pipeline");
 
         query = query.arg("name", name.into());
@@ -1709,19 +2691,21 @@ impl Directory {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
 
     /// Creates a named sub-pipeline
     ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `name` - Pipeline name.
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
+    /// * `opts` - Optional arguments, see [`DirectoryPipelineOpts`].
     pub fn pipeline_opts<'a>(
         &self,
         name: impl Into<String>,
-        opts: DirectoryPipelineOpts<'a>,
+        opts: DirectoryPipelineOpts<'a>
     ) -> Directory {
         let mut query = self.selection.select("pipeline");
 
@@ -1737,16 +2721,40 @@ impl Directory {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
+    }
+
+    /// Creates a named sub-pipeline
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    /// * `opts` - Optional arguments, see [`DirectoryPipelineOpts`].
+    pub fn pipeline_with<'a>(
+        &self,
+        name: impl Into<String>,
+        opts: impl FnOnce(DirectoryPipelineOpts<'a>) -> DirectoryPipelineOpts<'a>,
+    ) -> Directory {
+        self.pipeline_opts(name, opts(Default::default()))
     }
     /// Retrieves this directory plus a directory written at the given path.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`DirectoryWithDirectoryOpts`], are taken by [`Self::with_directory_opts`] and [`Self::with_directory_with`].
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the written directory (e.g., "/src/").
     /// * `directory` - Identifier of the directory to copy.
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn with_directory(&self, path: impl Into<String>, directory: DirectoryId) -> Directory {
+    ///   Identifies a [`Directory`].
+    pub fn with_directory(
+        &self,
+        path: impl Into<String>,
+        directory: DirectoryId,
+    ) -> Directory {
         let mut query = self.selection.select("withDirectory");
 
         query = query.arg("path", path.into());
@@ -1756,21 +2764,24 @@ impl Directory {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
 
     /// Retrieves this directory plus a directory written at the given path.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the written directory (e.g., "/src/").
     /// * `directory` - Identifier of the directory to copy.
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
+    ///   Identifies a [`Directory`].
+    /// * `opts` - Optional arguments, see [`DirectoryWithDirectoryOpts`].
     pub fn with_directory_opts<'a>(
         &self,
         path: impl Into<String>,
         directory: DirectoryId,
-        opts: DirectoryWithDirectoryOpts<'a>,
+        opts: DirectoryWithDirectoryOpts<'a>
     ) -> Directory {
         let mut query = self.selection.select("withDirectory");
 
@@ -1787,16 +2798,43 @@ impl Directory {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
+    }
+
+    /// Retrieves this directory plus a directory written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written directory (e.g., "/src/").
+    /// * `directory` - Identifier of the directory to copy.
+    ///   Identifies a [`Directory`].
+    /// * `opts` - Optional arguments, see [`DirectoryWithDirectoryOpts`].
+    pub fn with_directory_with<'a>(
+        &self,
+        path: impl Into<String>,
+        directory: DirectoryId,
+        opts: impl FnOnce(DirectoryWithDirectoryOpts<'a>) -> DirectoryWithDirectoryOpts<'a>,
+    ) -> Directory {
+        self.with_directory_opts(path,directory, opts(Default::default()))
     }
     /// Retrieves this directory plus the contents of the given file copied to the given path.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`DirectoryWithFileOpts`], are taken by [`Self::with_file_opts`] and [`Self::with_file_with`].
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the copied file (e.g., "/file.txt").
     /// * `source` - Identifier of the file to copy.
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn with_file(&self, path: impl Into<String>, source: FileId) -> Directory {
+    ///   Identifies a [`File`].
+    pub fn with_file(
+        &self,
+        path: impl Into<String>,
+        source: FileId,
+    ) -> Directory {
         let mut query = self.selection.select("withFile");
 
         query = query.arg("path", path.into());
@@ -1806,21 +2844,24 @@ impl Directory {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
 
     /// Retrieves this directory plus the contents of the given file copied to the given path.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the copied file (e.g., "/file.txt").
     /// * `source` - Identifier of the file to copy.
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
+    ///   Identifies a [`File`].
+    /// * `opts` - Optional arguments, see [`DirectoryWithFileOpts`].
     pub fn with_file_opts(
         &self,
         path: impl Into<String>,
         source: FileId,
-        opts: DirectoryWithFileOpts,
+        opts: DirectoryWithFileOpts
     ) -> Directory {
         let mut query = self.selection.select("withFile");
 
@@ -1834,15 +2875,40 @@ impl Directory {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
+    }
+
+    /// Retrieves this directory plus the contents of the given file copied to the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the copied file (e.g., "/file.txt").
+    /// * `source` - Identifier of the file to copy.
+    ///   Identifies a [`File`].
+    /// * `opts` - Optional arguments, see [`DirectoryWithFileOpts`].
+    pub fn with_file_with(
+        &self,
+        path: impl Into<String>,
+        source: FileId,
+        opts: impl FnOnce(DirectoryWithFileOpts) -> DirectoryWithFileOpts,
+    ) -> Directory {
+        self.with_file_opts(path,source, opts(Default::default()))
     }
     /// Retrieves this directory plus a new directory created at the given path.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`DirectoryWithNewDirectoryOpts`], are taken by [`Self::with_new_directory_opts`] and [`Self::with_new_directory_with`].
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the directory created (e.g., "/logs").
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn with_new_directory(&self, path: impl Into<String>) -> Directory {
+    pub fn with_new_directory(
+        &self,
+        path: impl Into<String>,
+    ) -> Directory {
         let mut query = self.selection.select("withNewDirectory");
 
         query = query.arg("path", path.into());
@@ -1851,19 +2917,21 @@ impl Directory {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
 
     /// Retrieves this directory plus a new directory created at the given path.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the directory created (e.g., "/logs").
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
+    /// * `opts` - Optional arguments, see [`DirectoryWithNewDirectoryOpts`].
     pub fn with_new_directory_opts(
         &self,
         path: impl Into<String>,
-        opts: DirectoryWithNewDirectoryOpts,
+        opts: DirectoryWithNewDirectoryOpts
     ) -> Directory {
         let mut query = self.selection.select("withNewDirectory");
 
@@ -1876,16 +2944,39 @@ impl Directory {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
+    }
+
+    /// Retrieves this directory plus a new directory created at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the directory created (e.g., "/logs").
+    /// * `opts` - Optional arguments, see [`DirectoryWithNewDirectoryOpts`].
+    pub fn with_new_directory_with(
+        &self,
+        path: impl Into<String>,
+        opts: impl FnOnce(DirectoryWithNewDirectoryOpts) -> DirectoryWithNewDirectoryOpts,
+    ) -> Directory {
+        self.with_new_directory_opts(path, opts(Default::default()))
     }
     /// Retrieves this directory plus a new file written at the given path.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`DirectoryWithNewFileOpts`], are taken by [`Self::with_new_file_opts`] and [`Self::with_new_file_with`].
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the written file (e.g., "/file.txt").
     /// * `contents` - Content of the written file (e.g., "Hello world!").
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn with_new_file(&self, path: impl Into<String>, contents: impl Into<String>) -> Directory {
+    pub fn with_new_file(
+        &self,
+        path: impl Into<String>,
+        contents: impl Into<String>,
+    ) -> Directory {
         let mut query = self.selection.select("withNewFile");
 
         query = query.arg("path", path.into());
@@ -1895,21 +2986,23 @@ impl Directory {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
 
     /// Retrieves this directory plus a new file written at the given path.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the written file (e.g., "/file.txt").
     /// * `contents` - Content of the written file (e.g., "Hello world!").
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
+    /// * `opts` - Optional arguments, see [`DirectoryWithNewFileOpts`].
     pub fn with_new_file_opts(
         &self,
         path: impl Into<String>,
         contents: impl Into<String>,
-        opts: DirectoryWithNewFileOpts,
+        opts: DirectoryWithNewFileOpts
     ) -> Directory {
         let mut query = self.selection.select("withNewFile");
 
@@ -1923,16 +3016,39 @@ impl Directory {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
+    }
+
+    /// Retrieves this directory plus a new file written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written file (e.g., "/file.txt").
+    /// * `contents` - Content of the written file (e.g., "Hello world!").
+    /// * `opts` - Optional arguments, see [`DirectoryWithNewFileOpts`].
+    pub fn with_new_file_with(
+        &self,
+        path: impl Into<String>,
+        contents: impl Into<String>,
+        opts: impl FnOnce(DirectoryWithNewFileOpts) -> DirectoryWithNewFileOpts,
+    ) -> Directory {
+        self.with_new_file_opts(path,contents, opts(Default::default()))
     }
     /// Retrieves this directory with all file/dir timestamps set to the given time.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `timestamp` - Timestamp to set dir/files in.
     ///
-    /// Formatted in seconds following Unix epoch (e.g., 1672531199).
-    pub fn with_timestamps(&self, timestamp: isize) -> Directory {
+    ///   Formatted in seconds following Unix epoch (e.g., 1672531199).
+    pub fn with_timestamps(
+        &self,
+        timestamp: isize,
+    ) -> Directory {
         let mut query = self.selection.select("withTimestamps");
 
         query = query.arg("timestamp", timestamp);
@@ -1941,14 +3057,19 @@ impl Directory {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Retrieves this directory with the directory at the given path removed.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the directory to remove (e.g., ".github/").
-    pub fn without_directory(&self, path: impl Into<String>) -> Directory {
+    pub fn without_directory(
+        &self,
+        path: impl Into<String>,
+    ) -> Directory {
         let mut query = self.selection.select("withoutDirectory");
 
         query = query.arg("path", path.into());
@@ -1957,15 +3078,20 @@ impl Directory {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Retrieves this directory with the file at the given path removed.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the file to remove (e.g., "/file.txt").
-    pub fn without_file(&self, path: impl Into<String>) -> Directory {
-        let mut query = self.selection.select("withoutFile");
+    pub fn without_file(
+        &self,
+        path: impl Into<String>,
+    ) -> Directory {
+        let mut query = self.selection.select("withoutFile");
 
         query = query.arg("path", path.into());
 
@@ -1973,9 +3099,10 @@ impl Directory {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
 }
+
 #[derive(Debug, Clone)]
 pub struct EnvVariable {
     pub proc: Arc<Child>,
@@ -1985,18 +3112,27 @@ pub struct EnvVariable {
 
 impl EnvVariable {
     /// The environment variable name.
-    pub async fn name(&self) -> eyre::Result<String> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn name(
+        &self,
+    ) -> eyre::Result<String> {
         let query = self.selection.select("name");
 
         query.execute(&graphql_client(&self.conn)).await
     }
     /// The environment variable value.
-    pub async fn value(&self) -> eyre::Result<String> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn value(
+        &self,
+    ) -> eyre::Result<String> {
         let query = self.selection.select("value");
 
         query.execute(&graphql_client(&self.conn)).await
     }
 }
+
 #[derive(Debug, Clone)]
 pub struct File {
     pub proc: Arc<Child>,
@@ -2006,17 +3142,26 @@ pub struct File {
 
 impl File {
     /// Retrieves the contents of the file.
-    pub async fn contents(&self) -> eyre::Result<String> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn contents(
+        &self,
+    ) -> eyre::Result<String> {
         let query = self.selection.select("contents");
 
         query.execute(&graphql_client(&self.conn)).await
     }
     /// Writes the file to a file path on the host.
     ///
+    /// Executes the query and returns its result.
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the written directory (e.g., "output.txt").
-    pub async fn export(&self, path: impl Into<String>) -> eyre::Result<bool> {
+    pub async fn export(
+        &self,
+        path: impl Into<String>,
+    ) -> eyre::Result<bool> {
         let mut query = self.selection.select("export");
 
         query = query.arg("path", path.into());
@@ -2024,35 +3169,52 @@ impl File {
         query.execute(&graphql_client(&self.conn)).await
     }
     /// Retrieves the content-addressed identifier of the file.
-    pub async fn id(&self) -> eyre::Result<FileId> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn id(
+        &self,
+    ) -> eyre::Result<FileId> {
         let query = self.selection.select("id");
 
         query.execute(&graphql_client(&self.conn)).await
     }
     /// Retrieves a secret referencing the contents of this file.
-    pub fn secret(&self) -> Secret {
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Secret`] is awaited.
+    pub fn secret(
+        &self,
+    ) -> Secret {
         let query = self.selection.select("secret");
 
         return Secret {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Gets the size of the file, in bytes.
-    pub async fn size(&self) -> eyre::Result<isize> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn size(
+        &self,
+    ) -> eyre::Result<isize> {
         let query = self.selection.select("size");
 
         query.execute(&graphql_client(&self.conn)).await
     }
     /// Retrieves this file with its created/modified timestamps set to the given time.
     ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `timestamp` - Timestamp to set dir/files in.
     ///
-    /// Formatted in seconds following Unix epoch (e.g., 1672531199).
-    pub fn with_timestamps(&self, timestamp: isize) -> File {
+    ///   Formatted in seconds following Unix epoch (e.g., 1672531199).
+    pub fn with_timestamps(
+        &self,
+        timestamp: isize,
+    ) -> File {
         let mut query = self.selection.select("withTimestamps");
 
         query = query.arg("timestamp", timestamp);
@@ -2061,9 +3223,10 @@ impl File {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
 }
+
 #[derive(Debug, Clone)]
 pub struct GitRef {
     pub proc: Arc<Child>,
@@ -2071,42 +3234,68 @@ pub struct GitRef {
     pub conn: ConnectParams,
 }
 
-#[derive(Builder, Debug, PartialEq)]
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
 pub struct GitRefTreeOpts<'a> {
+
     #[builder(setter(into, strip_option), default)]
     pub ssh_known_hosts: Option<&'a str>,
+    /// Identifies a [`Socket`].
     #[builder(setter(into, strip_option), default)]
     pub ssh_auth_socket: Option<SocketId>,
 }
 
+impl<'a> GitRefTreeOpts<'a> {
+    pub fn ssh_known_hosts(mut self, ssh_known_hosts: &'a str) -> Self {
+        self.ssh_known_hosts = Some(ssh_known_hosts);
+        self
+    }
+    /// Identifies a [`Socket`].
+    pub fn ssh_auth_socket(mut self, ssh_auth_socket: impl Into<SocketId>) -> Self {
+        self.ssh_auth_socket = Some(ssh_auth_socket.into());
+        self
+    }
+}
+
 impl GitRef {
     /// The digest of the current value of this ref.
-    pub async fn digest(&self) -> eyre::Result<String> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn digest(
+        &self,
+    ) -> eyre::Result<String> {
         let query = self.selection.select("digest");
 
         query.execute(&graphql_client(&self.conn)).await
     }
     /// The filesystem tree at this ref.
     ///
-    /// # Arguments
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
     ///
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn tree(&self) -> Directory {
+    /// Optional arguments, see [`GitRefTreeOpts`], are taken by [`Self::tree_opts`] and [`Self::tree_with`].
+    pub fn tree(
+        &self,
+    ) -> Directory {
         let query = self.selection.select("tree");
 
         return Directory {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
 
     /// The filesystem tree at this ref.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
     /// # Arguments
     ///
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn tree_opts<'a>(&self, opts: GitRefTreeOpts<'a>) -> Directory {
+    /// * `opts` - Optional arguments, see [`GitRefTreeOpts`].
+    pub fn tree_opts<'a>(
+        &self,
+        opts: GitRefTreeOpts<'a>
+    ) -> Directory {
         let mut query = self.selection.select("tree");
 
         if let Some(ssh_known_hosts) = opts.ssh_known_hosts {
@@ -2120,9 +3309,24 @@ impl GitRef {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
+    }
+
+    /// The filesystem tree at this ref.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`GitRefTreeOpts`].
+    pub fn tree_with<'a>(
+        &self,
+        opts: impl FnOnce(GitRefTreeOpts<'a>) -> GitRefTreeOpts<'a>,
+    ) -> Directory {
+        self.tree_opts( opts(Default::default()))
     }
 }
+
 #[derive(Debug, Clone)]
 pub struct GitRepository {
     pub proc: Arc<Child>,
@@ -2133,10 +3337,15 @@ pub struct GitRepository {
 impl GitRepository {
     /// Returns details on one branch.
     ///
+    /// Lazy, the query only runs once a field of the returned [`GitRef`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `name` - Branch's name (e.g., "main").
-    pub fn branch(&self, name: impl Into<String>) -> GitRef {
+    pub fn branch(
+        &self,
+        name: impl Into<String>,
+    ) -> GitRef {
         let mut query = self.selection.select("branch");
 
         query = query.arg("name", name.into());
@@ -2145,20 +3354,29 @@ impl GitRepository {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Lists of branches on the repository.
-    pub async fn branches(&self) -> eyre::Result<Vec<String>> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn branches(
+        &self,
+    ) -> eyre::Result<Vec<String>> {
         let query = self.selection.select("branches");
 
         query.execute(&graphql_client(&self.conn)).await
     }
     /// Returns details on one commit.
     ///
+    /// Lazy, the query only runs once a field of the returned [`GitRef`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `id` - Identifier of the commit (e.g., "b6315d8f2810962c601af73f86831f6866ea798b").
-    pub fn commit(&self, id: impl Into<String>) -> GitRef {
+    pub fn commit(
+        &self,
+        id: impl Into<String>,
+    ) -> GitRef {
         let mut query = self.selection.select("commit");
 
         query = query.arg("id", id.into());
@@ -2167,14 +3385,19 @@ impl GitRepository {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Returns details on one tag.
     ///
+    /// Lazy, the query only runs once a field of the returned [`GitRef`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `name` - Tag's name (e.g., "v0.3.9").
-    pub fn tag(&self, name: impl Into<String>) -> GitRef {
+    pub fn tag(
+        &self,
+        name: impl Into<String>,
+    ) -> GitRef {
         let mut query = self.selection.select("tag");
 
         query = query.arg("name", name.into());
@@ -2183,15 +3406,20 @@ impl GitRepository {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Lists of tags on the repository.
-    pub async fn tags(&self) -> eyre::Result<Vec<String>> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn tags(
+        &self,
+    ) -> eyre::Result<Vec<String>> {
         let query = self.selection.select("tags");
 
         query.execute(&graphql_client(&self.conn)).await
     }
 }
+
 #[derive(Debug, Clone)]
 pub struct Host {
     pub proc: Arc<Child>,
@@ -2199,33 +3427,69 @@ pub struct Host {
     pub conn: ConnectParams,
 }
 
-#[derive(Builder, Debug, PartialEq)]
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
 pub struct HostDirectoryOpts<'a> {
-    /// Exclude artifacts that match the given pattern (e.g., ["node_modules/", ".git*"]).
+
+    /// Exclude artifacts that match the given pattern (e.g., \["node_modules/", ".git*"\]).
     #[builder(setter(into, strip_option), default)]
     pub exclude: Option<Vec<&'a str>>,
-    /// Include only artifacts that match the given pattern (e.g., ["app/", "package.*"]).
+    /// Include only artifacts that match the given pattern (e.g., \["app/", "package.*"\]).
     #[builder(setter(into, strip_option), default)]
     pub include: Option<Vec<&'a str>>,
 }
-#[derive(Builder, Debug, PartialEq)]
+
+impl<'a> HostDirectoryOpts<'a> {
+    /// Exclude artifacts that match the given pattern (e.g., \["node_modules/", ".git*"\]).
+    pub fn exclude(mut self, exclude: Vec<&'a str>) -> Self {
+        self.exclude = Some(exclude);
+        self
+    }
+    /// Include only artifacts that match the given pattern (e.g., \["app/", "package.*"\]).
+    pub fn include(mut self, include: Vec<&'a str>) -> Self {
+        self.include = Some(include);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
 pub struct HostWorkdirOpts<'a> {
-    /// Exclude artifacts that match the given pattern (e.g., ["node_modules/", ".git*"]).
+
+    /// Exclude artifacts that match the given pattern (e.g., \["node_modules/", ".git*"\]).
     #[builder(setter(into, strip_option), default)]
     pub exclude: Option<Vec<&'a str>>,
-    /// Include only artifacts that match the given pattern (e.g., ["app/", "package.*"]).
+    /// Include only artifacts that match the given pattern (e.g., \["app/", "package.*"\]).
     #[builder(setter(into, strip_option), default)]
     pub include: Option<Vec<&'a str>>,
 }
 
+impl<'a> HostWorkdirOpts<'a> {
+    /// Exclude artifacts that match the given pattern (e.g., \["node_modules/", ".git*"\]).
+    pub fn exclude(mut self, exclude: Vec<&'a str>) -> Self {
+        self.exclude = Some(exclude);
+        self
+    }
+    /// Include only artifacts that match the given pattern (e.g., \["app/", "package.*"\]).
+    pub fn include(mut self, include: Vec<&'a str>) -> Self {
+        self.include = Some(include);
+        self
+    }
+}
+
 impl Host {
     /// Accesses a directory on the host.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`HostDirectoryOpts`], are taken by [`Self::directory_opts`] and [`Self::directory_with`].
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the directory to access (e.g., ".").
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn directory(&self, path: impl Into<String>) -> Directory {
+    pub fn directory(
+        &self,
+        path: impl Into<String>,
+    ) -> Directory {
         let mut query = self.selection.select("directory");
 
         query = query.arg("path", path.into());
@@ -2234,19 +3498,21 @@ impl Host {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
 
     /// Accesses a directory on the host.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the directory to access (e.g., ".").
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
+    /// * `opts` - Optional arguments, see [`HostDirectoryOpts`].
     pub fn directory_opts<'a>(
         &self,
         path: impl Into<String>,
-        opts: HostDirectoryOpts<'a>,
+        opts: HostDirectoryOpts<'a>
     ) -> Directory {
         let mut query = self.selection.select("directory");
 
@@ -2262,14 +3528,35 @@ impl Host {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
+    }
+
+    /// Accesses a directory on the host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the directory to access (e.g., ".").
+    /// * `opts` - Optional arguments, see [`HostDirectoryOpts`].
+    pub fn directory_with<'a>(
+        &self,
+        path: impl Into<String>,
+        opts: impl FnOnce(HostDirectoryOpts<'a>) -> HostDirectoryOpts<'a>,
+    ) -> Directory {
+        self.directory_opts(path, opts(Default::default()))
     }
     /// Accesses an environment variable on the host.
     ///
+    /// Lazy, the query only runs once a field of the returned [`HostVariable`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `name` - Name of the environment variable (e.g., "PATH").
-    pub fn env_variable(&self, name: impl Into<String>) -> HostVariable {
+    pub fn env_variable(
+        &self,
+        name: impl Into<String>,
+    ) -> HostVariable {
         let mut query = self.selection.select("envVariable");
 
         query = query.arg("name", name.into());
@@ -2278,14 +3565,19 @@ impl Host {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Accesses a Unix socket on the host.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Socket`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `path` - Location of the Unix socket (e.g., "/var/run/docker.sock").
-    pub fn unix_socket(&self, path: impl Into<String>) -> Socket {
+    pub fn unix_socket(
+        &self,
+        path: impl Into<String>,
+    ) -> Socket {
         let mut query = self.selection.select("unixSocket");
 
         query = query.arg("path", path.into());
@@ -2294,29 +3586,36 @@ impl Host {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Retrieves the current working directory on the host.
     ///
-    /// # Arguments
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
     ///
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn workdir(&self) -> Directory {
+    /// Optional arguments, see [`HostWorkdirOpts`], are taken by [`Self::workdir_opts`] and [`Self::workdir_with`].
+    pub fn workdir(
+        &self,
+    ) -> Directory {
         let query = self.selection.select("workdir");
 
         return Directory {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
 
     /// Retrieves the current working directory on the host.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
     /// # Arguments
     ///
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn workdir_opts<'a>(&self, opts: HostWorkdirOpts<'a>) -> Directory {
+    /// * `opts` - Optional arguments, see [`HostWorkdirOpts`].
+    pub fn workdir_opts<'a>(
+        &self,
+        opts: HostWorkdirOpts<'a>
+    ) -> Directory {
         let mut query = self.selection.select("workdir");
 
         if let Some(exclude) = opts.exclude {
@@ -2330,9 +3629,24 @@ impl Host {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
+    }
+
+    /// Retrieves the current working directory on the host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`HostWorkdirOpts`].
+    pub fn workdir_with<'a>(
+        &self,
+        opts: impl FnOnce(HostWorkdirOpts<'a>) -> HostWorkdirOpts<'a>,
+    ) -> Directory {
+        self.workdir_opts( opts(Default::default()))
     }
 }
+
 #[derive(Debug, Clone)]
 pub struct HostVariable {
     pub proc: Arc<Child>,
@@ -2342,22 +3656,31 @@ pub struct HostVariable {
 
 impl HostVariable {
     /// A secret referencing the value of this variable.
-    pub fn secret(&self) -> Secret {
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Secret`] is awaited.
+    pub fn secret(
+        &self,
+    ) -> Secret {
         let query = self.selection.select("secret");
 
         return Secret {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// The value of this variable.
-    pub async fn value(&self) -> eyre::Result<String> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn value(
+        &self,
+    ) -> eyre::Result<String> {
         let query = self.selection.select("value");
 
         query.execute(&graphql_client(&self.conn)).await
     }
 }
+
 #[derive(Debug, Clone)]
 pub struct Label {
     pub proc: Arc<Child>,
@@ -2367,18 +3690,27 @@ pub struct Label {
 
 impl Label {
     /// The label name.
-    pub async fn name(&self) -> eyre::Result<String> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn name(
+        &self,
+    ) -> eyre::Result<String> {
         let query = self.selection.select("name");
 
         query.execute(&graphql_client(&self.conn)).await
     }
     /// The label value.
-    pub async fn value(&self) -> eyre::Result<String> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn value(
+        &self,
+    ) -> eyre::Result<String> {
         let query = self.selection.select("value");
 
         query.execute(&graphql_client(&self.conn)).await
     }
 }
+
 #[derive(Debug, Clone)]
 pub struct Port {
     pub proc: Arc<Child>,
@@ -2388,24 +3720,37 @@ pub struct Port {
 
 impl Port {
     /// The port description.
-    pub async fn description(&self) -> eyre::Result<String> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn description(
+        &self,
+    ) -> eyre::Result<String> {
         let query = self.selection.select("description");
 
         query.execute(&graphql_client(&self.conn)).await
     }
     /// The port number.
-    pub async fn port(&self) -> eyre::Result<isize> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn port(
+        &self,
+    ) -> eyre::Result<isize> {
         let query = self.selection.select("port");
 
         query.execute(&graphql_client(&self.conn)).await
     }
     /// The transport layer network protocol.
-    pub async fn protocol(&self) -> eyre::Result<NetworkProtocol> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn protocol(
+        &self,
+    ) -> eyre::Result<NetworkProtocol> {
         let query = self.selection.select("protocol");
 
         query.execute(&graphql_client(&self.conn)).await
     }
 }
+
 #[derive(Debug, Clone)]
 pub struct Project {
     pub proc: Arc<Child>,
@@ -2415,50 +3760,75 @@ pub struct Project {
 
 impl Project {
     /// extensions in this project
-    pub fn extensions(&self) -> Vec<Project> {
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Project`] is awaited.
+    pub fn extensions(
+        &self,
+    ) -> Vec<Project> {
         let query = self.selection.select("extensions");
 
         return vec![Project {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        }];
+        }]
     }
     /// Code files generated by the SDKs in the project
-    pub fn generated_code(&self) -> Directory {
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    pub fn generated_code(
+        &self,
+    ) -> Directory {
         let query = self.selection.select("generatedCode");
 
         return Directory {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// install the project's schema
-    pub async fn install(&self) -> eyre::Result<bool> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn install(
+        &self,
+    ) -> eyre::Result<bool> {
         let query = self.selection.select("install");
 
         query.execute(&graphql_client(&self.conn)).await
     }
     /// name of the project
-    pub async fn name(&self) -> eyre::Result<String> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn name(
+        &self,
+    ) -> eyre::Result<String> {
         let query = self.selection.select("name");
 
         query.execute(&graphql_client(&self.conn)).await
     }
     /// schema provided by the project
-    pub async fn schema(&self) -> eyre::Result<String> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn schema(
+        &self,
+    ) -> eyre::Result<String> {
         let query = self.selection.select("schema");
 
         query.execute(&graphql_client(&self.conn)).await
     }
     /// sdk used to generate code for and/or execute this project
-    pub async fn sdk(&self) -> eyre::Result<String> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn sdk(
+        &self,
+    ) -> eyre::Result<String> {
         let query = self.selection.select("sdk");
 
         query.execute(&graphql_client(&self.conn)).await
     }
 }
+
 #[derive(Debug, Clone)]
 pub struct Query {
     pub proc: Arc<Child>,
@@ -2466,35 +3836,92 @@ pub struct Query {
     pub conn: ConnectParams,
 }
 
-#[derive(Builder, Debug, PartialEq)]
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
 pub struct QueryContainerOpts {
+
+    /// Identifies a [`Container`].
     #[builder(setter(into, strip_option), default)]
     pub id: Option<ContainerId>,
     #[builder(setter(into, strip_option), default)]
     pub platform: Option<Platform>,
 }
-#[derive(Builder, Debug, PartialEq)]
+
+impl QueryContainerOpts {
+    /// Identifies a [`Container`].
+    pub fn id(mut self, id: impl Into<ContainerId>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+    pub fn platform(mut self, platform: impl Into<Platform>) -> Self {
+        self.platform = Some(platform.into());
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
 pub struct QueryDirectoryOpts {
+
+    /// Identifies a [`Directory`].
     #[builder(setter(into, strip_option), default)]
     pub id: Option<DirectoryId>,
 }
-#[derive(Builder, Debug, PartialEq)]
+
+impl QueryDirectoryOpts {
+    /// Identifies a [`Directory`].
+    pub fn id(mut self, id: impl Into<DirectoryId>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
 pub struct QueryGitOpts {
+
     /// Set to true to keep .git directory.
     #[builder(setter(into, strip_option), default)]
     pub keep_git_dir: Option<bool>,
     /// A service which must be started before the repo is fetched.
+    /// Identifies a [`Container`].
     #[builder(setter(into, strip_option), default)]
     pub experimental_service_host: Option<ContainerId>,
 }
-#[derive(Builder, Debug, PartialEq)]
+
+impl QueryGitOpts {
+    /// Set to true to keep .git directory.
+    pub fn keep_git_dir(mut self, keep_git_dir: bool) -> Self {
+        self.keep_git_dir = Some(keep_git_dir);
+        self
+    }
+    /// A service which must be started before the repo is fetched.
+    /// Identifies a [`Container`].
+    pub fn experimental_service_host(mut self, experimental_service_host: impl Into<ContainerId>) -> Self {
+        self.experimental_service_host = Some(experimental_service_host.into());
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
 pub struct QueryHttpOpts {
+
     /// A service which must be started before the URL is fetched.
+    /// Identifies a [`Container`].
     #[builder(setter(into, strip_option), default)]
     pub experimental_service_host: Option<ContainerId>,
 }
-#[derive(Builder, Debug, PartialEq)]
+
+impl QueryHttpOpts {
+    /// A service which must be started before the URL is fetched.
+    /// Identifies a [`Container`].
+    pub fn experimental_service_host(mut self, experimental_service_host: impl Into<ContainerId>) -> Self {
+        self.experimental_service_host = Some(experimental_service_host.into());
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
 pub struct QueryPipelineOpts<'a> {
+
     /// Pipeline description.
     #[builder(setter(into, strip_option), default)]
     pub description: Option<&'a str>,
@@ -2502,19 +3929,48 @@ pub struct QueryPipelineOpts<'a> {
     #[builder(setter(into, strip_option), default)]
     pub labels: Option<Vec<PipelineLabel>>,
 }
-#[derive(Builder, Debug, PartialEq)]
+
+impl<'a> QueryPipelineOpts<'a> {
+    /// Pipeline description.
+    pub fn description(mut self, description: &'a str) -> Self {
+        self.description = Some(description);
+        self
+    }
+    /// Pipeline labels.
+    pub fn labels(mut self, labels: Vec<PipelineLabel>) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
 pub struct QuerySocketOpts {
+
+    /// Identifies a [`Socket`].
     #[builder(setter(into, strip_option), default)]
     pub id: Option<SocketId>,
 }
 
+impl QuerySocketOpts {
+    /// Identifies a [`Socket`].
+    pub fn id(mut self, id: impl Into<SocketId>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+}
+
 impl Query {
     /// Constructs a cache volume for a given cache key.
     ///
+    /// Lazy, the query only runs once a field of the returned [`CacheVolume`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `key` - A string identifier to target this cache volume (e.g., "modules-cache").
-    pub fn cache_volume(&self, key: impl Into<String>) -> CacheVolume {
+    pub fn cache_volume(
+        &self,
+        key: impl Into<String>,
+    ) -> CacheVolume {
         let mut query = self.selection.select("cacheVolume");
 
         query = query.arg("key", key.into());
@@ -2523,24 +3979,26 @@ impl Query {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Loads a container from ID.
     /// Null ID returns an empty container (scratch).
     /// Optional platform argument initializes new containers to execute and publish as that platform.
     /// Platform defaults to that of the builder's host.
     ///
-    /// # Arguments
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
     ///
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn container(&self) -> Container {
+    /// Optional arguments, see [`QueryContainerOpts`], are taken by [`Self::container_opts`] and [`Self::container_with`].
+    pub fn container(
+        &self,
+    ) -> Container {
         let query = self.selection.select("container");
 
         return Container {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
 
     /// Loads a container from ID.
@@ -2548,10 +4006,15 @@ impl Query {
     /// Optional platform argument initializes new containers to execute and publish as that platform.
     /// Platform defaults to that of the builder's host.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
     /// # Arguments
     ///
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn container_opts(&self, opts: QueryContainerOpts) -> Container {
+    /// * `opts` - Optional arguments, see [`QueryContainerOpts`].
+    pub fn container_opts(
+        &self,
+        opts: QueryContainerOpts
+    ) -> Container {
         let mut query = self.selection.select("container");
 
         if let Some(id) = opts.id {
@@ -2565,35 +4028,63 @@ impl Query {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
+    }
+
+    /// Loads a container from ID.
+    /// Null ID returns an empty container (scratch).
+    /// Optional platform argument initializes new containers to execute and publish as that platform.
+    /// Platform defaults to that of the builder's host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`QueryContainerOpts`].
+    pub fn container_with(
+        &self,
+        opts: impl FnOnce(QueryContainerOpts) -> QueryContainerOpts,
+    ) -> Container {
+        self.container_opts( opts(Default::default()))
     }
     /// The default platform of the builder.
-    pub async fn default_platform(&self) -> eyre::Result<Platform> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn default_platform(
+        &self,
+    ) -> eyre::Result<Platform> {
         let query = self.selection.select("defaultPlatform");
 
         query.execute(&graphql_client(&self.conn)).await
     }
     /// Load a directory by ID. No argument produces an empty directory.
     ///
-    /// # Arguments
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
     ///
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn directory(&self) -> Directory {
+    /// Optional arguments, see [`QueryDirectoryOpts`], are taken by [`Self::directory_opts`] and [`Self::directory_with`].
+    pub fn directory(
+        &self,
+    ) -> Directory {
         let query = self.selection.select("directory");
 
         return Directory {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
 
     /// Load a directory by ID. No argument produces an empty directory.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
     /// # Arguments
     ///
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn directory_opts(&self, opts: QueryDirectoryOpts) -> Directory {
+    /// * `opts` - Optional arguments, see [`QueryDirectoryOpts`].
+    pub fn directory_opts(
+        &self,
+        opts: QueryDirectoryOpts
+    ) -> Directory {
         let mut query = self.selection.select("directory");
 
         if let Some(id) = opts.id {
@@ -2604,10 +4095,33 @@ impl Query {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
+    }
+
+    /// Load a directory by ID. No argument produces an empty directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`QueryDirectoryOpts`].
+    pub fn directory_with(
+        &self,
+        opts: impl FnOnce(QueryDirectoryOpts) -> QueryDirectoryOpts,
+    ) -> Directory {
+        self.directory_opts( opts(Default::default()))
     }
     /// Loads a file by ID.
-    pub fn file(&self, id: FileId) -> File {
+    ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Identifies a [`File`].
+    pub fn file(
+        &self,
+        id: FileId,
+    ) -> File {
         let mut query = self.selection.select("file");
 
         query = query.arg("id", id);
@@ -2616,17 +4130,23 @@ impl Query {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Queries a git repository.
     ///
+    /// Lazy, the query only runs once a field of the returned [`GitRepository`] is awaited.
+    ///
+    /// Optional arguments, see [`QueryGitOpts`], are taken by [`Self::git_opts`] and [`Self::git_with`].
+    ///
     /// # Arguments
     ///
     /// * `url` - Url of the git repository.
-    /// Can be formatted as https://{host}/{owner}/{repo}, git@{host}/{owner}/{repo}
-    /// Suffix ".git" is optional.
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn git(&self, url: impl Into<String>) -> GitRepository {
+    ///   Can be formatted as <https://{host}/{owner}/{repo}>, git@{host}/{owner}/{repo}
+    ///   Suffix ".git" is optional.
+    pub fn git(
+        &self,
+        url: impl Into<String>,
+    ) -> GitRepository {
         let mut query = self.selection.select("git");
 
         query = query.arg("url", url.into());
@@ -2635,18 +4155,24 @@ impl Query {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
 
     /// Queries a git repository.
     ///
+    /// Lazy, the query only runs once a field of the returned [`GitRepository`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `url` - Url of the git repository.
-    /// Can be formatted as https://{host}/{owner}/{repo}, git@{host}/{owner}/{repo}
-    /// Suffix ".git" is optional.
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn git_opts(&self, url: impl Into<String>, opts: QueryGitOpts) -> GitRepository {
+    ///   Can be formatted as <https://{host}/{owner}/{repo}>, git@{host}/{owner}/{repo}
+    ///   Suffix ".git" is optional.
+    /// * `opts` - Optional arguments, see [`QueryGitOpts`].
+    pub fn git_opts(
+        &self,
+        url: impl Into<String>,
+        opts: QueryGitOpts
+    ) -> GitRepository {
         let mut query = self.selection.select("git");
 
         query = query.arg("url", url.into());
@@ -2661,25 +4187,53 @@ impl Query {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
+    }
+
+    /// Queries a git repository.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`GitRepository`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Url of the git repository.
+    ///   Can be formatted as <https://{host}/{owner}/{repo}>, git@{host}/{owner}/{repo}
+    ///   Suffix ".git" is optional.
+    /// * `opts` - Optional arguments, see [`QueryGitOpts`].
+    pub fn git_with(
+        &self,
+        url: impl Into<String>,
+        opts: impl FnOnce(QueryGitOpts) -> QueryGitOpts,
+    ) -> GitRepository {
+        self.git_opts(url, opts(Default::default()))
     }
     /// Queries the host environment.
-    pub fn host(&self) -> Host {
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Host`] is awaited.
+    pub fn host(
+        &self,
+    ) -> Host {
         let query = self.selection.select("host");
 
         return Host {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Returns a file containing an http remote url content.
     ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is awaited.
+    ///
+    /// Optional arguments, see [`QueryHttpOpts`], are taken by [`Self::http_opts`] and [`Self::http_with`].
+    ///
     /// # Arguments
     ///
-    /// * `url` - HTTP url to get the content from (e.g., "https://docs.dagger.io").
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn http(&self, url: impl Into<String>) -> File {
+    /// * `url` - HTTP url to get the content from (e.g., "<https://docs.dagger.io>").
+    pub fn http(
+        &self,
+        url: impl Into<String>,
+    ) -> File {
         let mut query = self.selection.select("http");
 
         query = query.arg("url", url.into());
@@ -2688,16 +4242,22 @@ impl Query {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
 
     /// Returns a file containing an http remote url content.
     ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is awaited.
+    ///
     /// # Arguments
     ///
-    /// * `url` - HTTP url to get the content from (e.g., "https://docs.dagger.io").
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn http_opts(&self, url: impl Into<String>, opts: QueryHttpOpts) -> File {
+    /// * `url` - HTTP url to get the content from (e.g., "<https://docs.dagger.io>").
+    /// * `opts` - Optional arguments, see [`QueryHttpOpts`].
+    pub fn http_opts(
+        &self,
+        url: impl Into<String>,
+        opts: QueryHttpOpts
+    ) -> File {
         let mut query = self.selection.select("http");
 
         query = query.arg("url", url.into());
@@ -2709,15 +4269,37 @@ impl Query {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
+    }
+
+    /// Returns a file containing an http remote url content.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - HTTP url to get the content from (e.g., "<https://docs.dagger.io>").
+    /// * `opts` - Optional arguments, see [`QueryHttpOpts`].
+    pub fn http_with(
+        &self,
+        url: impl Into<String>,
+        opts: impl FnOnce(QueryHttpOpts) -> QueryHttpOpts,
+    ) -> File {
+        self.http_opts(url, opts(Default::default()))
     }
     /// Creates a named sub-pipeline.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Query`] is awaited.
+    ///
+    /// Optional arguments, see [`QueryPipelineOpts`], are taken by [`Self::pipeline_opts`] and [`Self::pipeline_with`].
+    ///
     /// # Arguments
     ///
     /// * `name` - Pipeline name.
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn pipeline(&self, name: impl Into<String>) -> Query {
+    pub fn pipeline(
+        &self,
+        name: impl Into<String>,
+    ) -> Query {
         let mut query = self.selection.select("pipeline");
 
         query = query.arg("name", name.into());
@@ -2726,16 +4308,22 @@ impl Query {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
 
     /// Creates a named sub-pipeline.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Query`] is awaited.
+    ///
     /// # Arguments
     ///
     /// * `name` - Pipeline name.
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn pipeline_opts<'a>(&self, name: impl Into<String>, opts: QueryPipelineOpts<'a>) -> Query {
+    /// * `opts` - Optional arguments, see [`QueryPipelineOpts`].
+    pub fn pipeline_opts<'a>(
+        &self,
+        name: impl Into<String>,
+        opts: QueryPipelineOpts<'a>
+    ) -> Query {
         let mut query = self.selection.select("pipeline");
 
         query = query.arg("name", name.into());
@@ -2750,10 +4338,31 @@ impl Query {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
+    }
+
+    /// Creates a named sub-pipeline.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Query`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    /// * `opts` - Optional arguments, see [`QueryPipelineOpts`].
+    pub fn pipeline_with<'a>(
+        &self,
+        name: impl Into<String>,
+        opts: impl FnOnce(QueryPipelineOpts<'a>) -> QueryPipelineOpts<'a>,
+    ) -> Query {
+        self.pipeline_opts(name, opts(Default::default()))
     }
     /// Look up a project by name
-    pub fn project(&self, name: impl Into<String>) -> Project {
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Project`] is awaited.
+    pub fn project(
+        &self,
+        name: impl Into<String>,
+    ) -> Project {
         let mut query = self.selection.select("project");
 
         query = query.arg("name", name.into());
@@ -2762,10 +4371,19 @@ impl Query {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Loads a secret from its ID.
-    pub fn secret(&self, id: SecretId) -> Secret {
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Secret`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Identifies a [`Secret`].
+    pub fn secret(
+        &self,
+        id: SecretId,
+    ) -> Secret {
         let mut query = self.selection.select("secret");
 
         query = query.arg("id", id);
@@ -2774,29 +4392,36 @@ impl Query {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
     /// Loads a socket by its ID.
     ///
-    /// # Arguments
+    /// Lazy, the query only runs once a field of the returned [`Socket`] is awaited.
     ///
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn socket(&self) -> Socket {
+    /// Optional arguments, see [`QuerySocketOpts`], are taken by [`Self::socket_opts`] and [`Self::socket_with`].
+    pub fn socket(
+        &self,
+    ) -> Socket {
         let query = self.selection.select("socket");
 
         return Socket {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
     }
 
     /// Loads a socket by its ID.
     ///
+    /// Lazy, the query only runs once a field of the returned [`Socket`] is awaited.
+    ///
     /// # Arguments
     ///
-    /// * `opt` - optional argument, see inner type for documentation, use <func>_opts to use
-    pub fn socket_opts(&self, opts: QuerySocketOpts) -> Socket {
+    /// * `opts` - Optional arguments, see [`QuerySocketOpts`].
+    pub fn socket_opts(
+        &self,
+        opts: QuerySocketOpts
+    ) -> Socket {
         let mut query = self.selection.select("socket");
 
         if let Some(id) = opts.id {
@@ -2807,9 +4432,24 @@ impl Query {
             proc: self.proc.clone(),
             selection: query,
             conn: self.conn.clone(),
-        };
+        }
+    }
+
+    /// Loads a socket by its ID.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Socket`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`QuerySocketOpts`].
+    pub fn socket_with(
+        &self,
+        opts: impl FnOnce(QuerySocketOpts) -> QuerySocketOpts,
+    ) -> Socket {
+        self.socket_opts( opts(Default::default()))
     }
 }
+
 #[derive(Debug, Clone)]
 pub struct Secret {
     pub proc: Arc<Child>,
@@ -2819,18 +4459,27 @@ pub struct Secret {
 
 impl Secret {
     /// The identifier for this secret.
-    pub async fn id(&self) -> eyre::Result<SecretId> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn id(
+        &self,
+    ) -> eyre::Result<SecretId> {
         let query = self.selection.select("id");
 
         query.execute(&graphql_client(&self.conn)).await
     }
     /// The value of this secret.
-    pub async fn plaintext(&self) -> eyre::Result<String> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn plaintext(
+        &self,
+    ) -> eyre::Result<String> {
         let query = self.selection.select("plaintext");
 
         query.execute(&graphql_client(&self.conn)).await
     }
 }
+
 #[derive(Debug, Clone)]
 pub struct Socket {
     pub proc: Arc<Child>,
@@ -2840,20 +4489,135 @@ pub struct Socket {
 
 impl Socket {
     /// The content-addressed identifier of the socket.
-    pub async fn id(&self) -> eyre::Result<SocketId> {
+    ///
+    /// Executes the query and returns its result.
+    pub async fn id(
+        &self,
+    ) -> eyre::Result<SocketId> {
         let query = self.selection.select("id");
 
         query.execute(&graphql_client(&self.conn)).await
     }
 }
-#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+
+#[derive(Clone, PartialEq, Debug)]
+#[non_exhaustive]
 pub enum CacheSharingMode {
     LOCKED,
     SHARED,
     PRIVATE,
+    /// A value added to the schema after this was generated
+    Unknown(String),
+}
+
+impl CacheSharingMode {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::LOCKED => "LOCKED",
+            Self::SHARED => "SHARED",
+            Self::PRIVATE => "PRIVATE",
+            Self::Unknown(value) => value,
+        }
+    }
+}
+
+impl AsRef<str> for CacheSharingMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
 }
-#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+
+impl Display for CacheSharingMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for CacheSharingMode {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "LOCKED" => Self::LOCKED,
+            "SHARED" => Self::SHARED,
+            "PRIVATE" => Self::PRIVATE,
+            _ => Self::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CacheSharingMode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for CacheSharingMode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+
+        Ok(match value.parse() {
+            Ok(value) => value,
+            Err(never) => match never {},
+        })
+    }
+}
+#[derive(Clone, PartialEq, Debug)]
+#[non_exhaustive]
 pub enum NetworkProtocol {
     TCP,
     UDP,
+    /// A value added to the schema after this was generated
+    Unknown(String),
+}
+
+impl NetworkProtocol {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::TCP => "TCP",
+            Self::UDP => "UDP",
+            Self::Unknown(value) => value,
+        }
+    }
+}
+
+impl AsRef<str> for NetworkProtocol {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for NetworkProtocol {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for NetworkProtocol {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "TCP" => Self::TCP,
+            "UDP" => Self::UDP,
+            _ => Self::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for NetworkProtocol {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for NetworkProtocol {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+
+        Ok(match value.parse() {
+            Ok(value) => value,
+            Err(never) => match never {},
+        })
+    }
 }
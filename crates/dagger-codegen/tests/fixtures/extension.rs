@@ -0,0 +1,215 @@
+use async_trait::async_trait;
+use crate::{Selection, graphql_client};
+use dagger_core::connect_params::ConnectParams;
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::process::Child;
+
+use crate::*;
+
+/// Identifies a [`Greeter`].
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct GreeterId(pub String);
+
+impl Into<GreeterId> for &str {
+    fn into(self) -> GreeterId {
+        GreeterId(self.to_string())
+    }
+}
+
+impl Into<GreeterId> for String {
+    fn into(self) -> GreeterId {
+        GreeterId(self.clone())
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ContainerWithGreetingOpts {
+
+    /// Shout the greeting.
+    #[builder(setter(into, strip_option), default)]
+    pub loud: Option<bool>,
+}
+
+impl ContainerWithGreetingOpts {
+    /// Shout the greeting.
+    pub fn loud(mut self, loud: bool) -> Self {
+        self.loud = Some(loud);
+        self
+    }
+}
+
+/// Fields added to [`Container`] by the schema
+#[async_trait]
+pub trait ContainerExt {
+    /// Runs the greeter in this container.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithGreetingOpts`], are taken by [`Self::with_greeting_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `greeter` - Identifies a [`Greeter`].
+     fn with_greeting(
+        &self,
+        greeter: GreeterId,
+    ) -> Container;
+
+    /// Runs the greeter in this container.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `greeter` - Identifies a [`Greeter`].
+    /// * `opts` - Optional arguments, see [`ContainerWithGreetingOpts`].
+     fn with_greeting_opts(
+        &self,
+        greeter: GreeterId,
+        opts: ContainerWithGreetingOpts
+    ) -> Container;
+}
+
+#[async_trait]
+impl ContainerExt for Container {
+    /// Runs the greeter in this container.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithGreetingOpts`], are taken by [`Self::with_greeting_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `greeter` - Identifies a [`Greeter`].
+     fn with_greeting(
+        &self,
+        greeter: GreeterId,
+    ) -> Container {
+        let mut query = self.selection.select("withGreeting");
+
+        query = query.arg("greeter", greeter);
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }
+    }
+
+    /// Runs the greeter in this container.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `greeter` - Identifies a [`Greeter`].
+    /// * `opts` - Optional arguments, see [`ContainerWithGreetingOpts`].
+     fn with_greeting_opts(
+        &self,
+        greeter: GreeterId,
+        opts: ContainerWithGreetingOpts
+    ) -> Container {
+        let mut query = self.selection.select("withGreeting");
+
+        query = query.arg("greeter", greeter);
+        if let Some(loud) = opts.loud {
+            query = query.arg("loud", loud);
+        }
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }
+    }
+}
+/// Greets people from a container.
+#[derive(Debug, Clone)]
+pub struct Greeter {
+    pub proc: Arc<Child>,
+    pub selection: Selection,
+    pub conn: ConnectParams,
+}
+
+impl Greeter {
+    /// A unique identifier for this greeter.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn id(
+        &self,
+    ) -> eyre::Result<GreeterId> {
+        let query = self.selection.select("id");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// Returns a greeting for name.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn hello(
+        &self,
+        name: impl Into<String>,
+    ) -> eyre::Result<String> {
+        let mut query = self.selection.select("hello");
+
+        query = query.arg("name", name.into());
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// The container the greeter runs in.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    pub fn container(
+        &self,
+    ) -> Container {
+        let query = self.selection.select("container");
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }
+    }
+}
+
+/// Fields added to [`Query`] by the schema
+#[async_trait]
+pub trait QueryExt {
+    /// Greets people from a container.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Greeter`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the greeter.
+     fn greeter(
+        &self,
+        name: impl Into<String> + Send,
+    ) -> Greeter;
+}
+
+#[async_trait]
+impl QueryExt for Query {
+    /// Greets people from a container.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Greeter`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the greeter.
+     fn greeter(
+        &self,
+        name: impl Into<String> + Send,
+    ) -> Greeter {
+        let mut query = self.selection.select("greeter");
+
+        query = query.arg("name", name.into());
+
+        return Greeter {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }
+    }
+}
@@ -0,0 +1,509 @@
+use async_trait::async_trait;
+use crate::client::graphql_client;
+use crate::querybuilder::Selection;
+use dagger_core::connect_params::ConnectParams;
+use derive_builder::Builder;
+use std::sync::Arc;
+use tokio::process::Child;
+
+#[derive(Debug, Clone)]
+pub struct Container {
+    pub proc: Arc<Child>,
+    pub selection: Selection,
+    pub conn: ConnectParams,
+}
+
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ContainerLabelOpts<'a> {
+
+    #[builder(setter(into, strip_option), default)]
+    pub fallback: Option<&'a str>,
+}
+
+impl<'a> ContainerLabelOpts<'a> {
+    pub fn fallback(mut self, fallback: &'a str) -> Self {
+        self.fallback = Some(fallback);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ContainerWithExecOpts<'a> {
+
+    #[builder(setter(into, strip_option), default)]
+    pub stdin: Option<&'a str>,
+}
+
+impl<'a> ContainerWithExecOpts<'a> {
+    pub fn stdin(mut self, stdin: &'a str) -> Self {
+        self.stdin = Some(stdin);
+        self
+    }
+}
+
+impl Container {
+    /// Retrieves a label
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`ContainerLabelOpts`], are taken by [`Self::label_opts`] and [`Self::label_with`].
+    pub async fn label(
+        &self,
+        key: impl Into<String>,
+    ) -> eyre::Result<String> {
+        let mut query = self.selection.select("label");
+
+        query = query.arg("key", key.into());
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+
+    /// Retrieves a label
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerLabelOpts`].
+    pub async fn label_opts<'a>(
+        &self,
+        key: impl Into<String>,
+        opts: ContainerLabelOpts<'a>
+    ) -> eyre::Result<String> {
+        let mut query = self.selection.select("label");
+
+        query = query.arg("key", key.into());
+        if let Some(fallback) = opts.fallback {
+            query = query.arg("fallback", fallback);
+        }
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+
+    /// Retrieves a label
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerLabelOpts`].
+    pub async fn label_with<'a>(
+        &self,
+        key: impl Into<String>,
+        opts: impl FnOnce(ContainerLabelOpts<'a>) -> ContainerLabelOpts<'a>,
+    ) -> eyre::Result<String> {
+        self.label_opts(key, opts(Default::default())).await
+    }
+    /// Executes the query and returns its result.
+    pub async fn stdout(
+        &self,
+    ) -> eyre::Result<String> {
+        let query = self.selection.select("stdout");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithExecOpts`], are taken by [`Self::with_exec_opts`] and [`Self::with_exec_with`].
+    pub fn with_exec(
+        &self,
+        args: Vec<impl Into<String>>,
+    ) -> Container {
+        let mut query = self.selection.select("withExec");
+
+        query = query.arg("args", args.into_iter().map(|i| i.into()).collect::<Vec<String>>());
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }
+    }
+
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerWithExecOpts`].
+    pub fn with_exec_opts<'a>(
+        &self,
+        args: Vec<impl Into<String>>,
+        opts: ContainerWithExecOpts<'a>
+    ) -> Container {
+        let mut query = self.selection.select("withExec");
+
+        query = query.arg("args", args.into_iter().map(|i| i.into()).collect::<Vec<String>>());
+        if let Some(stdin) = opts.stdin {
+            query = query.arg("stdin", stdin);
+        }
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }
+    }
+
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerWithExecOpts`].
+    pub fn with_exec_with<'a>(
+        &self,
+        args: Vec<impl Into<String>>,
+        opts: impl FnOnce(ContainerWithExecOpts<'a>) -> ContainerWithExecOpts<'a>,
+    ) -> Container {
+        self.with_exec_opts(args, opts(Default::default()))
+    }
+}
+
+#[async_trait]
+impl Node for Container {
+    /// Retrieves a label
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`NodeLabelOpts`], are taken by [`Self::label_opts`].
+     async fn label(
+        &self,
+        key: impl Into<String> + Send,
+    ) -> eyre::Result<String> {
+        let mut query = self.selection.select("label");
+
+        query = query.arg("key", key.into());
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+
+    /// Retrieves a label
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`NodeLabelOpts`].
+     async fn label_opts<'a>(
+        &self,
+        key: impl Into<String> + Send,
+        opts: NodeLabelOpts<'a>
+    ) -> eyre::Result<String> {
+        let mut query = self.selection.select("label");
+
+        query = query.arg("key", key.into());
+        if let Some(fallback) = opts.fallback {
+            query = query.arg("fallback", fallback);
+        }
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+}
+#[derive(Debug, Clone)]
+pub struct Directory {
+    pub proc: Arc<Child>,
+    pub selection: Selection,
+    pub conn: ConnectParams,
+}
+
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct DirectoryLabelOpts<'a> {
+
+    #[builder(setter(into, strip_option), default)]
+    pub fallback: Option<&'a str>,
+}
+
+impl<'a> DirectoryLabelOpts<'a> {
+    pub fn fallback(mut self, fallback: &'a str) -> Self {
+        self.fallback = Some(fallback);
+        self
+    }
+}
+
+impl Directory {
+    /// Retrieves a label
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`DirectoryLabelOpts`], are taken by [`Self::label_opts`] and [`Self::label_with`].
+    pub async fn label(
+        &self,
+        key: impl Into<String>,
+    ) -> eyre::Result<String> {
+        let mut query = self.selection.select("label");
+
+        query = query.arg("key", key.into());
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+
+    /// Retrieves a label
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`DirectoryLabelOpts`].
+    pub async fn label_opts<'a>(
+        &self,
+        key: impl Into<String>,
+        opts: DirectoryLabelOpts<'a>
+    ) -> eyre::Result<String> {
+        let mut query = self.selection.select("label");
+
+        query = query.arg("key", key.into());
+        if let Some(fallback) = opts.fallback {
+            query = query.arg("fallback", fallback);
+        }
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+
+    /// Retrieves a label
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`DirectoryLabelOpts`].
+    pub async fn label_with<'a>(
+        &self,
+        key: impl Into<String>,
+        opts: impl FnOnce(DirectoryLabelOpts<'a>) -> DirectoryLabelOpts<'a>,
+    ) -> eyre::Result<String> {
+        self.label_opts(key, opts(Default::default())).await
+    }
+    /// Executes the query and returns its result.
+    pub async fn entries(
+        &self,
+    ) -> eyre::Result<Vec<String>> {
+        let query = self.selection.select("entries");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+}
+
+#[async_trait]
+impl Node for Directory {
+    /// Retrieves a label
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`NodeLabelOpts`], are taken by [`Self::label_opts`].
+     async fn label(
+        &self,
+        key: impl Into<String> + Send,
+    ) -> eyre::Result<String> {
+        let mut query = self.selection.select("label");
+
+        query = query.arg("key", key.into());
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+
+    /// Retrieves a label
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`NodeLabelOpts`].
+     async fn label_opts<'a>(
+        &self,
+        key: impl Into<String> + Send,
+        opts: NodeLabelOpts<'a>
+    ) -> eyre::Result<String> {
+        let mut query = self.selection.select("label");
+
+        query = query.arg("key", key.into());
+        if let Some(fallback) = opts.fallback {
+            query = query.arg("fallback", fallback);
+        }
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+}
+#[derive(Debug, Clone)]
+pub struct Query {
+    pub proc: Arc<Child>,
+    pub selection: Selection,
+    pub conn: ConnectParams,
+}
+
+impl Query {
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    pub fn container(
+        &self,
+    ) -> Container {
+        let query = self.selection.select("container");
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }
+    }
+    /// Lazy, the query only runs once a field of the returned [`AnyNode`] is awaited.
+    pub fn node(
+        &self,
+    ) -> AnyNode {
+        let query = self.selection.select("node");
+
+        return AnyNode {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }
+    }
+    /// Executes the query and returns its result.
+    pub async fn search(
+        &self,
+        text: impl Into<String>,
+    ) -> eyre::Result<SearchResult> {
+        let mut query = self.selection.select("search");
+
+        query = query.arg("text", text.into());
+
+        let typename: String = query
+            .select("__typename")
+            .execute(&graphql_client(&self.conn))
+            .await?;
+
+        SearchResult::from_typename(&typename, self.proc.clone(), query, self.conn.clone())
+    }
+}
+
+/// Something with labels
+#[async_trait]
+pub trait Node {
+    /// Retrieves a label
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`NodeLabelOpts`], are taken by [`Self::label_opts`].
+     async fn label(
+        &self,
+        key: impl Into<String> + Send,
+    ) -> eyre::Result<String>;
+
+    /// Retrieves a label
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`NodeLabelOpts`].
+     async fn label_opts<'a>(
+        &self,
+        key: impl Into<String> + Send,
+        opts: NodeLabelOpts<'a>
+    ) -> eyre::Result<String>;
+}
+
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct NodeLabelOpts<'a> {
+
+    #[builder(setter(into, strip_option), default)]
+    pub fallback: Option<&'a str>,
+}
+
+impl<'a> NodeLabelOpts<'a> {
+    pub fn fallback(mut self, fallback: &'a str) -> Self {
+        self.fallback = Some(fallback);
+        self
+    }
+}
+
+/// Any object implementing [`Node`], use the `as_*` functions to select a concrete type
+#[derive(Debug, Clone)]
+pub struct AnyNode {
+    pub proc: Arc<Child>,
+    pub selection: Selection,
+    pub conn: ConnectParams,
+}
+
+impl AnyNode {
+    pub fn as_container(&self) -> Container {
+        Container {
+            proc: self.proc.clone(),
+            selection: self.selection.inline_fragment("Container"),
+            conn: self.conn.clone(),
+        }
+    }
+    pub fn as_directory(&self) -> Directory {
+        Directory {
+            proc: self.proc.clone(),
+            selection: self.selection.inline_fragment("Directory"),
+            conn: self.conn.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl Node for AnyNode {
+    /// Retrieves a label
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`NodeLabelOpts`], are taken by [`Self::label_opts`].
+     async fn label(
+        &self,
+        key: impl Into<String> + Send,
+    ) -> eyre::Result<String> {
+        let mut query = self.selection.select("label");
+
+        query = query.arg("key", key.into());
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+
+    /// Retrieves a label
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`NodeLabelOpts`].
+     async fn label_opts<'a>(
+        &self,
+        key: impl Into<String> + Send,
+        opts: NodeLabelOpts<'a>
+    ) -> eyre::Result<String> {
+        let mut query = self.selection.select("label");
+
+        query = query.arg("key", key.into());
+        if let Some(fallback) = opts.fallback {
+            query = query.arg("fallback", fallback);
+        }
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+}
+/// A search hit
+#[derive(Debug, Clone)]
+pub enum SearchResult {
+    Container(Container),
+    Directory(Directory),
+}
+
+impl SearchResult {
+    /// Selects the variant matching the `__typename` the engine returned for `selection`
+    pub fn from_typename(
+        typename: &str,
+        proc: Arc<Child>,
+        selection: Selection,
+        conn: ConnectParams,
+    ) -> eyre::Result<Self> {
+        match typename {
+             "Container" => Ok(SearchResult::Container(Container {proc,
+            selection: selection.inline_fragment("Container"),
+            conn,})),
+             "Directory" => Ok(SearchResult::Directory(Directory {proc,
+            selection: selection.inline_fragment("Directory"),
+            conn,})),
+            _ => eyre::bail!("unknown SearchResult type: {}", typename),
+        }
+    }
+}
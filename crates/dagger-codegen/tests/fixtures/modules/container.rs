@@ -0,0 +1,203 @@
+use async_trait::async_trait;
+use crate::client::graphql_client;
+use crate::querybuilder::Selection;
+use dagger_core::connect_params::ConnectParams;
+use derive_builder::Builder;
+use std::sync::Arc;
+use tokio::process::Child;
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct Container {
+    pub proc: Arc<Child>,
+    pub selection: Selection,
+    pub conn: ConnectParams,
+}
+
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ContainerLabelOpts<'a> {
+
+    #[builder(setter(into, strip_option), default)]
+    pub fallback: Option<&'a str>,
+}
+
+impl<'a> ContainerLabelOpts<'a> {
+    pub fn fallback(mut self, fallback: &'a str) -> Self {
+        self.fallback = Some(fallback);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ContainerWithExecOpts<'a> {
+
+    #[builder(setter(into, strip_option), default)]
+    pub stdin: Option<&'a str>,
+}
+
+impl<'a> ContainerWithExecOpts<'a> {
+    pub fn stdin(mut self, stdin: &'a str) -> Self {
+        self.stdin = Some(stdin);
+        self
+    }
+}
+
+impl Container {
+    /// Retrieves a label
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`ContainerLabelOpts`], are taken by [`Self::label_opts`] and [`Self::label_with`].
+    pub async fn label(
+        &self,
+        key: impl Into<String>,
+    ) -> eyre::Result<String> {
+        let mut query = self.selection.select("label");
+
+        query = query.arg("key", key.into());
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+
+    /// Retrieves a label
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerLabelOpts`].
+    pub async fn label_opts<'a>(
+        &self,
+        key: impl Into<String>,
+        opts: ContainerLabelOpts<'a>
+    ) -> eyre::Result<String> {
+        let mut query = self.selection.select("label");
+
+        query = query.arg("key", key.into());
+        if let Some(fallback) = opts.fallback {
+            query = query.arg("fallback", fallback);
+        }
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+
+    /// Retrieves a label
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerLabelOpts`].
+    pub async fn label_with<'a>(
+        &self,
+        key: impl Into<String>,
+        opts: impl FnOnce(ContainerLabelOpts<'a>) -> ContainerLabelOpts<'a>,
+    ) -> eyre::Result<String> {
+        self.label_opts(key, opts(Default::default())).await
+    }
+    /// Executes the query and returns its result.
+    pub async fn stdout(
+        &self,
+    ) -> eyre::Result<String> {
+        let query = self.selection.select("stdout");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithExecOpts`], are taken by [`Self::with_exec_opts`] and [`Self::with_exec_with`].
+    pub fn with_exec(
+        &self,
+        args: Vec<impl Into<String>>,
+    ) -> Container {
+        let mut query = self.selection.select("withExec");
+
+        query = query.arg("args", args.into_iter().map(|i| i.into()).collect::<Vec<String>>());
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }
+    }
+
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerWithExecOpts`].
+    pub fn with_exec_opts<'a>(
+        &self,
+        args: Vec<impl Into<String>>,
+        opts: ContainerWithExecOpts<'a>
+    ) -> Container {
+        let mut query = self.selection.select("withExec");
+
+        query = query.arg("args", args.into_iter().map(|i| i.into()).collect::<Vec<String>>());
+        if let Some(stdin) = opts.stdin {
+            query = query.arg("stdin", stdin);
+        }
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }
+    }
+
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerWithExecOpts`].
+    pub fn with_exec_with<'a>(
+        &self,
+        args: Vec<impl Into<String>>,
+        opts: impl FnOnce(ContainerWithExecOpts<'a>) -> ContainerWithExecOpts<'a>,
+    ) -> Container {
+        self.with_exec_opts(args, opts(Default::default()))
+    }
+}
+
+#[async_trait]
+impl Node for Container {
+    /// Retrieves a label
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`NodeLabelOpts`], are taken by [`Self::label_opts`].
+     async fn label(
+        &self,
+        key: impl Into<String> + Send,
+    ) -> eyre::Result<String> {
+        let mut query = self.selection.select("label");
+
+        query = query.arg("key", key.into());
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+
+    /// Retrieves a label
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`NodeLabelOpts`].
+     async fn label_opts<'a>(
+        &self,
+        key: impl Into<String> + Send,
+        opts: NodeLabelOpts<'a>
+    ) -> eyre::Result<String> {
+        let mut query = self.selection.select("label");
+
+        query = query.arg("key", key.into());
+        if let Some(fallback) = opts.fallback {
+            query = query.arg("fallback", fallback);
+        }
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+}
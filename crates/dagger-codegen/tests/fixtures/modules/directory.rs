@@ -0,0 +1,135 @@
+use async_trait::async_trait;
+use crate::client::graphql_client;
+use crate::querybuilder::Selection;
+use dagger_core::connect_params::ConnectParams;
+use derive_builder::Builder;
+use std::sync::Arc;
+use tokio::process::Child;
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct Directory {
+    pub proc: Arc<Child>,
+    pub selection: Selection,
+    pub conn: ConnectParams,
+}
+
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct DirectoryLabelOpts<'a> {
+
+    #[builder(setter(into, strip_option), default)]
+    pub fallback: Option<&'a str>,
+}
+
+impl<'a> DirectoryLabelOpts<'a> {
+    pub fn fallback(mut self, fallback: &'a str) -> Self {
+        self.fallback = Some(fallback);
+        self
+    }
+}
+
+impl Directory {
+    /// Retrieves a label
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`DirectoryLabelOpts`], are taken by [`Self::label_opts`] and [`Self::label_with`].
+    pub async fn label(
+        &self,
+        key: impl Into<String>,
+    ) -> eyre::Result<String> {
+        let mut query = self.selection.select("label");
+
+        query = query.arg("key", key.into());
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+
+    /// Retrieves a label
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`DirectoryLabelOpts`].
+    pub async fn label_opts<'a>(
+        &self,
+        key: impl Into<String>,
+        opts: DirectoryLabelOpts<'a>
+    ) -> eyre::Result<String> {
+        let mut query = self.selection.select("label");
+
+        query = query.arg("key", key.into());
+        if let Some(fallback) = opts.fallback {
+            query = query.arg("fallback", fallback);
+        }
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+
+    /// Retrieves a label
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`DirectoryLabelOpts`].
+    pub async fn label_with<'a>(
+        &self,
+        key: impl Into<String>,
+        opts: impl FnOnce(DirectoryLabelOpts<'a>) -> DirectoryLabelOpts<'a>,
+    ) -> eyre::Result<String> {
+        self.label_opts(key, opts(Default::default())).await
+    }
+    /// Executes the query and returns its result.
+    pub async fn entries(
+        &self,
+    ) -> eyre::Result<Vec<String>> {
+        let query = self.selection.select("entries");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+}
+
+#[async_trait]
+impl Node for Directory {
+    /// Retrieves a label
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`NodeLabelOpts`], are taken by [`Self::label_opts`].
+     async fn label(
+        &self,
+        key: impl Into<String> + Send,
+    ) -> eyre::Result<String> {
+        let mut query = self.selection.select("label");
+
+        query = query.arg("key", key.into());
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+
+    /// Retrieves a label
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`NodeLabelOpts`].
+     async fn label_opts<'a>(
+        &self,
+        key: impl Into<String> + Send,
+        opts: NodeLabelOpts<'a>
+    ) -> eyre::Result<String> {
+        let mut query = self.selection.select("label");
+
+        query = query.arg("key", key.into());
+        if let Some(fallback) = opts.fallback {
+            query = query.arg("fallback", fallback);
+        }
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+}
@@ -0,0 +1,12 @@
+mod container;
+pub use container::*;
+#[cfg(feature = "directory")]
+mod directory;
+#[cfg(feature = "directory")]
+pub use directory::*;
+mod node;
+pub use node::*;
+mod query;
+pub use query::*;
+mod search_result;
+pub use search_result::*;
@@ -0,0 +1,118 @@
+use async_trait::async_trait;
+use crate::client::graphql_client;
+use crate::querybuilder::Selection;
+use dagger_core::connect_params::ConnectParams;
+use derive_builder::Builder;
+use std::sync::Arc;
+use tokio::process::Child;
+
+use super::*;
+
+/// Something with labels
+#[async_trait]
+pub trait Node {
+    /// Retrieves a label
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`NodeLabelOpts`], are taken by [`Self::label_opts`].
+     async fn label(
+        &self,
+        key: impl Into<String> + Send,
+    ) -> eyre::Result<String>;
+
+    /// Retrieves a label
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`NodeLabelOpts`].
+     async fn label_opts<'a>(
+        &self,
+        key: impl Into<String> + Send,
+        opts: NodeLabelOpts<'a>
+    ) -> eyre::Result<String>;
+}
+
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct NodeLabelOpts<'a> {
+
+    #[builder(setter(into, strip_option), default)]
+    pub fallback: Option<&'a str>,
+}
+
+impl<'a> NodeLabelOpts<'a> {
+    pub fn fallback(mut self, fallback: &'a str) -> Self {
+        self.fallback = Some(fallback);
+        self
+    }
+}
+
+/// Any object implementing [`Node`], use the `as_*` functions to select a concrete type
+#[derive(Debug, Clone)]
+pub struct AnyNode {
+    pub proc: Arc<Child>,
+    pub selection: Selection,
+    pub conn: ConnectParams,
+}
+
+impl AnyNode {
+    pub fn as_container(&self) -> Container {
+        Container {
+            proc: self.proc.clone(),
+            selection: self.selection.inline_fragment("Container"),
+            conn: self.conn.clone(),
+        }
+    }
+    #[cfg(feature = "directory")]
+    pub fn as_directory(&self) -> Directory {
+        Directory {
+            proc: self.proc.clone(),
+            selection: self.selection.inline_fragment("Directory"),
+            conn: self.conn.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl Node for AnyNode {
+    /// Retrieves a label
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`NodeLabelOpts`], are taken by [`Self::label_opts`].
+     async fn label(
+        &self,
+        key: impl Into<String> + Send,
+    ) -> eyre::Result<String> {
+        let mut query = self.selection.select("label");
+
+        query = query.arg("key", key.into());
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+
+    /// Retrieves a label
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`NodeLabelOpts`].
+     async fn label_opts<'a>(
+        &self,
+        key: impl Into<String> + Send,
+        opts: NodeLabelOpts<'a>
+    ) -> eyre::Result<String> {
+        let mut query = self.selection.select("label");
+
+        query = query.arg("key", key.into());
+        if let Some(fallback) = opts.fallback {
+            query = query.arg("fallback", fallback);
+        }
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+}
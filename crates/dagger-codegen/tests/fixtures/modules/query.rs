@@ -0,0 +1,57 @@
+use crate::client::graphql_client;
+use crate::querybuilder::Selection;
+use dagger_core::connect_params::ConnectParams;
+use std::sync::Arc;
+use tokio::process::Child;
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct Query {
+    pub proc: Arc<Child>,
+    pub selection: Selection,
+    pub conn: ConnectParams,
+}
+
+impl Query {
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    pub fn container(
+        &self,
+    ) -> Container {
+        let query = self.selection.select("container");
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }
+    }
+    /// Lazy, the query only runs once a field of the returned [`AnyNode`] is awaited.
+    pub fn node(
+        &self,
+    ) -> AnyNode {
+        let query = self.selection.select("node");
+
+        return AnyNode {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }
+    }
+    /// Executes the query and returns its result.
+    pub async fn search(
+        &self,
+        text: impl Into<String>,
+    ) -> eyre::Result<SearchResult> {
+        let mut query = self.selection.select("search");
+
+        query = query.arg("text", text.into());
+
+        let typename: String = query
+            .select("__typename")
+            .execute(&graphql_client(&self.conn))
+            .await?;
+
+        SearchResult::from_typename(&typename, self.proc.clone(), query, self.conn.clone())
+    }
+}
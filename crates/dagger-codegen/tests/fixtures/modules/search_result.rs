@@ -0,0 +1,34 @@
+use crate::querybuilder::Selection;
+use dagger_core::connect_params::ConnectParams;
+use std::sync::Arc;
+use tokio::process::Child;
+
+use super::*;
+
+/// A search hit
+#[derive(Debug, Clone)]
+pub enum SearchResult {
+    Container(Container),
+    #[cfg(feature = "directory")]
+    Directory(Directory),
+}
+
+impl SearchResult {
+    /// Selects the variant matching the `__typename` the engine returned for `selection`
+    pub fn from_typename(
+        typename: &str,
+        proc: Arc<Child>,
+        selection: Selection,
+        conn: ConnectParams,
+    ) -> eyre::Result<Self> {
+        match typename {
+             "Container" => Ok(SearchResult::Container(Container {proc,
+            selection: selection.inline_fragment("Container"),
+            conn,})),
+            #[cfg(feature = "directory")] "Directory" => Ok(SearchResult::Directory(Directory {proc,
+            selection: selection.inline_fragment("Directory"),
+            conn,})),
+            _ => eyre::bail!("unknown SearchResult type: {}", typename),
+        }
+    }
+}
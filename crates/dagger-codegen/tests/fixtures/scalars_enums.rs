@@ -0,0 +1,457 @@
+use crate::client::graphql_client;
+use crate::querybuilder::Selection;
+use dagger_core::connect_params::ConnectParams;
+use derive_builder::Builder;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::process::Child;
+
+/// A unique container identifier.
+///
+/// Identifies a [`Container`].
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct ContainerId(pub String);
+
+impl Into<ContainerId> for &str {
+    fn into(self) -> ContainerId {
+        ContainerId(self.to_string())
+    }
+}
+
+impl Into<ContainerId> for String {
+    fn into(self) -> ContainerId {
+        ContainerId(self.clone())
+    }
+}
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Platform(pub String);
+
+impl Into<Platform> for &str {
+    fn into(self) -> Platform {
+        Platform(self.to_string())
+    }
+}
+
+impl Into<Platform> for String {
+    fn into(self) -> Platform {
+        Platform(self.clone())
+    }
+}
+/// An OCI-compatible container.
+#[derive(Debug, Clone)]
+pub struct Container {
+    pub proc: Arc<Child>,
+    pub selection: Selection,
+    pub conn: ConnectParams,
+}
+
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ContainerWithExposedPortOpts {
+
+    /// Defaults to `TCP`.
+    #[builder(setter(into, strip_option), default)]
+    pub protocol: Option<NetworkProtocol>,
+}
+
+impl ContainerWithExposedPortOpts {
+    /// Defaults to `TCP`.
+    pub fn protocol(mut self, protocol: NetworkProtocol) -> Self {
+        self.protocol = Some(protocol);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ContainerWithMountedCacheOpts {
+
+    #[builder(setter(into, strip_option), default)]
+    pub sharing: Option<CacheSharingMode>,
+}
+
+impl ContainerWithMountedCacheOpts {
+    pub fn sharing(mut self, sharing: CacheSharingMode) -> Self {
+        self.sharing = Some(sharing);
+        self
+    }
+}
+
+impl Container {
+    /// A unique identifier for this container.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn id(
+        &self,
+    ) -> eyre::Result<ContainerId> {
+        let query = self.selection.select("id");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// Exit code of the last executed command.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn exit_code(
+        &self,
+    ) -> eyre::Result<isize> {
+        let query = self.selection.select("exitCode");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// Executes the query and returns its result.
+    pub async fn healthy(
+        &self,
+    ) -> eyre::Result<bool> {
+        let query = self.selection.select("healthy");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// Executes the query and returns its result.
+    pub async fn protocol(
+        &self,
+    ) -> eyre::Result<NetworkProtocol> {
+        let query = self.selection.select("protocol");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// Expose a network port.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithExposedPortOpts`], are taken by [`Self::with_exposed_port_opts`] and [`Self::with_exposed_port_with`].
+    pub fn with_exposed_port(
+        &self,
+        port: isize,
+    ) -> Container {
+        let mut query = self.selection.select("withExposedPort");
+
+        query = query.arg("port", port);
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }
+    }
+
+    /// Expose a network port.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerWithExposedPortOpts`].
+    pub fn with_exposed_port_opts(
+        &self,
+        port: isize,
+        opts: ContainerWithExposedPortOpts
+    ) -> Container {
+        let mut query = self.selection.select("withExposedPort");
+
+        query = query.arg("port", port);
+        if let Some(protocol) = opts.protocol {
+            query = query.arg_enum("protocol", protocol);
+        }
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }
+    }
+
+    /// Expose a network port.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerWithExposedPortOpts`].
+    pub fn with_exposed_port_with(
+        &self,
+        port: isize,
+        opts: impl FnOnce(ContainerWithExposedPortOpts) -> ContainerWithExposedPortOpts,
+    ) -> Container {
+        self.with_exposed_port_opts(port, opts(Default::default()))
+    }
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithMountedCacheOpts`], are taken by [`Self::with_mounted_cache_opts`] and [`Self::with_mounted_cache_with`].
+    pub fn with_mounted_cache(
+        &self,
+        path: impl Into<String>,
+    ) -> Container {
+        let mut query = self.selection.select("withMountedCache");
+
+        query = query.arg("path", path.into());
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }
+    }
+
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerWithMountedCacheOpts`].
+    pub fn with_mounted_cache_opts(
+        &self,
+        path: impl Into<String>,
+        opts: ContainerWithMountedCacheOpts
+    ) -> Container {
+        let mut query = self.selection.select("withMountedCache");
+
+        query = query.arg("path", path.into());
+        if let Some(sharing) = opts.sharing {
+            query = query.arg_enum("sharing", sharing);
+        }
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }
+    }
+
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerWithMountedCacheOpts`].
+    pub fn with_mounted_cache_with(
+        &self,
+        path: impl Into<String>,
+        opts: impl FnOnce(ContainerWithMountedCacheOpts) -> ContainerWithMountedCacheOpts,
+    ) -> Container {
+        self.with_mounted_cache_opts(path, opts(Default::default()))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Query {
+    pub proc: Arc<Child>,
+    pub selection: Selection,
+    pub conn: ConnectParams,
+}
+
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct QueryContainerOpts {
+
+    /// Identifies a [`Container`].
+    #[builder(setter(into, strip_option), default)]
+    pub id: Option<ContainerId>,
+    #[builder(setter(into, strip_option), default)]
+    pub platform: Option<Platform>,
+}
+
+impl QueryContainerOpts {
+    /// Identifies a [`Container`].
+    pub fn id(mut self, id: impl Into<ContainerId>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+    pub fn platform(mut self, platform: impl Into<Platform>) -> Self {
+        self.platform = Some(platform.into());
+        self
+    }
+}
+
+impl Query {
+    /// Loads a container from ID.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`QueryContainerOpts`], are taken by [`Self::container_opts`] and [`Self::container_with`].
+    pub fn container(
+        &self,
+    ) -> Container {
+        let query = self.selection.select("container");
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }
+    }
+
+    /// Loads a container from ID.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`QueryContainerOpts`].
+    pub fn container_opts(
+        &self,
+        opts: QueryContainerOpts
+    ) -> Container {
+        let mut query = self.selection.select("container");
+
+        if let Some(id) = opts.id {
+            query = query.arg("id", id);
+        }
+        if let Some(platform) = opts.platform {
+            query = query.arg("platform", platform);
+        }
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }
+    }
+
+    /// Loads a container from ID.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`QueryContainerOpts`].
+    pub fn container_with(
+        &self,
+        opts: impl FnOnce(QueryContainerOpts) -> QueryContainerOpts,
+    ) -> Container {
+        self.container_opts( opts(Default::default()))
+    }
+    /// The default platform of the builder.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn default_platform(
+        &self,
+    ) -> eyre::Result<Platform> {
+        let query = self.selection.select("defaultPlatform");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+}
+
+/// Sharing mode of the cache volume.
+#[derive(Clone, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum CacheSharingMode {
+    SHARED,
+    PRIVATE,
+    LOCKED,
+    /// A value added to the schema after this was generated
+    Unknown(String),
+}
+
+impl CacheSharingMode {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::SHARED => "SHARED",
+            Self::PRIVATE => "PRIVATE",
+            Self::LOCKED => "LOCKED",
+            Self::Unknown(value) => value,
+        }
+    }
+}
+
+impl AsRef<str> for CacheSharingMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for CacheSharingMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for CacheSharingMode {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "SHARED" => Self::SHARED,
+            "PRIVATE" => Self::PRIVATE,
+            "LOCKED" => Self::LOCKED,
+            _ => Self::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CacheSharingMode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for CacheSharingMode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+
+        Ok(match value.parse() {
+            Ok(value) => value,
+            Err(never) => match never {},
+        })
+    }
+}
+/// Transport layer network protocol
+#[derive(Clone, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum NetworkProtocol {
+    TCP,
+    UDP,
+    /// A value added to the schema after this was generated
+    Unknown(String),
+}
+
+impl NetworkProtocol {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::TCP => "TCP",
+            Self::UDP => "UDP",
+            Self::Unknown(value) => value,
+        }
+    }
+}
+
+impl AsRef<str> for NetworkProtocol {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for NetworkProtocol {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for NetworkProtocol {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "TCP" => Self::TCP,
+            "UDP" => Self::UDP,
+            _ => Self::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for NetworkProtocol {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for NetworkProtocol {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+
+        Ok(match value.parse() {
+            Ok(value) => value,
+            Err(never) => match never {},
+        })
+    }
+}
@@ -0,0 +1,1212 @@
+use async_trait::async_trait;
+use crate::client::graphql_client;
+use crate::fake::Fake;
+use crate::querybuilder::Selection;
+use dagger_core::connect_params::ConnectParams;
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::process::Child;
+
+/// Identifies a [`Directory`].
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct DirectoryId(pub String);
+
+impl Into<DirectoryId> for &str {
+    fn into(self) -> DirectoryId {
+        DirectoryId(self.to_string())
+    }
+}
+
+impl Into<DirectoryId> for String {
+    fn into(self) -> DirectoryId {
+        DirectoryId(self.clone())
+    }
+}
+/// Key value object that represents a build argument.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct BuildArg {
+    /// The build argument name.
+    #[serde(rename = "name")]
+    pub name: String,
+    /// The build argument value.
+    #[serde(rename = "value")]
+    pub value: String,
+}
+/// A secret mounted while building.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct BuildSecret {
+    /// Name of the secret.
+    #[serde(rename = "secretName")]
+    pub secret_name: String,
+    #[serde(rename = "mountPath")]
+    pub mount_path: Option<String>,
+}
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct PipelineLabel {
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "value")]
+    pub value: String,
+}
+#[derive(Debug, Clone)]
+pub struct Container {
+    pub proc: Arc<Child>,
+    pub selection: Selection,
+    pub conn: ConnectParams,
+}
+
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ContainerWithExecOpts<'a> {
+
+    #[builder(setter(into, strip_option), default)]
+    pub stdin: Option<&'a str>,
+    #[builder(setter(into, strip_option), default)]
+    pub redirect_stdout: Option<&'a str>,
+    #[builder(setter(into, strip_option), default)]
+    pub experimental_privileged_nesting: Option<bool>,
+}
+
+impl<'a> ContainerWithExecOpts<'a> {
+    pub fn stdin(mut self, stdin: &'a str) -> Self {
+        self.stdin = Some(stdin);
+        self
+    }
+    pub fn redirect_stdout(mut self, redirect_stdout: &'a str) -> Self {
+        self.redirect_stdout = Some(redirect_stdout);
+        self
+    }
+    pub fn experimental_privileged_nesting(mut self, experimental_privileged_nesting: bool) -> Self {
+        self.experimental_privileged_nesting = Some(experimental_privileged_nesting);
+        self
+    }
+}
+
+impl Container {
+    /// Executes the query and returns its result.
+    pub async fn stdout(
+        &self,
+    ) -> eyre::Result<String> {
+        let query = self.selection.select("stdout");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithExecOpts`], are taken by [`Self::with_exec_opts`] and [`Self::with_exec_with`].
+    pub fn with_exec(
+        &self,
+        args: Vec<impl Into<String>>,
+    ) -> Container {
+        let mut query = self.selection.select("withExec");
+
+        query = query.arg("args", args.into_iter().map(|i| i.into()).collect::<Vec<String>>());
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }
+    }
+
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerWithExecOpts`].
+    pub fn with_exec_opts<'a>(
+        &self,
+        args: Vec<impl Into<String>>,
+        opts: ContainerWithExecOpts<'a>
+    ) -> Container {
+        let mut query = self.selection.select("withExec");
+
+        query = query.arg("args", args.into_iter().map(|i| i.into()).collect::<Vec<String>>());
+        if let Some(stdin) = opts.stdin {
+            query = query.arg("stdin", stdin);
+        }
+        if let Some(redirect_stdout) = opts.redirect_stdout {
+            query = query.arg("redirectStdout", redirect_stdout);
+        }
+        if let Some(experimental_privileged_nesting) = opts.experimental_privileged_nesting {
+            query = query.arg("experimentalPrivilegedNesting", experimental_privileged_nesting);
+        }
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }
+    }
+
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerWithExecOpts`].
+    pub fn with_exec_with<'a>(
+        &self,
+        args: Vec<impl Into<String>>,
+        opts: impl FnOnce(ContainerWithExecOpts<'a>) -> ContainerWithExecOpts<'a>,
+    ) -> Container {
+        self.with_exec_opts(args, opts(Default::default()))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Directory {
+    pub proc: Arc<Child>,
+    pub selection: Selection,
+    pub conn: ConnectParams,
+}
+
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct DirectoryEntriesOpts<'a> {
+
+    #[builder(setter(into, strip_option), default)]
+    pub path: Option<&'a str>,
+}
+
+impl<'a> DirectoryEntriesOpts<'a> {
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.path = Some(path);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct DirectoryDockerOpts<'a> {
+
+    #[builder(setter(into, strip_option), default)]
+    pub dockerfile: Option<&'a str>,
+    #[builder(setter(into, strip_option), default)]
+    pub platform: Option<&'a str>,
+    #[builder(setter(into, strip_option), default)]
+    pub build_args: Option<Vec<BuildArg>>,
+    #[builder(setter(into, strip_option), default)]
+    pub target: Option<&'a str>,
+}
+
+impl<'a> DirectoryDockerOpts<'a> {
+    pub fn dockerfile(mut self, dockerfile: &'a str) -> Self {
+        self.dockerfile = Some(dockerfile);
+        self
+    }
+    pub fn platform(mut self, platform: &'a str) -> Self {
+        self.platform = Some(platform);
+        self
+    }
+    pub fn build_args(mut self, build_args: Vec<BuildArg>) -> Self {
+        self.build_args = Some(build_args);
+        self
+    }
+    pub fn target(mut self, target: &'a str) -> Self {
+        self.target = Some(target);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct DirectoryPipelineOpts<'a> {
+
+    #[builder(setter(into, strip_option), default)]
+    pub description: Option<&'a str>,
+    #[builder(setter(into, strip_option), default)]
+    pub labels: Option<Vec<PipelineLabel>>,
+}
+
+impl<'a> DirectoryPipelineOpts<'a> {
+    pub fn description(mut self, description: &'a str) -> Self {
+        self.description = Some(description);
+        self
+    }
+    pub fn labels(mut self, labels: Vec<PipelineLabel>) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct DirectoryWithNewFileOpts<'a> {
+
+    #[builder(setter(into, strip_option), default)]
+    pub contents: Option<&'a str>,
+    #[builder(setter(into, strip_option), default)]
+    pub permissions: Option<isize>,
+}
+
+impl<'a> DirectoryWithNewFileOpts<'a> {
+    pub fn contents(mut self, contents: &'a str) -> Self {
+        self.contents = Some(contents);
+        self
+    }
+    pub fn permissions(mut self, permissions: isize) -> Self {
+        self.permissions = Some(permissions);
+        self
+    }
+}
+
+impl Directory {
+    /// Executes the query and returns its result.
+    pub async fn id(
+        &self,
+    ) -> eyre::Result<DirectoryId> {
+        let query = self.selection.select("id");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// Returns a list of files and directories at the given path.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`DirectoryEntriesOpts`], are taken by [`Self::entries_opts`] and [`Self::entries_with`].
+    pub async fn entries(
+        &self,
+    ) -> eyre::Result<Vec<String>> {
+        let query = self.selection.select("entries");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+
+    /// Returns a list of files and directories at the given path.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`DirectoryEntriesOpts`].
+    pub async fn entries_opts<'a>(
+        &self,
+        opts: DirectoryEntriesOpts<'a>
+    ) -> eyre::Result<Vec<String>> {
+        let mut query = self.selection.select("entries");
+
+        if let Some(path) = opts.path {
+            query = query.arg("path", path);
+        }
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+
+    /// Returns a list of files and directories at the given path.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`DirectoryEntriesOpts`].
+    pub async fn entries_with<'a>(
+        &self,
+        opts: impl FnOnce(DirectoryEntriesOpts<'a>) -> DirectoryEntriesOpts<'a>,
+    ) -> eyre::Result<Vec<String>> {
+        self.entries_opts( opts(Default::default())).await
+    }
+    /// Executes the query and returns its result.
+    pub async fn glob(
+        &self,
+        pattern: impl Into<String>,
+    ) -> eyre::Result<Vec<String>> {
+        let mut query = self.selection.select("glob");
+
+        query = query.arg("pattern", pattern.into());
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// Builds a new Docker container from this directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`DirectoryDockerOpts`], are taken by [`Self::docker_opts`] and [`Self::docker_with`].
+    pub fn docker(
+        &self,
+    ) -> Container {
+        let query = self.selection.select("docker");
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }
+    }
+
+    /// Builds a new Docker container from this directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`DirectoryDockerOpts`].
+    pub fn docker_opts<'a>(
+        &self,
+        opts: DirectoryDockerOpts<'a>
+    ) -> Container {
+        let mut query = self.selection.select("docker");
+
+        if let Some(dockerfile) = opts.dockerfile {
+            query = query.arg("dockerfile", dockerfile);
+        }
+        if let Some(platform) = opts.platform {
+            query = query.arg("platform", platform);
+        }
+        if let Some(build_args) = opts.build_args {
+            query = query.arg("buildArgs", build_args);
+        }
+        if let Some(target) = opts.target {
+            query = query.arg("target", target);
+        }
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }
+    }
+
+    /// Builds a new Docker container from this directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`DirectoryDockerOpts`].
+    pub fn docker_with<'a>(
+        &self,
+        opts: impl FnOnce(DirectoryDockerOpts<'a>) -> DirectoryDockerOpts<'a>,
+    ) -> Container {
+        self.docker_opts( opts(Default::default()))
+    }
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`DirectoryPipelineOpts`], are taken by [`Self::pipeline_opts`] and [`Self::pipeline_with`].
+    pub fn pipeline(
+        &self,
+        name: impl Into<String>,
+    ) -> Directory {
+        let mut query = self.selection.select("pipeline");
+
+        query = query.arg("name", name.into());
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }
+    }
+
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`DirectoryPipelineOpts`].
+    pub fn pipeline_opts<'a>(
+        &self,
+        name: impl Into<String>,
+        opts: DirectoryPipelineOpts<'a>
+    ) -> Directory {
+        let mut query = self.selection.select("pipeline");
+
+        query = query.arg("name", name.into());
+        if let Some(description) = opts.description {
+            query = query.arg("description", description);
+        }
+        if let Some(labels) = opts.labels {
+            query = query.arg("labels", labels);
+        }
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }
+    }
+
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`DirectoryPipelineOpts`].
+    pub fn pipeline_with<'a>(
+        &self,
+        name: impl Into<String>,
+        opts: impl FnOnce(DirectoryPipelineOpts<'a>) -> DirectoryPipelineOpts<'a>,
+    ) -> Directory {
+        self.pipeline_opts(name, opts(Default::default()))
+    }
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`DirectoryWithNewFileOpts`], are taken by [`Self::with_new_file_opts`] and [`Self::with_new_file_with`].
+    pub fn with_new_file(
+        &self,
+        path: impl Into<String>,
+    ) -> Directory {
+        let mut query = self.selection.select("withNewFile");
+
+        query = query.arg("path", path.into());
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }
+    }
+
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`DirectoryWithNewFileOpts`].
+    pub fn with_new_file_opts<'a>(
+        &self,
+        path: impl Into<String>,
+        opts: DirectoryWithNewFileOpts<'a>
+    ) -> Directory {
+        let mut query = self.selection.select("withNewFile");
+
+        query = query.arg("path", path.into());
+        if let Some(contents) = opts.contents {
+            query = query.arg("contents", contents);
+        }
+        if let Some(permissions) = opts.permissions {
+            query = query.arg("permissions", permissions);
+        }
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }
+    }
+
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`DirectoryWithNewFileOpts`].
+    pub fn with_new_file_with<'a>(
+        &self,
+        path: impl Into<String>,
+        opts: impl FnOnce(DirectoryWithNewFileOpts<'a>) -> DirectoryWithNewFileOpts<'a>,
+    ) -> Directory {
+        self.with_new_file_opts(path, opts(Default::default()))
+    }
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    pub fn subdirectories(
+        &self,
+    ) -> Vec<Directory> {
+        let query = self.selection.select("subdirectories");
+
+        return vec![Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }]
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Query {
+    pub proc: Arc<Child>,
+    pub selection: Selection,
+    pub conn: ConnectParams,
+}
+
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct QueryDirectoryOpts {
+
+    /// Identifies a [`Directory`].
+    #[builder(setter(into, strip_option), default)]
+    pub id: Option<DirectoryId>,
+}
+
+impl QueryDirectoryOpts {
+    /// Identifies a [`Directory`].
+    pub fn id(mut self, id: impl Into<DirectoryId>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+}
+
+impl Query {
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`QueryDirectoryOpts`], are taken by [`Self::directory_opts`] and [`Self::directory_with`].
+    pub fn directory(
+        &self,
+    ) -> Directory {
+        let query = self.selection.select("directory");
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }
+    }
+
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`QueryDirectoryOpts`].
+    pub fn directory_opts(
+        &self,
+        opts: QueryDirectoryOpts
+    ) -> Directory {
+        let mut query = self.selection.select("directory");
+
+        if let Some(id) = opts.id {
+            query = query.arg("id", id);
+        }
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }
+    }
+
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`QueryDirectoryOpts`].
+    pub fn directory_with(
+        &self,
+        opts: impl FnOnce(QueryDirectoryOpts) -> QueryDirectoryOpts,
+    ) -> Directory {
+        self.directory_opts( opts(Default::default()))
+    }
+}
+
+pub mod api {
+    use super::*;
+
+    /// Trait of [`super::Container`]
+    #[async_trait]
+    pub trait ContainerApi: Send + Sync {
+        type Container: ContainerApi;
+
+        /// Executes the query and returns its result.
+         async fn stdout(
+            &self,
+        ) -> eyre::Result<String>;
+        /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+        ///
+        /// Optional arguments, see [`ContainerWithExecOpts`], are taken by [`Self::with_exec_opts`].
+         fn with_exec(
+            &self,
+            args: Vec<impl Into<String> + Send>,
+        ) -> Self::Container;
+
+        /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+        ///
+        /// # Arguments
+        ///
+        /// * `opts` - Optional arguments, see [`ContainerWithExecOpts`].
+         fn with_exec_opts<'a>(
+            &self,
+            args: Vec<impl Into<String> + Send>,
+            opts: ContainerWithExecOpts<'a>
+        ) -> Self::Container;
+    }
+
+    #[async_trait]
+    impl ContainerApi for super::Container {
+        type Container = super::Container;
+
+        /// Executes the query and returns its result.
+         async fn stdout(
+            &self,
+        ) -> eyre::Result<String> {
+            self.stdout().await
+        }
+        /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+        ///
+        /// Optional arguments, see [`ContainerWithExecOpts`], are taken by [`Self::with_exec_opts`].
+         fn with_exec(
+            &self,
+            args: Vec<impl Into<String> + Send>,
+        ) -> Self::Container {
+            self.with_exec(args)
+        }
+
+        /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+        ///
+        /// # Arguments
+        ///
+        /// * `opts` - Optional arguments, see [`ContainerWithExecOpts`].
+         fn with_exec_opts<'a>(
+            &self,
+            args: Vec<impl Into<String> + Send>,
+            opts: ContainerWithExecOpts<'a>
+        ) -> Self::Container {
+            self.with_exec_opts(args, opts)
+        }
+    }
+
+    /// In-memory fake of [`ContainerApi`]
+    #[derive(Debug, Clone)]
+    pub struct FakeContainer {
+        pub selection: Selection,
+        pub fake: Fake,
+    }
+
+    impl FakeContainer {
+        /// A fake whose queries start at this object, answered by `fake`
+        pub fn new(fake: &Fake) -> Self {
+            Self {
+                selection: Selection::default(),
+                fake: fake.clone(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl ContainerApi for FakeContainer {
+        type Container = FakeContainer;
+
+         async fn stdout(
+            &self,
+        ) -> eyre::Result<String> {
+            let query = self.selection.select("stdout");
+
+            self.fake.execute(&query)
+        }
+         fn with_exec(
+            &self,
+            args: Vec<impl Into<String> + Send>,
+        ) -> Self::Container {
+            let mut query = self.selection.select("withExec");
+
+            query = query.arg("args", args.into_iter().map(|i| i.into()).collect::<Vec<String>>());
+
+            FakeContainer {
+                selection: query,
+                fake: self.fake.clone(),
+            }
+        }
+
+         fn with_exec_opts<'a>(
+            &self,
+            args: Vec<impl Into<String> + Send>,
+            opts: ContainerWithExecOpts<'a>
+        ) -> Self::Container {
+            let mut query = self.selection.select("withExec");
+
+            query = query.arg("args", args.into_iter().map(|i| i.into()).collect::<Vec<String>>());
+            if let Some(stdin) = opts.stdin {
+                query = query.arg("stdin", stdin);
+            }
+            if let Some(redirect_stdout) = opts.redirect_stdout {
+                query = query.arg("redirectStdout", redirect_stdout);
+            }
+            if let Some(experimental_privileged_nesting) = opts.experimental_privileged_nesting {
+                query = query.arg("experimentalPrivilegedNesting", experimental_privileged_nesting);
+            }
+
+            FakeContainer {
+                selection: query,
+                fake: self.fake.clone(),
+            }
+        }
+    }
+    /// Trait of [`super::Directory`]
+    #[async_trait]
+    pub trait DirectoryApi: Send + Sync {
+        type Container: ContainerApi;
+        type Directory: DirectoryApi;
+
+        /// Executes the query and returns its result.
+         async fn id(
+            &self,
+        ) -> eyre::Result<DirectoryId>;
+        /// Returns a list of files and directories at the given path.
+        ///
+        /// Executes the query and returns its result.
+        ///
+        /// Optional arguments, see [`DirectoryEntriesOpts`], are taken by [`Self::entries_opts`].
+         async fn entries(
+            &self,
+        ) -> eyre::Result<Vec<String>>;
+
+        /// Returns a list of files and directories at the given path.
+        ///
+        /// Executes the query and returns its result.
+        ///
+        /// # Arguments
+        ///
+        /// * `opts` - Optional arguments, see [`DirectoryEntriesOpts`].
+         async fn entries_opts<'a>(
+            &self,
+            opts: DirectoryEntriesOpts<'a>
+        ) -> eyre::Result<Vec<String>>;
+
+        /// Executes the query and returns its result.
+         async fn glob(
+            &self,
+            pattern: impl Into<String> + Send,
+        ) -> eyre::Result<Vec<String>>;
+        /// Builds a new Docker container from this directory.
+        ///
+        /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+        ///
+        /// Optional arguments, see [`DirectoryDockerOpts`], are taken by [`Self::docker_opts`].
+         fn docker(
+            &self,
+        ) -> Self::Container;
+
+        /// Builds a new Docker container from this directory.
+        ///
+        /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+        ///
+        /// # Arguments
+        ///
+        /// * `opts` - Optional arguments, see [`DirectoryDockerOpts`].
+         fn docker_opts<'a>(
+            &self,
+            opts: DirectoryDockerOpts<'a>
+        ) -> Self::Container;
+
+        /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+        ///
+        /// Optional arguments, see [`DirectoryPipelineOpts`], are taken by [`Self::pipeline_opts`].
+         fn pipeline(
+            &self,
+            name: impl Into<String> + Send,
+        ) -> Self::Directory;
+
+        /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+        ///
+        /// # Arguments
+        ///
+        /// * `opts` - Optional arguments, see [`DirectoryPipelineOpts`].
+         fn pipeline_opts<'a>(
+            &self,
+            name: impl Into<String> + Send,
+            opts: DirectoryPipelineOpts<'a>
+        ) -> Self::Directory;
+
+        /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+        ///
+        /// Optional arguments, see [`DirectoryWithNewFileOpts`], are taken by [`Self::with_new_file_opts`].
+         fn with_new_file(
+            &self,
+            path: impl Into<String> + Send,
+        ) -> Self::Directory;
+
+        /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+        ///
+        /// # Arguments
+        ///
+        /// * `opts` - Optional arguments, see [`DirectoryWithNewFileOpts`].
+         fn with_new_file_opts<'a>(
+            &self,
+            path: impl Into<String> + Send,
+            opts: DirectoryWithNewFileOpts<'a>
+        ) -> Self::Directory;
+
+        /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+         fn subdirectories(
+            &self,
+        ) -> Vec<Self::Directory>;
+    }
+
+    #[async_trait]
+    impl DirectoryApi for super::Directory {
+        type Container = super::Container;
+        type Directory = super::Directory;
+
+        /// Executes the query and returns its result.
+         async fn id(
+            &self,
+        ) -> eyre::Result<DirectoryId> {
+            self.id().await
+        }
+        /// Returns a list of files and directories at the given path.
+        ///
+        /// Executes the query and returns its result.
+        ///
+        /// Optional arguments, see [`DirectoryEntriesOpts`], are taken by [`Self::entries_opts`].
+         async fn entries(
+            &self,
+        ) -> eyre::Result<Vec<String>> {
+            self.entries().await
+        }
+
+        /// Returns a list of files and directories at the given path.
+        ///
+        /// Executes the query and returns its result.
+        ///
+        /// # Arguments
+        ///
+        /// * `opts` - Optional arguments, see [`DirectoryEntriesOpts`].
+         async fn entries_opts<'a>(
+            &self,
+            opts: DirectoryEntriesOpts<'a>
+        ) -> eyre::Result<Vec<String>> {
+            self.entries_opts(opts).await
+        }
+
+        /// Executes the query and returns its result.
+         async fn glob(
+            &self,
+            pattern: impl Into<String> + Send,
+        ) -> eyre::Result<Vec<String>> {
+            self.glob(pattern).await
+        }
+        /// Builds a new Docker container from this directory.
+        ///
+        /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+        ///
+        /// Optional arguments, see [`DirectoryDockerOpts`], are taken by [`Self::docker_opts`].
+         fn docker(
+            &self,
+        ) -> Self::Container {
+            self.docker()
+        }
+
+        /// Builds a new Docker container from this directory.
+        ///
+        /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+        ///
+        /// # Arguments
+        ///
+        /// * `opts` - Optional arguments, see [`DirectoryDockerOpts`].
+         fn docker_opts<'a>(
+            &self,
+            opts: DirectoryDockerOpts<'a>
+        ) -> Self::Container {
+            self.docker_opts(opts)
+        }
+
+        /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+        ///
+        /// Optional arguments, see [`DirectoryPipelineOpts`], are taken by [`Self::pipeline_opts`].
+         fn pipeline(
+            &self,
+            name: impl Into<String> + Send,
+        ) -> Self::Directory {
+            self.pipeline(name)
+        }
+
+        /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+        ///
+        /// # Arguments
+        ///
+        /// * `opts` - Optional arguments, see [`DirectoryPipelineOpts`].
+         fn pipeline_opts<'a>(
+            &self,
+            name: impl Into<String> + Send,
+            opts: DirectoryPipelineOpts<'a>
+        ) -> Self::Directory {
+            self.pipeline_opts(name, opts)
+        }
+
+        /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+        ///
+        /// Optional arguments, see [`DirectoryWithNewFileOpts`], are taken by [`Self::with_new_file_opts`].
+         fn with_new_file(
+            &self,
+            path: impl Into<String> + Send,
+        ) -> Self::Directory {
+            self.with_new_file(path)
+        }
+
+        /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+        ///
+        /// # Arguments
+        ///
+        /// * `opts` - Optional arguments, see [`DirectoryWithNewFileOpts`].
+         fn with_new_file_opts<'a>(
+            &self,
+            path: impl Into<String> + Send,
+            opts: DirectoryWithNewFileOpts<'a>
+        ) -> Self::Directory {
+            self.with_new_file_opts(path, opts)
+        }
+
+        /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+         fn subdirectories(
+            &self,
+        ) -> Vec<Self::Directory> {
+            self.subdirectories()
+        }
+    }
+
+    /// In-memory fake of [`DirectoryApi`]
+    #[derive(Debug, Clone)]
+    pub struct FakeDirectory {
+        pub selection: Selection,
+        pub fake: Fake,
+    }
+
+    impl FakeDirectory {
+        /// A fake whose queries start at this object, answered by `fake`
+        pub fn new(fake: &Fake) -> Self {
+            Self {
+                selection: Selection::default(),
+                fake: fake.clone(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl DirectoryApi for FakeDirectory {
+        type Container = FakeContainer;
+        type Directory = FakeDirectory;
+
+         async fn id(
+            &self,
+        ) -> eyre::Result<DirectoryId> {
+            let query = self.selection.select("id");
+
+            self.fake.execute(&query)
+        }
+         async fn entries(
+            &self,
+        ) -> eyre::Result<Vec<String>> {
+            let query = self.selection.select("entries");
+
+            self.fake.execute(&query)
+        }
+
+         async fn entries_opts<'a>(
+            &self,
+            opts: DirectoryEntriesOpts<'a>
+        ) -> eyre::Result<Vec<String>> {
+            let mut query = self.selection.select("entries");
+
+            if let Some(path) = opts.path {
+                query = query.arg("path", path);
+            }
+
+            self.fake.execute(&query)
+        }
+
+         async fn glob(
+            &self,
+            pattern: impl Into<String> + Send,
+        ) -> eyre::Result<Vec<String>> {
+            let mut query = self.selection.select("glob");
+
+            query = query.arg("pattern", pattern.into());
+
+            self.fake.execute(&query)
+        }
+         fn docker(
+            &self,
+        ) -> Self::Container {
+            let query = self.selection.select("docker");
+
+            FakeContainer {
+                selection: query,
+                fake: self.fake.clone(),
+            }
+        }
+
+         fn docker_opts<'a>(
+            &self,
+            opts: DirectoryDockerOpts<'a>
+        ) -> Self::Container {
+            let mut query = self.selection.select("docker");
+
+            if let Some(dockerfile) = opts.dockerfile {
+                query = query.arg("dockerfile", dockerfile);
+            }
+            if let Some(platform) = opts.platform {
+                query = query.arg("platform", platform);
+            }
+            if let Some(build_args) = opts.build_args {
+                query = query.arg("buildArgs", build_args);
+            }
+            if let Some(target) = opts.target {
+                query = query.arg("target", target);
+            }
+
+            FakeContainer {
+                selection: query,
+                fake: self.fake.clone(),
+            }
+        }
+
+         fn pipeline(
+            &self,
+            name: impl Into<String> + Send,
+        ) -> Self::Directory {
+            let mut query = self.selection.select("pipeline");
+
+            query = query.arg("name", name.into());
+
+            FakeDirectory {
+                selection: query,
+                fake: self.fake.clone(),
+            }
+        }
+
+         fn pipeline_opts<'a>(
+            &self,
+            name: impl Into<String> + Send,
+            opts: DirectoryPipelineOpts<'a>
+        ) -> Self::Directory {
+            let mut query = self.selection.select("pipeline");
+
+            query = query.arg("name", name.into());
+            if let Some(description) = opts.description {
+                query = query.arg("description", description);
+            }
+            if let Some(labels) = opts.labels {
+                query = query.arg("labels", labels);
+            }
+
+            FakeDirectory {
+                selection: query,
+                fake: self.fake.clone(),
+            }
+        }
+
+         fn with_new_file(
+            &self,
+            path: impl Into<String> + Send,
+        ) -> Self::Directory {
+            let mut query = self.selection.select("withNewFile");
+
+            query = query.arg("path", path.into());
+
+            FakeDirectory {
+                selection: query,
+                fake: self.fake.clone(),
+            }
+        }
+
+         fn with_new_file_opts<'a>(
+            &self,
+            path: impl Into<String> + Send,
+            opts: DirectoryWithNewFileOpts<'a>
+        ) -> Self::Directory {
+            let mut query = self.selection.select("withNewFile");
+
+            query = query.arg("path", path.into());
+            if let Some(contents) = opts.contents {
+                query = query.arg("contents", contents);
+            }
+            if let Some(permissions) = opts.permissions {
+                query = query.arg("permissions", permissions);
+            }
+
+            FakeDirectory {
+                selection: query,
+                fake: self.fake.clone(),
+            }
+        }
+
+         fn subdirectories(
+            &self,
+        ) -> Vec<Self::Directory> {
+            let query = self.selection.select("subdirectories");
+
+            vec![FakeDirectory {
+                selection: query,
+                fake: self.fake.clone(),
+            }]
+        }
+    }
+    /// Trait of [`super::Query`]
+    #[async_trait]
+    pub trait QueryApi: Send + Sync {
+        type Directory: DirectoryApi;
+
+        /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+        ///
+        /// Optional arguments, see [`QueryDirectoryOpts`], are taken by [`Self::directory_opts`].
+         fn directory(
+            &self,
+        ) -> Self::Directory;
+
+        /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+        ///
+        /// # Arguments
+        ///
+        /// * `opts` - Optional arguments, see [`QueryDirectoryOpts`].
+         fn directory_opts(
+            &self,
+            opts: QueryDirectoryOpts
+        ) -> Self::Directory;
+    }
+
+    #[async_trait]
+    impl QueryApi for super::Query {
+        type Directory = super::Directory;
+
+        /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+        ///
+        /// Optional arguments, see [`QueryDirectoryOpts`], are taken by [`Self::directory_opts`].
+         fn directory(
+            &self,
+        ) -> Self::Directory {
+            self.directory()
+        }
+
+        /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+        ///
+        /// # Arguments
+        ///
+        /// * `opts` - Optional arguments, see [`QueryDirectoryOpts`].
+         fn directory_opts(
+            &self,
+            opts: QueryDirectoryOpts
+        ) -> Self::Directory {
+            self.directory_opts(opts)
+        }
+    }
+
+    /// In-memory fake of [`QueryApi`]
+    #[derive(Debug, Clone)]
+    pub struct FakeQuery {
+        pub selection: Selection,
+        pub fake: Fake,
+    }
+
+    impl FakeQuery {
+        /// A fake whose queries start at this object, answered by `fake`
+        pub fn new(fake: &Fake) -> Self {
+            Self {
+                selection: Selection::default(),
+                fake: fake.clone(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl QueryApi for FakeQuery {
+        type Directory = FakeDirectory;
+
+         fn directory(
+            &self,
+        ) -> Self::Directory {
+            let query = self.selection.select("directory");
+
+            FakeDirectory {
+                selection: query,
+                fake: self.fake.clone(),
+            }
+        }
+
+         fn directory_opts(
+            &self,
+            opts: QueryDirectoryOpts
+        ) -> Self::Directory {
+            let mut query = self.selection.select("directory");
+
+            if let Some(id) = opts.id {
+                query = query.arg("id", id);
+            }
+
+            FakeDirectory {
+                selection: query,
+                fake: self.fake.clone(),
+            }
+        }
+    }
+}
@@ -0,0 +1,258 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use dagger_codegen::config::Config;
+use dagger_codegen::generator::Generator;
+use dagger_codegen::rust::RustGenerator;
+use dagger_codegen::sdl::SdlGenerator;
+use dagger_codegen::{generate_modules_with_diagnostics, generate_with_diagnostics};
+use dagger_core::introspection::{IntrospectionResponse, Schema};
+use pretty_assertions::assert_eq;
+
+/// Generates `tests/fixtures/<schema>.json` and compares it with `tests/fixtures/<name>.rs`.
+///
+/// Run with `UPDATE_GOLDEN=1` to rewrite the golden files after an intended change to the
+/// generated code.
+fn assert_golden(schema: &str, name: &str, config: Config) {
+    assert_golden_with(schema, &format!("{}.rs", name), RustGenerator::new(config))
+}
+
+/// Like [`assert_golden`], for any generator and golden file name
+fn assert_golden_with(
+    schema: &str,
+    golden: &str,
+    generator: impl Generator + Send + Sync + 'static,
+) {
+    let fixtures = fixtures();
+    let schema = read_schema(schema);
+
+    let generated = generate_with_diagnostics(schema, Arc::new(generator)).unwrap();
+    assert_eq!(generated.diagnostics, vec![]);
+
+    let golden = fixtures.join(golden);
+    if std::env::var("UPDATE_GOLDEN").is_ok() {
+        std::fs::write(&golden, &generated.code).unwrap();
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&golden).unwrap();
+    assert_eq!(expected, generated.code);
+}
+
+/// Like [`assert_golden`], but for the module tree compared with `tests/fixtures/<name>/`
+fn assert_golden_modules(schema: &str, name: &str, config: Config) {
+    let dir = fixtures().join(name);
+    let schema = read_schema(schema);
+
+    let generated =
+        generate_modules_with_diagnostics(schema, Arc::new(RustGenerator::new(config))).unwrap();
+    assert_eq!(generated.diagnostics, vec![]);
+
+    if std::env::var("UPDATE_GOLDEN").is_ok() {
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        for (path, code) in generated.files.iter() {
+            std::fs::write(dir.join(path), code).unwrap();
+        }
+        return;
+    }
+
+    let mut expected = BTreeMap::new();
+    for entry in std::fs::read_dir(&dir).unwrap() {
+        let entry = entry.unwrap();
+        expected.insert(
+            entry.file_name().to_string_lossy().to_string(),
+            std::fs::read_to_string(entry.path()).unwrap(),
+        );
+    }
+    assert_eq!(expected, generated.files);
+}
+
+fn fixtures() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")
+}
+
+fn read_schema(name: &str) -> Schema {
+    let schema = std::fs::read_to_string(fixtures().join(format!("{}.json", name))).unwrap();
+
+    serde_json::from_str::<IntrospectionResponse>(&schema)
+        .unwrap()
+        .into_schema()
+        .schema
+        .unwrap()
+}
+
+#[test]
+fn scalars_enums() {
+    assert_golden("scalars_enums", "scalars_enums", Config::default())
+}
+
+#[test]
+fn inputs_and_args() {
+    assert_golden("inputs_and_args", "inputs_and_args", Config::default())
+}
+
+#[test]
+fn interfaces_unions() {
+    assert_golden("interfaces_unions", "interfaces_unions", Config::default())
+}
+
+/// The schema of engine 0.4.0, which the checked in `gen.rs` was generated from before the SDK
+/// was split into modules. It was rebuilt from that file rather than exported from an engine.
+#[test]
+fn dagger_0_4_0() {
+    assert_golden("dagger_0_4_0", "dagger_0_4_0", Config::default())
+}
+
+#[test]
+fn customized() {
+    let config = std::fs::read_to_string(fixtures().join("customized.toml")).unwrap();
+
+    assert_golden(
+        "inputs_and_args",
+        "customized",
+        Config::from_toml(&config).unwrap(),
+    )
+}
+
+#[test]
+fn blocking() {
+    let config = Config {
+        blocking: true,
+        ..Default::default()
+    };
+
+    assert_golden("inputs_and_args", "blocking", config)
+}
+
+#[test]
+fn traits() {
+    let config = Config {
+        traits: true,
+        ..Default::default()
+    };
+
+    assert_golden("inputs_and_args", "traits", config)
+}
+
+#[test]
+fn extension() {
+    let config = Config {
+        sdk: Some("crate".into()),
+        ..Default::default()
+    };
+
+    assert_golden_with(
+        "extension",
+        "extension.rs",
+        RustGenerator::new(config).extending(read_schema("extension_base")),
+    )
+}
+
+#[test]
+fn sdl() {
+    assert_golden_with(
+        "interfaces_unions",
+        "interfaces_unions.graphql",
+        SdlGenerator,
+    )
+}
+
+#[test]
+fn sdl_inputs_and_args() {
+    assert_golden_with("inputs_and_args", "inputs_and_args.graphql", SdlGenerator)
+}
+
+#[test]
+fn modules() {
+    let config = std::fs::read_to_string(fixtures().join("modules.toml")).unwrap();
+
+    assert_golden_modules(
+        "interfaces_unions",
+        "modules",
+        Config::from_toml(&config).unwrap(),
+    )
+}
+
+#[test]
+fn malformed_types_are_diagnostics() {
+    let schema = serde_json::json!({
+        "data": {"__schema": {
+            "queryType": {"name": "Query"},
+            "types": [
+                {"kind": "SCALAR", "name": "String"},
+                {"kind": "OBJECT", "description": null},
+                {"kind": "OBJECT", "name": "Query", "description": null, "interfaces": [], "fields": [
+                    {"name": "names", "description": null, "args": [], "isDeprecated": false,
+                        "type": {"kind": "NON_NULL", "ofType": {"kind": "LIST", "ofType": null}}},
+                    {"name": "version", "description": null, "args": [], "isDeprecated": false,
+                        "type": {"kind": "NON_NULL", "ofType": {"kind": "SCALAR", "name": "String"}}}
+                ]}
+            ]
+        }}
+    });
+    let schema = serde_json::from_str::<IntrospectionResponse>(&schema.to_string())
+        .unwrap()
+        .into_schema()
+        .schema
+        .unwrap();
+
+    let generated =
+        generate_with_diagnostics(schema, Arc::new(RustGenerator::new(Config::default()))).unwrap();
+
+    assert_eq!(
+        generated
+            .diagnostics
+            .iter()
+            .map(|d| d.to_string())
+            .collect::<Vec<_>>(),
+        vec![
+            "warning: skipped type without a name",
+            "warning: Query.names: skipped field returning a list without an element type",
+        ]
+    );
+    assert!(generated.code.contains("pub async fn version("));
+    assert!(!generated.code.contains("pub async fn names("));
+}
+
+#[test]
+fn lists_of_unions_are_diagnostics() {
+    let schema = serde_json::json!({
+        "data": {"__schema": {
+            "queryType": {"name": "Query"},
+            "types": [
+                {"kind": "OBJECT", "name": "Container", "description": null, "interfaces": [], "fields": []},
+                {"kind": "UNION", "name": "SearchResult", "description": null,
+                    "possibleTypes": [{"kind": "OBJECT", "name": "Container"}]},
+                {"kind": "OBJECT", "name": "Query", "description": null, "interfaces": [], "fields": [
+                    {"name": "searchAll", "description": null, "args": [], "isDeprecated": false,
+                        "type": {"kind": "NON_NULL", "ofType": {"kind": "LIST", "ofType":
+                            {"kind": "NON_NULL", "ofType": {"kind": "UNION", "name": "SearchResult"}}}}}
+                ]}
+            ]
+        }}
+    });
+    let schema = serde_json::from_str::<IntrospectionResponse>(&schema.to_string())
+        .unwrap()
+        .into_schema()
+        .schema
+        .unwrap();
+    let config = Config {
+        traits: true,
+        ..Default::default()
+    };
+
+    let generated =
+        generate_with_diagnostics(schema, Arc::new(RustGenerator::new(config))).unwrap();
+
+    assert_eq!(
+        generated
+            .diagnostics
+            .iter()
+            .map(|d| d.to_string())
+            .collect::<Vec<_>>(),
+        vec!["warning: Query.searchAll: skipped field returning a list of unions, as GraphQL can't select a single element of a list"]
+    );
+    assert!(!generated.code.contains("fn search_all("));
+}
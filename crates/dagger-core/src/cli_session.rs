@@ -2,7 +2,11 @@ use std::{fs::canonicalize, path::PathBuf, process::Stdio, sync::Arc};
 
 use tokio::io::AsyncBufReadExt;
 
-use crate::{config::Config, connect_params::ConnectParams};
+use crate::{
+    config::Config,
+    connect_params::ConnectParams,
+    redaction::{redact, register_secret},
+};
 
 #[derive(Clone, Debug)]
 pub struct CliSession {
@@ -86,17 +90,18 @@ impl InnerCliSession {
             let mut stdout_bufr = tokio::io::BufReader::new(stdout).lines();
             while let Ok(Some(line)) = stdout_bufr.next_line().await {
                 if let Ok(conn) = serde_json::from_str::<ConnectParams>(&line) {
+                    register_secret(conn.session_token.clone());
                     sender.send(conn).await.unwrap();
                 }
 
-                println!("dagger: {}", line);
+                println!("dagger: {}", redact(&line));
             }
         });
 
         tokio::spawn(async move {
             let mut stdout_bufr = tokio::io::BufReader::new(stderr).lines();
             while let Ok(Some(line)) = stdout_bufr.next_line().await {
-                println!("dagger: {}", line);
+                println!("dagger: {}", redact(&line));
             }
         });
 
@@ -8,7 +8,9 @@ pub mod connect_params;
 pub mod downloader;
 pub mod engine;
 pub mod introspection;
+pub mod redaction;
 pub mod schema;
+pub mod schema_diff;
 pub mod session;
 
 pub struct Scalar(String);
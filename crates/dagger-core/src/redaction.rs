@@ -0,0 +1,105 @@
+//! Secret values kept out of everything printed, like queries, errors and the engine's output.
+
+use std::sync::RwLock;
+
+/// What a secret value is replaced with
+pub const REDACTED: &str = "***";
+
+/// Values shorter than this, in characters, are not redacted, as they would be all over the output
+pub const MIN_SECRET_LEN: usize = 3;
+
+static SECRETS: RwLock<Vec<String>> = RwLock::new(Vec::new());
+
+/// Redacts `value` from everything printed from now on. Values shorter than [`MIN_SECRET_LEN`]
+/// are skipped with a warning, empty ones silently.
+pub fn register_secret(value: impl Into<String>) {
+    let value = value.into();
+    let len = value.chars().count();
+    if len == 0 {
+        return;
+    }
+    if len < MIN_SECRET_LEN {
+        tracing::warn!(
+            len,
+            "not redacting a secret shorter than {} characters",
+            MIN_SECRET_LEN
+        );
+        return;
+    }
+
+    // queries hold values json escaped, so that form is redacted as well
+    let escaped = serde_json::to_string(&value).unwrap();
+    let escaped = escaped[1..escaped.len() - 1].to_string();
+
+    let mut secrets = SECRETS.write().unwrap();
+    for value in [value, escaped] {
+        if !secrets.contains(&value) {
+            secrets.push(value);
+        }
+    }
+    // longest first, so a secret containing another is redacted whole
+    secrets.sort_by_key(|s| std::cmp::Reverse(s.len()));
+}
+
+/// `text` with every registered secret replaced by [`REDACTED`]
+pub fn redact(text: &str) -> String {
+    let secrets = SECRETS.read().unwrap();
+    let mut text = text.to_string();
+    for secret in secrets.iter() {
+        if text.contains(secret.as_str()) {
+            text = text.replace(secret.as_str(), REDACTED);
+        }
+    }
+
+    text
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::{redact, register_secret};
+
+    #[test]
+    fn test_redact() {
+        register_secret("hunter2");
+
+        assert_eq!(
+            redact("password: hunter2, again hunter2"),
+            "password: ***, again ***"
+        );
+        assert_eq!(redact("nothing to hide"), "nothing to hide");
+    }
+
+    #[test]
+    fn test_redact_escaped() {
+        register_secret("line\n\"quoted\"");
+
+        assert_eq!(
+            redact(r#"{ value: "line\n\"quoted\"" }"#),
+            r#"{ value: "***" }"#
+        );
+    }
+
+    #[test]
+    fn test_redact_longest_first() {
+        register_secret("abc-token");
+        register_secret("abc-token-suffixed");
+
+        assert_eq!(redact("abc-token-suffixed"), "***");
+    }
+
+    #[test]
+    fn test_empty_ignored() {
+        register_secret("");
+
+        assert_eq!(redact("text"), "text");
+    }
+
+    #[test]
+    fn test_short_ignored() {
+        register_secret("ab");
+
+        assert_eq!(redact("abc cab"), "abc cab");
+    }
+}
@@ -0,0 +1,659 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use crate::introspection::{__TypeKind, FullType, InputValue, Schema, TypeRef};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ChangeKind {
+    TypeAdded,
+    TypeRemoved,
+    TypeKindChanged { old: String, new: String },
+    FieldAdded,
+    FieldRemoved,
+    FieldTypeChanged { old: String, new: String },
+    ArgumentAdded { required: bool },
+    ArgumentRemoved,
+    ArgumentBecameRequired,
+    ArgumentBecameOptional,
+    ArgumentTypeChanged { old: String, new: String },
+    InputFieldAdded { required: bool },
+    InputFieldRemoved,
+    InputFieldBecameRequired,
+    InputFieldBecameOptional,
+    InputFieldTypeChanged { old: String, new: String },
+    EnumValueAdded,
+    EnumValueRemoved,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Change {
+    /// Dotted path of the changed item, e.g. `Container.withExec.args`
+    pub path: String,
+    pub kind: ChangeKind,
+}
+
+impl Change {
+    fn new(path: impl Into<String>, kind: ChangeKind) -> Self {
+        Self {
+            path: path.into(),
+            kind,
+        }
+    }
+
+    /// Whether the change can break existing callers of the api
+    pub fn is_breaking(&self) -> bool {
+        match self.kind {
+            ChangeKind::TypeAdded => false,
+            ChangeKind::TypeRemoved => true,
+            ChangeKind::TypeKindChanged { .. } => true,
+            ChangeKind::FieldAdded => false,
+            ChangeKind::FieldRemoved => true,
+            ChangeKind::FieldTypeChanged { .. } => true,
+            ChangeKind::ArgumentAdded { required } => required,
+            ChangeKind::ArgumentRemoved => true,
+            ChangeKind::ArgumentBecameRequired => true,
+            ChangeKind::ArgumentBecameOptional => false,
+            ChangeKind::ArgumentTypeChanged { .. } => true,
+            ChangeKind::InputFieldAdded { required } => required,
+            ChangeKind::InputFieldRemoved => true,
+            ChangeKind::InputFieldBecameRequired => true,
+            ChangeKind::InputFieldBecameOptional => false,
+            ChangeKind::InputFieldTypeChanged { .. } => true,
+            ChangeKind::EnumValueAdded => false,
+            ChangeKind::EnumValueRemoved => true,
+        }
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let severity = if self.is_breaking() {
+            "BREAKING"
+        } else {
+            "non-breaking"
+        };
+
+        let description = match &self.kind {
+            ChangeKind::TypeAdded => "type added".to_string(),
+            ChangeKind::TypeRemoved => "type removed".to_string(),
+            ChangeKind::TypeKindChanged { old, new } => {
+                format!("type kind changed from {} to {}", old, new)
+            }
+            ChangeKind::FieldAdded => "field added".to_string(),
+            ChangeKind::FieldRemoved => "field removed".to_string(),
+            ChangeKind::FieldTypeChanged { old, new } => {
+                format!("field type changed from {} to {}", old, new)
+            }
+            ChangeKind::ArgumentAdded { required: true } => "required argument added".to_string(),
+            ChangeKind::ArgumentAdded { required: false } => "optional argument added".to_string(),
+            ChangeKind::ArgumentRemoved => "argument removed".to_string(),
+            ChangeKind::ArgumentBecameRequired => "argument became required".to_string(),
+            ChangeKind::ArgumentBecameOptional => "argument became optional".to_string(),
+            ChangeKind::ArgumentTypeChanged { old, new } => {
+                format!("argument type changed from {} to {}", old, new)
+            }
+            ChangeKind::InputFieldAdded { required: true } => {
+                "required input field added".to_string()
+            }
+            ChangeKind::InputFieldAdded { required: false } => {
+                "optional input field added".to_string()
+            }
+            ChangeKind::InputFieldRemoved => "input field removed".to_string(),
+            ChangeKind::InputFieldBecameRequired => "input field became required".to_string(),
+            ChangeKind::InputFieldBecameOptional => "input field became optional".to_string(),
+            ChangeKind::InputFieldTypeChanged { old, new } => {
+                format!("input field type changed from {} to {}", old, new)
+            }
+            ChangeKind::EnumValueAdded => "enum value added".to_string(),
+            ChangeKind::EnumValueRemoved => "enum value removed".to_string(),
+        };
+
+        write!(f, "{:<12} {}: {}", severity, self.path, description)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SchemaDiff {
+    pub changes: Vec<Change>,
+}
+
+impl SchemaDiff {
+    pub fn breaking(&self) -> impl Iterator<Item = &Change> {
+        self.changes.iter().filter(|c| c.is_breaking())
+    }
+
+    pub fn non_breaking(&self) -> impl Iterator<Item = &Change> {
+        self.changes.iter().filter(|c| !c.is_breaking())
+    }
+
+    pub fn has_breaking_changes(&self) -> bool {
+        self.breaking().next().is_some()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// Compares two introspected schemas and classifies every change between them.
+pub fn diff(old: &Schema, new: &Schema) -> SchemaDiff {
+    let old_types = types_by_name(old);
+    let new_types = types_by_name(new);
+
+    let mut changes = Vec::new();
+
+    for (name, old_type) in old_types.iter() {
+        match new_types.get(name) {
+            Some(new_type) => diff_type(name, old_type, new_type, &mut changes),
+            None => changes.push(Change::new(*name, ChangeKind::TypeRemoved)),
+        }
+    }
+
+    for name in new_types.keys() {
+        if !old_types.contains_key(name) {
+            changes.push(Change::new(*name, ChangeKind::TypeAdded));
+        }
+    }
+
+    changes.sort_by(|a, b| a.path.cmp(&b.path));
+
+    SchemaDiff { changes }
+}
+
+fn types_by_name(schema: &Schema) -> BTreeMap<&str, &FullType> {
+    schema
+        .types
+        .iter()
+        .flatten()
+        .flatten()
+        .map(|t| &t.full_type)
+        .filter_map(|t| t.name.as_deref().map(|n| (n, t)))
+        .filter(|(n, _)| !n.starts_with("__"))
+        .collect()
+}
+
+fn diff_type(name: &str, old: &FullType, new: &FullType, changes: &mut Vec<Change>) {
+    if old.kind != new.kind {
+        changes.push(Change::new(
+            name,
+            ChangeKind::TypeKindChanged {
+                old: format_kind(old.kind.as_ref()),
+                new: format_kind(new.kind.as_ref()),
+            },
+        ));
+        return;
+    }
+
+    diff_fields(name, old, new, changes);
+    diff_input_fields(name, old, new, changes);
+    diff_enum_values(name, old, new, changes);
+}
+
+fn diff_fields(name: &str, old: &FullType, new: &FullType, changes: &mut Vec<Change>) {
+    let old_fields: BTreeMap<_, _> = old
+        .fields
+        .iter()
+        .flatten()
+        .filter_map(|f| f.name.as_deref().map(|n| (n, f)))
+        .collect();
+    let new_fields: BTreeMap<_, _> = new
+        .fields
+        .iter()
+        .flatten()
+        .filter_map(|f| f.name.as_deref().map(|n| (n, f)))
+        .collect();
+
+    for (field_name, old_field) in old_fields.iter() {
+        let path = format!("{}.{}", name, field_name);
+        let new_field = match new_fields.get(field_name) {
+            Some(f) => f,
+            None => {
+                changes.push(Change::new(path, ChangeKind::FieldRemoved));
+                continue;
+            }
+        };
+
+        let old_type = old_field
+            .type_
+            .as_ref()
+            .map(|t| format_type_ref(&t.type_ref));
+        let new_type = new_field
+            .type_
+            .as_ref()
+            .map(|t| format_type_ref(&t.type_ref));
+        if old_type != new_type {
+            changes.push(Change::new(
+                path.clone(),
+                ChangeKind::FieldTypeChanged {
+                    old: old_type.unwrap_or_default(),
+                    new: new_type.unwrap_or_default(),
+                },
+            ));
+        }
+
+        let old_args = old_field
+            .args
+            .iter()
+            .flatten()
+            .flatten()
+            .map(|a| &a.input_value);
+        let new_args = new_field
+            .args
+            .iter()
+            .flatten()
+            .flatten()
+            .map(|a| &a.input_value);
+        diff_input_values(InputValueKind::Argument, &path, old_args, new_args, changes);
+    }
+
+    for field_name in new_fields.keys() {
+        if !old_fields.contains_key(field_name) {
+            changes.push(Change::new(
+                format!("{}.{}", name, field_name),
+                ChangeKind::FieldAdded,
+            ));
+        }
+    }
+}
+
+fn diff_input_fields(name: &str, old: &FullType, new: &FullType, changes: &mut Vec<Change>) {
+    let old_fields = old.input_fields.iter().flatten().map(|f| &f.input_value);
+    let new_fields = new.input_fields.iter().flatten().map(|f| &f.input_value);
+
+    diff_input_values(
+        InputValueKind::InputField,
+        name,
+        old_fields,
+        new_fields,
+        changes,
+    );
+}
+
+/// What the input values compared by [`diff_input_values`] are
+#[derive(Clone, Copy)]
+enum InputValueKind {
+    Argument,
+    InputField,
+}
+
+impl InputValueKind {
+    fn added(self, required: bool) -> ChangeKind {
+        match self {
+            Self::Argument => ChangeKind::ArgumentAdded { required },
+            Self::InputField => ChangeKind::InputFieldAdded { required },
+        }
+    }
+
+    fn removed(self) -> ChangeKind {
+        match self {
+            Self::Argument => ChangeKind::ArgumentRemoved,
+            Self::InputField => ChangeKind::InputFieldRemoved,
+        }
+    }
+
+    fn became_required(self) -> ChangeKind {
+        match self {
+            Self::Argument => ChangeKind::ArgumentBecameRequired,
+            Self::InputField => ChangeKind::InputFieldBecameRequired,
+        }
+    }
+
+    fn became_optional(self) -> ChangeKind {
+        match self {
+            Self::Argument => ChangeKind::ArgumentBecameOptional,
+            Self::InputField => ChangeKind::InputFieldBecameOptional,
+        }
+    }
+
+    fn type_changed(self, old: String, new: String) -> ChangeKind {
+        match self {
+            Self::Argument => ChangeKind::ArgumentTypeChanged { old, new },
+            Self::InputField => ChangeKind::InputFieldTypeChanged { old, new },
+        }
+    }
+}
+
+fn diff_input_values<'a>(
+    kind: InputValueKind,
+    path: &str,
+    old: impl Iterator<Item = &'a InputValue>,
+    new: impl Iterator<Item = &'a InputValue>,
+    changes: &mut Vec<Change>,
+) {
+    let old: BTreeMap<_, _> = old.map(|v| (v.name.as_str(), v)).collect();
+    let new: BTreeMap<_, _> = new.map(|v| (v.name.as_str(), v)).collect();
+
+    for (arg_name, old_arg) in old.iter() {
+        let path = format!("{}.{}", path, arg_name);
+        let new_arg = match new.get(arg_name) {
+            Some(a) => a,
+            None => {
+                changes.push(Change::new(path, kind.removed()));
+                continue;
+            }
+        };
+
+        match (is_required(old_arg), is_required(new_arg)) {
+            (false, true) => changes.push(Change::new(path.clone(), kind.became_required())),
+            (true, false) => changes.push(Change::new(path.clone(), kind.became_optional())),
+            _ => {}
+        }
+
+        let old_type = format_type_ref(&old_arg.type_);
+        let new_type = format_type_ref(&new_arg.type_);
+        if old_type.trim_end_matches('!') != new_type.trim_end_matches('!') {
+            changes.push(Change::new(path, kind.type_changed(old_type, new_type)));
+        }
+    }
+
+    for (arg_name, new_arg) in new.iter() {
+        if !old.contains_key(arg_name) {
+            changes.push(Change::new(
+                format!("{}.{}", path, arg_name),
+                kind.added(is_required(new_arg)),
+            ));
+        }
+    }
+}
+
+fn diff_enum_values(name: &str, old: &FullType, new: &FullType, changes: &mut Vec<Change>) {
+    let old_values: Vec<_> = old
+        .enum_values
+        .iter()
+        .flatten()
+        .filter_map(|v| v.name.as_deref())
+        .collect();
+    let new_values: Vec<_> = new
+        .enum_values
+        .iter()
+        .flatten()
+        .filter_map(|v| v.name.as_deref())
+        .collect();
+
+    for value in old_values.iter() {
+        if !new_values.contains(value) {
+            changes.push(Change::new(
+                format!("{}.{}", name, value),
+                ChangeKind::EnumValueRemoved,
+            ));
+        }
+    }
+
+    for value in new_values.iter() {
+        if !old_values.contains(value) {
+            changes.push(Change::new(
+                format!("{}.{}", name, value),
+                ChangeKind::EnumValueAdded,
+            ));
+        }
+    }
+}
+
+fn is_required(input_value: &InputValue) -> bool {
+    input_value.type_.kind == Some(__TypeKind::NON_NULL) && input_value.default_value.is_none()
+}
+
+fn format_kind(kind: Option<&__TypeKind>) -> String {
+    kind.and_then(|k| serde_json::to_value(k).ok())
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .unwrap_or_default()
+}
+
+/// Renders a type reference in GraphQL notation, e.g. `[String!]!`
+pub fn format_type_ref(type_ref: &TypeRef) -> String {
+    match type_ref.kind {
+        Some(__TypeKind::NON_NULL) => format!(
+            "{}!",
+            type_ref
+                .of_type
+                .as_ref()
+                .map(|t| format_type_ref(t))
+                .unwrap_or_default()
+        ),
+        Some(__TypeKind::LIST) => format!(
+            "[{}]",
+            type_ref
+                .of_type
+                .as_ref()
+                .map(|t| format_type_ref(t))
+                .unwrap_or_default()
+        ),
+        _ => type_ref.name.clone().unwrap_or_default(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value};
+
+    use crate::introspection::{IntrospectionResponse, Schema};
+
+    use super::{diff, ChangeKind};
+
+    fn schema(types: Value) -> Schema {
+        let json = json!({ "__schema": { "types": types } }).to_string();
+        let resp: IntrospectionResponse = serde_json::from_str(&json).unwrap();
+
+        resp.into_schema().schema.unwrap()
+    }
+
+    fn type_ref(kind: &str, name: Option<&str>, of_type: Option<Value>) -> Value {
+        json!({ "kind": kind, "name": name, "ofType": of_type })
+    }
+
+    fn string() -> Value {
+        type_ref("SCALAR", Some("String"), None)
+    }
+
+    fn int() -> Value {
+        type_ref("SCALAR", Some("Int"), None)
+    }
+
+    fn non_null(inner: Value) -> Value {
+        type_ref("NON_NULL", None, Some(inner))
+    }
+
+    fn container(args: Value) -> Value {
+        json!({
+            "kind": "OBJECT",
+            "name": "Container",
+            "fields": [{
+                "name": "withExec",
+                "args": args,
+                "type": non_null(type_ref("OBJECT", Some("Container"), None)),
+            }],
+        })
+    }
+
+    fn build_arg(fields: Value) -> Value {
+        json!({ "kind": "INPUT_OBJECT", "name": "BuildArg", "inputFields": fields })
+    }
+
+    fn value(name: &str, type_: Value) -> Value {
+        json!({ "name": name, "type": type_, "defaultValue": null })
+    }
+
+    #[test]
+    fn test_diff() {
+        let cases = vec![
+            (
+                "identical schemas",
+                json!([container(json!([]))]),
+                json!([container(json!([]))]),
+                vec![],
+            ),
+            (
+                "types",
+                json!([{ "kind": "SCALAR", "name": "OldId" }]),
+                json!([{ "kind": "SCALAR", "name": "NewId" }]),
+                vec![
+                    ("NewId", ChangeKind::TypeAdded, false),
+                    ("OldId", ChangeKind::TypeRemoved, true),
+                ],
+            ),
+            (
+                "arguments",
+                json!([container(json!([
+                    value("args", non_null(string())),
+                    value("stdin", string()),
+                    value("removed", string()),
+                ]))]),
+                json!([container(json!([
+                    value("args", non_null(string())),
+                    value("stdin", non_null(string())),
+                    value("optional", string()),
+                    value("required", non_null(string())),
+                ]))]),
+                vec![
+                    (
+                        "Container.withExec.optional",
+                        ChangeKind::ArgumentAdded { required: false },
+                        false,
+                    ),
+                    (
+                        "Container.withExec.removed",
+                        ChangeKind::ArgumentRemoved,
+                        true,
+                    ),
+                    (
+                        "Container.withExec.required",
+                        ChangeKind::ArgumentAdded { required: true },
+                        true,
+                    ),
+                    (
+                        "Container.withExec.stdin",
+                        ChangeKind::ArgumentBecameRequired,
+                        true,
+                    ),
+                ],
+            ),
+            (
+                "argument required with another type",
+                json!([container(json!([value("stdin", string())]))]),
+                json!([container(json!([value("stdin", non_null(int()))]))]),
+                vec![
+                    (
+                        "Container.withExec.stdin",
+                        ChangeKind::ArgumentBecameRequired,
+                        true,
+                    ),
+                    (
+                        "Container.withExec.stdin",
+                        ChangeKind::ArgumentTypeChanged {
+                            old: "String".into(),
+                            new: "Int!".into(),
+                        },
+                        true,
+                    ),
+                ],
+            ),
+            (
+                "argument optional with a default",
+                json!([container(json!([
+                    value("args", non_null(string())),
+                    value("stdin", non_null(string())),
+                ]))]),
+                json!([container(json!([
+                    value("args", string()),
+                    { "name": "stdin", "type": non_null(string()), "defaultValue": "\"\"" },
+                ]))]),
+                vec![
+                    (
+                        "Container.withExec.args",
+                        ChangeKind::ArgumentBecameOptional,
+                        false,
+                    ),
+                    (
+                        "Container.withExec.stdin",
+                        ChangeKind::ArgumentBecameOptional,
+                        false,
+                    ),
+                ],
+            ),
+            (
+                "input fields",
+                json!([build_arg(json!([
+                    value("key", non_null(string())),
+                    value("name", string()),
+                    value("value", string()),
+                ]))]),
+                json!([build_arg(json!([
+                    value("name", non_null(string())),
+                    value("description", string()),
+                    value("key", string()),
+                ]))]),
+                vec![
+                    (
+                        "BuildArg.description",
+                        ChangeKind::InputFieldAdded { required: false },
+                        false,
+                    ),
+                    ("BuildArg.key", ChangeKind::InputFieldBecameOptional, false),
+                    ("BuildArg.name", ChangeKind::InputFieldBecameRequired, true),
+                    ("BuildArg.value", ChangeKind::InputFieldRemoved, true),
+                ],
+            ),
+            (
+                "fields",
+                json!([container(json!([]))]),
+                json!([{
+                    "kind": "OBJECT",
+                    "name": "Container",
+                    "fields": [{ "name": "stdout", "args": [], "type": string() }],
+                }]),
+                vec![
+                    ("Container.stdout", ChangeKind::FieldAdded, false),
+                    ("Container.withExec", ChangeKind::FieldRemoved, true),
+                ],
+            ),
+            (
+                "enum values",
+                json!([{
+                    "kind": "ENUM",
+                    "name": "NetworkProtocol",
+                    "enumValues": [{ "name": "TCP" }, { "name": "SCTP" }],
+                }]),
+                json!([{
+                    "kind": "ENUM",
+                    "name": "NetworkProtocol",
+                    "enumValues": [{ "name": "TCP" }, { "name": "UDP" }],
+                }]),
+                vec![
+                    ("NetworkProtocol.SCTP", ChangeKind::EnumValueRemoved, true),
+                    ("NetworkProtocol.UDP", ChangeKind::EnumValueAdded, false),
+                ],
+            ),
+        ];
+
+        for (name, old, new, expected) in cases {
+            let diff = diff(&schema(old), &schema(new));
+
+            let changes = diff
+                .changes
+                .iter()
+                .map(|c| (c.path.as_str(), c.kind.clone(), c.is_breaking()))
+                .collect::<Vec<_>>();
+            assert_eq!(changes, expected, "{}", name);
+            assert!(
+                diff.has_breaking_changes() == expected.iter().any(|(_, _, b)| *b),
+                "{}",
+                name
+            );
+            assert!(diff.is_empty() == expected.is_empty(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_input_field_display() {
+        let old = schema(json!([build_arg(json!([value("name", string())]))]));
+        let new = schema(json!([build_arg(json!([]))]));
+
+        let diff = diff(&old, &new);
+
+        assert_eq!(
+            diff.changes[0].to_string(),
+            "BREAKING     BuildArg.name: input field removed"
+        );
+    }
+}
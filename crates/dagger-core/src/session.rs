@@ -38,6 +38,13 @@ impl Session {
     }
 
     pub async fn schema(&self, req_builder: RequestBuilder) -> eyre::Result<IntrospectionResponse> {
+        let json = self.schema_json(req_builder).await?;
+
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    /// The introspection response as the engine sent it, to be stored and generated from later
+    pub async fn schema_json(&self, req_builder: RequestBuilder) -> eyre::Result<String> {
         let request_body: graphql_client::QueryBody<()> = graphql_client::QueryBody {
             variables: (),
             query: introspection_query::QUERY,
@@ -64,9 +71,7 @@ impl Session {
             return Err(eyre::anyhow!(error_message));
         }
 
-        let json: IntrospectionResponse = res.json().await?;
-
-        Ok(json)
+        Ok(res.text().await?)
     }
 }
 
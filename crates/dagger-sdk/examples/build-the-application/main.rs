@@ -1,16 +1,12 @@
-use dagger_sdk::HostDirectoryOpts;
-
 #[tokio::main]
 async fn main() -> eyre::Result<()> {
     let client = dagger_sdk::connect().await?;
 
-    let host_source_dir = client.host().directory_opts(
-        "examples/build-the-application/app",
-        HostDirectoryOpts {
-            exclude: Some(vec!["node_modules".into(), "ci/".into()]),
-            include: None,
-        },
-    );
+    let host_source_dir = client
+        .host()
+        .directory_with("examples/build-the-application/app", |o| {
+            o.exclude(vec!["node_modules", "ci/"])
+        });
 
     let source = client
         .container()
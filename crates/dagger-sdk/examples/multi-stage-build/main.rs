@@ -1,17 +1,14 @@
-use dagger_sdk::HostDirectoryOpts;
 use rand::Rng;
 
 #[tokio::main]
 async fn main() -> eyre::Result<()> {
     let client = dagger_sdk::connect().await?;
 
-    let host_source_dir = client.host().directory_opts(
-        "examples/publish-the-application/app",
-        HostDirectoryOpts {
-            exclude: Some(vec!["node_modules", "ci/"]),
-            include: None,
-        },
-    );
+    let host_source_dir = client
+        .host()
+        .directory_with("examples/publish-the-application/app", |o| {
+            o.exclude(vec!["node_modules", "ci/"])
+        });
 
     let source = client
         .container()
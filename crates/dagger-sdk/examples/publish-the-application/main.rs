@@ -1,4 +1,3 @@
-use dagger_sdk::HostDirectoryOpts;
 use rand::Rng;
 
 #[tokio::main]
@@ -6,13 +5,11 @@ async fn main() -> eyre::Result<()> {
     let client = dagger_sdk::connect().await?;
     let output = "examples/publish-the-application/app/build";
 
-    let host_source_dir = client.host().directory_opts(
-        "examples/publish-the-application/app",
-        HostDirectoryOpts {
-            exclude: Some(vec!["node_modules", "ci/"]),
-            include: None,
-        },
-    );
+    let host_source_dir = client
+        .host()
+        .directory_with("examples/publish-the-application/app", |o| {
+            o.exclude(vec!["node_modules", "ci/"])
+        });
 
     let source = client
         .container()
@@ -0,0 +1,80 @@
+//! Traits of the objects, to test pipelines without an engine.
+//!
+//! Every object implements its `*Api` trait, which returns other objects as associated types. A
+//! pipeline written against the traits runs on the engine when given the objects of this crate,
+//! and in memory when given their fakes, like [`FakeContainer`]. Fakes build the same queries as
+//! the objects, and answer the ones they execute from a [`Fake`](crate::Fake), which tests give a
+//! response per query and ask for the queries which ran.
+//!
+//! ```no_run
+//! use dagger_sdk::api::{ContainerApi, FakeContainer};
+//!
+//! async fn version(container: &impl ContainerApi) -> eyre::Result<String> {
+//!     container.with_exec(vec!["go", "version"]).stdout().await
+//! }
+//!
+//! # async fn run() -> eyre::Result<()> {
+//! let client = dagger_sdk::connect().await?;
+//! let on_engine = version(&client.container().from("golang:1.19")).await?;
+//!
+//! let fake = dagger_sdk::Fake::new();
+//! fake.respond(
+//!     r#"query{withExec(args:["go","version"]){stdout}}"#,
+//!     serde_json::json!("go version go1.19 linux/amd64\n"),
+//! );
+//! let in_memory = version(&FakeContainer::new(&fake)).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+pub use crate::gen::api::*;
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::{ContainerApi, DirectoryApi, FakeContainer, FakeQuery, QueryApi};
+    use crate::Fake;
+
+    async fn version(container: &impl ContainerApi) -> eyre::Result<String> {
+        let stdout = container.with_exec(vec!["go", "version"]).stdout().await?;
+
+        Ok(stdout.trim().to_string())
+    }
+
+    #[tokio::test]
+    async fn pipelines_run_on_fakes() {
+        let fake = Fake::new();
+        fake.respond(
+            r#"query{withExec(args:["go","version"]){stdout}}"#,
+            json!("go version go1.19 linux/amd64\n"),
+        );
+
+        let version = version(&FakeContainer::new(&fake)).await.unwrap();
+
+        assert_eq!(version, "go version go1.19 linux/amd64");
+        assert_eq!(
+            fake.queries(),
+            vec![r#"query{withExec(args:["go","version"]){stdout}}"#.to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn fakes_fail_on_queries_without_response() {
+        let fake = Fake::new();
+
+        let err = FakeQuery::new(&fake)
+            .container()
+            .from("alpine")
+            .directory("/etc")
+            .entries()
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            r#"no response faked for query{container{from(address:"alpine"){directory(path:"/etc"){entries}}}}"#
+        );
+    }
+}
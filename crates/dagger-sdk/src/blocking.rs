@@ -0,0 +1,39 @@
+//! A blocking API for programs which aren't async, like `build.rs` scripts.
+//!
+//! It mirrors the async API, every object wraps its async counterpart and blocks on a runtime it
+//! owns until queries return. It must not be used from within an async runtime.
+//!
+//! ```no_run
+//! fn main() -> eyre::Result<()> {
+//!     let client = dagger_sdk::blocking::connect()?;
+//!
+//!     let version = client
+//!         .container()
+//!         .from("golang:1.19")
+//!         .with_exec(vec!["go", "version"])
+//!         .stdout()?;
+//!
+//!     println!("Hello from Dagger and {}", version.trim());
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::sync::Arc;
+
+use tokio::runtime::Runtime;
+
+pub use crate::gen::blocking::*;
+
+pub type DaggerConn = Arc<Query>;
+
+/// Starts a runtime and connects to the engine on it
+pub fn connect() -> eyre::Result<DaggerConn> {
+    let runtime = Arc::new(Runtime::new()?);
+    let inner = runtime.block_on(crate::connect())?;
+
+    Ok(Arc::new(Query {
+        inner: inner.as_ref().clone(),
+        runtime,
+    }))
+}
@@ -0,0 +1,337 @@
+//! Record and replay of the queries sent to the engine.
+//!
+//! A [`Cassette`] in [`CassetteMode::Record`] executes queries as usual and writes each query and
+//! its response to a file. In [`CassetteMode::Replay`] it answers from that file instead, so tests
+//! run the same way without an engine. Queries are matched exactly, a query asked for more often
+//! than it was recorded gets the last of its responses again. Registered secrets are redacted
+//! from what is written, see [`crate::secrets`].
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use dagger_core::redaction::redact;
+use eyre::Context;
+use serde::{Deserialize, Serialize};
+
+/// Selects the mode of [`Cassette::from_env`], `record` to record and anything else to replay
+pub const CASSETTE_ENV: &str = "DAGGER_CASSETTE";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CassetteMode {
+    Record,
+    Replay,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+struct Interaction {
+    query: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    data: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct Cassette {
+    path: PathBuf,
+    mode: CassetteMode,
+    interactions: Mutex<Vec<Interaction>>,
+    /// How many responses to each query were replayed
+    replayed: Mutex<HashMap<String, usize>>,
+}
+
+impl Cassette {
+    /// Records to `path`, replacing what it held before
+    pub fn record(path: impl AsRef<Path>) -> Self {
+        Self::new(path.as_ref(), CassetteMode::Record, Vec::new())
+    }
+
+    /// Replays what was recorded to `path`
+    pub fn replay(path: impl AsRef<Path>) -> eyre::Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("could not read cassette {}", path.display()))?;
+        let interactions = serde_json::from_str(&content)
+            .with_context(|| format!("could not parse cassette {}", path.display()))?;
+
+        Ok(Self::new(path, CassetteMode::Replay, interactions))
+    }
+
+    /// Records when the `DAGGER_CASSETTE` environment variable is `record`, replays otherwise
+    pub fn from_env(path: impl AsRef<Path>) -> eyre::Result<Self> {
+        match std::env::var(CASSETTE_ENV).as_deref() {
+            Ok("record") => Ok(Self::record(path)),
+            _ => Self::replay(path),
+        }
+    }
+
+    fn new(path: &Path, mode: CassetteMode, interactions: Vec<Interaction>) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            mode,
+            interactions: Mutex::new(interactions),
+            replayed: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Executes `query` with `gql_client` when recording, or answers it from the cassette
+    pub(crate) async fn query(
+        &self,
+        query: &str,
+        gql_client: &gql_client::Client,
+    ) -> eyre::Result<Option<serde_json::Value>> {
+        match self.mode {
+            CassetteMode::Record => {
+                let result = gql_client.query::<serde_json::Value>(query).await;
+                let interaction = match &result {
+                    Ok(data) => Interaction {
+                        query: redact(query),
+                        data: data.as_ref().map(redact_value).transpose()?,
+                        error: None,
+                    },
+                    Err(e) => Interaction {
+                        query: redact(query),
+                        data: None,
+                        error: Some(redact(&e.to_string())),
+                    },
+                };
+                self.save(interaction)?;
+
+                result.map_err(|e| eyre::eyre!(redact(&e.to_string())))
+            }
+            CassetteMode::Replay => {
+                // recorded queries are redacted, so secrets in this one are as well
+                let interaction = self.next_response(&redact(query))?;
+                match interaction.error {
+                    Some(error) => eyre::bail!(error),
+                    None => Ok(interaction.data),
+                }
+            }
+        }
+    }
+
+    fn save(&self, interaction: Interaction) -> eyre::Result<()> {
+        let mut interactions = self.interactions.lock().unwrap();
+        interactions.push(interaction);
+
+        let content = serde_json::to_string_pretty(&*interactions)?;
+        std::fs::write(&self.path, content)
+            .with_context(|| format!("could not write cassette {}", self.path.display()))
+    }
+
+    fn next_response(&self, query: &str) -> eyre::Result<Interaction> {
+        let interactions = self.interactions.lock().unwrap();
+        let recorded = interactions
+            .iter()
+            .filter(|i| i.query == query)
+            .collect::<Vec<_>>();
+        let last = match recorded.last() {
+            Some(last) => last,
+            None => eyre::bail!(
+                "no response to query {} in cassette {}, record it again with {}=record",
+                query,
+                self.path.display(),
+                CASSETTE_ENV
+            ),
+        };
+
+        let mut replayed = self.replayed.lock().unwrap();
+        let count = replayed.entry(query.to_string()).or_default();
+        let interaction = recorded.get(*count).unwrap_or(last);
+        *count += 1;
+
+        Ok((*interaction).clone())
+    }
+}
+
+fn redact_value(value: &serde_json::Value) -> eyre::Result<serde_json::Value> {
+    Ok(serde_json::from_str(&redact(&serde_json::to_string(
+        value,
+    )?))?)
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::{Cassette, CassetteMode};
+    use crate::client::connect_with_cassette;
+    use crate::gen::Query;
+    use crate::querybuilder::query;
+    use crate::test_util::FakeEngine;
+
+    const PLATFORM: &str = "query{defaultPlatform}";
+
+    #[tokio::test]
+    async fn replays_recorded_responses() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("replays.json");
+        let engine = FakeEngine::start().await.unwrap();
+        engine.respond(PLATFORM, json!({"defaultPlatform": "linux/amd64"}));
+        let recording = Query {
+            proc: engine.proc(),
+            selection: query().with_cassette(Arc::new(Cassette::record(&path))),
+            conn: engine.conn(),
+        };
+        let recorded = recording.default_platform().await.unwrap();
+        drop(engine);
+
+        let cassette = Cassette::replay(&path).unwrap();
+        assert_eq!(cassette.mode(), CassetteMode::Replay);
+        let client = connect_with_cassette(cassette).await.unwrap();
+        let replayed = client.default_platform().await.unwrap();
+
+        assert_eq!(replayed, recorded);
+    }
+
+    #[tokio::test]
+    async fn records_secrets_redacted() {
+        crate::secrets::register_secret("cassette-s3cr3t");
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("secrets.json");
+        let env_query = r#"query{container{withEnvVariable(name:"TOKEN", value:"cassette-s3cr3t"){envVariable(name:"TOKEN")}}}"#;
+        let engine = FakeEngine::start().await.unwrap();
+        engine.respond(
+            env_query,
+            json!({"container": {"withEnvVariable": {"envVariable": "cassette-s3cr3t"}}}),
+        );
+        let recording = Query {
+            proc: engine.proc(),
+            selection: query().with_cassette(Arc::new(Cassette::record(&path))),
+            conn: engine.conn(),
+        };
+        let container = recording
+            .container()
+            .with_env_variable("TOKEN", "cassette-s3cr3t");
+        container.env_variable("TOKEN").await.unwrap();
+        drop(engine);
+
+        let recorded = std::fs::read_to_string(&path).unwrap();
+        assert!(!recorded.contains("cassette-s3cr3t"), "{}", recorded);
+
+        let client = connect_with_cassette(Cassette::replay(&path).unwrap())
+            .await
+            .unwrap();
+        let replayed = client
+            .container()
+            .with_env_variable("TOKEN", "cassette-s3cr3t")
+            .env_variable("TOKEN")
+            .await
+            .unwrap();
+
+        assert_eq!(replayed, "***");
+    }
+
+    #[tokio::test]
+    async fn replays_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("errors.json");
+        let engine = FakeEngine::start().await.unwrap();
+        engine.respond_error(PLATFORM, "engine is gone");
+        let recording = Query {
+            proc: engine.proc(),
+            selection: query().with_cassette(Arc::new(Cassette::record(&path))),
+            conn: engine.conn(),
+        };
+        let _ = recording.default_platform().await.unwrap_err();
+
+        let client = connect_with_cassette(Cassette::replay(&path).unwrap())
+            .await
+            .unwrap();
+        let err = client.default_platform().await.unwrap_err();
+
+        assert!(err.to_string().contains("engine is gone"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn records_errors_redacted() {
+        crate::secrets::register_secret("cassette-err0r");
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("error_secrets.json");
+        let engine = FakeEngine::start().await.unwrap();
+        engine.respond_error(
+            r#"query{container{from(address:"cassette-err0r"){id}}}"#,
+            "pull access denied for cassette-err0r",
+        );
+        let recording = Query {
+            proc: engine.proc(),
+            selection: query().with_cassette(Arc::new(Cassette::record(&path))),
+            conn: engine.conn(),
+        };
+
+        let err = recording
+            .container()
+            .from("cassette-err0r")
+            .id()
+            .await
+            .unwrap_err();
+
+        assert!(!err.to_string().contains("cassette-err0r"), "{}", err);
+        assert!(
+            err.to_string().contains("pull access denied for ***"),
+            "{}",
+            err
+        );
+        let recorded = std::fs::read_to_string(&path).unwrap();
+        assert!(!recorded.contains("cassette-err0r"), "{}", recorded);
+    }
+
+    #[test]
+    fn repeats_the_last_response() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("repeats.json");
+        std::fs::write(
+            &path,
+            json!([
+                {"query": PLATFORM, "data": {"defaultPlatform": "linux/amd64"}},
+                {"query": PLATFORM, "data": {"defaultPlatform": "linux/arm64"}},
+            ])
+            .to_string(),
+        )
+        .unwrap();
+        let cassette = Cassette::replay(&path).unwrap();
+
+        let responses = (0..3)
+            .map(|_| cassette.next_response(PLATFORM).unwrap().data.unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            responses,
+            vec![
+                json!({"defaultPlatform": "linux/amd64"}),
+                json!({"defaultPlatform": "linux/arm64"}),
+                json!({"defaultPlatform": "linux/arm64"}),
+            ]
+        );
+    }
+
+    #[test]
+    fn fails_unrecorded_queries() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("unrecorded.json");
+        std::fs::write(&path, "[]").unwrap();
+        let cassette = Cassette::replay(&path).unwrap();
+
+        let err = cassette.next_response(PLATFORM).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "no response to query query{{defaultPlatform}} in cassette {}, record it again with DAGGER_CASSETTE=record",
+                path.display()
+            )
+        );
+    }
+}
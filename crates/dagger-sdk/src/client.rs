@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::process::Stdio;
 use std::sync::Arc;
 
 use base64::engine::general_purpose;
@@ -6,8 +7,11 @@ use base64::Engine;
 use dagger_core::config::Config;
 use dagger_core::connect_params::ConnectParams;
 use dagger_core::engine::Engine as DaggerEngine;
+use eyre::Context;
 use gql_client::ClientConfig;
+use tokio::process::{Child, Command};
 
+use crate::cassette::{Cassette, CassetteMode};
 use crate::gen::Query;
 use crate::querybuilder::query;
 
@@ -24,11 +28,48 @@ pub async fn connect() -> eyre::Result<DaggerConn> {
     }))
 }
 
-pub fn graphql_client(conn: &ConnectParams) -> gql_client::Client {
-    let token = general_purpose::URL_SAFE.encode(format!("{}:", conn.session_token));
+/// Connects with every query going through `cassette`. A replaying cassette answers all of them,
+/// so no engine is started.
+pub async fn connect_with_cassette(cassette: Cassette) -> eyre::Result<DaggerConn> {
+    let cassette = Arc::new(cassette);
+    if cassette.mode() == CassetteMode::Replay {
+        return Ok(Arc::new(Query {
+            conn: ConnectParams::new(0, ""),
+            proc: Arc::new(placeholder_process()?),
+            selection: query().with_cassette(cassette),
+        }));
+    }
+
+    let client = connect().await?;
+
+    Ok(Arc::new(Query {
+        conn: client.conn.clone(),
+        proc: client.proc.clone(),
+        selection: client.selection.with_cassette(cassette),
+    }))
+}
 
+/// A process which exits right away, for clients without an engine, as objects hold the process
+/// of the engine they query
+pub(crate) fn placeholder_process() -> eyre::Result<Child> {
+    #[cfg(windows)]
+    let mut command = Command::new("cmd");
+    #[cfg(windows)]
+    command.args(["/C", "exit 0"]);
+    #[cfg(not(windows))]
+    let mut command = Command::new("true");
+
+    command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .context("could not start a placeholder process")
+}
+
+pub fn graphql_client(conn: &ConnectParams) -> gql_client::Client {
     let mut headers = HashMap::new();
-    headers.insert("Authorization".to_string(), format!("Basic {}", token));
+    headers.insert("Authorization".to_string(), authorization(conn));
 
     gql_client::Client::new_with_config(ClientConfig {
         endpoint: conn.url(),
@@ -38,6 +79,13 @@ pub fn graphql_client(conn: &ConnectParams) -> gql_client::Client {
     })
 }
 
+/// Value of the `Authorization` header the engine expects for the session of `conn`
+pub(crate) fn authorization(conn: &ConnectParams) -> String {
+    let token = general_purpose::URL_SAFE.encode(format!("{}:", conn.session_token));
+
+    format!("Basic {}", token)
+}
+
 // Conn will automatically close on drop of proc
 
 #[cfg(test)]
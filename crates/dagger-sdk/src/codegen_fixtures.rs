@@ -0,0 +1,118 @@
+//! Compiles the golden files of dagger-codegen against this crate, so the generated code is
+//! checked for more than textual equality.
+
+#![allow(dead_code)]
+
+mod scalars_enums {
+    include!("../../dagger-codegen/tests/fixtures/scalars_enums.rs");
+}
+
+mod inputs_and_args {
+    include!("../../dagger-codegen/tests/fixtures/inputs_and_args.rs");
+}
+
+mod interfaces_unions {
+    include!("../../dagger-codegen/tests/fixtures/interfaces_unions.rs");
+}
+
+// Repeats the code of `gen`, whose lints are already reported there
+#[allow(
+    clippy::from_over_into,
+    clippy::needless_return,
+    clippy::upper_case_acronyms
+)]
+mod dagger_0_4_0 {
+    include!("../../dagger-codegen/tests/fixtures/dagger_0_4_0.rs");
+}
+
+mod customized {
+    include!("../../dagger-codegen/tests/fixtures/customized.rs");
+}
+
+// Repeats the code of `inputs_and_args`, whose lints are already reported there
+#[allow(clippy::from_over_into, clippy::needless_return)]
+mod with_blocking {
+    include!("../../dagger-codegen/tests/fixtures/blocking.rs");
+}
+
+// Repeats the code of `inputs_and_args` as well
+#[allow(clippy::from_over_into, clippy::needless_return)]
+mod traits {
+    include!("../../dagger-codegen/tests/fixtures/traits.rs");
+}
+
+mod extension {
+    include!("../../dagger-codegen/tests/fixtures/extension.rs");
+}
+
+// `directory` isn't a feature of this crate, which checks the code compiles with it disabled
+#[allow(unexpected_cfgs, unused_imports)]
+#[rustfmt::skip]
+#[path = "../../dagger-codegen/tests/fixtures/modules/mod.rs"]
+mod modules;
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use serde::de::DeserializeOwned;
+    use serde::Serialize;
+    use serde_json::{Map, Value};
+
+    use super::inputs_and_args::{BuildArg, BuildSecret, PipelineLabel};
+    use super::scalars_enums::NetworkProtocol;
+
+    fn round_trip<T: Serialize + DeserializeOwned>(value: &Value) -> Value {
+        serde_json::to_value(serde_json::from_value::<T>(value.clone()).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn inputs_round_trip_under_schema_names() {
+        let schema: Value = serde_json::from_str(include_str!(
+            "../../dagger-codegen/tests/fixtures/inputs_and_args.json"
+        ))
+        .unwrap();
+        let inputs = schema["data"]["__schema"]["types"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|t| t["kind"] == "INPUT_OBJECT");
+
+        for input in inputs {
+            let value = input["inputFields"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|f| {
+                    let name = f["name"].as_str().unwrap();
+                    (name.to_string(), Value::from(format!("{}-value", name)))
+                })
+                .collect::<Map<_, _>>()
+                .into();
+
+            let round_tripped = match input["name"].as_str().unwrap() {
+                "BuildArg" => round_trip::<BuildArg>(&value),
+                "BuildSecret" => round_trip::<BuildSecret>(&value),
+                "PipelineLabel" => round_trip::<PipelineLabel>(&value),
+                name => panic!("{} is missing from this test", name),
+            };
+            assert_eq!(round_tripped, value);
+        }
+    }
+
+    #[test]
+    fn enums_round_trip_through_strings() {
+        assert_eq!(NetworkProtocol::TCP.to_string(), "TCP");
+        assert_eq!(
+            "UDP".parse::<NetworkProtocol>().unwrap(),
+            NetworkProtocol::UDP
+        );
+        assert_eq!(
+            serde_json::from_str::<NetworkProtocol>(r#""SCTP""#).unwrap(),
+            NetworkProtocol::Unknown("SCTP".into())
+        );
+        assert_eq!(
+            serde_json::to_string(&NetworkProtocol::Unknown("SCTP".into())).unwrap(),
+            r#""SCTP""#
+        );
+    }
+}
@@ -0,0 +1,192 @@
+//! The result of the last command a container executed, fetched in a single query.
+
+use std::fmt::{Display, Formatter};
+
+use dagger_core::redaction::redact;
+use serde::Deserialize;
+
+use crate::client::graphql_client;
+use crate::gen::Container;
+
+/// Lines of output an [`ExecError`] shows
+const TAIL_LINES: usize = 20;
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecResult {
+    pub exit_code: isize,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+/// A command which exited with a non-zero code, returned by [`Container::sync_ok`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExecError {
+    /// Arguments of the last `with_exec`, if the container was built with one
+    pub args: Option<Vec<String>>,
+    pub result: ExecResult,
+}
+
+impl Display for ExecError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.args.as_ref() {
+            Some(args) => write!(f, "`{}`", redact(&args.join(" ")))?,
+            None => write!(f, "command")?,
+        }
+        write!(f, " exited with code {}", self.result.exit_code)?;
+
+        for (name, output) in [
+            ("stderr", &self.result.stderr),
+            ("stdout", &self.result.stdout),
+        ] {
+            let lines = output.lines().collect::<Vec<_>>();
+            if lines.is_empty() {
+                continue;
+            }
+
+            let tail = &lines[lines.len().saturating_sub(TAIL_LINES)..];
+            write!(f, "\n\n{}", name)?;
+            if tail.len() < lines.len() {
+                write!(f, " (last {} lines)", tail.len())?;
+            }
+            write!(f, ":\n{}", redact(&tail.join("\n")))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ExecError {}
+
+impl Container {
+    /// Exit code, stdout and stderr of the last executed command.
+    /// Errors if no command has been executed.
+    pub async fn exec_result(&self) -> eyre::Result<ExecResult> {
+        let query = self
+            .selection
+            .select_fields(&["exitCode", "stdout", "stderr"]);
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+
+    /// Like [`Self::exec_result`], failing with an [`ExecError`] when the command exited with a
+    /// non-zero code.
+    pub async fn sync_ok(&self) -> eyre::Result<ExecResult> {
+        let result = self.exec_result().await?;
+        if result.exit_code != 0 {
+            let args = self
+                .selection
+                .last_arg("withExec", "args")
+                .and_then(|args| serde_json::from_str(&args).ok());
+
+            return Err(ExecError { args, result }.into());
+        }
+
+        Ok(result)
+    }
+}
+
+impl crate::blocking::Container {
+    /// Exit code, stdout and stderr of the last executed command.
+    /// Errors if no command has been executed.
+    pub fn exec_result(&self) -> eyre::Result<ExecResult> {
+        self.runtime.block_on(self.inner.exec_result())
+    }
+
+    /// Like [`Self::exec_result`], failing with an [`ExecError`] when the command exited with a
+    /// non-zero code.
+    pub fn sync_ok(&self) -> eyre::Result<ExecResult> {
+        self.runtime.block_on(self.inner.sync_ok())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::{ExecError, ExecResult};
+    use crate::test_util::FakeEngine;
+
+    const EXEC_RESULT: &str = r#"query{container{from(address:"alpine"){withExec(args:["ls","/nope"]){exitCode stdout stderr}}}}"#;
+
+    #[tokio::test]
+    async fn exec_result_in_one_query() {
+        let engine = FakeEngine::start().await.unwrap();
+        engine.respond(
+            EXEC_RESULT,
+            json!({"container": {"from": {"withExec": {"exitCode": 0, "stdout": "nope\n", "stderr": ""}}}}),
+        );
+
+        let result = engine
+            .client()
+            .container()
+            .from("alpine")
+            .with_exec(vec!["ls", "/nope"])
+            .exec_result()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result,
+            ExecResult {
+                exit_code: 0,
+                stdout: "nope\n".into(),
+                stderr: "".into(),
+            }
+        );
+        assert_eq!(engine.queries(), vec![EXEC_RESULT.to_string()]);
+    }
+
+    #[tokio::test]
+    async fn sync_ok_fails_on_non_zero_exit_codes() {
+        let engine = FakeEngine::start().await.unwrap();
+        engine.respond(
+            EXEC_RESULT,
+            json!({"container": {"from": {"withExec": {
+                "exitCode": 2,
+                "stdout": "",
+                "stderr": "ls: /nope: No such file or directory\n",
+            }}}}),
+        );
+
+        let err = engine
+            .client()
+            .container()
+            .from("alpine")
+            .with_exec(vec!["ls", "/nope"])
+            .sync_ok()
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<ExecError>().unwrap().args,
+            Some(vec!["ls".to_string(), "/nope".to_string()])
+        );
+        assert_eq!(
+            err.to_string(),
+            "`ls /nope` exited with code 2\n\nstderr:\nls: /nope: No such file or directory"
+        );
+    }
+
+    #[test]
+    fn exec_error_shows_the_tail_of_the_output() {
+        let err = ExecError {
+            args: None,
+            result: ExecResult {
+                exit_code: 1,
+                stdout: (1..=25).map(|i| format!("line {}\n", i)).collect(),
+                stderr: "".into(),
+            },
+        };
+
+        let expected = format!(
+            "command exited with code 1\n\nstdout (last 20 lines):\n{}",
+            (6..=25)
+                .map(|i| format!("line {}", i))
+                .collect::<Vec<_>>()
+                .join("\n")
+        );
+        assert_eq!(err.to_string(), expected);
+    }
+}
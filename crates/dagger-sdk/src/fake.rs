@@ -0,0 +1,50 @@
+//! In-memory answers for the fakes of the `api` module, see [`crate::api`].
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use serde::de::DeserializeOwned;
+
+use crate::querybuilder::Selection;
+
+/// Answers the queries fakes execute with the values it was given, keyed by query, and records
+/// the queries in the order they ran. Clones share both.
+#[derive(Clone, Debug, Default)]
+pub struct Fake {
+    responses: Arc<Mutex<HashMap<String, serde_json::Value>>>,
+    queries: Arc<Mutex<Vec<String>>>,
+}
+
+impl Fake {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answers `query` with `value`, the value of its last field, like `json!("go1.19\n")` for
+    /// `query{withExec(args:["go","version"]){stdout}}`
+    pub fn respond(&self, query: impl Into<String>, value: serde_json::Value) {
+        self.responses.lock().unwrap().insert(query.into(), value);
+    }
+
+    /// The queries executed so far, in order
+    pub fn queries(&self) -> Vec<String> {
+        self.queries.lock().unwrap().clone()
+    }
+
+    /// Records the query of `selection` and returns the value it was answered with
+    pub fn execute<D: DeserializeOwned>(&self, selection: &Selection) -> eyre::Result<D> {
+        let query = selection.build()?;
+        self.queries.lock().unwrap().push(query.clone());
+
+        let value = self
+            .responses
+            .lock()
+            .unwrap()
+            .get(&query)
+            .cloned()
+            .ok_or_else(|| eyre::eyre!("no response faked for {}", query))?;
+
+        serde_json::from_value(value)
+            .map_err(|e| eyre::eyre!("could not deserialize the response to {}: {}", query, e))
+    }
+}
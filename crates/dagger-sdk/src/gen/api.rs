@@ -0,0 +1,5188 @@
+use crate::fake::Fake;
+use crate::querybuilder::Selection;
+use async_trait::async_trait;
+
+use super::*;
+
+/// Trait of [`super::CacheVolume`]
+#[async_trait]
+pub trait CacheVolumeApi: Send + Sync {
+    /// Executes the query and returns its result.
+    async fn id(&self) -> eyre::Result<CacheId>;
+}
+
+#[async_trait]
+impl CacheVolumeApi for super::CacheVolume {
+    /// Executes the query and returns its result.
+    async fn id(&self) -> eyre::Result<CacheId> {
+        self.id().await
+    }
+}
+
+/// In-memory fake of [`CacheVolumeApi`]
+#[derive(Debug, Clone)]
+pub struct FakeCacheVolume {
+    pub selection: Selection,
+    pub fake: Fake,
+}
+
+impl FakeCacheVolume {
+    /// A fake whose queries start at this object, answered by `fake`
+    pub fn new(fake: &Fake) -> Self {
+        Self {
+            selection: Selection::default(),
+            fake: fake.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl CacheVolumeApi for FakeCacheVolume {
+    async fn id(&self) -> eyre::Result<CacheId> {
+        let query = self.selection.select("id");
+
+        self.fake.execute(&query)
+    }
+}
+/// Trait of [`super::Container`]
+#[async_trait]
+pub trait ContainerApi: Send + Sync {
+    type Container: ContainerApi;
+    type Directory: DirectoryApi;
+    type EnvVariable: EnvVariableApi;
+    type File: FileApi;
+    type Label: LabelApi;
+    type Port: PortApi;
+
+    /// Initializes this container from a Dockerfile build.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerBuildOpts`], are taken by [`Self::build_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - Directory context used by the Dockerfile.
+    ///   Identifies a [`Directory`].
+    fn build(&self, context: DirectoryId) -> Self::Container;
+
+    /// Initializes this container from a Dockerfile build.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - Directory context used by the Dockerfile.
+    ///   Identifies a [`Directory`].
+    /// * `opts` - Optional arguments, see [`ContainerBuildOpts`].
+    fn build_opts<'a>(&self, context: DirectoryId, opts: ContainerBuildOpts<'a>)
+        -> Self::Container;
+
+    /// Retrieves default arguments for future commands.
+    ///
+    /// Executes the query and returns its result.
+    async fn default_args(&self) -> eyre::Result<Vec<String>>;
+    /// Retrieves a directory at the given path.
+    /// Mounts are included.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the directory to retrieve (e.g., "./src").
+    fn directory(&self, path: impl Into<String> + Send) -> Self::Directory;
+    /// Retrieves an endpoint that clients can use to reach this container.
+    /// If no port is specified, the first exposed port is used. If none exist an error is returned.
+    /// If a scheme is specified, a URL is returned. Otherwise, a host:port pair is returned.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`ContainerEndpointOpts`], are taken by [`Self::endpoint_opts`].
+    async fn endpoint(&self) -> eyre::Result<String>;
+
+    /// Retrieves an endpoint that clients can use to reach this container.
+    /// If no port is specified, the first exposed port is used. If none exist an error is returned.
+    /// If a scheme is specified, a URL is returned. Otherwise, a host:port pair is returned.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerEndpointOpts`].
+    async fn endpoint_opts<'a>(&self, opts: ContainerEndpointOpts<'a>) -> eyre::Result<String>;
+
+    /// Retrieves entrypoint to be prepended to the arguments of all commands.
+    ///
+    /// Executes the query and returns its result.
+    async fn entrypoint(&self) -> eyre::Result<Vec<String>>;
+    /// Retrieves the value of the specified environment variable.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the environment variable to retrieve (e.g., "PATH").
+    async fn env_variable(&self, name: impl Into<String> + Send) -> eyre::Result<String>;
+    /// Retrieves the list of environment variables passed to commands.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`EnvVariable`] is awaited.
+    fn env_variables(&self) -> Vec<Self::EnvVariable>;
+    /// Retrieves this container after executing the specified command inside it.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerExecOpts`], are taken by [`Self::exec_opts`].
+    fn exec(&self) -> Self::Container;
+
+    /// Retrieves this container after executing the specified command inside it.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerExecOpts`].
+    fn exec_opts<'a>(&self, opts: ContainerExecOpts<'a>) -> Self::Container;
+
+    /// Exit code of the last executed command. Zero means success.
+    /// Errors if no command has been executed.
+    ///
+    /// Executes the query and returns its result.
+    async fn exit_code(&self) -> eyre::Result<isize>;
+    /// Writes the container as an OCI tarball to the destination file path on the host for the specified platform variants.
+    /// Return true on success.
+    /// It can also publishes platform variants.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`ContainerExportOpts`], are taken by [`Self::export_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Host's destination path (e.g., "./tarball").
+    ///   Path can be relative to the engine's workdir or absolute.
+    async fn export(&self, path: impl Into<String> + Send) -> eyre::Result<bool>;
+
+    /// Writes the container as an OCI tarball to the destination file path on the host for the specified platform variants.
+    /// Return true on success.
+    /// It can also publishes platform variants.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Host's destination path (e.g., "./tarball").
+    ///   Path can be relative to the engine's workdir or absolute.
+    /// * `opts` - Optional arguments, see [`ContainerExportOpts`].
+    async fn export_opts(
+        &self,
+        path: impl Into<String> + Send,
+        opts: ContainerExportOpts,
+    ) -> eyre::Result<bool>;
+
+    /// Retrieves the list of exposed ports.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Port`] is awaited.
+    fn exposed_ports(&self) -> Vec<Self::Port>;
+    /// Retrieves a file at the given path.
+    /// Mounts are included.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file to retrieve (e.g., "./README.md").
+    fn file(&self, path: impl Into<String> + Send) -> Self::File;
+    /// Initializes this container from a pulled base image.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Image's address from its registry.
+    ///
+    ///   Formatted as \[host\]/\[user\]/\[repo\]:\[tag\] (e.g., "docker.io/dagger/dagger:main").
+    fn from(&self, address: impl Into<String> + Send) -> Self::Container;
+    /// Retrieves this container's root filesystem. Mounts are not included.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    fn fs(&self) -> Self::Directory;
+    /// Retrieves a hostname which can be used by clients to reach this container.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Executes the query and returns its result.
+    async fn hostname(&self) -> eyre::Result<String>;
+    /// A unique identifier for this container.
+    ///
+    /// Executes the query and returns its result.
+    async fn id(&self) -> eyre::Result<ContainerId>;
+    /// The unique image reference which can only be retrieved immediately after the 'Container.From' call.
+    ///
+    /// Executes the query and returns its result.
+    async fn image_ref(&self) -> eyre::Result<String>;
+    /// Retrieves the value of the specified label.
+    ///
+    /// Executes the query and returns its result.
+    async fn label(&self, name: impl Into<String> + Send) -> eyre::Result<String>;
+    /// Retrieves the list of labels passed to container.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Label`] is awaited.
+    fn labels(&self) -> Vec<Self::Label>;
+    /// Retrieves the list of paths where a directory is mounted.
+    ///
+    /// Executes the query and returns its result.
+    async fn mounts(&self) -> eyre::Result<Vec<String>>;
+    /// Creates a named sub-pipeline
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerPipelineOpts`], are taken by [`Self::pipeline_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    fn pipeline(&self, name: impl Into<String> + Send) -> Self::Container;
+
+    /// Creates a named sub-pipeline
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    /// * `opts` - Optional arguments, see [`ContainerPipelineOpts`].
+    fn pipeline_opts<'a>(
+        &self,
+        name: impl Into<String> + Send,
+        opts: ContainerPipelineOpts<'a>,
+    ) -> Self::Container;
+
+    /// The platform this container executes and publishes as.
+    ///
+    /// Executes the query and returns its result.
+    async fn platform(&self) -> eyre::Result<Platform>;
+    /// Publishes this container as a new image to the specified address.
+    /// Publish returns a fully qualified ref.
+    /// It can also publish platform variants.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`ContainerPublishOpts`], are taken by [`Self::publish_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Registry's address to publish the image to.
+    ///
+    ///   Formatted as \[host\]/\[user\]/\[repo\]:\[tag\] (e.g. "docker.io/dagger/dagger:main").
+    async fn publish(&self, address: impl Into<String> + Send) -> eyre::Result<String>;
+
+    /// Publishes this container as a new image to the specified address.
+    /// Publish returns a fully qualified ref.
+    /// It can also publish platform variants.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Registry's address to publish the image to.
+    ///
+    ///   Formatted as \[host\]/\[user\]/\[repo\]:\[tag\] (e.g. "docker.io/dagger/dagger:main").
+    /// * `opts` - Optional arguments, see [`ContainerPublishOpts`].
+    async fn publish_opts(
+        &self,
+        address: impl Into<String> + Send,
+        opts: ContainerPublishOpts,
+    ) -> eyre::Result<String>;
+
+    /// Retrieves this container's root filesystem. Mounts are not included.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    fn rootfs(&self) -> Self::Directory;
+    /// The error stream of the last executed command.
+    /// Errors if no command has been executed.
+    ///
+    /// Executes the query and returns its result.
+    async fn stderr(&self) -> eyre::Result<String>;
+    /// The output stream of the last executed command.
+    /// Errors if no command has been executed.
+    ///
+    /// Executes the query and returns its result.
+    async fn stdout(&self) -> eyre::Result<String>;
+    /// Retrieves the user to be set for all commands.
+    ///
+    /// Executes the query and returns its result.
+    async fn user(&self) -> eyre::Result<String>;
+    /// Configures default arguments for future commands.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithDefaultArgsOpts`], are taken by [`Self::with_default_args_opts`].
+    fn with_default_args(&self) -> Self::Container;
+
+    /// Configures default arguments for future commands.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerWithDefaultArgsOpts`].
+    fn with_default_args_opts<'a>(&self, opts: ContainerWithDefaultArgsOpts<'a>)
+        -> Self::Container;
+
+    /// Retrieves this container plus a directory written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithDirectoryOpts`], are taken by [`Self::with_directory_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written directory (e.g., "/tmp/directory").
+    /// * `directory` - Identifier of the directory to write
+    ///   Identifies a [`Directory`].
+    fn with_directory(
+        &self,
+        path: impl Into<String> + Send,
+        directory: DirectoryId,
+    ) -> Self::Container;
+
+    /// Retrieves this container plus a directory written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written directory (e.g., "/tmp/directory").
+    /// * `directory` - Identifier of the directory to write
+    ///   Identifies a [`Directory`].
+    /// * `opts` - Optional arguments, see [`ContainerWithDirectoryOpts`].
+    fn with_directory_opts<'a>(
+        &self,
+        path: impl Into<String> + Send,
+        directory: DirectoryId,
+        opts: ContainerWithDirectoryOpts<'a>,
+    ) -> Self::Container;
+
+    /// Retrieves this container but with a different command entrypoint.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Entrypoint to use for future executions (e.g., \["go", "run"\]).
+    fn with_entrypoint(&self, args: Vec<impl Into<String> + Send>) -> Self::Container;
+    /// Retrieves this container plus the given environment variable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the environment variable (e.g., "HOST").
+    /// * `value` - The value of the environment variable. (e.g., "localhost").
+    fn with_env_variable(
+        &self,
+        name: impl Into<String> + Send,
+        value: impl Into<String> + Send,
+    ) -> Self::Container;
+    /// Retrieves this container after executing the specified command inside it.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithExecOpts`], are taken by [`Self::with_exec_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Command to run instead of the container's default command (e.g., \["run", "main.go"\]).
+    fn with_exec(&self, args: Vec<impl Into<String> + Send>) -> Self::Container;
+
+    /// Retrieves this container after executing the specified command inside it.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Command to run instead of the container's default command (e.g., \["run", "main.go"\]).
+    /// * `opts` - Optional arguments, see [`ContainerWithExecOpts`].
+    fn with_exec_opts<'a>(
+        &self,
+        args: Vec<impl Into<String> + Send>,
+        opts: ContainerWithExecOpts<'a>,
+    ) -> Self::Container;
+
+    /// Expose a network port.
+    /// Exposed ports serve two purposes:
+    /// - For health checks and introspection, when running services
+    /// - For setting the EXPOSE OCI field when publishing the container
+    ///
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithExposedPortOpts`], are taken by [`Self::with_exposed_port_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Port number to expose
+    fn with_exposed_port(&self, port: isize) -> Self::Container;
+
+    /// Expose a network port.
+    /// Exposed ports serve two purposes:
+    /// - For health checks and introspection, when running services
+    /// - For setting the EXPOSE OCI field when publishing the container
+    ///
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Port number to expose
+    /// * `opts` - Optional arguments, see [`ContainerWithExposedPortOpts`].
+    fn with_exposed_port_opts<'a>(
+        &self,
+        port: isize,
+        opts: ContainerWithExposedPortOpts<'a>,
+    ) -> Self::Container;
+
+    /// Initializes this container from this DirectoryID.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Identifies a [`Directory`].
+    fn with_fs(&self, id: DirectoryId) -> Self::Container;
+    /// Retrieves this container plus the contents of the given file copied to the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithFileOpts`], are taken by [`Self::with_file_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the copied file (e.g., "/tmp/file.txt").
+    /// * `source` - Identifier of the file to copy.
+    ///   Identifies a [`File`].
+    fn with_file(&self, path: impl Into<String> + Send, source: FileId) -> Self::Container;
+
+    /// Retrieves this container plus the contents of the given file copied to the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the copied file (e.g., "/tmp/file.txt").
+    /// * `source` - Identifier of the file to copy.
+    ///   Identifies a [`File`].
+    /// * `opts` - Optional arguments, see [`ContainerWithFileOpts`].
+    fn with_file_opts(
+        &self,
+        path: impl Into<String> + Send,
+        source: FileId,
+        opts: ContainerWithFileOpts,
+    ) -> Self::Container;
+
+    /// Retrieves this container plus the given label.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the label (e.g., "org.opencontainers.artifact.created").
+    /// * `value` - The value of the label (e.g., "2023-01-01T00:00:00Z").
+    fn with_label(
+        &self,
+        name: impl Into<String> + Send,
+        value: impl Into<String> + Send,
+    ) -> Self::Container;
+    /// Retrieves this container plus a cache volume mounted at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithMountedCacheOpts`], are taken by [`Self::with_mounted_cache_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the cache directory (e.g., "/cache/node_modules").
+    /// * `cache` - Identifier of the cache volume to mount.
+    fn with_mounted_cache(&self, path: impl Into<String> + Send, cache: CacheId)
+        -> Self::Container;
+
+    /// Retrieves this container plus a cache volume mounted at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the cache directory (e.g., "/cache/node_modules").
+    /// * `cache` - Identifier of the cache volume to mount.
+    /// * `opts` - Optional arguments, see [`ContainerWithMountedCacheOpts`].
+    fn with_mounted_cache_opts(
+        &self,
+        path: impl Into<String> + Send,
+        cache: CacheId,
+        opts: ContainerWithMountedCacheOpts,
+    ) -> Self::Container;
+
+    /// Retrieves this container plus a directory mounted at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the mounted directory (e.g., "/mnt/directory").
+    /// * `source` - Identifier of the mounted directory.
+    ///   Identifies a [`Directory`].
+    fn with_mounted_directory(
+        &self,
+        path: impl Into<String> + Send,
+        source: DirectoryId,
+    ) -> Self::Container;
+    /// Retrieves this container plus a file mounted at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the mounted file (e.g., "/tmp/file.txt").
+    /// * `source` - Identifier of the mounted file.
+    ///   Identifies a [`File`].
+    fn with_mounted_file(&self, path: impl Into<String> + Send, source: FileId) -> Self::Container;
+    /// Retrieves this container plus a secret mounted into a file at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the secret file (e.g., "/tmp/secret.txt").
+    /// * `source` - Identifier of the secret to mount.
+    ///   Identifies a [`Secret`].
+    fn with_mounted_secret(
+        &self,
+        path: impl Into<String> + Send,
+        source: SecretId,
+    ) -> Self::Container;
+    /// Retrieves this container plus a temporary directory mounted at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the temporary directory (e.g., "/tmp/temp_dir").
+    fn with_mounted_temp(&self, path: impl Into<String> + Send) -> Self::Container;
+    /// Retrieves this container plus a new file written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithNewFileOpts`], are taken by [`Self::with_new_file_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written file (e.g., "/tmp/file.txt").
+    fn with_new_file(&self, path: impl Into<String> + Send) -> Self::Container;
+
+    /// Retrieves this container plus a new file written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written file (e.g., "/tmp/file.txt").
+    /// * `opts` - Optional arguments, see [`ContainerWithNewFileOpts`].
+    fn with_new_file_opts<'a>(
+        &self,
+        path: impl Into<String> + Send,
+        opts: ContainerWithNewFileOpts<'a>,
+    ) -> Self::Container;
+
+    /// Retrieves this container with a registry authentication for a given address.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Registry's address to bind the authentication to.
+    ///   Formatted as \[host\]/\[user\]/\[repo\]:\[tag\] (e.g. docker.io/dagger/dagger:main).
+    /// * `username` - The username of the registry's account (e.g., "Dagger").
+    /// * `secret` - The API key, password or token to authenticate to this registry.
+    ///   Identifies a [`Secret`].
+    fn with_registry_auth(
+        &self,
+        address: impl Into<String> + Send,
+        username: impl Into<String> + Send,
+        secret: SecretId,
+    ) -> Self::Container;
+    /// Initializes this container from this DirectoryID.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Identifies a [`Directory`].
+    fn with_rootfs(&self, id: DirectoryId) -> Self::Container;
+    /// Retrieves this container plus an env variable containing the given secret.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the secret variable (e.g., "API_SECRET").
+    /// * `secret` - The identifier of the secret value.
+    ///   Identifies a [`Secret`].
+    fn with_secret_variable(
+        &self,
+        name: impl Into<String> + Send,
+        secret: SecretId,
+    ) -> Self::Container;
+    /// Establish a runtime dependency on a service. The service will be started automatically when needed and detached when it is no longer needed.
+    /// The service will be reachable from the container via the provided hostname alias.
+    /// The service dependency will also convey to any files or directories produced by the container.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `alias` - A name that can be used to reach the service from the container
+    /// * `service` - Identifier of the service container
+    ///   Identifies a [`Container`].
+    fn with_service_binding(
+        &self,
+        alias: impl Into<String> + Send,
+        service: ContainerId,
+    ) -> Self::Container;
+    /// Retrieves this container plus a socket forwarded to the given Unix socket path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the forwarded Unix socket (e.g., "/tmp/socket").
+    /// * `source` - Identifier of the socket to forward.
+    fn with_unix_socket(&self, path: impl Into<String> + Send, source: SocketId)
+        -> Self::Container;
+    /// Retrieves this container with a different command user.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The user to set (e.g., "root").
+    fn with_user(&self, name: impl Into<String> + Send) -> Self::Container;
+    /// Retrieves this container with a different working directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to set as the working directory (e.g., "/app").
+    fn with_workdir(&self, path: impl Into<String> + Send) -> Self::Container;
+    /// Retrieves this container minus the given environment variable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the environment variable (e.g., "HOST").
+    fn without_env_variable(&self, name: impl Into<String> + Send) -> Self::Container;
+    /// Unexpose a previously exposed port.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithoutExposedPortOpts`], are taken by [`Self::without_exposed_port_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Port number to unexpose
+    fn without_exposed_port(&self, port: isize) -> Self::Container;
+
+    /// Unexpose a previously exposed port.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Port number to unexpose
+    /// * `opts` - Optional arguments, see [`ContainerWithoutExposedPortOpts`].
+    fn without_exposed_port_opts(
+        &self,
+        port: isize,
+        opts: ContainerWithoutExposedPortOpts,
+    ) -> Self::Container;
+
+    /// Retrieves this container minus the given environment label.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the label to remove (e.g., "org.opencontainers.artifact.created").
+    fn without_label(&self, name: impl Into<String> + Send) -> Self::Container;
+    /// Retrieves this container after unmounting everything at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the cache directory (e.g., "/cache/node_modules").
+    fn without_mount(&self, path: impl Into<String> + Send) -> Self::Container;
+    /// Retrieves this container without the registry authentication of a given address.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Registry's address to remove the authentication from.
+    ///   Formatted as \[host\]/\[user\]/\[repo\]:\[tag\] (e.g. docker.io/dagger/dagger:main).
+    fn without_registry_auth(&self, address: impl Into<String> + Send) -> Self::Container;
+    /// Retrieves this container with a previously added Unix socket removed.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the socket to remove (e.g., "/tmp/socket").
+    fn without_unix_socket(&self, path: impl Into<String> + Send) -> Self::Container;
+    /// Retrieves the working directory for all commands.
+    ///
+    /// Executes the query and returns its result.
+    async fn workdir(&self) -> eyre::Result<String>;
+}
+
+#[async_trait]
+impl ContainerApi for super::Container {
+    type Container = super::Container;
+    type Directory = super::Directory;
+    type EnvVariable = super::EnvVariable;
+    type File = super::File;
+    type Label = super::Label;
+    type Port = super::Port;
+
+    /// Initializes this container from a Dockerfile build.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerBuildOpts`], are taken by [`Self::build_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - Directory context used by the Dockerfile.
+    ///   Identifies a [`Directory`].
+    fn build(&self, context: DirectoryId) -> Self::Container {
+        self.build(context)
+    }
+
+    /// Initializes this container from a Dockerfile build.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - Directory context used by the Dockerfile.
+    ///   Identifies a [`Directory`].
+    /// * `opts` - Optional arguments, see [`ContainerBuildOpts`].
+    fn build_opts<'a>(
+        &self,
+        context: DirectoryId,
+        opts: ContainerBuildOpts<'a>,
+    ) -> Self::Container {
+        self.build_opts(context, opts)
+    }
+
+    /// Retrieves default arguments for future commands.
+    ///
+    /// Executes the query and returns its result.
+    async fn default_args(&self) -> eyre::Result<Vec<String>> {
+        self.default_args().await
+    }
+    /// Retrieves a directory at the given path.
+    /// Mounts are included.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the directory to retrieve (e.g., "./src").
+    fn directory(&self, path: impl Into<String> + Send) -> Self::Directory {
+        self.directory(path)
+    }
+    /// Retrieves an endpoint that clients can use to reach this container.
+    /// If no port is specified, the first exposed port is used. If none exist an error is returned.
+    /// If a scheme is specified, a URL is returned. Otherwise, a host:port pair is returned.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`ContainerEndpointOpts`], are taken by [`Self::endpoint_opts`].
+    async fn endpoint(&self) -> eyre::Result<String> {
+        self.endpoint().await
+    }
+
+    /// Retrieves an endpoint that clients can use to reach this container.
+    /// If no port is specified, the first exposed port is used. If none exist an error is returned.
+    /// If a scheme is specified, a URL is returned. Otherwise, a host:port pair is returned.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerEndpointOpts`].
+    async fn endpoint_opts<'a>(&self, opts: ContainerEndpointOpts<'a>) -> eyre::Result<String> {
+        self.endpoint_opts(opts).await
+    }
+
+    /// Retrieves entrypoint to be prepended to the arguments of all commands.
+    ///
+    /// Executes the query and returns its result.
+    async fn entrypoint(&self) -> eyre::Result<Vec<String>> {
+        self.entrypoint().await
+    }
+    /// Retrieves the value of the specified environment variable.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the environment variable to retrieve (e.g., "PATH").
+    async fn env_variable(&self, name: impl Into<String> + Send) -> eyre::Result<String> {
+        self.env_variable(name).await
+    }
+    /// Retrieves the list of environment variables passed to commands.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`EnvVariable`] is awaited.
+    fn env_variables(&self) -> Vec<Self::EnvVariable> {
+        self.env_variables()
+    }
+    /// Retrieves this container after executing the specified command inside it.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerExecOpts`], are taken by [`Self::exec_opts`].
+    fn exec(&self) -> Self::Container {
+        self.exec()
+    }
+
+    /// Retrieves this container after executing the specified command inside it.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerExecOpts`].
+    fn exec_opts<'a>(&self, opts: ContainerExecOpts<'a>) -> Self::Container {
+        self.exec_opts(opts)
+    }
+
+    /// Exit code of the last executed command. Zero means success.
+    /// Errors if no command has been executed.
+    ///
+    /// Executes the query and returns its result.
+    async fn exit_code(&self) -> eyre::Result<isize> {
+        self.exit_code().await
+    }
+    /// Writes the container as an OCI tarball to the destination file path on the host for the specified platform variants.
+    /// Return true on success.
+    /// It can also publishes platform variants.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`ContainerExportOpts`], are taken by [`Self::export_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Host's destination path (e.g., "./tarball").
+    ///   Path can be relative to the engine's workdir or absolute.
+    async fn export(&self, path: impl Into<String> + Send) -> eyre::Result<bool> {
+        self.export(path).await
+    }
+
+    /// Writes the container as an OCI tarball to the destination file path on the host for the specified platform variants.
+    /// Return true on success.
+    /// It can also publishes platform variants.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Host's destination path (e.g., "./tarball").
+    ///   Path can be relative to the engine's workdir or absolute.
+    /// * `opts` - Optional arguments, see [`ContainerExportOpts`].
+    async fn export_opts(
+        &self,
+        path: impl Into<String> + Send,
+        opts: ContainerExportOpts,
+    ) -> eyre::Result<bool> {
+        self.export_opts(path, opts).await
+    }
+
+    /// Retrieves the list of exposed ports.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Port`] is awaited.
+    fn exposed_ports(&self) -> Vec<Self::Port> {
+        self.exposed_ports()
+    }
+    /// Retrieves a file at the given path.
+    /// Mounts are included.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file to retrieve (e.g., "./README.md").
+    fn file(&self, path: impl Into<String> + Send) -> Self::File {
+        self.file(path)
+    }
+    /// Initializes this container from a pulled base image.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Image's address from its registry.
+    ///
+    ///   Formatted as \[host\]/\[user\]/\[repo\]:\[tag\] (e.g., "docker.io/dagger/dagger:main").
+    fn from(&self, address: impl Into<String> + Send) -> Self::Container {
+        self.from(address)
+    }
+    /// Retrieves this container's root filesystem. Mounts are not included.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    fn fs(&self) -> Self::Directory {
+        self.fs()
+    }
+    /// Retrieves a hostname which can be used by clients to reach this container.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Executes the query and returns its result.
+    async fn hostname(&self) -> eyre::Result<String> {
+        self.hostname().await
+    }
+    /// A unique identifier for this container.
+    ///
+    /// Executes the query and returns its result.
+    async fn id(&self) -> eyre::Result<ContainerId> {
+        self.id().await
+    }
+    /// The unique image reference which can only be retrieved immediately after the 'Container.From' call.
+    ///
+    /// Executes the query and returns its result.
+    async fn image_ref(&self) -> eyre::Result<String> {
+        self.image_ref().await
+    }
+    /// Retrieves the value of the specified label.
+    ///
+    /// Executes the query and returns its result.
+    async fn label(&self, name: impl Into<String> + Send) -> eyre::Result<String> {
+        self.label(name).await
+    }
+    /// Retrieves the list of labels passed to container.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Label`] is awaited.
+    fn labels(&self) -> Vec<Self::Label> {
+        self.labels()
+    }
+    /// Retrieves the list of paths where a directory is mounted.
+    ///
+    /// Executes the query and returns its result.
+    async fn mounts(&self) -> eyre::Result<Vec<String>> {
+        self.mounts().await
+    }
+    /// Creates a named sub-pipeline
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerPipelineOpts`], are taken by [`Self::pipeline_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    fn pipeline(&self, name: impl Into<String> + Send) -> Self::Container {
+        self.pipeline(name)
+    }
+
+    /// Creates a named sub-pipeline
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    /// * `opts` - Optional arguments, see [`ContainerPipelineOpts`].
+    fn pipeline_opts<'a>(
+        &self,
+        name: impl Into<String> + Send,
+        opts: ContainerPipelineOpts<'a>,
+    ) -> Self::Container {
+        self.pipeline_opts(name, opts)
+    }
+
+    /// The platform this container executes and publishes as.
+    ///
+    /// Executes the query and returns its result.
+    async fn platform(&self) -> eyre::Result<Platform> {
+        self.platform().await
+    }
+    /// Publishes this container as a new image to the specified address.
+    /// Publish returns a fully qualified ref.
+    /// It can also publish platform variants.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`ContainerPublishOpts`], are taken by [`Self::publish_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Registry's address to publish the image to.
+    ///
+    ///   Formatted as \[host\]/\[user\]/\[repo\]:\[tag\] (e.g. "docker.io/dagger/dagger:main").
+    async fn publish(&self, address: impl Into<String> + Send) -> eyre::Result<String> {
+        self.publish(address).await
+    }
+
+    /// Publishes this container as a new image to the specified address.
+    /// Publish returns a fully qualified ref.
+    /// It can also publish platform variants.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Registry's address to publish the image to.
+    ///
+    ///   Formatted as \[host\]/\[user\]/\[repo\]:\[tag\] (e.g. "docker.io/dagger/dagger:main").
+    /// * `opts` - Optional arguments, see [`ContainerPublishOpts`].
+    async fn publish_opts(
+        &self,
+        address: impl Into<String> + Send,
+        opts: ContainerPublishOpts,
+    ) -> eyre::Result<String> {
+        self.publish_opts(address, opts).await
+    }
+
+    /// Retrieves this container's root filesystem. Mounts are not included.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    fn rootfs(&self) -> Self::Directory {
+        self.rootfs()
+    }
+    /// The error stream of the last executed command.
+    /// Errors if no command has been executed.
+    ///
+    /// Executes the query and returns its result.
+    async fn stderr(&self) -> eyre::Result<String> {
+        self.stderr().await
+    }
+    /// The output stream of the last executed command.
+    /// Errors if no command has been executed.
+    ///
+    /// Executes the query and returns its result.
+    async fn stdout(&self) -> eyre::Result<String> {
+        self.stdout().await
+    }
+    /// Retrieves the user to be set for all commands.
+    ///
+    /// Executes the query and returns its result.
+    async fn user(&self) -> eyre::Result<String> {
+        self.user().await
+    }
+    /// Configures default arguments for future commands.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithDefaultArgsOpts`], are taken by [`Self::with_default_args_opts`].
+    fn with_default_args(&self) -> Self::Container {
+        self.with_default_args()
+    }
+
+    /// Configures default arguments for future commands.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerWithDefaultArgsOpts`].
+    fn with_default_args_opts<'a>(
+        &self,
+        opts: ContainerWithDefaultArgsOpts<'a>,
+    ) -> Self::Container {
+        self.with_default_args_opts(opts)
+    }
+
+    /// Retrieves this container plus a directory written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithDirectoryOpts`], are taken by [`Self::with_directory_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written directory (e.g., "/tmp/directory").
+    /// * `directory` - Identifier of the directory to write
+    ///   Identifies a [`Directory`].
+    fn with_directory(
+        &self,
+        path: impl Into<String> + Send,
+        directory: DirectoryId,
+    ) -> Self::Container {
+        self.with_directory(path, directory)
+    }
+
+    /// Retrieves this container plus a directory written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written directory (e.g., "/tmp/directory").
+    /// * `directory` - Identifier of the directory to write
+    ///   Identifies a [`Directory`].
+    /// * `opts` - Optional arguments, see [`ContainerWithDirectoryOpts`].
+    fn with_directory_opts<'a>(
+        &self,
+        path: impl Into<String> + Send,
+        directory: DirectoryId,
+        opts: ContainerWithDirectoryOpts<'a>,
+    ) -> Self::Container {
+        self.with_directory_opts(path, directory, opts)
+    }
+
+    /// Retrieves this container but with a different command entrypoint.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Entrypoint to use for future executions (e.g., \["go", "run"\]).
+    fn with_entrypoint(&self, args: Vec<impl Into<String> + Send>) -> Self::Container {
+        self.with_entrypoint(args)
+    }
+    /// Retrieves this container plus the given environment variable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the environment variable (e.g., "HOST").
+    /// * `value` - The value of the environment variable. (e.g., "localhost").
+    fn with_env_variable(
+        &self,
+        name: impl Into<String> + Send,
+        value: impl Into<String> + Send,
+    ) -> Self::Container {
+        self.with_env_variable(name, value)
+    }
+    /// Retrieves this container after executing the specified command inside it.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithExecOpts`], are taken by [`Self::with_exec_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Command to run instead of the container's default command (e.g., \["run", "main.go"\]).
+    fn with_exec(&self, args: Vec<impl Into<String> + Send>) -> Self::Container {
+        self.with_exec(args)
+    }
+
+    /// Retrieves this container after executing the specified command inside it.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Command to run instead of the container's default command (e.g., \["run", "main.go"\]).
+    /// * `opts` - Optional arguments, see [`ContainerWithExecOpts`].
+    fn with_exec_opts<'a>(
+        &self,
+        args: Vec<impl Into<String> + Send>,
+        opts: ContainerWithExecOpts<'a>,
+    ) -> Self::Container {
+        self.with_exec_opts(args, opts)
+    }
+
+    /// Expose a network port.
+    /// Exposed ports serve two purposes:
+    /// - For health checks and introspection, when running services
+    /// - For setting the EXPOSE OCI field when publishing the container
+    ///
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithExposedPortOpts`], are taken by [`Self::with_exposed_port_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Port number to expose
+    fn with_exposed_port(&self, port: isize) -> Self::Container {
+        self.with_exposed_port(port)
+    }
+
+    /// Expose a network port.
+    /// Exposed ports serve two purposes:
+    /// - For health checks and introspection, when running services
+    /// - For setting the EXPOSE OCI field when publishing the container
+    ///
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Port number to expose
+    /// * `opts` - Optional arguments, see [`ContainerWithExposedPortOpts`].
+    fn with_exposed_port_opts<'a>(
+        &self,
+        port: isize,
+        opts: ContainerWithExposedPortOpts<'a>,
+    ) -> Self::Container {
+        self.with_exposed_port_opts(port, opts)
+    }
+
+    /// Initializes this container from this DirectoryID.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Identifies a [`Directory`].
+    fn with_fs(&self, id: DirectoryId) -> Self::Container {
+        self.with_fs(id)
+    }
+    /// Retrieves this container plus the contents of the given file copied to the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithFileOpts`], are taken by [`Self::with_file_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the copied file (e.g., "/tmp/file.txt").
+    /// * `source` - Identifier of the file to copy.
+    ///   Identifies a [`File`].
+    fn with_file(&self, path: impl Into<String> + Send, source: FileId) -> Self::Container {
+        self.with_file(path, source)
+    }
+
+    /// Retrieves this container plus the contents of the given file copied to the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the copied file (e.g., "/tmp/file.txt").
+    /// * `source` - Identifier of the file to copy.
+    ///   Identifies a [`File`].
+    /// * `opts` - Optional arguments, see [`ContainerWithFileOpts`].
+    fn with_file_opts(
+        &self,
+        path: impl Into<String> + Send,
+        source: FileId,
+        opts: ContainerWithFileOpts,
+    ) -> Self::Container {
+        self.with_file_opts(path, source, opts)
+    }
+
+    /// Retrieves this container plus the given label.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the label (e.g., "org.opencontainers.artifact.created").
+    /// * `value` - The value of the label (e.g., "2023-01-01T00:00:00Z").
+    fn with_label(
+        &self,
+        name: impl Into<String> + Send,
+        value: impl Into<String> + Send,
+    ) -> Self::Container {
+        self.with_label(name, value)
+    }
+    /// Retrieves this container plus a cache volume mounted at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithMountedCacheOpts`], are taken by [`Self::with_mounted_cache_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the cache directory (e.g., "/cache/node_modules").
+    /// * `cache` - Identifier of the cache volume to mount.
+    fn with_mounted_cache(
+        &self,
+        path: impl Into<String> + Send,
+        cache: CacheId,
+    ) -> Self::Container {
+        self.with_mounted_cache(path, cache)
+    }
+
+    /// Retrieves this container plus a cache volume mounted at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the cache directory (e.g., "/cache/node_modules").
+    /// * `cache` - Identifier of the cache volume to mount.
+    /// * `opts` - Optional arguments, see [`ContainerWithMountedCacheOpts`].
+    fn with_mounted_cache_opts(
+        &self,
+        path: impl Into<String> + Send,
+        cache: CacheId,
+        opts: ContainerWithMountedCacheOpts,
+    ) -> Self::Container {
+        self.with_mounted_cache_opts(path, cache, opts)
+    }
+
+    /// Retrieves this container plus a directory mounted at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the mounted directory (e.g., "/mnt/directory").
+    /// * `source` - Identifier of the mounted directory.
+    ///   Identifies a [`Directory`].
+    fn with_mounted_directory(
+        &self,
+        path: impl Into<String> + Send,
+        source: DirectoryId,
+    ) -> Self::Container {
+        self.with_mounted_directory(path, source)
+    }
+    /// Retrieves this container plus a file mounted at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the mounted file (e.g., "/tmp/file.txt").
+    /// * `source` - Identifier of the mounted file.
+    ///   Identifies a [`File`].
+    fn with_mounted_file(&self, path: impl Into<String> + Send, source: FileId) -> Self::Container {
+        self.with_mounted_file(path, source)
+    }
+    /// Retrieves this container plus a secret mounted into a file at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the secret file (e.g., "/tmp/secret.txt").
+    /// * `source` - Identifier of the secret to mount.
+    ///   Identifies a [`Secret`].
+    fn with_mounted_secret(
+        &self,
+        path: impl Into<String> + Send,
+        source: SecretId,
+    ) -> Self::Container {
+        self.with_mounted_secret(path, source)
+    }
+    /// Retrieves this container plus a temporary directory mounted at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the temporary directory (e.g., "/tmp/temp_dir").
+    fn with_mounted_temp(&self, path: impl Into<String> + Send) -> Self::Container {
+        self.with_mounted_temp(path)
+    }
+    /// Retrieves this container plus a new file written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithNewFileOpts`], are taken by [`Self::with_new_file_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written file (e.g., "/tmp/file.txt").
+    fn with_new_file(&self, path: impl Into<String> + Send) -> Self::Container {
+        self.with_new_file(path)
+    }
+
+    /// Retrieves this container plus a new file written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written file (e.g., "/tmp/file.txt").
+    /// * `opts` - Optional arguments, see [`ContainerWithNewFileOpts`].
+    fn with_new_file_opts<'a>(
+        &self,
+        path: impl Into<String> + Send,
+        opts: ContainerWithNewFileOpts<'a>,
+    ) -> Self::Container {
+        self.with_new_file_opts(path, opts)
+    }
+
+    /// Retrieves this container with a registry authentication for a given address.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Registry's address to bind the authentication to.
+    ///   Formatted as \[host\]/\[user\]/\[repo\]:\[tag\] (e.g. docker.io/dagger/dagger:main).
+    /// * `username` - The username of the registry's account (e.g., "Dagger").
+    /// * `secret` - The API key, password or token to authenticate to this registry.
+    ///   Identifies a [`Secret`].
+    fn with_registry_auth(
+        &self,
+        address: impl Into<String> + Send,
+        username: impl Into<String> + Send,
+        secret: SecretId,
+    ) -> Self::Container {
+        self.with_registry_auth(address, username, secret)
+    }
+    /// Initializes this container from this DirectoryID.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Identifies a [`Directory`].
+    fn with_rootfs(&self, id: DirectoryId) -> Self::Container {
+        self.with_rootfs(id)
+    }
+    /// Retrieves this container plus an env variable containing the given secret.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the secret variable (e.g., "API_SECRET").
+    /// * `secret` - The identifier of the secret value.
+    ///   Identifies a [`Secret`].
+    fn with_secret_variable(
+        &self,
+        name: impl Into<String> + Send,
+        secret: SecretId,
+    ) -> Self::Container {
+        self.with_secret_variable(name, secret)
+    }
+    /// Establish a runtime dependency on a service. The service will be started automatically when needed and detached when it is no longer needed.
+    /// The service will be reachable from the container via the provided hostname alias.
+    /// The service dependency will also convey to any files or directories produced by the container.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `alias` - A name that can be used to reach the service from the container
+    /// * `service` - Identifier of the service container
+    ///   Identifies a [`Container`].
+    fn with_service_binding(
+        &self,
+        alias: impl Into<String> + Send,
+        service: ContainerId,
+    ) -> Self::Container {
+        self.with_service_binding(alias, service)
+    }
+    /// Retrieves this container plus a socket forwarded to the given Unix socket path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the forwarded Unix socket (e.g., "/tmp/socket").
+    /// * `source` - Identifier of the socket to forward.
+    fn with_unix_socket(
+        &self,
+        path: impl Into<String> + Send,
+        source: SocketId,
+    ) -> Self::Container {
+        self.with_unix_socket(path, source)
+    }
+    /// Retrieves this container with a different command user.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The user to set (e.g., "root").
+    fn with_user(&self, name: impl Into<String> + Send) -> Self::Container {
+        self.with_user(name)
+    }
+    /// Retrieves this container with a different working directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to set as the working directory (e.g., "/app").
+    fn with_workdir(&self, path: impl Into<String> + Send) -> Self::Container {
+        self.with_workdir(path)
+    }
+    /// Retrieves this container minus the given environment variable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the environment variable (e.g., "HOST").
+    fn without_env_variable(&self, name: impl Into<String> + Send) -> Self::Container {
+        self.without_env_variable(name)
+    }
+    /// Unexpose a previously exposed port.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithoutExposedPortOpts`], are taken by [`Self::without_exposed_port_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Port number to unexpose
+    fn without_exposed_port(&self, port: isize) -> Self::Container {
+        self.without_exposed_port(port)
+    }
+
+    /// Unexpose a previously exposed port.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Port number to unexpose
+    /// * `opts` - Optional arguments, see [`ContainerWithoutExposedPortOpts`].
+    fn without_exposed_port_opts(
+        &self,
+        port: isize,
+        opts: ContainerWithoutExposedPortOpts,
+    ) -> Self::Container {
+        self.without_exposed_port_opts(port, opts)
+    }
+
+    /// Retrieves this container minus the given environment label.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the label to remove (e.g., "org.opencontainers.artifact.created").
+    fn without_label(&self, name: impl Into<String> + Send) -> Self::Container {
+        self.without_label(name)
+    }
+    /// Retrieves this container after unmounting everything at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the cache directory (e.g., "/cache/node_modules").
+    fn without_mount(&self, path: impl Into<String> + Send) -> Self::Container {
+        self.without_mount(path)
+    }
+    /// Retrieves this container without the registry authentication of a given address.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Registry's address to remove the authentication from.
+    ///   Formatted as \[host\]/\[user\]/\[repo\]:\[tag\] (e.g. docker.io/dagger/dagger:main).
+    fn without_registry_auth(&self, address: impl Into<String> + Send) -> Self::Container {
+        self.without_registry_auth(address)
+    }
+    /// Retrieves this container with a previously added Unix socket removed.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the socket to remove (e.g., "/tmp/socket").
+    fn without_unix_socket(&self, path: impl Into<String> + Send) -> Self::Container {
+        self.without_unix_socket(path)
+    }
+    /// Retrieves the working directory for all commands.
+    ///
+    /// Executes the query and returns its result.
+    async fn workdir(&self) -> eyre::Result<String> {
+        self.workdir().await
+    }
+}
+
+/// In-memory fake of [`ContainerApi`]
+#[derive(Debug, Clone)]
+pub struct FakeContainer {
+    pub selection: Selection,
+    pub fake: Fake,
+}
+
+impl FakeContainer {
+    /// A fake whose queries start at this object, answered by `fake`
+    pub fn new(fake: &Fake) -> Self {
+        Self {
+            selection: Selection::default(),
+            fake: fake.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl ContainerApi for FakeContainer {
+    type Container = FakeContainer;
+    type Directory = FakeDirectory;
+    type EnvVariable = FakeEnvVariable;
+    type File = FakeFile;
+    type Label = FakeLabel;
+    type Port = FakePort;
+
+    fn build(&self, context: DirectoryId) -> Self::Container {
+        let mut query = self.selection.select("build");
+
+        query = query.arg("context", context);
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn build_opts<'a>(
+        &self,
+        context: DirectoryId,
+        opts: ContainerBuildOpts<'a>,
+    ) -> Self::Container {
+        let mut query = self.selection.select("build");
+
+        query = query.arg("context", context);
+        if let Some(dockerfile) = opts.dockerfile {
+            query = query.arg("dockerfile", dockerfile);
+        }
+        if let Some(build_args) = opts.build_args {
+            query = query.arg("buildArgs", build_args);
+        }
+        if let Some(target) = opts.target {
+            query = query.arg("target", target);
+        }
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    async fn default_args(&self) -> eyre::Result<Vec<String>> {
+        let query = self.selection.select("defaultArgs");
+
+        self.fake.execute(&query)
+    }
+    fn directory(&self, path: impl Into<String> + Send) -> Self::Directory {
+        let mut query = self.selection.select("directory");
+
+        query = query.arg("path", path.into());
+
+        FakeDirectory {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    async fn endpoint(&self) -> eyre::Result<String> {
+        let query = self.selection.select("endpoint");
+
+        self.fake.execute(&query)
+    }
+
+    async fn endpoint_opts<'a>(&self, opts: ContainerEndpointOpts<'a>) -> eyre::Result<String> {
+        let mut query = self.selection.select("endpoint");
+
+        if let Some(port) = opts.port {
+            query = query.arg("port", port);
+        }
+        if let Some(scheme) = opts.scheme {
+            query = query.arg("scheme", scheme);
+        }
+
+        self.fake.execute(&query)
+    }
+
+    async fn entrypoint(&self) -> eyre::Result<Vec<String>> {
+        let query = self.selection.select("entrypoint");
+
+        self.fake.execute(&query)
+    }
+    async fn env_variable(&self, name: impl Into<String> + Send) -> eyre::Result<String> {
+        let mut query = self.selection.select("envVariable");
+
+        query = query.arg("name", name.into());
+
+        self.fake.execute(&query)
+    }
+    fn env_variables(&self) -> Vec<Self::EnvVariable> {
+        let query = self.selection.select("envVariables");
+
+        vec![FakeEnvVariable {
+            selection: query,
+            fake: self.fake.clone(),
+        }]
+    }
+    fn exec(&self) -> Self::Container {
+        let query = self.selection.select("exec");
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn exec_opts<'a>(&self, opts: ContainerExecOpts<'a>) -> Self::Container {
+        let mut query = self.selection.select("exec");
+
+        if let Some(args) = opts.args {
+            query = query.arg("args", args);
+        }
+        if let Some(stdin) = opts.stdin {
+            query = query.arg("stdin", stdin);
+        }
+        if let Some(redirect_stdout) = opts.redirect_stdout {
+            query = query.arg("redirectStdout", redirect_stdout);
+        }
+        if let Some(redirect_stderr) = opts.redirect_stderr {
+            query = query.arg("redirectStderr", redirect_stderr);
+        }
+        if let Some(experimental_privileged_nesting) = opts.experimental_privileged_nesting {
+            query = query.arg(
+                "experimentalPrivilegedNesting",
+                experimental_privileged_nesting,
+            );
+        }
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    async fn exit_code(&self) -> eyre::Result<isize> {
+        let query = self.selection.select("exitCode");
+
+        self.fake.execute(&query)
+    }
+    async fn export(&self, path: impl Into<String> + Send) -> eyre::Result<bool> {
+        let mut query = self.selection.select("export");
+
+        query = query.arg("path", path.into());
+
+        self.fake.execute(&query)
+    }
+
+    async fn export_opts(
+        &self,
+        path: impl Into<String> + Send,
+        opts: ContainerExportOpts,
+    ) -> eyre::Result<bool> {
+        let mut query = self.selection.select("export");
+
+        query = query.arg("path", path.into());
+        if let Some(platform_variants) = opts.platform_variants {
+            query = query.arg("platformVariants", platform_variants);
+        }
+
+        self.fake.execute(&query)
+    }
+
+    fn exposed_ports(&self) -> Vec<Self::Port> {
+        let query = self.selection.select("exposedPorts");
+
+        vec![FakePort {
+            selection: query,
+            fake: self.fake.clone(),
+        }]
+    }
+    fn file(&self, path: impl Into<String> + Send) -> Self::File {
+        let mut query = self.selection.select("file");
+
+        query = query.arg("path", path.into());
+
+        FakeFile {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn from(&self, address: impl Into<String> + Send) -> Self::Container {
+        let mut query = self.selection.select("from");
+
+        query = query.arg("address", address.into());
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn fs(&self) -> Self::Directory {
+        let query = self.selection.select("fs");
+
+        FakeDirectory {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    async fn hostname(&self) -> eyre::Result<String> {
+        let query = self.selection.select("hostname");
+
+        self.fake.execute(&query)
+    }
+    async fn id(&self) -> eyre::Result<ContainerId> {
+        let query = self.selection.select("id");
+
+        self.fake.execute(&query)
+    }
+    async fn image_ref(&self) -> eyre::Result<String> {
+        let query = self.selection.select("imageRef");
+
+        self.fake.execute(&query)
+    }
+    async fn label(&self, name: impl Into<String> + Send) -> eyre::Result<String> {
+        let mut query = self.selection.select("label");
+
+        query = query.arg("name", name.into());
+
+        self.fake.execute(&query)
+    }
+    fn labels(&self) -> Vec<Self::Label> {
+        let query = self.selection.select("labels");
+
+        vec![FakeLabel {
+            selection: query,
+            fake: self.fake.clone(),
+        }]
+    }
+    async fn mounts(&self) -> eyre::Result<Vec<String>> {
+        let query = self.selection.select("mounts");
+
+        self.fake.execute(&query)
+    }
+    fn pipeline(&self, name: impl Into<String> + Send) -> Self::Container {
+        let mut query = self.selection.select("pipeline");
+
+        query = query.arg("name", name.into());
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn pipeline_opts<'a>(
+        &self,
+        name: impl Into<String> + Send,
+        opts: ContainerPipelineOpts<'a>,
+    ) -> Self::Container {
+        let mut query = self.selection.select("pipeline");
+
+        query = query.arg("name", name.into());
+        if let Some(description) = opts.description {
+            query = query.arg("description", description);
+        }
+        if let Some(labels) = opts.labels {
+            query = query.arg("labels", labels);
+        }
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    async fn platform(&self) -> eyre::Result<Platform> {
+        let query = self.selection.select("platform");
+
+        self.fake.execute(&query)
+    }
+    async fn publish(&self, address: impl Into<String> + Send) -> eyre::Result<String> {
+        let mut query = self.selection.select("publish");
+
+        query = query.arg("address", address.into());
+
+        self.fake.execute(&query)
+    }
+
+    async fn publish_opts(
+        &self,
+        address: impl Into<String> + Send,
+        opts: ContainerPublishOpts,
+    ) -> eyre::Result<String> {
+        let mut query = self.selection.select("publish");
+
+        query = query.arg("address", address.into());
+        if let Some(platform_variants) = opts.platform_variants {
+            query = query.arg("platformVariants", platform_variants);
+        }
+
+        self.fake.execute(&query)
+    }
+
+    fn rootfs(&self) -> Self::Directory {
+        let query = self.selection.select("rootfs");
+
+        FakeDirectory {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    async fn stderr(&self) -> eyre::Result<String> {
+        let query = self.selection.select("stderr");
+
+        self.fake.execute(&query)
+    }
+    async fn stdout(&self) -> eyre::Result<String> {
+        let query = self.selection.select("stdout");
+
+        self.fake.execute(&query)
+    }
+    async fn user(&self) -> eyre::Result<String> {
+        let query = self.selection.select("user");
+
+        self.fake.execute(&query)
+    }
+    fn with_default_args(&self) -> Self::Container {
+        let query = self.selection.select("withDefaultArgs");
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn with_default_args_opts<'a>(
+        &self,
+        opts: ContainerWithDefaultArgsOpts<'a>,
+    ) -> Self::Container {
+        let mut query = self.selection.select("withDefaultArgs");
+
+        if let Some(args) = opts.args {
+            query = query.arg("args", args);
+        }
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn with_directory(
+        &self,
+        path: impl Into<String> + Send,
+        directory: DirectoryId,
+    ) -> Self::Container {
+        let mut query = self.selection.select("withDirectory");
+
+        query = query.arg("path", path.into());
+        query = query.arg("directory", directory);
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn with_directory_opts<'a>(
+        &self,
+        path: impl Into<String> + Send,
+        directory: DirectoryId,
+        opts: ContainerWithDirectoryOpts<'a>,
+    ) -> Self::Container {
+        let mut query = self.selection.select("withDirectory");
+
+        query = query.arg("path", path.into());
+        query = query.arg("directory", directory);
+        if let Some(exclude) = opts.exclude {
+            query = query.arg("exclude", exclude);
+        }
+        if let Some(include) = opts.include {
+            query = query.arg("include", include);
+        }
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn with_entrypoint(&self, args: Vec<impl Into<String> + Send>) -> Self::Container {
+        let mut query = self.selection.select("withEntrypoint");
+
+        query = query.arg(
+            "args",
+            args.into_iter().map(|i| i.into()).collect::<Vec<String>>(),
+        );
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn with_env_variable(
+        &self,
+        name: impl Into<String> + Send,
+        value: impl Into<String> + Send,
+    ) -> Self::Container {
+        let mut query = self.selection.select("withEnvVariable");
+
+        query = query.arg("name", name.into());
+        query = query.arg("value", value.into());
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn with_exec(&self, args: Vec<impl Into<String> + Send>) -> Self::Container {
+        let mut query = self.selection.select("withExec");
+
+        query = query.arg(
+            "args",
+            args.into_iter().map(|i| i.into()).collect::<Vec<String>>(),
+        );
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn with_exec_opts<'a>(
+        &self,
+        args: Vec<impl Into<String> + Send>,
+        opts: ContainerWithExecOpts<'a>,
+    ) -> Self::Container {
+        let mut query = self.selection.select("withExec");
+
+        query = query.arg(
+            "args",
+            args.into_iter().map(|i| i.into()).collect::<Vec<String>>(),
+        );
+        if let Some(stdin) = opts.stdin {
+            query = query.arg("stdin", stdin);
+        }
+        if let Some(redirect_stdout) = opts.redirect_stdout {
+            query = query.arg("redirectStdout", redirect_stdout);
+        }
+        if let Some(redirect_stderr) = opts.redirect_stderr {
+            query = query.arg("redirectStderr", redirect_stderr);
+        }
+        if let Some(experimental_privileged_nesting) = opts.experimental_privileged_nesting {
+            query = query.arg(
+                "experimentalPrivilegedNesting",
+                experimental_privileged_nesting,
+            );
+        }
+        if let Some(insecure_root_capabilities) = opts.insecure_root_capabilities {
+            query = query.arg("insecureRootCapabilities", insecure_root_capabilities);
+        }
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn with_exposed_port(&self, port: isize) -> Self::Container {
+        let mut query = self.selection.select("withExposedPort");
+
+        query = query.arg("port", port);
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn with_exposed_port_opts<'a>(
+        &self,
+        port: isize,
+        opts: ContainerWithExposedPortOpts<'a>,
+    ) -> Self::Container {
+        let mut query = self.selection.select("withExposedPort");
+
+        query = query.arg("port", port);
+        if let Some(protocol) = opts.protocol {
+            query = query.arg_enum("protocol", protocol);
+        }
+        if let Some(description) = opts.description {
+            query = query.arg("description", description);
+        }
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn with_fs(&self, id: DirectoryId) -> Self::Container {
+        let mut query = self.selection.select("withFS");
+
+        query = query.arg("id", id);
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn with_file(&self, path: impl Into<String> + Send, source: FileId) -> Self::Container {
+        let mut query = self.selection.select("withFile");
+
+        query = query.arg("path", path.into());
+        query = query.arg("source", source);
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn with_file_opts(
+        &self,
+        path: impl Into<String> + Send,
+        source: FileId,
+        opts: ContainerWithFileOpts,
+    ) -> Self::Container {
+        let mut query = self.selection.select("withFile");
+
+        query = query.arg("path", path.into());
+        query = query.arg("source", source);
+        if let Some(permissions) = opts.permissions {
+            query = query.arg("permissions", permissions);
+        }
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn with_label(
+        &self,
+        name: impl Into<String> + Send,
+        value: impl Into<String> + Send,
+    ) -> Self::Container {
+        let mut query = self.selection.select("withLabel");
+
+        query = query.arg("name", name.into());
+        query = query.arg("value", value.into());
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn with_mounted_cache(
+        &self,
+        path: impl Into<String> + Send,
+        cache: CacheId,
+    ) -> Self::Container {
+        let mut query = self.selection.select("withMountedCache");
+
+        query = query.arg("path", path.into());
+        query = query.arg("cache", cache);
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn with_mounted_cache_opts(
+        &self,
+        path: impl Into<String> + Send,
+        cache: CacheId,
+        opts: ContainerWithMountedCacheOpts,
+    ) -> Self::Container {
+        let mut query = self.selection.select("withMountedCache");
+
+        query = query.arg("path", path.into());
+        query = query.arg("cache", cache);
+        if let Some(source) = opts.source {
+            query = query.arg("source", source);
+        }
+        if let Some(sharing) = opts.sharing {
+            query = query.arg_enum("sharing", sharing);
+        }
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn with_mounted_directory(
+        &self,
+        path: impl Into<String> + Send,
+        source: DirectoryId,
+    ) -> Self::Container {
+        let mut query = self.selection.select("withMountedDirectory");
+
+        query = query.arg("path", path.into());
+        query = query.arg("source", source);
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn with_mounted_file(&self, path: impl Into<String> + Send, source: FileId) -> Self::Container {
+        let mut query = self.selection.select("withMountedFile");
+
+        query = query.arg("path", path.into());
+        query = query.arg("source", source);
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn with_mounted_secret(
+        &self,
+        path: impl Into<String> + Send,
+        source: SecretId,
+    ) -> Self::Container {
+        let mut query = self.selection.select("withMountedSecret");
+
+        query = query.arg("path", path.into());
+        query = query.arg("source", source);
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn with_mounted_temp(&self, path: impl Into<String> + Send) -> Self::Container {
+        let mut query = self.selection.select("withMountedTemp");
+
+        query = query.arg("path", path.into());
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn with_new_file(&self, path: impl Into<String> + Send) -> Self::Container {
+        let mut query = self.selection.select("withNewFile");
+
+        query = query.arg("path", path.into());
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn with_new_file_opts<'a>(
+        &self,
+        path: impl Into<String> + Send,
+        opts: ContainerWithNewFileOpts<'a>,
+    ) -> Self::Container {
+        let mut query = self.selection.select("withNewFile");
+
+        query = query.arg("path", path.into());
+        if let Some(contents) = opts.contents {
+            query = query.arg("contents", contents);
+        }
+        if let Some(permissions) = opts.permissions {
+            query = query.arg("permissions", permissions);
+        }
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn with_registry_auth(
+        &self,
+        address: impl Into<String> + Send,
+        username: impl Into<String> + Send,
+        secret: SecretId,
+    ) -> Self::Container {
+        let mut query = self.selection.select("withRegistryAuth");
+
+        query = query.arg("address", address.into());
+        query = query.arg("username", username.into());
+        query = query.arg("secret", secret);
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn with_rootfs(&self, id: DirectoryId) -> Self::Container {
+        let mut query = self.selection.select("withRootfs");
+
+        query = query.arg("id", id);
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn with_secret_variable(
+        &self,
+        name: impl Into<String> + Send,
+        secret: SecretId,
+    ) -> Self::Container {
+        let mut query = self.selection.select("withSecretVariable");
+
+        query = query.arg("name", name.into());
+        query = query.arg("secret", secret);
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn with_service_binding(
+        &self,
+        alias: impl Into<String> + Send,
+        service: ContainerId,
+    ) -> Self::Container {
+        let mut query = self.selection.select("withServiceBinding");
+
+        query = query.arg("alias", alias.into());
+        query = query.arg("service", service);
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn with_unix_socket(
+        &self,
+        path: impl Into<String> + Send,
+        source: SocketId,
+    ) -> Self::Container {
+        let mut query = self.selection.select("withUnixSocket");
+
+        query = query.arg("path", path.into());
+        query = query.arg("source", source);
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn with_user(&self, name: impl Into<String> + Send) -> Self::Container {
+        let mut query = self.selection.select("withUser");
+
+        query = query.arg("name", name.into());
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn with_workdir(&self, path: impl Into<String> + Send) -> Self::Container {
+        let mut query = self.selection.select("withWorkdir");
+
+        query = query.arg("path", path.into());
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn without_env_variable(&self, name: impl Into<String> + Send) -> Self::Container {
+        let mut query = self.selection.select("withoutEnvVariable");
+
+        query = query.arg("name", name.into());
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn without_exposed_port(&self, port: isize) -> Self::Container {
+        let mut query = self.selection.select("withoutExposedPort");
+
+        query = query.arg("port", port);
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn without_exposed_port_opts(
+        &self,
+        port: isize,
+        opts: ContainerWithoutExposedPortOpts,
+    ) -> Self::Container {
+        let mut query = self.selection.select("withoutExposedPort");
+
+        query = query.arg("port", port);
+        if let Some(protocol) = opts.protocol {
+            query = query.arg_enum("protocol", protocol);
+        }
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn without_label(&self, name: impl Into<String> + Send) -> Self::Container {
+        let mut query = self.selection.select("withoutLabel");
+
+        query = query.arg("name", name.into());
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn without_mount(&self, path: impl Into<String> + Send) -> Self::Container {
+        let mut query = self.selection.select("withoutMount");
+
+        query = query.arg("path", path.into());
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn without_registry_auth(&self, address: impl Into<String> + Send) -> Self::Container {
+        let mut query = self.selection.select("withoutRegistryAuth");
+
+        query = query.arg("address", address.into());
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn without_unix_socket(&self, path: impl Into<String> + Send) -> Self::Container {
+        let mut query = self.selection.select("withoutUnixSocket");
+
+        query = query.arg("path", path.into());
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    async fn workdir(&self) -> eyre::Result<String> {
+        let query = self.selection.select("workdir");
+
+        self.fake.execute(&query)
+    }
+}
+/// Trait of [`super::Directory`]
+#[async_trait]
+pub trait DirectoryApi: Send + Sync {
+    type Container: ContainerApi;
+    type Directory: DirectoryApi;
+    type File: FileApi;
+    #[cfg(feature = "project")]
+    type Project: ProjectApi;
+
+    /// Gets the difference between this directory and an another directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Identifier of the directory to compare.
+    ///   Identifies a [`Directory`].
+    fn diff(&self, other: DirectoryId) -> Self::Directory;
+    /// Retrieves a directory at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the directory to retrieve (e.g., "/src").
+    fn directory(&self, path: impl Into<String> + Send) -> Self::Directory;
+    /// Builds a new Docker container from this directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`DirectoryDockerBuildOpts`], are taken by [`Self::docker_build_opts`].
+    fn docker_build(&self) -> Self::Container;
+
+    /// Builds a new Docker container from this directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`DirectoryDockerBuildOpts`].
+    fn docker_build_opts<'a>(&self, opts: DirectoryDockerBuildOpts<'a>) -> Self::Container;
+
+    /// Returns a list of files and directories at the given path.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`DirectoryEntriesOpts`], are taken by [`Self::entries_opts`].
+    async fn entries(&self) -> eyre::Result<Vec<String>>;
+
+    /// Returns a list of files and directories at the given path.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`DirectoryEntriesOpts`].
+    async fn entries_opts<'a>(&self, opts: DirectoryEntriesOpts<'a>) -> eyre::Result<Vec<String>>;
+
+    /// Writes the contents of the directory to a path on the host.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the copied directory (e.g., "logs/").
+    async fn export(&self, path: impl Into<String> + Send) -> eyre::Result<bool>;
+    /// Retrieves a file at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the file to retrieve (e.g., "README.md").
+    fn file(&self, path: impl Into<String> + Send) -> Self::File;
+    /// The content-addressed identifier of the directory.
+    ///
+    /// Executes the query and returns its result.
+    async fn id(&self) -> eyre::Result<DirectoryId>;
+    /// load a project's metadata
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Project`] is awaited.
+    #[cfg(feature = "project")]
+    fn load_project(&self, config_path: impl Into<String> + Send) -> Self::Project;
+    /// Creates a named sub-pipeline
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`DirectoryPipelineOpts`], are taken by [`Self::pipeline_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    fn pipeline(&self, name: impl Into<String> + Send) -> Self::Directory;
+
+    /// Creates a named sub-pipeline
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    /// * `opts` - Optional arguments, see [`DirectoryPipelineOpts`].
+    fn pipeline_opts<'a>(
+        &self,
+        name: impl Into<String> + Send,
+        opts: DirectoryPipelineOpts<'a>,
+    ) -> Self::Directory;
+
+    /// Retrieves this directory plus a directory written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`DirectoryWithDirectoryOpts`], are taken by [`Self::with_directory_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written directory (e.g., "/src/").
+    /// * `directory` - Identifier of the directory to copy.
+    ///   Identifies a [`Directory`].
+    fn with_directory(
+        &self,
+        path: impl Into<String> + Send,
+        directory: DirectoryId,
+    ) -> Self::Directory;
+
+    /// Retrieves this directory plus a directory written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written directory (e.g., "/src/").
+    /// * `directory` - Identifier of the directory to copy.
+    ///   Identifies a [`Directory`].
+    /// * `opts` - Optional arguments, see [`DirectoryWithDirectoryOpts`].
+    fn with_directory_opts<'a>(
+        &self,
+        path: impl Into<String> + Send,
+        directory: DirectoryId,
+        opts: DirectoryWithDirectoryOpts<'a>,
+    ) -> Self::Directory;
+
+    /// Retrieves this directory plus the contents of the given file copied to the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`DirectoryWithFileOpts`], are taken by [`Self::with_file_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the copied file (e.g., "/file.txt").
+    /// * `source` - Identifier of the file to copy.
+    ///   Identifies a [`File`].
+    fn with_file(&self, path: impl Into<String> + Send, source: FileId) -> Self::Directory;
+
+    /// Retrieves this directory plus the contents of the given file copied to the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the copied file (e.g., "/file.txt").
+    /// * `source` - Identifier of the file to copy.
+    ///   Identifies a [`File`].
+    /// * `opts` - Optional arguments, see [`DirectoryWithFileOpts`].
+    fn with_file_opts(
+        &self,
+        path: impl Into<String> + Send,
+        source: FileId,
+        opts: DirectoryWithFileOpts,
+    ) -> Self::Directory;
+
+    /// Retrieves this directory plus a new directory created at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`DirectoryWithNewDirectoryOpts`], are taken by [`Self::with_new_directory_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the directory created (e.g., "/logs").
+    fn with_new_directory(&self, path: impl Into<String> + Send) -> Self::Directory;
+
+    /// Retrieves this directory plus a new directory created at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the directory created (e.g., "/logs").
+    /// * `opts` - Optional arguments, see [`DirectoryWithNewDirectoryOpts`].
+    fn with_new_directory_opts(
+        &self,
+        path: impl Into<String> + Send,
+        opts: DirectoryWithNewDirectoryOpts,
+    ) -> Self::Directory;
+
+    /// Retrieves this directory plus a new file written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`DirectoryWithNewFileOpts`], are taken by [`Self::with_new_file_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written file (e.g., "/file.txt").
+    /// * `contents` - Content of the written file (e.g., "Hello world!").
+    fn with_new_file(
+        &self,
+        path: impl Into<String> + Send,
+        contents: impl Into<String> + Send,
+    ) -> Self::Directory;
+
+    /// Retrieves this directory plus a new file written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written file (e.g., "/file.txt").
+    /// * `contents` - Content of the written file (e.g., "Hello world!").
+    /// * `opts` - Optional arguments, see [`DirectoryWithNewFileOpts`].
+    fn with_new_file_opts(
+        &self,
+        path: impl Into<String> + Send,
+        contents: impl Into<String> + Send,
+        opts: DirectoryWithNewFileOpts,
+    ) -> Self::Directory;
+
+    /// Retrieves this directory with all file/dir timestamps set to the given time.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - Timestamp to set dir/files in.
+    ///
+    ///   Formatted in seconds following Unix epoch (e.g., 1672531199).
+    fn with_timestamps(&self, timestamp: isize) -> Self::Directory;
+    /// Retrieves this directory with the directory at the given path removed.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the directory to remove (e.g., ".github/").
+    fn without_directory(&self, path: impl Into<String> + Send) -> Self::Directory;
+    /// Retrieves this directory with the file at the given path removed.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the file to remove (e.g., "/file.txt").
+    fn without_file(&self, path: impl Into<String> + Send) -> Self::Directory;
+}
+
+#[async_trait]
+impl DirectoryApi for super::Directory {
+    type Container = super::Container;
+    type Directory = super::Directory;
+    type File = super::File;
+    #[cfg(feature = "project")]
+    type Project = super::Project;
+
+    /// Gets the difference between this directory and an another directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Identifier of the directory to compare.
+    ///   Identifies a [`Directory`].
+    fn diff(&self, other: DirectoryId) -> Self::Directory {
+        self.diff(other)
+    }
+    /// Retrieves a directory at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the directory to retrieve (e.g., "/src").
+    fn directory(&self, path: impl Into<String> + Send) -> Self::Directory {
+        self.directory(path)
+    }
+    /// Builds a new Docker container from this directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`DirectoryDockerBuildOpts`], are taken by [`Self::docker_build_opts`].
+    fn docker_build(&self) -> Self::Container {
+        self.docker_build()
+    }
+
+    /// Builds a new Docker container from this directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`DirectoryDockerBuildOpts`].
+    fn docker_build_opts<'a>(&self, opts: DirectoryDockerBuildOpts<'a>) -> Self::Container {
+        self.docker_build_opts(opts)
+    }
+
+    /// Returns a list of files and directories at the given path.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`DirectoryEntriesOpts`], are taken by [`Self::entries_opts`].
+    async fn entries(&self) -> eyre::Result<Vec<String>> {
+        self.entries().await
+    }
+
+    /// Returns a list of files and directories at the given path.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`DirectoryEntriesOpts`].
+    async fn entries_opts<'a>(&self, opts: DirectoryEntriesOpts<'a>) -> eyre::Result<Vec<String>> {
+        self.entries_opts(opts).await
+    }
+
+    /// Writes the contents of the directory to a path on the host.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the copied directory (e.g., "logs/").
+    async fn export(&self, path: impl Into<String> + Send) -> eyre::Result<bool> {
+        self.export(path).await
+    }
+    /// Retrieves a file at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the file to retrieve (e.g., "README.md").
+    fn file(&self, path: impl Into<String> + Send) -> Self::File {
+        self.file(path)
+    }
+    /// The content-addressed identifier of the directory.
+    ///
+    /// Executes the query and returns its result.
+    async fn id(&self) -> eyre::Result<DirectoryId> {
+        self.id().await
+    }
+    /// load a project's metadata
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Project`] is awaited.
+    #[cfg(feature = "project")]
+    fn load_project(&self, config_path: impl Into<String> + Send) -> Self::Project {
+        self.load_project(config_path)
+    }
+    /// Creates a named sub-pipeline
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`DirectoryPipelineOpts`], are taken by [`Self::pipeline_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    fn pipeline(&self, name: impl Into<String> + Send) -> Self::Directory {
+        self.pipeline(name)
+    }
+
+    /// Creates a named sub-pipeline
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    /// * `opts` - Optional arguments, see [`DirectoryPipelineOpts`].
+    fn pipeline_opts<'a>(
+        &self,
+        name: impl Into<String> + Send,
+        opts: DirectoryPipelineOpts<'a>,
+    ) -> Self::Directory {
+        self.pipeline_opts(name, opts)
+    }
+
+    /// Retrieves this directory plus a directory written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`DirectoryWithDirectoryOpts`], are taken by [`Self::with_directory_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written directory (e.g., "/src/").
+    /// * `directory` - Identifier of the directory to copy.
+    ///   Identifies a [`Directory`].
+    fn with_directory(
+        &self,
+        path: impl Into<String> + Send,
+        directory: DirectoryId,
+    ) -> Self::Directory {
+        self.with_directory(path, directory)
+    }
+
+    /// Retrieves this directory plus a directory written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written directory (e.g., "/src/").
+    /// * `directory` - Identifier of the directory to copy.
+    ///   Identifies a [`Directory`].
+    /// * `opts` - Optional arguments, see [`DirectoryWithDirectoryOpts`].
+    fn with_directory_opts<'a>(
+        &self,
+        path: impl Into<String> + Send,
+        directory: DirectoryId,
+        opts: DirectoryWithDirectoryOpts<'a>,
+    ) -> Self::Directory {
+        self.with_directory_opts(path, directory, opts)
+    }
+
+    /// Retrieves this directory plus the contents of the given file copied to the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`DirectoryWithFileOpts`], are taken by [`Self::with_file_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the copied file (e.g., "/file.txt").
+    /// * `source` - Identifier of the file to copy.
+    ///   Identifies a [`File`].
+    fn with_file(&self, path: impl Into<String> + Send, source: FileId) -> Self::Directory {
+        self.with_file(path, source)
+    }
+
+    /// Retrieves this directory plus the contents of the given file copied to the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the copied file (e.g., "/file.txt").
+    /// * `source` - Identifier of the file to copy.
+    ///   Identifies a [`File`].
+    /// * `opts` - Optional arguments, see [`DirectoryWithFileOpts`].
+    fn with_file_opts(
+        &self,
+        path: impl Into<String> + Send,
+        source: FileId,
+        opts: DirectoryWithFileOpts,
+    ) -> Self::Directory {
+        self.with_file_opts(path, source, opts)
+    }
+
+    /// Retrieves this directory plus a new directory created at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`DirectoryWithNewDirectoryOpts`], are taken by [`Self::with_new_directory_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the directory created (e.g., "/logs").
+    fn with_new_directory(&self, path: impl Into<String> + Send) -> Self::Directory {
+        self.with_new_directory(path)
+    }
+
+    /// Retrieves this directory plus a new directory created at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the directory created (e.g., "/logs").
+    /// * `opts` - Optional arguments, see [`DirectoryWithNewDirectoryOpts`].
+    fn with_new_directory_opts(
+        &self,
+        path: impl Into<String> + Send,
+        opts: DirectoryWithNewDirectoryOpts,
+    ) -> Self::Directory {
+        self.with_new_directory_opts(path, opts)
+    }
+
+    /// Retrieves this directory plus a new file written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`DirectoryWithNewFileOpts`], are taken by [`Self::with_new_file_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written file (e.g., "/file.txt").
+    /// * `contents` - Content of the written file (e.g., "Hello world!").
+    fn with_new_file(
+        &self,
+        path: impl Into<String> + Send,
+        contents: impl Into<String> + Send,
+    ) -> Self::Directory {
+        self.with_new_file(path, contents)
+    }
+
+    /// Retrieves this directory plus a new file written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written file (e.g., "/file.txt").
+    /// * `contents` - Content of the written file (e.g., "Hello world!").
+    /// * `opts` - Optional arguments, see [`DirectoryWithNewFileOpts`].
+    fn with_new_file_opts(
+        &self,
+        path: impl Into<String> + Send,
+        contents: impl Into<String> + Send,
+        opts: DirectoryWithNewFileOpts,
+    ) -> Self::Directory {
+        self.with_new_file_opts(path, contents, opts)
+    }
+
+    /// Retrieves this directory with all file/dir timestamps set to the given time.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - Timestamp to set dir/files in.
+    ///
+    ///   Formatted in seconds following Unix epoch (e.g., 1672531199).
+    fn with_timestamps(&self, timestamp: isize) -> Self::Directory {
+        self.with_timestamps(timestamp)
+    }
+    /// Retrieves this directory with the directory at the given path removed.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the directory to remove (e.g., ".github/").
+    fn without_directory(&self, path: impl Into<String> + Send) -> Self::Directory {
+        self.without_directory(path)
+    }
+    /// Retrieves this directory with the file at the given path removed.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the file to remove (e.g., "/file.txt").
+    fn without_file(&self, path: impl Into<String> + Send) -> Self::Directory {
+        self.without_file(path)
+    }
+}
+
+/// In-memory fake of [`DirectoryApi`]
+#[derive(Debug, Clone)]
+pub struct FakeDirectory {
+    pub selection: Selection,
+    pub fake: Fake,
+}
+
+impl FakeDirectory {
+    /// A fake whose queries start at this object, answered by `fake`
+    pub fn new(fake: &Fake) -> Self {
+        Self {
+            selection: Selection::default(),
+            fake: fake.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl DirectoryApi for FakeDirectory {
+    type Container = FakeContainer;
+    type Directory = FakeDirectory;
+    type File = FakeFile;
+    #[cfg(feature = "project")]
+    type Project = FakeProject;
+
+    fn diff(&self, other: DirectoryId) -> Self::Directory {
+        let mut query = self.selection.select("diff");
+
+        query = query.arg("other", other);
+
+        FakeDirectory {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn directory(&self, path: impl Into<String> + Send) -> Self::Directory {
+        let mut query = self.selection.select("directory");
+
+        query = query.arg("path", path.into());
+
+        FakeDirectory {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn docker_build(&self) -> Self::Container {
+        let query = self.selection.select("dockerBuild");
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn docker_build_opts<'a>(&self, opts: DirectoryDockerBuildOpts<'a>) -> Self::Container {
+        let mut query = self.selection.select("dockerBuild");
+
+        if let Some(dockerfile) = opts.dockerfile {
+            query = query.arg("dockerfile", dockerfile);
+        }
+        if let Some(platform) = opts.platform {
+            query = query.arg("platform", platform);
+        }
+        if let Some(build_args) = opts.build_args {
+            query = query.arg("buildArgs", build_args);
+        }
+        if let Some(target) = opts.target {
+            query = query.arg("target", target);
+        }
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    async fn entries(&self) -> eyre::Result<Vec<String>> {
+        let query = self.selection.select("entries");
+
+        self.fake.execute(&query)
+    }
+
+    async fn entries_opts<'a>(&self, opts: DirectoryEntriesOpts<'a>) -> eyre::Result<Vec<String>> {
+        let mut query = self.selection.select("entries");
+
+        if let Some(path) = opts.path {
+            query = query.arg("path", path);
+        }
+
+        self.fake.execute(&query)
+    }
+
+    async fn export(&self, path: impl Into<String> + Send) -> eyre::Result<bool> {
+        let mut query = self.selection.select("export");
+
+        query = query.arg("path", path.into());
+
+        self.fake.execute(&query)
+    }
+    fn file(&self, path: impl Into<String> + Send) -> Self::File {
+        let mut query = self.selection.select("file");
+
+        query = query.arg("path", path.into());
+
+        FakeFile {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    async fn id(&self) -> eyre::Result<DirectoryId> {
+        let query = self.selection.select("id");
+
+        self.fake.execute(&query)
+    }
+    #[cfg(feature = "project")]
+    fn load_project(&self, config_path: impl Into<String> + Send) -> Self::Project {
+        let mut query = self.selection.select("loadProject");
+
+        query = query.arg("configPath", config_path.into());
+
+        FakeProject {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn pipeline(&self, name: impl Into<String> + Send) -> Self::Directory {
+        let mut query = self.selection.select("pipeline");
+
+        query = query.arg("name", name.into());
+
+        FakeDirectory {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn pipeline_opts<'a>(
+        &self,
+        name: impl Into<String> + Send,
+        opts: DirectoryPipelineOpts<'a>,
+    ) -> Self::Directory {
+        let mut query = self.selection.select("pipeline");
+
+        query = query.arg("name", name.into());
+        if let Some(description) = opts.description {
+            query = query.arg("description", description);
+        }
+        if let Some(labels) = opts.labels {
+            query = query.arg("labels", labels);
+        }
+
+        FakeDirectory {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn with_directory(
+        &self,
+        path: impl Into<String> + Send,
+        directory: DirectoryId,
+    ) -> Self::Directory {
+        let mut query = self.selection.select("withDirectory");
+
+        query = query.arg("path", path.into());
+        query = query.arg("directory", directory);
+
+        FakeDirectory {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn with_directory_opts<'a>(
+        &self,
+        path: impl Into<String> + Send,
+        directory: DirectoryId,
+        opts: DirectoryWithDirectoryOpts<'a>,
+    ) -> Self::Directory {
+        let mut query = self.selection.select("withDirectory");
+
+        query = query.arg("path", path.into());
+        query = query.arg("directory", directory);
+        if let Some(exclude) = opts.exclude {
+            query = query.arg("exclude", exclude);
+        }
+        if let Some(include) = opts.include {
+            query = query.arg("include", include);
+        }
+
+        FakeDirectory {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn with_file(&self, path: impl Into<String> + Send, source: FileId) -> Self::Directory {
+        let mut query = self.selection.select("withFile");
+
+        query = query.arg("path", path.into());
+        query = query.arg("source", source);
+
+        FakeDirectory {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn with_file_opts(
+        &self,
+        path: impl Into<String> + Send,
+        source: FileId,
+        opts: DirectoryWithFileOpts,
+    ) -> Self::Directory {
+        let mut query = self.selection.select("withFile");
+
+        query = query.arg("path", path.into());
+        query = query.arg("source", source);
+        if let Some(permissions) = opts.permissions {
+            query = query.arg("permissions", permissions);
+        }
+
+        FakeDirectory {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn with_new_directory(&self, path: impl Into<String> + Send) -> Self::Directory {
+        let mut query = self.selection.select("withNewDirectory");
+
+        query = query.arg("path", path.into());
+
+        FakeDirectory {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn with_new_directory_opts(
+        &self,
+        path: impl Into<String> + Send,
+        opts: DirectoryWithNewDirectoryOpts,
+    ) -> Self::Directory {
+        let mut query = self.selection.select("withNewDirectory");
+
+        query = query.arg("path", path.into());
+        if let Some(permissions) = opts.permissions {
+            query = query.arg("permissions", permissions);
+        }
+
+        FakeDirectory {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn with_new_file(
+        &self,
+        path: impl Into<String> + Send,
+        contents: impl Into<String> + Send,
+    ) -> Self::Directory {
+        let mut query = self.selection.select("withNewFile");
+
+        query = query.arg("path", path.into());
+        query = query.arg("contents", contents.into());
+
+        FakeDirectory {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn with_new_file_opts(
+        &self,
+        path: impl Into<String> + Send,
+        contents: impl Into<String> + Send,
+        opts: DirectoryWithNewFileOpts,
+    ) -> Self::Directory {
+        let mut query = self.selection.select("withNewFile");
+
+        query = query.arg("path", path.into());
+        query = query.arg("contents", contents.into());
+        if let Some(permissions) = opts.permissions {
+            query = query.arg("permissions", permissions);
+        }
+
+        FakeDirectory {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn with_timestamps(&self, timestamp: isize) -> Self::Directory {
+        let mut query = self.selection.select("withTimestamps");
+
+        query = query.arg("timestamp", timestamp);
+
+        FakeDirectory {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn without_directory(&self, path: impl Into<String> + Send) -> Self::Directory {
+        let mut query = self.selection.select("withoutDirectory");
+
+        query = query.arg("path", path.into());
+
+        FakeDirectory {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn without_file(&self, path: impl Into<String> + Send) -> Self::Directory {
+        let mut query = self.selection.select("withoutFile");
+
+        query = query.arg("path", path.into());
+
+        FakeDirectory {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+}
+/// Trait of [`super::EnvVariable`]
+#[async_trait]
+pub trait EnvVariableApi: Send + Sync {
+    /// The environment variable name.
+    ///
+    /// Executes the query and returns its result.
+    async fn name(&self) -> eyre::Result<String>;
+    /// The environment variable value.
+    ///
+    /// Executes the query and returns its result.
+    async fn value(&self) -> eyre::Result<String>;
+}
+
+#[async_trait]
+impl EnvVariableApi for super::EnvVariable {
+    /// The environment variable name.
+    ///
+    /// Executes the query and returns its result.
+    async fn name(&self) -> eyre::Result<String> {
+        self.name().await
+    }
+    /// The environment variable value.
+    ///
+    /// Executes the query and returns its result.
+    async fn value(&self) -> eyre::Result<String> {
+        self.value().await
+    }
+}
+
+/// In-memory fake of [`EnvVariableApi`]
+#[derive(Debug, Clone)]
+pub struct FakeEnvVariable {
+    pub selection: Selection,
+    pub fake: Fake,
+}
+
+impl FakeEnvVariable {
+    /// A fake whose queries start at this object, answered by `fake`
+    pub fn new(fake: &Fake) -> Self {
+        Self {
+            selection: Selection::default(),
+            fake: fake.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl EnvVariableApi for FakeEnvVariable {
+    async fn name(&self) -> eyre::Result<String> {
+        let query = self.selection.select("name");
+
+        self.fake.execute(&query)
+    }
+    async fn value(&self) -> eyre::Result<String> {
+        let query = self.selection.select("value");
+
+        self.fake.execute(&query)
+    }
+}
+/// Trait of [`super::File`]
+#[async_trait]
+pub trait FileApi: Send + Sync {
+    type File: FileApi;
+    type Secret: SecretApi;
+
+    /// Retrieves the contents of the file.
+    ///
+    /// Executes the query and returns its result.
+    async fn contents(&self) -> eyre::Result<String>;
+    /// Writes the file to a file path on the host.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written directory (e.g., "output.txt").
+    async fn export(&self, path: impl Into<String> + Send) -> eyre::Result<bool>;
+    /// Retrieves the content-addressed identifier of the file.
+    ///
+    /// Executes the query and returns its result.
+    async fn id(&self) -> eyre::Result<FileId>;
+    /// Retrieves a secret referencing the contents of this file.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Secret`] is awaited.
+    fn secret(&self) -> Self::Secret;
+    /// Gets the size of the file, in bytes.
+    ///
+    /// Executes the query and returns its result.
+    async fn size(&self) -> eyre::Result<isize>;
+    /// Retrieves this file with its created/modified timestamps set to the given time.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - Timestamp to set dir/files in.
+    ///
+    ///   Formatted in seconds following Unix epoch (e.g., 1672531199).
+    fn with_timestamps(&self, timestamp: isize) -> Self::File;
+}
+
+#[async_trait]
+impl FileApi for super::File {
+    type File = super::File;
+    type Secret = super::Secret;
+
+    /// Retrieves the contents of the file.
+    ///
+    /// Executes the query and returns its result.
+    async fn contents(&self) -> eyre::Result<String> {
+        self.contents().await
+    }
+    /// Writes the file to a file path on the host.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written directory (e.g., "output.txt").
+    async fn export(&self, path: impl Into<String> + Send) -> eyre::Result<bool> {
+        self.export(path).await
+    }
+    /// Retrieves the content-addressed identifier of the file.
+    ///
+    /// Executes the query and returns its result.
+    async fn id(&self) -> eyre::Result<FileId> {
+        self.id().await
+    }
+    /// Retrieves a secret referencing the contents of this file.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Secret`] is awaited.
+    fn secret(&self) -> Self::Secret {
+        self.secret()
+    }
+    /// Gets the size of the file, in bytes.
+    ///
+    /// Executes the query and returns its result.
+    async fn size(&self) -> eyre::Result<isize> {
+        self.size().await
+    }
+    /// Retrieves this file with its created/modified timestamps set to the given time.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - Timestamp to set dir/files in.
+    ///
+    ///   Formatted in seconds following Unix epoch (e.g., 1672531199).
+    fn with_timestamps(&self, timestamp: isize) -> Self::File {
+        self.with_timestamps(timestamp)
+    }
+}
+
+/// In-memory fake of [`FileApi`]
+#[derive(Debug, Clone)]
+pub struct FakeFile {
+    pub selection: Selection,
+    pub fake: Fake,
+}
+
+impl FakeFile {
+    /// A fake whose queries start at this object, answered by `fake`
+    pub fn new(fake: &Fake) -> Self {
+        Self {
+            selection: Selection::default(),
+            fake: fake.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl FileApi for FakeFile {
+    type File = FakeFile;
+    type Secret = FakeSecret;
+
+    async fn contents(&self) -> eyre::Result<String> {
+        let query = self.selection.select("contents");
+
+        self.fake.execute(&query)
+    }
+    async fn export(&self, path: impl Into<String> + Send) -> eyre::Result<bool> {
+        let mut query = self.selection.select("export");
+
+        query = query.arg("path", path.into());
+
+        self.fake.execute(&query)
+    }
+    async fn id(&self) -> eyre::Result<FileId> {
+        let query = self.selection.select("id");
+
+        self.fake.execute(&query)
+    }
+    fn secret(&self) -> Self::Secret {
+        let query = self.selection.select("secret");
+
+        FakeSecret {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    async fn size(&self) -> eyre::Result<isize> {
+        let query = self.selection.select("size");
+
+        self.fake.execute(&query)
+    }
+    fn with_timestamps(&self, timestamp: isize) -> Self::File {
+        let mut query = self.selection.select("withTimestamps");
+
+        query = query.arg("timestamp", timestamp);
+
+        FakeFile {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+}
+/// Trait of [`super::GitRef`]
+#[cfg(feature = "git")]
+#[async_trait]
+pub trait GitRefApi: Send + Sync {
+    type Directory: DirectoryApi;
+
+    /// The digest of the current value of this ref.
+    ///
+    /// Executes the query and returns its result.
+    async fn digest(&self) -> eyre::Result<String>;
+    /// The filesystem tree at this ref.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`GitRefTreeOpts`], are taken by [`Self::tree_opts`].
+    fn tree(&self) -> Self::Directory;
+
+    /// The filesystem tree at this ref.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`GitRefTreeOpts`].
+    fn tree_opts<'a>(&self, opts: GitRefTreeOpts<'a>) -> Self::Directory;
+}
+
+#[cfg(feature = "git")]
+#[async_trait]
+impl GitRefApi for super::GitRef {
+    type Directory = super::Directory;
+
+    /// The digest of the current value of this ref.
+    ///
+    /// Executes the query and returns its result.
+    async fn digest(&self) -> eyre::Result<String> {
+        self.digest().await
+    }
+    /// The filesystem tree at this ref.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`GitRefTreeOpts`], are taken by [`Self::tree_opts`].
+    fn tree(&self) -> Self::Directory {
+        self.tree()
+    }
+
+    /// The filesystem tree at this ref.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`GitRefTreeOpts`].
+    fn tree_opts<'a>(&self, opts: GitRefTreeOpts<'a>) -> Self::Directory {
+        self.tree_opts(opts)
+    }
+}
+
+/// In-memory fake of [`GitRefApi`]
+#[cfg(feature = "git")]
+#[derive(Debug, Clone)]
+pub struct FakeGitRef {
+    pub selection: Selection,
+    pub fake: Fake,
+}
+
+#[cfg(feature = "git")]
+impl FakeGitRef {
+    /// A fake whose queries start at this object, answered by `fake`
+    pub fn new(fake: &Fake) -> Self {
+        Self {
+            selection: Selection::default(),
+            fake: fake.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "git")]
+#[async_trait]
+impl GitRefApi for FakeGitRef {
+    type Directory = FakeDirectory;
+
+    async fn digest(&self) -> eyre::Result<String> {
+        let query = self.selection.select("digest");
+
+        self.fake.execute(&query)
+    }
+    fn tree(&self) -> Self::Directory {
+        let query = self.selection.select("tree");
+
+        FakeDirectory {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn tree_opts<'a>(&self, opts: GitRefTreeOpts<'a>) -> Self::Directory {
+        let mut query = self.selection.select("tree");
+
+        if let Some(ssh_known_hosts) = opts.ssh_known_hosts {
+            query = query.arg("sshKnownHosts", ssh_known_hosts);
+        }
+        if let Some(ssh_auth_socket) = opts.ssh_auth_socket {
+            query = query.arg("sshAuthSocket", ssh_auth_socket);
+        }
+
+        FakeDirectory {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+}
+/// Trait of [`super::GitRepository`]
+#[cfg(feature = "git")]
+#[async_trait]
+pub trait GitRepositoryApi: Send + Sync {
+    type GitRef: GitRefApi;
+
+    /// Returns details on one branch.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`GitRef`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Branch's name (e.g., "main").
+    fn branch(&self, name: impl Into<String> + Send) -> Self::GitRef;
+    /// Lists of branches on the repository.
+    ///
+    /// Executes the query and returns its result.
+    async fn branches(&self) -> eyre::Result<Vec<String>>;
+    /// Returns details on one commit.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`GitRef`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Identifier of the commit (e.g., "b6315d8f2810962c601af73f86831f6866ea798b").
+    fn commit(&self, id: impl Into<String> + Send) -> Self::GitRef;
+    /// Returns details on one tag.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`GitRef`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Tag's name (e.g., "v0.3.9").
+    fn tag(&self, name: impl Into<String> + Send) -> Self::GitRef;
+    /// Lists of tags on the repository.
+    ///
+    /// Executes the query and returns its result.
+    async fn tags(&self) -> eyre::Result<Vec<String>>;
+}
+
+#[cfg(feature = "git")]
+#[async_trait]
+impl GitRepositoryApi for super::GitRepository {
+    type GitRef = super::GitRef;
+
+    /// Returns details on one branch.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`GitRef`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Branch's name (e.g., "main").
+    fn branch(&self, name: impl Into<String> + Send) -> Self::GitRef {
+        self.branch(name)
+    }
+    /// Lists of branches on the repository.
+    ///
+    /// Executes the query and returns its result.
+    async fn branches(&self) -> eyre::Result<Vec<String>> {
+        self.branches().await
+    }
+    /// Returns details on one commit.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`GitRef`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Identifier of the commit (e.g., "b6315d8f2810962c601af73f86831f6866ea798b").
+    fn commit(&self, id: impl Into<String> + Send) -> Self::GitRef {
+        self.commit(id)
+    }
+    /// Returns details on one tag.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`GitRef`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Tag's name (e.g., "v0.3.9").
+    fn tag(&self, name: impl Into<String> + Send) -> Self::GitRef {
+        self.tag(name)
+    }
+    /// Lists of tags on the repository.
+    ///
+    /// Executes the query and returns its result.
+    async fn tags(&self) -> eyre::Result<Vec<String>> {
+        self.tags().await
+    }
+}
+
+/// In-memory fake of [`GitRepositoryApi`]
+#[cfg(feature = "git")]
+#[derive(Debug, Clone)]
+pub struct FakeGitRepository {
+    pub selection: Selection,
+    pub fake: Fake,
+}
+
+#[cfg(feature = "git")]
+impl FakeGitRepository {
+    /// A fake whose queries start at this object, answered by `fake`
+    pub fn new(fake: &Fake) -> Self {
+        Self {
+            selection: Selection::default(),
+            fake: fake.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "git")]
+#[async_trait]
+impl GitRepositoryApi for FakeGitRepository {
+    type GitRef = FakeGitRef;
+
+    fn branch(&self, name: impl Into<String> + Send) -> Self::GitRef {
+        let mut query = self.selection.select("branch");
+
+        query = query.arg("name", name.into());
+
+        FakeGitRef {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    async fn branches(&self) -> eyre::Result<Vec<String>> {
+        let query = self.selection.select("branches");
+
+        self.fake.execute(&query)
+    }
+    fn commit(&self, id: impl Into<String> + Send) -> Self::GitRef {
+        let mut query = self.selection.select("commit");
+
+        query = query.arg("id", id.into());
+
+        FakeGitRef {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn tag(&self, name: impl Into<String> + Send) -> Self::GitRef {
+        let mut query = self.selection.select("tag");
+
+        query = query.arg("name", name.into());
+
+        FakeGitRef {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    async fn tags(&self) -> eyre::Result<Vec<String>> {
+        let query = self.selection.select("tags");
+
+        self.fake.execute(&query)
+    }
+}
+/// Trait of [`super::Host`]
+#[async_trait]
+pub trait HostApi: Send + Sync {
+    type Directory: DirectoryApi;
+    type HostVariable: HostVariableApi;
+    #[cfg(feature = "socket")]
+    type Socket: SocketApi;
+
+    /// Accesses a directory on the host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`HostDirectoryOpts`], are taken by [`Self::directory_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the directory to access (e.g., ".").
+    fn directory(&self, path: impl Into<String> + Send) -> Self::Directory;
+
+    /// Accesses a directory on the host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the directory to access (e.g., ".").
+    /// * `opts` - Optional arguments, see [`HostDirectoryOpts`].
+    fn directory_opts<'a>(
+        &self,
+        path: impl Into<String> + Send,
+        opts: HostDirectoryOpts<'a>,
+    ) -> Self::Directory;
+
+    /// Accesses an environment variable on the host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`HostVariable`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the environment variable (e.g., "PATH").
+    fn env_variable(&self, name: impl Into<String> + Send) -> Self::HostVariable;
+    /// Accesses a Unix socket on the host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Socket`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the Unix socket (e.g., "/var/run/docker.sock").
+    #[cfg(feature = "socket")]
+    fn unix_socket(&self, path: impl Into<String> + Send) -> Self::Socket;
+    /// Retrieves the current working directory on the host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`HostWorkdirOpts`], are taken by [`Self::workdir_opts`].
+    fn workdir(&self) -> Self::Directory;
+
+    /// Retrieves the current working directory on the host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`HostWorkdirOpts`].
+    fn workdir_opts<'a>(&self, opts: HostWorkdirOpts<'a>) -> Self::Directory;
+}
+
+#[async_trait]
+impl HostApi for super::Host {
+    type Directory = super::Directory;
+    type HostVariable = super::HostVariable;
+    #[cfg(feature = "socket")]
+    type Socket = super::Socket;
+
+    /// Accesses a directory on the host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`HostDirectoryOpts`], are taken by [`Self::directory_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the directory to access (e.g., ".").
+    fn directory(&self, path: impl Into<String> + Send) -> Self::Directory {
+        self.directory(path)
+    }
+
+    /// Accesses a directory on the host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the directory to access (e.g., ".").
+    /// * `opts` - Optional arguments, see [`HostDirectoryOpts`].
+    fn directory_opts<'a>(
+        &self,
+        path: impl Into<String> + Send,
+        opts: HostDirectoryOpts<'a>,
+    ) -> Self::Directory {
+        self.directory_opts(path, opts)
+    }
+
+    /// Accesses an environment variable on the host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`HostVariable`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the environment variable (e.g., "PATH").
+    fn env_variable(&self, name: impl Into<String> + Send) -> Self::HostVariable {
+        self.env_variable(name)
+    }
+    /// Accesses a Unix socket on the host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Socket`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the Unix socket (e.g., "/var/run/docker.sock").
+    #[cfg(feature = "socket")]
+    fn unix_socket(&self, path: impl Into<String> + Send) -> Self::Socket {
+        self.unix_socket(path)
+    }
+    /// Retrieves the current working directory on the host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`HostWorkdirOpts`], are taken by [`Self::workdir_opts`].
+    fn workdir(&self) -> Self::Directory {
+        self.workdir()
+    }
+
+    /// Retrieves the current working directory on the host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`HostWorkdirOpts`].
+    fn workdir_opts<'a>(&self, opts: HostWorkdirOpts<'a>) -> Self::Directory {
+        self.workdir_opts(opts)
+    }
+}
+
+/// In-memory fake of [`HostApi`]
+#[derive(Debug, Clone)]
+pub struct FakeHost {
+    pub selection: Selection,
+    pub fake: Fake,
+}
+
+impl FakeHost {
+    /// A fake whose queries start at this object, answered by `fake`
+    pub fn new(fake: &Fake) -> Self {
+        Self {
+            selection: Selection::default(),
+            fake: fake.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl HostApi for FakeHost {
+    type Directory = FakeDirectory;
+    type HostVariable = FakeHostVariable;
+    #[cfg(feature = "socket")]
+    type Socket = FakeSocket;
+
+    fn directory(&self, path: impl Into<String> + Send) -> Self::Directory {
+        let mut query = self.selection.select("directory");
+
+        query = query.arg("path", path.into());
+
+        FakeDirectory {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn directory_opts<'a>(
+        &self,
+        path: impl Into<String> + Send,
+        opts: HostDirectoryOpts<'a>,
+    ) -> Self::Directory {
+        let mut query = self.selection.select("directory");
+
+        query = query.arg("path", path.into());
+        if let Some(exclude) = opts.exclude {
+            query = query.arg("exclude", exclude);
+        }
+        if let Some(include) = opts.include {
+            query = query.arg("include", include);
+        }
+
+        FakeDirectory {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn env_variable(&self, name: impl Into<String> + Send) -> Self::HostVariable {
+        let mut query = self.selection.select("envVariable");
+
+        query = query.arg("name", name.into());
+
+        FakeHostVariable {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    #[cfg(feature = "socket")]
+    fn unix_socket(&self, path: impl Into<String> + Send) -> Self::Socket {
+        let mut query = self.selection.select("unixSocket");
+
+        query = query.arg("path", path.into());
+
+        FakeSocket {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn workdir(&self) -> Self::Directory {
+        let query = self.selection.select("workdir");
+
+        FakeDirectory {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn workdir_opts<'a>(&self, opts: HostWorkdirOpts<'a>) -> Self::Directory {
+        let mut query = self.selection.select("workdir");
+
+        if let Some(exclude) = opts.exclude {
+            query = query.arg("exclude", exclude);
+        }
+        if let Some(include) = opts.include {
+            query = query.arg("include", include);
+        }
+
+        FakeDirectory {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+}
+/// Trait of [`super::HostVariable`]
+#[async_trait]
+pub trait HostVariableApi: Send + Sync {
+    type Secret: SecretApi;
+
+    /// A secret referencing the value of this variable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Secret`] is awaited.
+    fn secret(&self) -> Self::Secret;
+    /// The value of this variable.
+    ///
+    /// Executes the query and returns its result.
+    async fn value(&self) -> eyre::Result<String>;
+}
+
+#[async_trait]
+impl HostVariableApi for super::HostVariable {
+    type Secret = super::Secret;
+
+    /// A secret referencing the value of this variable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Secret`] is awaited.
+    fn secret(&self) -> Self::Secret {
+        self.secret()
+    }
+    /// The value of this variable.
+    ///
+    /// Executes the query and returns its result.
+    async fn value(&self) -> eyre::Result<String> {
+        self.value().await
+    }
+}
+
+/// In-memory fake of [`HostVariableApi`]
+#[derive(Debug, Clone)]
+pub struct FakeHostVariable {
+    pub selection: Selection,
+    pub fake: Fake,
+}
+
+impl FakeHostVariable {
+    /// A fake whose queries start at this object, answered by `fake`
+    pub fn new(fake: &Fake) -> Self {
+        Self {
+            selection: Selection::default(),
+            fake: fake.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl HostVariableApi for FakeHostVariable {
+    type Secret = FakeSecret;
+
+    fn secret(&self) -> Self::Secret {
+        let query = self.selection.select("secret");
+
+        FakeSecret {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    async fn value(&self) -> eyre::Result<String> {
+        let query = self.selection.select("value");
+
+        self.fake.execute(&query)
+    }
+}
+/// Trait of [`super::Label`]
+#[async_trait]
+pub trait LabelApi: Send + Sync {
+    /// The label name.
+    ///
+    /// Executes the query and returns its result.
+    async fn name(&self) -> eyre::Result<String>;
+    /// The label value.
+    ///
+    /// Executes the query and returns its result.
+    async fn value(&self) -> eyre::Result<String>;
+}
+
+#[async_trait]
+impl LabelApi for super::Label {
+    /// The label name.
+    ///
+    /// Executes the query and returns its result.
+    async fn name(&self) -> eyre::Result<String> {
+        self.name().await
+    }
+    /// The label value.
+    ///
+    /// Executes the query and returns its result.
+    async fn value(&self) -> eyre::Result<String> {
+        self.value().await
+    }
+}
+
+/// In-memory fake of [`LabelApi`]
+#[derive(Debug, Clone)]
+pub struct FakeLabel {
+    pub selection: Selection,
+    pub fake: Fake,
+}
+
+impl FakeLabel {
+    /// A fake whose queries start at this object, answered by `fake`
+    pub fn new(fake: &Fake) -> Self {
+        Self {
+            selection: Selection::default(),
+            fake: fake.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl LabelApi for FakeLabel {
+    async fn name(&self) -> eyre::Result<String> {
+        let query = self.selection.select("name");
+
+        self.fake.execute(&query)
+    }
+    async fn value(&self) -> eyre::Result<String> {
+        let query = self.selection.select("value");
+
+        self.fake.execute(&query)
+    }
+}
+/// Trait of [`super::Port`]
+#[async_trait]
+pub trait PortApi: Send + Sync {
+    /// The port description.
+    ///
+    /// Executes the query and returns its result.
+    async fn description(&self) -> eyre::Result<String>;
+    /// The port number.
+    ///
+    /// Executes the query and returns its result.
+    async fn port(&self) -> eyre::Result<isize>;
+    /// The transport layer network protocol.
+    ///
+    /// Executes the query and returns its result.
+    async fn protocol(&self) -> eyre::Result<NetworkProtocol>;
+}
+
+#[async_trait]
+impl PortApi for super::Port {
+    /// The port description.
+    ///
+    /// Executes the query and returns its result.
+    async fn description(&self) -> eyre::Result<String> {
+        self.description().await
+    }
+    /// The port number.
+    ///
+    /// Executes the query and returns its result.
+    async fn port(&self) -> eyre::Result<isize> {
+        self.port().await
+    }
+    /// The transport layer network protocol.
+    ///
+    /// Executes the query and returns its result.
+    async fn protocol(&self) -> eyre::Result<NetworkProtocol> {
+        self.protocol().await
+    }
+}
+
+/// In-memory fake of [`PortApi`]
+#[derive(Debug, Clone)]
+pub struct FakePort {
+    pub selection: Selection,
+    pub fake: Fake,
+}
+
+impl FakePort {
+    /// A fake whose queries start at this object, answered by `fake`
+    pub fn new(fake: &Fake) -> Self {
+        Self {
+            selection: Selection::default(),
+            fake: fake.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl PortApi for FakePort {
+    async fn description(&self) -> eyre::Result<String> {
+        let query = self.selection.select("description");
+
+        self.fake.execute(&query)
+    }
+    async fn port(&self) -> eyre::Result<isize> {
+        let query = self.selection.select("port");
+
+        self.fake.execute(&query)
+    }
+    async fn protocol(&self) -> eyre::Result<NetworkProtocol> {
+        let query = self.selection.select("protocol");
+
+        self.fake.execute(&query)
+    }
+}
+/// Trait of [`super::Project`]
+#[cfg(feature = "project")]
+#[async_trait]
+pub trait ProjectApi: Send + Sync {
+    type Directory: DirectoryApi;
+    type Project: ProjectApi;
+
+    /// extensions in this project
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Project`] is awaited.
+    fn extensions(&self) -> Vec<Self::Project>;
+    /// Code files generated by the SDKs in the project
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    fn generated_code(&self) -> Self::Directory;
+    /// install the project's schema
+    ///
+    /// Executes the query and returns its result.
+    async fn install(&self) -> eyre::Result<bool>;
+    /// name of the project
+    ///
+    /// Executes the query and returns its result.
+    async fn name(&self) -> eyre::Result<String>;
+    /// schema provided by the project
+    ///
+    /// Executes the query and returns its result.
+    async fn schema(&self) -> eyre::Result<String>;
+    /// sdk used to generate code for and/or execute this project
+    ///
+    /// Executes the query and returns its result.
+    async fn sdk(&self) -> eyre::Result<String>;
+}
+
+#[cfg(feature = "project")]
+#[async_trait]
+impl ProjectApi for super::Project {
+    type Directory = super::Directory;
+    type Project = super::Project;
+
+    /// extensions in this project
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Project`] is awaited.
+    fn extensions(&self) -> Vec<Self::Project> {
+        self.extensions()
+    }
+    /// Code files generated by the SDKs in the project
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    fn generated_code(&self) -> Self::Directory {
+        self.generated_code()
+    }
+    /// install the project's schema
+    ///
+    /// Executes the query and returns its result.
+    async fn install(&self) -> eyre::Result<bool> {
+        self.install().await
+    }
+    /// name of the project
+    ///
+    /// Executes the query and returns its result.
+    async fn name(&self) -> eyre::Result<String> {
+        self.name().await
+    }
+    /// schema provided by the project
+    ///
+    /// Executes the query and returns its result.
+    async fn schema(&self) -> eyre::Result<String> {
+        self.schema().await
+    }
+    /// sdk used to generate code for and/or execute this project
+    ///
+    /// Executes the query and returns its result.
+    async fn sdk(&self) -> eyre::Result<String> {
+        self.sdk().await
+    }
+}
+
+/// In-memory fake of [`ProjectApi`]
+#[cfg(feature = "project")]
+#[derive(Debug, Clone)]
+pub struct FakeProject {
+    pub selection: Selection,
+    pub fake: Fake,
+}
+
+#[cfg(feature = "project")]
+impl FakeProject {
+    /// A fake whose queries start at this object, answered by `fake`
+    pub fn new(fake: &Fake) -> Self {
+        Self {
+            selection: Selection::default(),
+            fake: fake.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "project")]
+#[async_trait]
+impl ProjectApi for FakeProject {
+    type Directory = FakeDirectory;
+    type Project = FakeProject;
+
+    fn extensions(&self) -> Vec<Self::Project> {
+        let query = self.selection.select("extensions");
+
+        vec![FakeProject {
+            selection: query,
+            fake: self.fake.clone(),
+        }]
+    }
+    fn generated_code(&self) -> Self::Directory {
+        let query = self.selection.select("generatedCode");
+
+        FakeDirectory {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    async fn install(&self) -> eyre::Result<bool> {
+        let query = self.selection.select("install");
+
+        self.fake.execute(&query)
+    }
+    async fn name(&self) -> eyre::Result<String> {
+        let query = self.selection.select("name");
+
+        self.fake.execute(&query)
+    }
+    async fn schema(&self) -> eyre::Result<String> {
+        let query = self.selection.select("schema");
+
+        self.fake.execute(&query)
+    }
+    async fn sdk(&self) -> eyre::Result<String> {
+        let query = self.selection.select("sdk");
+
+        self.fake.execute(&query)
+    }
+}
+/// Trait of [`super::Query`]
+#[async_trait]
+pub trait QueryApi: Send + Sync {
+    type CacheVolume: CacheVolumeApi;
+    type Container: ContainerApi;
+    type Directory: DirectoryApi;
+    type File: FileApi;
+    #[cfg(feature = "git")]
+    type GitRepository: GitRepositoryApi;
+    type Host: HostApi;
+    #[cfg(feature = "project")]
+    type Project: ProjectApi;
+    type Query: QueryApi;
+    type Secret: SecretApi;
+    #[cfg(feature = "socket")]
+    type Socket: SocketApi;
+
+    /// Constructs a cache volume for a given cache key.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`CacheVolume`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A string identifier to target this cache volume (e.g., "modules-cache").
+    fn cache_volume(&self, key: impl Into<String> + Send) -> Self::CacheVolume;
+    /// Loads a container from ID.
+    /// Null ID returns an empty container (scratch).
+    /// Optional platform argument initializes new containers to execute and publish as that platform.
+    /// Platform defaults to that of the builder's host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`QueryContainerOpts`], are taken by [`Self::container_opts`].
+    fn container(&self) -> Self::Container;
+
+    /// Loads a container from ID.
+    /// Null ID returns an empty container (scratch).
+    /// Optional platform argument initializes new containers to execute and publish as that platform.
+    /// Platform defaults to that of the builder's host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`QueryContainerOpts`].
+    fn container_opts(&self, opts: QueryContainerOpts) -> Self::Container;
+
+    /// The default platform of the builder.
+    ///
+    /// Executes the query and returns its result.
+    async fn default_platform(&self) -> eyre::Result<Platform>;
+    /// Load a directory by ID. No argument produces an empty directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`QueryDirectoryOpts`], are taken by [`Self::directory_opts`].
+    fn directory(&self) -> Self::Directory;
+
+    /// Load a directory by ID. No argument produces an empty directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`QueryDirectoryOpts`].
+    fn directory_opts(&self, opts: QueryDirectoryOpts) -> Self::Directory;
+
+    /// Loads a file by ID.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Identifies a [`File`].
+    fn file(&self, id: FileId) -> Self::File;
+    /// Queries a git repository.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`GitRepository`] is awaited.
+    ///
+    /// Optional arguments, see [`QueryGitOpts`], are taken by [`Self::git_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Url of the git repository.
+    ///   Can be formatted as <https://{host}/{owner}/{repo}>, git@{host}/{owner}/{repo}
+    ///   Suffix ".git" is optional.
+    #[cfg(feature = "git")]
+    fn git(&self, url: impl Into<String> + Send) -> Self::GitRepository;
+
+    /// Queries a git repository.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`GitRepository`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Url of the git repository.
+    ///   Can be formatted as <https://{host}/{owner}/{repo}>, git@{host}/{owner}/{repo}
+    ///   Suffix ".git" is optional.
+    /// * `opts` - Optional arguments, see [`QueryGitOpts`].
+    #[cfg(feature = "git")]
+    fn git_opts(&self, url: impl Into<String> + Send, opts: QueryGitOpts) -> Self::GitRepository;
+
+    /// Queries the host environment.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Host`] is awaited.
+    fn host(&self) -> Self::Host;
+    /// Returns a file containing an http remote url content.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is awaited.
+    ///
+    /// Optional arguments, see [`QueryHttpOpts`], are taken by [`Self::http_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - HTTP url to get the content from (e.g., "<https://docs.dagger.io>").
+    fn http(&self, url: impl Into<String> + Send) -> Self::File;
+
+    /// Returns a file containing an http remote url content.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - HTTP url to get the content from (e.g., "<https://docs.dagger.io>").
+    /// * `opts` - Optional arguments, see [`QueryHttpOpts`].
+    fn http_opts(&self, url: impl Into<String> + Send, opts: QueryHttpOpts) -> Self::File;
+
+    /// Creates a named sub-pipeline.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Query`] is awaited.
+    ///
+    /// Optional arguments, see [`QueryPipelineOpts`], are taken by [`Self::pipeline_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    fn pipeline(&self, name: impl Into<String> + Send) -> Self::Query;
+
+    /// Creates a named sub-pipeline.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Query`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    /// * `opts` - Optional arguments, see [`QueryPipelineOpts`].
+    fn pipeline_opts<'a>(
+        &self,
+        name: impl Into<String> + Send,
+        opts: QueryPipelineOpts<'a>,
+    ) -> Self::Query;
+
+    /// Look up a project by name
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Project`] is awaited.
+    #[cfg(feature = "project")]
+    fn project(&self, name: impl Into<String> + Send) -> Self::Project;
+    /// Loads a secret from its ID.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Secret`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Identifies a [`Secret`].
+    fn secret(&self, id: SecretId) -> Self::Secret;
+    /// Loads a socket by its ID.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Socket`] is awaited.
+    ///
+    /// Optional arguments, see [`QuerySocketOpts`], are taken by [`Self::socket_opts`].
+    #[cfg(feature = "socket")]
+    fn socket(&self) -> Self::Socket;
+
+    /// Loads a socket by its ID.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Socket`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`QuerySocketOpts`].
+    #[cfg(feature = "socket")]
+    fn socket_opts(&self, opts: QuerySocketOpts) -> Self::Socket;
+}
+
+#[async_trait]
+impl QueryApi for super::Query {
+    type CacheVolume = super::CacheVolume;
+    type Container = super::Container;
+    type Directory = super::Directory;
+    type File = super::File;
+    #[cfg(feature = "git")]
+    type GitRepository = super::GitRepository;
+    type Host = super::Host;
+    #[cfg(feature = "project")]
+    type Project = super::Project;
+    type Query = super::Query;
+    type Secret = super::Secret;
+    #[cfg(feature = "socket")]
+    type Socket = super::Socket;
+
+    /// Constructs a cache volume for a given cache key.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`CacheVolume`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A string identifier to target this cache volume (e.g., "modules-cache").
+    fn cache_volume(&self, key: impl Into<String> + Send) -> Self::CacheVolume {
+        self.cache_volume(key)
+    }
+    /// Loads a container from ID.
+    /// Null ID returns an empty container (scratch).
+    /// Optional platform argument initializes new containers to execute and publish as that platform.
+    /// Platform defaults to that of the builder's host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`QueryContainerOpts`], are taken by [`Self::container_opts`].
+    fn container(&self) -> Self::Container {
+        self.container()
+    }
+
+    /// Loads a container from ID.
+    /// Null ID returns an empty container (scratch).
+    /// Optional platform argument initializes new containers to execute and publish as that platform.
+    /// Platform defaults to that of the builder's host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`QueryContainerOpts`].
+    fn container_opts(&self, opts: QueryContainerOpts) -> Self::Container {
+        self.container_opts(opts)
+    }
+
+    /// The default platform of the builder.
+    ///
+    /// Executes the query and returns its result.
+    async fn default_platform(&self) -> eyre::Result<Platform> {
+        self.default_platform().await
+    }
+    /// Load a directory by ID. No argument produces an empty directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`QueryDirectoryOpts`], are taken by [`Self::directory_opts`].
+    fn directory(&self) -> Self::Directory {
+        self.directory()
+    }
+
+    /// Load a directory by ID. No argument produces an empty directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`QueryDirectoryOpts`].
+    fn directory_opts(&self, opts: QueryDirectoryOpts) -> Self::Directory {
+        self.directory_opts(opts)
+    }
+
+    /// Loads a file by ID.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Identifies a [`File`].
+    fn file(&self, id: FileId) -> Self::File {
+        self.file(id)
+    }
+    /// Queries a git repository.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`GitRepository`] is awaited.
+    ///
+    /// Optional arguments, see [`QueryGitOpts`], are taken by [`Self::git_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Url of the git repository.
+    ///   Can be formatted as <https://{host}/{owner}/{repo}>, git@{host}/{owner}/{repo}
+    ///   Suffix ".git" is optional.
+    #[cfg(feature = "git")]
+    fn git(&self, url: impl Into<String> + Send) -> Self::GitRepository {
+        self.git(url)
+    }
+
+    /// Queries a git repository.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`GitRepository`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Url of the git repository.
+    ///   Can be formatted as <https://{host}/{owner}/{repo}>, git@{host}/{owner}/{repo}
+    ///   Suffix ".git" is optional.
+    /// * `opts` - Optional arguments, see [`QueryGitOpts`].
+    #[cfg(feature = "git")]
+    fn git_opts(&self, url: impl Into<String> + Send, opts: QueryGitOpts) -> Self::GitRepository {
+        self.git_opts(url, opts)
+    }
+
+    /// Queries the host environment.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Host`] is awaited.
+    fn host(&self) -> Self::Host {
+        self.host()
+    }
+    /// Returns a file containing an http remote url content.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is awaited.
+    ///
+    /// Optional arguments, see [`QueryHttpOpts`], are taken by [`Self::http_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - HTTP url to get the content from (e.g., "<https://docs.dagger.io>").
+    fn http(&self, url: impl Into<String> + Send) -> Self::File {
+        self.http(url)
+    }
+
+    /// Returns a file containing an http remote url content.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - HTTP url to get the content from (e.g., "<https://docs.dagger.io>").
+    /// * `opts` - Optional arguments, see [`QueryHttpOpts`].
+    fn http_opts(&self, url: impl Into<String> + Send, opts: QueryHttpOpts) -> Self::File {
+        self.http_opts(url, opts)
+    }
+
+    /// Creates a named sub-pipeline.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Query`] is awaited.
+    ///
+    /// Optional arguments, see [`QueryPipelineOpts`], are taken by [`Self::pipeline_opts`].
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    fn pipeline(&self, name: impl Into<String> + Send) -> Self::Query {
+        self.pipeline(name)
+    }
+
+    /// Creates a named sub-pipeline.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Query`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    /// * `opts` - Optional arguments, see [`QueryPipelineOpts`].
+    fn pipeline_opts<'a>(
+        &self,
+        name: impl Into<String> + Send,
+        opts: QueryPipelineOpts<'a>,
+    ) -> Self::Query {
+        self.pipeline_opts(name, opts)
+    }
+
+    /// Look up a project by name
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Project`] is awaited.
+    #[cfg(feature = "project")]
+    fn project(&self, name: impl Into<String> + Send) -> Self::Project {
+        self.project(name)
+    }
+    /// Loads a secret from its ID.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Secret`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Identifies a [`Secret`].
+    fn secret(&self, id: SecretId) -> Self::Secret {
+        self.secret(id)
+    }
+    /// Loads a socket by its ID.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Socket`] is awaited.
+    ///
+    /// Optional arguments, see [`QuerySocketOpts`], are taken by [`Self::socket_opts`].
+    #[cfg(feature = "socket")]
+    fn socket(&self) -> Self::Socket {
+        self.socket()
+    }
+
+    /// Loads a socket by its ID.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Socket`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`QuerySocketOpts`].
+    #[cfg(feature = "socket")]
+    fn socket_opts(&self, opts: QuerySocketOpts) -> Self::Socket {
+        self.socket_opts(opts)
+    }
+}
+
+/// In-memory fake of [`QueryApi`]
+#[derive(Debug, Clone)]
+pub struct FakeQuery {
+    pub selection: Selection,
+    pub fake: Fake,
+}
+
+impl FakeQuery {
+    /// A fake whose queries start at this object, answered by `fake`
+    pub fn new(fake: &Fake) -> Self {
+        Self {
+            selection: Selection::default(),
+            fake: fake.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl QueryApi for FakeQuery {
+    type CacheVolume = FakeCacheVolume;
+    type Container = FakeContainer;
+    type Directory = FakeDirectory;
+    type File = FakeFile;
+    #[cfg(feature = "git")]
+    type GitRepository = FakeGitRepository;
+    type Host = FakeHost;
+    #[cfg(feature = "project")]
+    type Project = FakeProject;
+    type Query = FakeQuery;
+    type Secret = FakeSecret;
+    #[cfg(feature = "socket")]
+    type Socket = FakeSocket;
+
+    fn cache_volume(&self, key: impl Into<String> + Send) -> Self::CacheVolume {
+        let mut query = self.selection.select("cacheVolume");
+
+        query = query.arg("key", key.into());
+
+        FakeCacheVolume {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn container(&self) -> Self::Container {
+        let query = self.selection.select("container");
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn container_opts(&self, opts: QueryContainerOpts) -> Self::Container {
+        let mut query = self.selection.select("container");
+
+        if let Some(id) = opts.id {
+            query = query.arg("id", id);
+        }
+        if let Some(platform) = opts.platform {
+            query = query.arg("platform", platform);
+        }
+
+        FakeContainer {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    async fn default_platform(&self) -> eyre::Result<Platform> {
+        let query = self.selection.select("defaultPlatform");
+
+        self.fake.execute(&query)
+    }
+    fn directory(&self) -> Self::Directory {
+        let query = self.selection.select("directory");
+
+        FakeDirectory {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn directory_opts(&self, opts: QueryDirectoryOpts) -> Self::Directory {
+        let mut query = self.selection.select("directory");
+
+        if let Some(id) = opts.id {
+            query = query.arg("id", id);
+        }
+
+        FakeDirectory {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn file(&self, id: FileId) -> Self::File {
+        let mut query = self.selection.select("file");
+
+        query = query.arg("id", id);
+
+        FakeFile {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    #[cfg(feature = "git")]
+    fn git(&self, url: impl Into<String> + Send) -> Self::GitRepository {
+        let mut query = self.selection.select("git");
+
+        query = query.arg("url", url.into());
+
+        FakeGitRepository {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    #[cfg(feature = "git")]
+    fn git_opts(&self, url: impl Into<String> + Send, opts: QueryGitOpts) -> Self::GitRepository {
+        let mut query = self.selection.select("git");
+
+        query = query.arg("url", url.into());
+        if let Some(keep_git_dir) = opts.keep_git_dir {
+            query = query.arg("keepGitDir", keep_git_dir);
+        }
+        if let Some(experimental_service_host) = opts.experimental_service_host {
+            query = query.arg("experimentalServiceHost", experimental_service_host);
+        }
+
+        FakeGitRepository {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn host(&self) -> Self::Host {
+        let query = self.selection.select("host");
+
+        FakeHost {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn http(&self, url: impl Into<String> + Send) -> Self::File {
+        let mut query = self.selection.select("http");
+
+        query = query.arg("url", url.into());
+
+        FakeFile {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn http_opts(&self, url: impl Into<String> + Send, opts: QueryHttpOpts) -> Self::File {
+        let mut query = self.selection.select("http");
+
+        query = query.arg("url", url.into());
+        if let Some(experimental_service_host) = opts.experimental_service_host {
+            query = query.arg("experimentalServiceHost", experimental_service_host);
+        }
+
+        FakeFile {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn pipeline(&self, name: impl Into<String> + Send) -> Self::Query {
+        let mut query = self.selection.select("pipeline");
+
+        query = query.arg("name", name.into());
+
+        FakeQuery {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    fn pipeline_opts<'a>(
+        &self,
+        name: impl Into<String> + Send,
+        opts: QueryPipelineOpts<'a>,
+    ) -> Self::Query {
+        let mut query = self.selection.select("pipeline");
+
+        query = query.arg("name", name.into());
+        if let Some(description) = opts.description {
+            query = query.arg("description", description);
+        }
+        if let Some(labels) = opts.labels {
+            query = query.arg("labels", labels);
+        }
+
+        FakeQuery {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    #[cfg(feature = "project")]
+    fn project(&self, name: impl Into<String> + Send) -> Self::Project {
+        let mut query = self.selection.select("project");
+
+        query = query.arg("name", name.into());
+
+        FakeProject {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    fn secret(&self, id: SecretId) -> Self::Secret {
+        let mut query = self.selection.select("secret");
+
+        query = query.arg("id", id);
+
+        FakeSecret {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+    #[cfg(feature = "socket")]
+    fn socket(&self) -> Self::Socket {
+        let query = self.selection.select("socket");
+
+        FakeSocket {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+
+    #[cfg(feature = "socket")]
+    fn socket_opts(&self, opts: QuerySocketOpts) -> Self::Socket {
+        let mut query = self.selection.select("socket");
+
+        if let Some(id) = opts.id {
+            query = query.arg("id", id);
+        }
+
+        FakeSocket {
+            selection: query,
+            fake: self.fake.clone(),
+        }
+    }
+}
+/// Trait of [`super::Secret`]
+#[async_trait]
+pub trait SecretApi: Send + Sync {
+    /// The identifier for this secret.
+    ///
+    /// Executes the query and returns its result.
+    async fn id(&self) -> eyre::Result<SecretId>;
+    /// The value of this secret.
+    ///
+    /// Executes the query and returns its result.
+    async fn plaintext(&self) -> eyre::Result<String>;
+}
+
+#[async_trait]
+impl SecretApi for super::Secret {
+    /// The identifier for this secret.
+    ///
+    /// Executes the query and returns its result.
+    async fn id(&self) -> eyre::Result<SecretId> {
+        self.id().await
+    }
+    /// The value of this secret.
+    ///
+    /// Executes the query and returns its result.
+    async fn plaintext(&self) -> eyre::Result<String> {
+        self.plaintext().await
+    }
+}
+
+/// In-memory fake of [`SecretApi`]
+#[derive(Debug, Clone)]
+pub struct FakeSecret {
+    pub selection: Selection,
+    pub fake: Fake,
+}
+
+impl FakeSecret {
+    /// A fake whose queries start at this object, answered by `fake`
+    pub fn new(fake: &Fake) -> Self {
+        Self {
+            selection: Selection::default(),
+            fake: fake.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl SecretApi for FakeSecret {
+    async fn id(&self) -> eyre::Result<SecretId> {
+        let query = self.selection.select("id");
+
+        self.fake.execute(&query)
+    }
+    async fn plaintext(&self) -> eyre::Result<String> {
+        let query = self.selection.select("plaintext");
+
+        self.fake.execute(&query)
+    }
+}
+/// Trait of [`super::Socket`]
+#[cfg(feature = "socket")]
+#[async_trait]
+pub trait SocketApi: Send + Sync {
+    /// The content-addressed identifier of the socket.
+    ///
+    /// Executes the query and returns its result.
+    async fn id(&self) -> eyre::Result<SocketId>;
+}
+
+#[cfg(feature = "socket")]
+#[async_trait]
+impl SocketApi for super::Socket {
+    /// The content-addressed identifier of the socket.
+    ///
+    /// Executes the query and returns its result.
+    async fn id(&self) -> eyre::Result<SocketId> {
+        self.id().await
+    }
+}
+
+/// In-memory fake of [`SocketApi`]
+#[cfg(feature = "socket")]
+#[derive(Debug, Clone)]
+pub struct FakeSocket {
+    pub selection: Selection,
+    pub fake: Fake,
+}
+
+#[cfg(feature = "socket")]
+impl FakeSocket {
+    /// A fake whose queries start at this object, answered by `fake`
+    pub fn new(fake: &Fake) -> Self {
+        Self {
+            selection: Selection::default(),
+            fake: fake.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "socket")]
+#[async_trait]
+impl SocketApi for FakeSocket {
+    async fn id(&self) -> eyre::Result<SocketId> {
+        let query = self.selection.select("id");
+
+        self.fake.execute(&query)
+    }
+}
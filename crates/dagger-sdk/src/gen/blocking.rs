@@ -0,0 +1,2533 @@
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+use super::*;
+
+/// Blocking version of [`super::CacheVolume`]
+#[derive(Debug, Clone)]
+pub struct CacheVolume {
+    pub inner: super::CacheVolume,
+    pub runtime: Arc<Runtime>,
+}
+
+impl CacheVolume {
+    /// Executes the query and returns its result.
+    pub fn id(&self) -> eyre::Result<CacheId> {
+        self.runtime.block_on(self.inner.id())
+    }
+}
+/// Blocking version of [`super::Container`]
+#[derive(Debug, Clone)]
+pub struct Container {
+    pub inner: super::Container,
+    pub runtime: Arc<Runtime>,
+}
+
+impl Container {
+    /// Initializes this container from a Dockerfile build.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// Optional arguments, see [`ContainerBuildOpts`], are taken by [`Self::build_opts`] and [`Self::build_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - Directory context used by the Dockerfile.
+    ///   Identifies a [`Directory`].
+    pub fn build(&self, context: DirectoryId) -> Container {
+        Container {
+            inner: self.inner.build(context),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Initializes this container from a Dockerfile build.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - Directory context used by the Dockerfile.
+    ///   Identifies a [`Directory`].
+    /// * `opts` - Optional arguments, see [`ContainerBuildOpts`].
+    pub fn build_opts<'a>(&self, context: DirectoryId, opts: ContainerBuildOpts<'a>) -> Container {
+        Container {
+            inner: self.inner.build_opts(context, opts),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Initializes this container from a Dockerfile build.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - Directory context used by the Dockerfile.
+    ///   Identifies a [`Directory`].
+    /// * `opts` - Optional arguments, see [`ContainerBuildOpts`].
+    pub fn build_with<'a>(
+        &self,
+        context: DirectoryId,
+        opts: impl FnOnce(ContainerBuildOpts<'a>) -> ContainerBuildOpts<'a>,
+    ) -> Container {
+        self.build_opts(context, opts(Default::default()))
+    }
+    /// Retrieves default arguments for future commands.
+    ///
+    /// Executes the query and returns its result.
+    pub fn default_args(&self) -> eyre::Result<Vec<String>> {
+        self.runtime.block_on(self.inner.default_args())
+    }
+    /// Retrieves a directory at the given path.
+    /// Mounts are included.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the directory to retrieve (e.g., "./src").
+    pub fn directory(&self, path: impl Into<String>) -> Directory {
+        Directory {
+            inner: self.inner.directory(path),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Retrieves an endpoint that clients can use to reach this container.
+    /// If no port is specified, the first exposed port is used. If none exist an error is returned.
+    /// If a scheme is specified, a URL is returned. Otherwise, a host:port pair is returned.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`ContainerEndpointOpts`], are taken by [`Self::endpoint_opts`] and [`Self::endpoint_with`].
+    pub fn endpoint(&self) -> eyre::Result<String> {
+        self.runtime.block_on(self.inner.endpoint())
+    }
+
+    /// Retrieves an endpoint that clients can use to reach this container.
+    /// If no port is specified, the first exposed port is used. If none exist an error is returned.
+    /// If a scheme is specified, a URL is returned. Otherwise, a host:port pair is returned.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerEndpointOpts`].
+    pub fn endpoint_opts<'a>(&self, opts: ContainerEndpointOpts<'a>) -> eyre::Result<String> {
+        self.runtime.block_on(self.inner.endpoint_opts(opts))
+    }
+
+    /// Retrieves an endpoint that clients can use to reach this container.
+    /// If no port is specified, the first exposed port is used. If none exist an error is returned.
+    /// If a scheme is specified, a URL is returned. Otherwise, a host:port pair is returned.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerEndpointOpts`].
+    pub fn endpoint_with<'a>(
+        &self,
+        opts: impl FnOnce(ContainerEndpointOpts<'a>) -> ContainerEndpointOpts<'a>,
+    ) -> eyre::Result<String> {
+        self.endpoint_opts(opts(Default::default()))
+    }
+    /// Retrieves entrypoint to be prepended to the arguments of all commands.
+    ///
+    /// Executes the query and returns its result.
+    pub fn entrypoint(&self) -> eyre::Result<Vec<String>> {
+        self.runtime.block_on(self.inner.entrypoint())
+    }
+    /// Retrieves the value of the specified environment variable.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the environment variable to retrieve (e.g., "PATH").
+    pub fn env_variable(&self, name: impl Into<String>) -> eyre::Result<String> {
+        self.runtime.block_on(self.inner.env_variable(name))
+    }
+    /// Retrieves the list of environment variables passed to commands.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`EnvVariable`] is called.
+    pub fn env_variables(&self) -> Vec<EnvVariable> {
+        self.inner
+            .env_variables()
+            .into_iter()
+            .map(|inner| EnvVariable {
+                inner,
+                runtime: self.runtime.clone(),
+            })
+            .collect()
+    }
+    /// Retrieves this container after executing the specified command inside it.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// Optional arguments, see [`ContainerExecOpts`], are taken by [`Self::exec_opts`] and [`Self::exec_with`].
+    pub fn exec(&self) -> Container {
+        Container {
+            inner: self.inner.exec(),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Retrieves this container after executing the specified command inside it.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerExecOpts`].
+    pub fn exec_opts<'a>(&self, opts: ContainerExecOpts<'a>) -> Container {
+        Container {
+            inner: self.inner.exec_opts(opts),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Retrieves this container after executing the specified command inside it.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerExecOpts`].
+    pub fn exec_with<'a>(
+        &self,
+        opts: impl FnOnce(ContainerExecOpts<'a>) -> ContainerExecOpts<'a>,
+    ) -> Container {
+        self.exec_opts(opts(Default::default()))
+    }
+    /// Exit code of the last executed command. Zero means success.
+    /// Errors if no command has been executed.
+    ///
+    /// Executes the query and returns its result.
+    pub fn exit_code(&self) -> eyre::Result<isize> {
+        self.runtime.block_on(self.inner.exit_code())
+    }
+    /// Writes the container as an OCI tarball to the destination file path on the host for the specified platform variants.
+    /// Return true on success.
+    /// It can also publishes platform variants.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`ContainerExportOpts`], are taken by [`Self::export_opts`] and [`Self::export_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Host's destination path (e.g., "./tarball").
+    ///   Path can be relative to the engine's workdir or absolute.
+    pub fn export(&self, path: impl Into<String>) -> eyre::Result<bool> {
+        self.runtime.block_on(self.inner.export(path))
+    }
+
+    /// Writes the container as an OCI tarball to the destination file path on the host for the specified platform variants.
+    /// Return true on success.
+    /// It can also publishes platform variants.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Host's destination path (e.g., "./tarball").
+    ///   Path can be relative to the engine's workdir or absolute.
+    /// * `opts` - Optional arguments, see [`ContainerExportOpts`].
+    pub fn export_opts(
+        &self,
+        path: impl Into<String>,
+        opts: ContainerExportOpts,
+    ) -> eyre::Result<bool> {
+        self.runtime.block_on(self.inner.export_opts(path, opts))
+    }
+
+    /// Writes the container as an OCI tarball to the destination file path on the host for the specified platform variants.
+    /// Return true on success.
+    /// It can also publishes platform variants.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Host's destination path (e.g., "./tarball").
+    ///   Path can be relative to the engine's workdir or absolute.
+    /// * `opts` - Optional arguments, see [`ContainerExportOpts`].
+    pub fn export_with(
+        &self,
+        path: impl Into<String>,
+        opts: impl FnOnce(ContainerExportOpts) -> ContainerExportOpts,
+    ) -> eyre::Result<bool> {
+        self.export_opts(path, opts(Default::default()))
+    }
+    /// Retrieves the list of exposed ports.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Port`] is called.
+    pub fn exposed_ports(&self) -> Vec<Port> {
+        self.inner
+            .exposed_ports()
+            .into_iter()
+            .map(|inner| Port {
+                inner,
+                runtime: self.runtime.clone(),
+            })
+            .collect()
+    }
+    /// Retrieves a file at the given path.
+    /// Mounts are included.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file to retrieve (e.g., "./README.md").
+    pub fn file(&self, path: impl Into<String>) -> File {
+        File {
+            inner: self.inner.file(path),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Initializes this container from a pulled base image.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Image's address from its registry.
+    ///
+    ///   Formatted as \[host\]/\[user\]/\[repo\]:\[tag\] (e.g., "docker.io/dagger/dagger:main").
+    pub fn from(&self, address: impl Into<String>) -> Container {
+        Container {
+            inner: self.inner.from(address),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Retrieves this container's root filesystem. Mounts are not included.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    pub fn fs(&self) -> Directory {
+        Directory {
+            inner: self.inner.fs(),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Retrieves a hostname which can be used by clients to reach this container.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Executes the query and returns its result.
+    pub fn hostname(&self) -> eyre::Result<String> {
+        self.runtime.block_on(self.inner.hostname())
+    }
+    /// A unique identifier for this container.
+    ///
+    /// Executes the query and returns its result.
+    pub fn id(&self) -> eyre::Result<ContainerId> {
+        self.runtime.block_on(self.inner.id())
+    }
+    /// The unique image reference which can only be retrieved immediately after the 'Container.From' call.
+    ///
+    /// Executes the query and returns its result.
+    pub fn image_ref(&self) -> eyre::Result<String> {
+        self.runtime.block_on(self.inner.image_ref())
+    }
+    /// Retrieves the value of the specified label.
+    ///
+    /// Executes the query and returns its result.
+    pub fn label(&self, name: impl Into<String>) -> eyre::Result<String> {
+        self.runtime.block_on(self.inner.label(name))
+    }
+    /// Retrieves the list of labels passed to container.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Label`] is called.
+    pub fn labels(&self) -> Vec<Label> {
+        self.inner
+            .labels()
+            .into_iter()
+            .map(|inner| Label {
+                inner,
+                runtime: self.runtime.clone(),
+            })
+            .collect()
+    }
+    /// Retrieves the list of paths where a directory is mounted.
+    ///
+    /// Executes the query and returns its result.
+    pub fn mounts(&self) -> eyre::Result<Vec<String>> {
+        self.runtime.block_on(self.inner.mounts())
+    }
+    /// Creates a named sub-pipeline
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// Optional arguments, see [`ContainerPipelineOpts`], are taken by [`Self::pipeline_opts`] and [`Self::pipeline_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    pub fn pipeline(&self, name: impl Into<String>) -> Container {
+        Container {
+            inner: self.inner.pipeline(name),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Creates a named sub-pipeline
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    /// * `opts` - Optional arguments, see [`ContainerPipelineOpts`].
+    pub fn pipeline_opts<'a>(
+        &self,
+        name: impl Into<String>,
+        opts: ContainerPipelineOpts<'a>,
+    ) -> Container {
+        Container {
+            inner: self.inner.pipeline_opts(name, opts),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Creates a named sub-pipeline
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    /// * `opts` - Optional arguments, see [`ContainerPipelineOpts`].
+    pub fn pipeline_with<'a>(
+        &self,
+        name: impl Into<String>,
+        opts: impl FnOnce(ContainerPipelineOpts<'a>) -> ContainerPipelineOpts<'a>,
+    ) -> Container {
+        self.pipeline_opts(name, opts(Default::default()))
+    }
+    /// The platform this container executes and publishes as.
+    ///
+    /// Executes the query and returns its result.
+    pub fn platform(&self) -> eyre::Result<Platform> {
+        self.runtime.block_on(self.inner.platform())
+    }
+    /// Publishes this container as a new image to the specified address.
+    /// Publish returns a fully qualified ref.
+    /// It can also publish platform variants.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`ContainerPublishOpts`], are taken by [`Self::publish_opts`] and [`Self::publish_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Registry's address to publish the image to.
+    ///
+    ///   Formatted as \[host\]/\[user\]/\[repo\]:\[tag\] (e.g. "docker.io/dagger/dagger:main").
+    pub fn publish(&self, address: impl Into<String>) -> eyre::Result<String> {
+        self.runtime.block_on(self.inner.publish(address))
+    }
+
+    /// Publishes this container as a new image to the specified address.
+    /// Publish returns a fully qualified ref.
+    /// It can also publish platform variants.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Registry's address to publish the image to.
+    ///
+    ///   Formatted as \[host\]/\[user\]/\[repo\]:\[tag\] (e.g. "docker.io/dagger/dagger:main").
+    /// * `opts` - Optional arguments, see [`ContainerPublishOpts`].
+    pub fn publish_opts(
+        &self,
+        address: impl Into<String>,
+        opts: ContainerPublishOpts,
+    ) -> eyre::Result<String> {
+        self.runtime
+            .block_on(self.inner.publish_opts(address, opts))
+    }
+
+    /// Publishes this container as a new image to the specified address.
+    /// Publish returns a fully qualified ref.
+    /// It can also publish platform variants.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Registry's address to publish the image to.
+    ///
+    ///   Formatted as \[host\]/\[user\]/\[repo\]:\[tag\] (e.g. "docker.io/dagger/dagger:main").
+    /// * `opts` - Optional arguments, see [`ContainerPublishOpts`].
+    pub fn publish_with(
+        &self,
+        address: impl Into<String>,
+        opts: impl FnOnce(ContainerPublishOpts) -> ContainerPublishOpts,
+    ) -> eyre::Result<String> {
+        self.publish_opts(address, opts(Default::default()))
+    }
+    /// Retrieves this container's root filesystem. Mounts are not included.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    pub fn rootfs(&self) -> Directory {
+        Directory {
+            inner: self.inner.rootfs(),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// The error stream of the last executed command.
+    /// Errors if no command has been executed.
+    ///
+    /// Executes the query and returns its result.
+    pub fn stderr(&self) -> eyre::Result<String> {
+        self.runtime.block_on(self.inner.stderr())
+    }
+    /// The output stream of the last executed command.
+    /// Errors if no command has been executed.
+    ///
+    /// Executes the query and returns its result.
+    pub fn stdout(&self) -> eyre::Result<String> {
+        self.runtime.block_on(self.inner.stdout())
+    }
+    /// Retrieves the user to be set for all commands.
+    ///
+    /// Executes the query and returns its result.
+    pub fn user(&self) -> eyre::Result<String> {
+        self.runtime.block_on(self.inner.user())
+    }
+    /// Configures default arguments for future commands.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// Optional arguments, see [`ContainerWithDefaultArgsOpts`], are taken by [`Self::with_default_args_opts`] and [`Self::with_default_args_with`].
+    pub fn with_default_args(&self) -> Container {
+        Container {
+            inner: self.inner.with_default_args(),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Configures default arguments for future commands.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerWithDefaultArgsOpts`].
+    pub fn with_default_args_opts<'a>(&self, opts: ContainerWithDefaultArgsOpts<'a>) -> Container {
+        Container {
+            inner: self.inner.with_default_args_opts(opts),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Configures default arguments for future commands.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerWithDefaultArgsOpts`].
+    pub fn with_default_args_with<'a>(
+        &self,
+        opts: impl FnOnce(ContainerWithDefaultArgsOpts<'a>) -> ContainerWithDefaultArgsOpts<'a>,
+    ) -> Container {
+        self.with_default_args_opts(opts(Default::default()))
+    }
+    /// Retrieves this container plus a directory written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// Optional arguments, see [`ContainerWithDirectoryOpts`], are taken by [`Self::with_directory_opts`] and [`Self::with_directory_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written directory (e.g., "/tmp/directory").
+    /// * `directory` - Identifier of the directory to write
+    ///   Identifies a [`Directory`].
+    pub fn with_directory(&self, path: impl Into<String>, directory: DirectoryId) -> Container {
+        Container {
+            inner: self.inner.with_directory(path, directory),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Retrieves this container plus a directory written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written directory (e.g., "/tmp/directory").
+    /// * `directory` - Identifier of the directory to write
+    ///   Identifies a [`Directory`].
+    /// * `opts` - Optional arguments, see [`ContainerWithDirectoryOpts`].
+    pub fn with_directory_opts<'a>(
+        &self,
+        path: impl Into<String>,
+        directory: DirectoryId,
+        opts: ContainerWithDirectoryOpts<'a>,
+    ) -> Container {
+        Container {
+            inner: self.inner.with_directory_opts(path, directory, opts),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Retrieves this container plus a directory written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written directory (e.g., "/tmp/directory").
+    /// * `directory` - Identifier of the directory to write
+    ///   Identifies a [`Directory`].
+    /// * `opts` - Optional arguments, see [`ContainerWithDirectoryOpts`].
+    pub fn with_directory_with<'a>(
+        &self,
+        path: impl Into<String>,
+        directory: DirectoryId,
+        opts: impl FnOnce(ContainerWithDirectoryOpts<'a>) -> ContainerWithDirectoryOpts<'a>,
+    ) -> Container {
+        self.with_directory_opts(path, directory, opts(Default::default()))
+    }
+    /// Retrieves this container but with a different command entrypoint.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Entrypoint to use for future executions (e.g., \["go", "run"\]).
+    pub fn with_entrypoint(&self, args: Vec<impl Into<String>>) -> Container {
+        Container {
+            inner: self.inner.with_entrypoint(args),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Retrieves this container plus the given environment variable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the environment variable (e.g., "HOST").
+    /// * `value` - The value of the environment variable. (e.g., "localhost").
+    pub fn with_env_variable(
+        &self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Container {
+        Container {
+            inner: self.inner.with_env_variable(name, value),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Retrieves this container after executing the specified command inside it.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// Optional arguments, see [`ContainerWithExecOpts`], are taken by [`Self::with_exec_opts`] and [`Self::with_exec_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Command to run instead of the container's default command (e.g., \["run", "main.go"\]).
+    pub fn with_exec(&self, args: Vec<impl Into<String>>) -> Container {
+        Container {
+            inner: self.inner.with_exec(args),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Retrieves this container after executing the specified command inside it.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Command to run instead of the container's default command (e.g., \["run", "main.go"\]).
+    /// * `opts` - Optional arguments, see [`ContainerWithExecOpts`].
+    pub fn with_exec_opts<'a>(
+        &self,
+        args: Vec<impl Into<String>>,
+        opts: ContainerWithExecOpts<'a>,
+    ) -> Container {
+        Container {
+            inner: self.inner.with_exec_opts(args, opts),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Retrieves this container after executing the specified command inside it.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Command to run instead of the container's default command (e.g., \["run", "main.go"\]).
+    /// * `opts` - Optional arguments, see [`ContainerWithExecOpts`].
+    pub fn with_exec_with<'a>(
+        &self,
+        args: Vec<impl Into<String>>,
+        opts: impl FnOnce(ContainerWithExecOpts<'a>) -> ContainerWithExecOpts<'a>,
+    ) -> Container {
+        self.with_exec_opts(args, opts(Default::default()))
+    }
+    /// Expose a network port.
+    /// Exposed ports serve two purposes:
+    /// - For health checks and introspection, when running services
+    /// - For setting the EXPOSE OCI field when publishing the container
+    ///
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// Optional arguments, see [`ContainerWithExposedPortOpts`], are taken by [`Self::with_exposed_port_opts`] and [`Self::with_exposed_port_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Port number to expose
+    pub fn with_exposed_port(&self, port: isize) -> Container {
+        Container {
+            inner: self.inner.with_exposed_port(port),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Expose a network port.
+    /// Exposed ports serve two purposes:
+    /// - For health checks and introspection, when running services
+    /// - For setting the EXPOSE OCI field when publishing the container
+    ///
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Port number to expose
+    /// * `opts` - Optional arguments, see [`ContainerWithExposedPortOpts`].
+    pub fn with_exposed_port_opts<'a>(
+        &self,
+        port: isize,
+        opts: ContainerWithExposedPortOpts<'a>,
+    ) -> Container {
+        Container {
+            inner: self.inner.with_exposed_port_opts(port, opts),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Expose a network port.
+    /// Exposed ports serve two purposes:
+    /// - For health checks and introspection, when running services
+    /// - For setting the EXPOSE OCI field when publishing the container
+    ///
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Port number to expose
+    /// * `opts` - Optional arguments, see [`ContainerWithExposedPortOpts`].
+    pub fn with_exposed_port_with<'a>(
+        &self,
+        port: isize,
+        opts: impl FnOnce(ContainerWithExposedPortOpts<'a>) -> ContainerWithExposedPortOpts<'a>,
+    ) -> Container {
+        self.with_exposed_port_opts(port, opts(Default::default()))
+    }
+    /// Initializes this container from this DirectoryID.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Identifies a [`Directory`].
+    pub fn with_fs(&self, id: DirectoryId) -> Container {
+        Container {
+            inner: self.inner.with_fs(id),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Retrieves this container plus the contents of the given file copied to the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// Optional arguments, see [`ContainerWithFileOpts`], are taken by [`Self::with_file_opts`] and [`Self::with_file_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the copied file (e.g., "/tmp/file.txt").
+    /// * `source` - Identifier of the file to copy.
+    ///   Identifies a [`File`].
+    pub fn with_file(&self, path: impl Into<String>, source: FileId) -> Container {
+        Container {
+            inner: self.inner.with_file(path, source),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Retrieves this container plus the contents of the given file copied to the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the copied file (e.g., "/tmp/file.txt").
+    /// * `source` - Identifier of the file to copy.
+    ///   Identifies a [`File`].
+    /// * `opts` - Optional arguments, see [`ContainerWithFileOpts`].
+    pub fn with_file_opts(
+        &self,
+        path: impl Into<String>,
+        source: FileId,
+        opts: ContainerWithFileOpts,
+    ) -> Container {
+        Container {
+            inner: self.inner.with_file_opts(path, source, opts),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Retrieves this container plus the contents of the given file copied to the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the copied file (e.g., "/tmp/file.txt").
+    /// * `source` - Identifier of the file to copy.
+    ///   Identifies a [`File`].
+    /// * `opts` - Optional arguments, see [`ContainerWithFileOpts`].
+    pub fn with_file_with(
+        &self,
+        path: impl Into<String>,
+        source: FileId,
+        opts: impl FnOnce(ContainerWithFileOpts) -> ContainerWithFileOpts,
+    ) -> Container {
+        self.with_file_opts(path, source, opts(Default::default()))
+    }
+    /// Retrieves this container plus the given label.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the label (e.g., "org.opencontainers.artifact.created").
+    /// * `value` - The value of the label (e.g., "2023-01-01T00:00:00Z").
+    pub fn with_label(&self, name: impl Into<String>, value: impl Into<String>) -> Container {
+        Container {
+            inner: self.inner.with_label(name, value),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Retrieves this container plus a cache volume mounted at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// Optional arguments, see [`ContainerWithMountedCacheOpts`], are taken by [`Self::with_mounted_cache_opts`] and [`Self::with_mounted_cache_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the cache directory (e.g., "/cache/node_modules").
+    /// * `cache` - Identifier of the cache volume to mount.
+    pub fn with_mounted_cache(&self, path: impl Into<String>, cache: CacheId) -> Container {
+        Container {
+            inner: self.inner.with_mounted_cache(path, cache),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Retrieves this container plus a cache volume mounted at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the cache directory (e.g., "/cache/node_modules").
+    /// * `cache` - Identifier of the cache volume to mount.
+    /// * `opts` - Optional arguments, see [`ContainerWithMountedCacheOpts`].
+    pub fn with_mounted_cache_opts(
+        &self,
+        path: impl Into<String>,
+        cache: CacheId,
+        opts: ContainerWithMountedCacheOpts,
+    ) -> Container {
+        Container {
+            inner: self.inner.with_mounted_cache_opts(path, cache, opts),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Retrieves this container plus a cache volume mounted at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the cache directory (e.g., "/cache/node_modules").
+    /// * `cache` - Identifier of the cache volume to mount.
+    /// * `opts` - Optional arguments, see [`ContainerWithMountedCacheOpts`].
+    pub fn with_mounted_cache_with(
+        &self,
+        path: impl Into<String>,
+        cache: CacheId,
+        opts: impl FnOnce(ContainerWithMountedCacheOpts) -> ContainerWithMountedCacheOpts,
+    ) -> Container {
+        self.with_mounted_cache_opts(path, cache, opts(Default::default()))
+    }
+    /// Retrieves this container plus a directory mounted at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the mounted directory (e.g., "/mnt/directory").
+    /// * `source` - Identifier of the mounted directory.
+    ///   Identifies a [`Directory`].
+    pub fn with_mounted_directory(
+        &self,
+        path: impl Into<String>,
+        source: DirectoryId,
+    ) -> Container {
+        Container {
+            inner: self.inner.with_mounted_directory(path, source),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Retrieves this container plus a file mounted at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the mounted file (e.g., "/tmp/file.txt").
+    /// * `source` - Identifier of the mounted file.
+    ///   Identifies a [`File`].
+    pub fn with_mounted_file(&self, path: impl Into<String>, source: FileId) -> Container {
+        Container {
+            inner: self.inner.with_mounted_file(path, source),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Retrieves this container plus a secret mounted into a file at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the secret file (e.g., "/tmp/secret.txt").
+    /// * `source` - Identifier of the secret to mount.
+    ///   Identifies a [`Secret`].
+    pub fn with_mounted_secret(&self, path: impl Into<String>, source: SecretId) -> Container {
+        Container {
+            inner: self.inner.with_mounted_secret(path, source),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Retrieves this container plus a temporary directory mounted at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the temporary directory (e.g., "/tmp/temp_dir").
+    pub fn with_mounted_temp(&self, path: impl Into<String>) -> Container {
+        Container {
+            inner: self.inner.with_mounted_temp(path),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Retrieves this container plus a new file written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// Optional arguments, see [`ContainerWithNewFileOpts`], are taken by [`Self::with_new_file_opts`] and [`Self::with_new_file_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written file (e.g., "/tmp/file.txt").
+    pub fn with_new_file(&self, path: impl Into<String>) -> Container {
+        Container {
+            inner: self.inner.with_new_file(path),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Retrieves this container plus a new file written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written file (e.g., "/tmp/file.txt").
+    /// * `opts` - Optional arguments, see [`ContainerWithNewFileOpts`].
+    pub fn with_new_file_opts<'a>(
+        &self,
+        path: impl Into<String>,
+        opts: ContainerWithNewFileOpts<'a>,
+    ) -> Container {
+        Container {
+            inner: self.inner.with_new_file_opts(path, opts),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Retrieves this container plus a new file written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written file (e.g., "/tmp/file.txt").
+    /// * `opts` - Optional arguments, see [`ContainerWithNewFileOpts`].
+    pub fn with_new_file_with<'a>(
+        &self,
+        path: impl Into<String>,
+        opts: impl FnOnce(ContainerWithNewFileOpts<'a>) -> ContainerWithNewFileOpts<'a>,
+    ) -> Container {
+        self.with_new_file_opts(path, opts(Default::default()))
+    }
+    /// Retrieves this container with a registry authentication for a given address.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Registry's address to bind the authentication to.
+    ///   Formatted as \[host\]/\[user\]/\[repo\]:\[tag\] (e.g. docker.io/dagger/dagger:main).
+    /// * `username` - The username of the registry's account (e.g., "Dagger").
+    /// * `secret` - The API key, password or token to authenticate to this registry.
+    ///   Identifies a [`Secret`].
+    pub fn with_registry_auth(
+        &self,
+        address: impl Into<String>,
+        username: impl Into<String>,
+        secret: SecretId,
+    ) -> Container {
+        Container {
+            inner: self.inner.with_registry_auth(address, username, secret),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Initializes this container from this DirectoryID.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Identifies a [`Directory`].
+    pub fn with_rootfs(&self, id: DirectoryId) -> Container {
+        Container {
+            inner: self.inner.with_rootfs(id),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Retrieves this container plus an env variable containing the given secret.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the secret variable (e.g., "API_SECRET").
+    /// * `secret` - The identifier of the secret value.
+    ///   Identifies a [`Secret`].
+    pub fn with_secret_variable(&self, name: impl Into<String>, secret: SecretId) -> Container {
+        Container {
+            inner: self.inner.with_secret_variable(name, secret),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Establish a runtime dependency on a service. The service will be started automatically when needed and detached when it is no longer needed.
+    /// The service will be reachable from the container via the provided hostname alias.
+    /// The service dependency will also convey to any files or directories produced by the container.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `alias` - A name that can be used to reach the service from the container
+    /// * `service` - Identifier of the service container
+    ///   Identifies a [`Container`].
+    pub fn with_service_binding(
+        &self,
+        alias: impl Into<String>,
+        service: ContainerId,
+    ) -> Container {
+        Container {
+            inner: self.inner.with_service_binding(alias, service),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Retrieves this container plus a socket forwarded to the given Unix socket path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the forwarded Unix socket (e.g., "/tmp/socket").
+    /// * `source` - Identifier of the socket to forward.
+    pub fn with_unix_socket(&self, path: impl Into<String>, source: SocketId) -> Container {
+        Container {
+            inner: self.inner.with_unix_socket(path, source),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Retrieves this container with a different command user.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The user to set (e.g., "root").
+    pub fn with_user(&self, name: impl Into<String>) -> Container {
+        Container {
+            inner: self.inner.with_user(name),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Retrieves this container with a different working directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to set as the working directory (e.g., "/app").
+    pub fn with_workdir(&self, path: impl Into<String>) -> Container {
+        Container {
+            inner: self.inner.with_workdir(path),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Retrieves this container minus the given environment variable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the environment variable (e.g., "HOST").
+    pub fn without_env_variable(&self, name: impl Into<String>) -> Container {
+        Container {
+            inner: self.inner.without_env_variable(name),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Unexpose a previously exposed port.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// Optional arguments, see [`ContainerWithoutExposedPortOpts`], are taken by [`Self::without_exposed_port_opts`] and [`Self::without_exposed_port_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Port number to unexpose
+    pub fn without_exposed_port(&self, port: isize) -> Container {
+        Container {
+            inner: self.inner.without_exposed_port(port),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Unexpose a previously exposed port.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Port number to unexpose
+    /// * `opts` - Optional arguments, see [`ContainerWithoutExposedPortOpts`].
+    pub fn without_exposed_port_opts(
+        &self,
+        port: isize,
+        opts: ContainerWithoutExposedPortOpts,
+    ) -> Container {
+        Container {
+            inner: self.inner.without_exposed_port_opts(port, opts),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Unexpose a previously exposed port.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Port number to unexpose
+    /// * `opts` - Optional arguments, see [`ContainerWithoutExposedPortOpts`].
+    pub fn without_exposed_port_with(
+        &self,
+        port: isize,
+        opts: impl FnOnce(ContainerWithoutExposedPortOpts) -> ContainerWithoutExposedPortOpts,
+    ) -> Container {
+        self.without_exposed_port_opts(port, opts(Default::default()))
+    }
+    /// Retrieves this container minus the given environment label.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the label to remove (e.g., "org.opencontainers.artifact.created").
+    pub fn without_label(&self, name: impl Into<String>) -> Container {
+        Container {
+            inner: self.inner.without_label(name),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Retrieves this container after unmounting everything at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the cache directory (e.g., "/cache/node_modules").
+    pub fn without_mount(&self, path: impl Into<String>) -> Container {
+        Container {
+            inner: self.inner.without_mount(path),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Retrieves this container without the registry authentication of a given address.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Registry's address to remove the authentication from.
+    ///   Formatted as \[host\]/\[user\]/\[repo\]:\[tag\] (e.g. docker.io/dagger/dagger:main).
+    pub fn without_registry_auth(&self, address: impl Into<String>) -> Container {
+        Container {
+            inner: self.inner.without_registry_auth(address),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Retrieves this container with a previously added Unix socket removed.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the socket to remove (e.g., "/tmp/socket").
+    pub fn without_unix_socket(&self, path: impl Into<String>) -> Container {
+        Container {
+            inner: self.inner.without_unix_socket(path),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Retrieves the working directory for all commands.
+    ///
+    /// Executes the query and returns its result.
+    pub fn workdir(&self) -> eyre::Result<String> {
+        self.runtime.block_on(self.inner.workdir())
+    }
+}
+/// Blocking version of [`super::Directory`]
+#[derive(Debug, Clone)]
+pub struct Directory {
+    pub inner: super::Directory,
+    pub runtime: Arc<Runtime>,
+}
+
+impl Directory {
+    /// Gets the difference between this directory and an another directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Identifier of the directory to compare.
+    ///   Identifies a [`Directory`].
+    pub fn diff(&self, other: DirectoryId) -> Directory {
+        Directory {
+            inner: self.inner.diff(other),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Retrieves a directory at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the directory to retrieve (e.g., "/src").
+    pub fn directory(&self, path: impl Into<String>) -> Directory {
+        Directory {
+            inner: self.inner.directory(path),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Builds a new Docker container from this directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// Optional arguments, see [`DirectoryDockerBuildOpts`], are taken by [`Self::docker_build_opts`] and [`Self::docker_build_with`].
+    pub fn docker_build(&self) -> Container {
+        Container {
+            inner: self.inner.docker_build(),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Builds a new Docker container from this directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`DirectoryDockerBuildOpts`].
+    pub fn docker_build_opts<'a>(&self, opts: DirectoryDockerBuildOpts<'a>) -> Container {
+        Container {
+            inner: self.inner.docker_build_opts(opts),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Builds a new Docker container from this directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`DirectoryDockerBuildOpts`].
+    pub fn docker_build_with<'a>(
+        &self,
+        opts: impl FnOnce(DirectoryDockerBuildOpts<'a>) -> DirectoryDockerBuildOpts<'a>,
+    ) -> Container {
+        self.docker_build_opts(opts(Default::default()))
+    }
+    /// Returns a list of files and directories at the given path.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`DirectoryEntriesOpts`], are taken by [`Self::entries_opts`] and [`Self::entries_with`].
+    pub fn entries(&self) -> eyre::Result<Vec<String>> {
+        self.runtime.block_on(self.inner.entries())
+    }
+
+    /// Returns a list of files and directories at the given path.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`DirectoryEntriesOpts`].
+    pub fn entries_opts<'a>(&self, opts: DirectoryEntriesOpts<'a>) -> eyre::Result<Vec<String>> {
+        self.runtime.block_on(self.inner.entries_opts(opts))
+    }
+
+    /// Returns a list of files and directories at the given path.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`DirectoryEntriesOpts`].
+    pub fn entries_with<'a>(
+        &self,
+        opts: impl FnOnce(DirectoryEntriesOpts<'a>) -> DirectoryEntriesOpts<'a>,
+    ) -> eyre::Result<Vec<String>> {
+        self.entries_opts(opts(Default::default()))
+    }
+    /// Writes the contents of the directory to a path on the host.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the copied directory (e.g., "logs/").
+    pub fn export(&self, path: impl Into<String>) -> eyre::Result<bool> {
+        self.runtime.block_on(self.inner.export(path))
+    }
+    /// Retrieves a file at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the file to retrieve (e.g., "README.md").
+    pub fn file(&self, path: impl Into<String>) -> File {
+        File {
+            inner: self.inner.file(path),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// The content-addressed identifier of the directory.
+    ///
+    /// Executes the query and returns its result.
+    pub fn id(&self) -> eyre::Result<DirectoryId> {
+        self.runtime.block_on(self.inner.id())
+    }
+    /// load a project's metadata
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Project`] is called.
+    #[cfg(feature = "project")]
+    pub fn load_project(&self, config_path: impl Into<String>) -> Project {
+        Project {
+            inner: self.inner.load_project(config_path),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Creates a named sub-pipeline
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// Optional arguments, see [`DirectoryPipelineOpts`], are taken by [`Self::pipeline_opts`] and [`Self::pipeline_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    pub fn pipeline(&self, name: impl Into<String>) -> Directory {
+        Directory {
+            inner: self.inner.pipeline(name),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Creates a named sub-pipeline
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    /// * `opts` - Optional arguments, see [`DirectoryPipelineOpts`].
+    pub fn pipeline_opts<'a>(
+        &self,
+        name: impl Into<String>,
+        opts: DirectoryPipelineOpts<'a>,
+    ) -> Directory {
+        Directory {
+            inner: self.inner.pipeline_opts(name, opts),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Creates a named sub-pipeline
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    /// * `opts` - Optional arguments, see [`DirectoryPipelineOpts`].
+    pub fn pipeline_with<'a>(
+        &self,
+        name: impl Into<String>,
+        opts: impl FnOnce(DirectoryPipelineOpts<'a>) -> DirectoryPipelineOpts<'a>,
+    ) -> Directory {
+        self.pipeline_opts(name, opts(Default::default()))
+    }
+    /// Retrieves this directory plus a directory written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// Optional arguments, see [`DirectoryWithDirectoryOpts`], are taken by [`Self::with_directory_opts`] and [`Self::with_directory_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written directory (e.g., "/src/").
+    /// * `directory` - Identifier of the directory to copy.
+    ///   Identifies a [`Directory`].
+    pub fn with_directory(&self, path: impl Into<String>, directory: DirectoryId) -> Directory {
+        Directory {
+            inner: self.inner.with_directory(path, directory),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Retrieves this directory plus a directory written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written directory (e.g., "/src/").
+    /// * `directory` - Identifier of the directory to copy.
+    ///   Identifies a [`Directory`].
+    /// * `opts` - Optional arguments, see [`DirectoryWithDirectoryOpts`].
+    pub fn with_directory_opts<'a>(
+        &self,
+        path: impl Into<String>,
+        directory: DirectoryId,
+        opts: DirectoryWithDirectoryOpts<'a>,
+    ) -> Directory {
+        Directory {
+            inner: self.inner.with_directory_opts(path, directory, opts),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Retrieves this directory plus a directory written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written directory (e.g., "/src/").
+    /// * `directory` - Identifier of the directory to copy.
+    ///   Identifies a [`Directory`].
+    /// * `opts` - Optional arguments, see [`DirectoryWithDirectoryOpts`].
+    pub fn with_directory_with<'a>(
+        &self,
+        path: impl Into<String>,
+        directory: DirectoryId,
+        opts: impl FnOnce(DirectoryWithDirectoryOpts<'a>) -> DirectoryWithDirectoryOpts<'a>,
+    ) -> Directory {
+        self.with_directory_opts(path, directory, opts(Default::default()))
+    }
+    /// Retrieves this directory plus the contents of the given file copied to the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// Optional arguments, see [`DirectoryWithFileOpts`], are taken by [`Self::with_file_opts`] and [`Self::with_file_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the copied file (e.g., "/file.txt").
+    /// * `source` - Identifier of the file to copy.
+    ///   Identifies a [`File`].
+    pub fn with_file(&self, path: impl Into<String>, source: FileId) -> Directory {
+        Directory {
+            inner: self.inner.with_file(path, source),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Retrieves this directory plus the contents of the given file copied to the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the copied file (e.g., "/file.txt").
+    /// * `source` - Identifier of the file to copy.
+    ///   Identifies a [`File`].
+    /// * `opts` - Optional arguments, see [`DirectoryWithFileOpts`].
+    pub fn with_file_opts(
+        &self,
+        path: impl Into<String>,
+        source: FileId,
+        opts: DirectoryWithFileOpts,
+    ) -> Directory {
+        Directory {
+            inner: self.inner.with_file_opts(path, source, opts),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Retrieves this directory plus the contents of the given file copied to the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the copied file (e.g., "/file.txt").
+    /// * `source` - Identifier of the file to copy.
+    ///   Identifies a [`File`].
+    /// * `opts` - Optional arguments, see [`DirectoryWithFileOpts`].
+    pub fn with_file_with(
+        &self,
+        path: impl Into<String>,
+        source: FileId,
+        opts: impl FnOnce(DirectoryWithFileOpts) -> DirectoryWithFileOpts,
+    ) -> Directory {
+        self.with_file_opts(path, source, opts(Default::default()))
+    }
+    /// Retrieves this directory plus a new directory created at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// Optional arguments, see [`DirectoryWithNewDirectoryOpts`], are taken by [`Self::with_new_directory_opts`] and [`Self::with_new_directory_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the directory created (e.g., "/logs").
+    pub fn with_new_directory(&self, path: impl Into<String>) -> Directory {
+        Directory {
+            inner: self.inner.with_new_directory(path),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Retrieves this directory plus a new directory created at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the directory created (e.g., "/logs").
+    /// * `opts` - Optional arguments, see [`DirectoryWithNewDirectoryOpts`].
+    pub fn with_new_directory_opts(
+        &self,
+        path: impl Into<String>,
+        opts: DirectoryWithNewDirectoryOpts,
+    ) -> Directory {
+        Directory {
+            inner: self.inner.with_new_directory_opts(path, opts),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Retrieves this directory plus a new directory created at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the directory created (e.g., "/logs").
+    /// * `opts` - Optional arguments, see [`DirectoryWithNewDirectoryOpts`].
+    pub fn with_new_directory_with(
+        &self,
+        path: impl Into<String>,
+        opts: impl FnOnce(DirectoryWithNewDirectoryOpts) -> DirectoryWithNewDirectoryOpts,
+    ) -> Directory {
+        self.with_new_directory_opts(path, opts(Default::default()))
+    }
+    /// Retrieves this directory plus a new file written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// Optional arguments, see [`DirectoryWithNewFileOpts`], are taken by [`Self::with_new_file_opts`] and [`Self::with_new_file_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written file (e.g., "/file.txt").
+    /// * `contents` - Content of the written file (e.g., "Hello world!").
+    pub fn with_new_file(&self, path: impl Into<String>, contents: impl Into<String>) -> Directory {
+        Directory {
+            inner: self.inner.with_new_file(path, contents),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Retrieves this directory plus a new file written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written file (e.g., "/file.txt").
+    /// * `contents` - Content of the written file (e.g., "Hello world!").
+    /// * `opts` - Optional arguments, see [`DirectoryWithNewFileOpts`].
+    pub fn with_new_file_opts(
+        &self,
+        path: impl Into<String>,
+        contents: impl Into<String>,
+        opts: DirectoryWithNewFileOpts,
+    ) -> Directory {
+        Directory {
+            inner: self.inner.with_new_file_opts(path, contents, opts),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Retrieves this directory plus a new file written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written file (e.g., "/file.txt").
+    /// * `contents` - Content of the written file (e.g., "Hello world!").
+    /// * `opts` - Optional arguments, see [`DirectoryWithNewFileOpts`].
+    pub fn with_new_file_with(
+        &self,
+        path: impl Into<String>,
+        contents: impl Into<String>,
+        opts: impl FnOnce(DirectoryWithNewFileOpts) -> DirectoryWithNewFileOpts,
+    ) -> Directory {
+        self.with_new_file_opts(path, contents, opts(Default::default()))
+    }
+    /// Retrieves this directory with all file/dir timestamps set to the given time.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - Timestamp to set dir/files in.
+    ///
+    ///   Formatted in seconds following Unix epoch (e.g., 1672531199).
+    pub fn with_timestamps(&self, timestamp: isize) -> Directory {
+        Directory {
+            inner: self.inner.with_timestamps(timestamp),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Retrieves this directory with the directory at the given path removed.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the directory to remove (e.g., ".github/").
+    pub fn without_directory(&self, path: impl Into<String>) -> Directory {
+        Directory {
+            inner: self.inner.without_directory(path),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Retrieves this directory with the file at the given path removed.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the file to remove (e.g., "/file.txt").
+    pub fn without_file(&self, path: impl Into<String>) -> Directory {
+        Directory {
+            inner: self.inner.without_file(path),
+            runtime: self.runtime.clone(),
+        }
+    }
+}
+/// Blocking version of [`super::EnvVariable`]
+#[derive(Debug, Clone)]
+pub struct EnvVariable {
+    pub inner: super::EnvVariable,
+    pub runtime: Arc<Runtime>,
+}
+
+impl EnvVariable {
+    /// The environment variable name.
+    ///
+    /// Executes the query and returns its result.
+    pub fn name(&self) -> eyre::Result<String> {
+        self.runtime.block_on(self.inner.name())
+    }
+    /// The environment variable value.
+    ///
+    /// Executes the query and returns its result.
+    pub fn value(&self) -> eyre::Result<String> {
+        self.runtime.block_on(self.inner.value())
+    }
+}
+/// Blocking version of [`super::File`]
+#[derive(Debug, Clone)]
+pub struct File {
+    pub inner: super::File,
+    pub runtime: Arc<Runtime>,
+}
+
+impl File {
+    /// Retrieves the contents of the file.
+    ///
+    /// Executes the query and returns its result.
+    pub fn contents(&self) -> eyre::Result<String> {
+        self.runtime.block_on(self.inner.contents())
+    }
+    /// Writes the file to a file path on the host.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written directory (e.g., "output.txt").
+    pub fn export(&self, path: impl Into<String>) -> eyre::Result<bool> {
+        self.runtime.block_on(self.inner.export(path))
+    }
+    /// Retrieves the content-addressed identifier of the file.
+    ///
+    /// Executes the query and returns its result.
+    pub fn id(&self) -> eyre::Result<FileId> {
+        self.runtime.block_on(self.inner.id())
+    }
+    /// Retrieves a secret referencing the contents of this file.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Secret`] is called.
+    pub fn secret(&self) -> Secret {
+        Secret {
+            inner: self.inner.secret(),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Gets the size of the file, in bytes.
+    ///
+    /// Executes the query and returns its result.
+    pub fn size(&self) -> eyre::Result<isize> {
+        self.runtime.block_on(self.inner.size())
+    }
+    /// Retrieves this file with its created/modified timestamps set to the given time.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - Timestamp to set dir/files in.
+    ///
+    ///   Formatted in seconds following Unix epoch (e.g., 1672531199).
+    pub fn with_timestamps(&self, timestamp: isize) -> File {
+        File {
+            inner: self.inner.with_timestamps(timestamp),
+            runtime: self.runtime.clone(),
+        }
+    }
+}
+/// Blocking version of [`super::GitRef`]
+#[cfg(feature = "git")]
+#[derive(Debug, Clone)]
+pub struct GitRef {
+    pub inner: super::GitRef,
+    pub runtime: Arc<Runtime>,
+}
+
+#[cfg(feature = "git")]
+impl GitRef {
+    /// The digest of the current value of this ref.
+    ///
+    /// Executes the query and returns its result.
+    pub fn digest(&self) -> eyre::Result<String> {
+        self.runtime.block_on(self.inner.digest())
+    }
+    /// The filesystem tree at this ref.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// Optional arguments, see [`GitRefTreeOpts`], are taken by [`Self::tree_opts`] and [`Self::tree_with`].
+    pub fn tree(&self) -> Directory {
+        Directory {
+            inner: self.inner.tree(),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// The filesystem tree at this ref.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`GitRefTreeOpts`].
+    pub fn tree_opts<'a>(&self, opts: GitRefTreeOpts<'a>) -> Directory {
+        Directory {
+            inner: self.inner.tree_opts(opts),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// The filesystem tree at this ref.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`GitRefTreeOpts`].
+    pub fn tree_with<'a>(
+        &self,
+        opts: impl FnOnce(GitRefTreeOpts<'a>) -> GitRefTreeOpts<'a>,
+    ) -> Directory {
+        self.tree_opts(opts(Default::default()))
+    }
+}
+/// Blocking version of [`super::GitRepository`]
+#[cfg(feature = "git")]
+#[derive(Debug, Clone)]
+pub struct GitRepository {
+    pub inner: super::GitRepository,
+    pub runtime: Arc<Runtime>,
+}
+
+#[cfg(feature = "git")]
+impl GitRepository {
+    /// Returns details on one branch.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`GitRef`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Branch's name (e.g., "main").
+    pub fn branch(&self, name: impl Into<String>) -> GitRef {
+        GitRef {
+            inner: self.inner.branch(name),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Lists of branches on the repository.
+    ///
+    /// Executes the query and returns its result.
+    pub fn branches(&self) -> eyre::Result<Vec<String>> {
+        self.runtime.block_on(self.inner.branches())
+    }
+    /// Returns details on one commit.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`GitRef`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Identifier of the commit (e.g., "b6315d8f2810962c601af73f86831f6866ea798b").
+    pub fn commit(&self, id: impl Into<String>) -> GitRef {
+        GitRef {
+            inner: self.inner.commit(id),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Returns details on one tag.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`GitRef`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Tag's name (e.g., "v0.3.9").
+    pub fn tag(&self, name: impl Into<String>) -> GitRef {
+        GitRef {
+            inner: self.inner.tag(name),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Lists of tags on the repository.
+    ///
+    /// Executes the query and returns its result.
+    pub fn tags(&self) -> eyre::Result<Vec<String>> {
+        self.runtime.block_on(self.inner.tags())
+    }
+}
+/// Blocking version of [`super::Host`]
+#[derive(Debug, Clone)]
+pub struct Host {
+    pub inner: super::Host,
+    pub runtime: Arc<Runtime>,
+}
+
+impl Host {
+    /// Accesses a directory on the host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// Optional arguments, see [`HostDirectoryOpts`], are taken by [`Self::directory_opts`] and [`Self::directory_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the directory to access (e.g., ".").
+    pub fn directory(&self, path: impl Into<String>) -> Directory {
+        Directory {
+            inner: self.inner.directory(path),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Accesses a directory on the host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the directory to access (e.g., ".").
+    /// * `opts` - Optional arguments, see [`HostDirectoryOpts`].
+    pub fn directory_opts<'a>(
+        &self,
+        path: impl Into<String>,
+        opts: HostDirectoryOpts<'a>,
+    ) -> Directory {
+        Directory {
+            inner: self.inner.directory_opts(path, opts),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Accesses a directory on the host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the directory to access (e.g., ".").
+    /// * `opts` - Optional arguments, see [`HostDirectoryOpts`].
+    pub fn directory_with<'a>(
+        &self,
+        path: impl Into<String>,
+        opts: impl FnOnce(HostDirectoryOpts<'a>) -> HostDirectoryOpts<'a>,
+    ) -> Directory {
+        self.directory_opts(path, opts(Default::default()))
+    }
+    /// Accesses an environment variable on the host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`HostVariable`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the environment variable (e.g., "PATH").
+    pub fn env_variable(&self, name: impl Into<String>) -> HostVariable {
+        HostVariable {
+            inner: self.inner.env_variable(name),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Accesses a Unix socket on the host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Socket`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the Unix socket (e.g., "/var/run/docker.sock").
+    #[cfg(feature = "socket")]
+    pub fn unix_socket(&self, path: impl Into<String>) -> Socket {
+        Socket {
+            inner: self.inner.unix_socket(path),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Retrieves the current working directory on the host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// Optional arguments, see [`HostWorkdirOpts`], are taken by [`Self::workdir_opts`] and [`Self::workdir_with`].
+    pub fn workdir(&self) -> Directory {
+        Directory {
+            inner: self.inner.workdir(),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Retrieves the current working directory on the host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`HostWorkdirOpts`].
+    pub fn workdir_opts<'a>(&self, opts: HostWorkdirOpts<'a>) -> Directory {
+        Directory {
+            inner: self.inner.workdir_opts(opts),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Retrieves the current working directory on the host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`HostWorkdirOpts`].
+    pub fn workdir_with<'a>(
+        &self,
+        opts: impl FnOnce(HostWorkdirOpts<'a>) -> HostWorkdirOpts<'a>,
+    ) -> Directory {
+        self.workdir_opts(opts(Default::default()))
+    }
+}
+/// Blocking version of [`super::HostVariable`]
+#[derive(Debug, Clone)]
+pub struct HostVariable {
+    pub inner: super::HostVariable,
+    pub runtime: Arc<Runtime>,
+}
+
+impl HostVariable {
+    /// A secret referencing the value of this variable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Secret`] is called.
+    pub fn secret(&self) -> Secret {
+        Secret {
+            inner: self.inner.secret(),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// The value of this variable.
+    ///
+    /// Executes the query and returns its result.
+    pub fn value(&self) -> eyre::Result<String> {
+        self.runtime.block_on(self.inner.value())
+    }
+}
+/// Blocking version of [`super::Label`]
+#[derive(Debug, Clone)]
+pub struct Label {
+    pub inner: super::Label,
+    pub runtime: Arc<Runtime>,
+}
+
+impl Label {
+    /// The label name.
+    ///
+    /// Executes the query and returns its result.
+    pub fn name(&self) -> eyre::Result<String> {
+        self.runtime.block_on(self.inner.name())
+    }
+    /// The label value.
+    ///
+    /// Executes the query and returns its result.
+    pub fn value(&self) -> eyre::Result<String> {
+        self.runtime.block_on(self.inner.value())
+    }
+}
+/// Blocking version of [`super::Port`]
+#[derive(Debug, Clone)]
+pub struct Port {
+    pub inner: super::Port,
+    pub runtime: Arc<Runtime>,
+}
+
+impl Port {
+    /// The port description.
+    ///
+    /// Executes the query and returns its result.
+    pub fn description(&self) -> eyre::Result<String> {
+        self.runtime.block_on(self.inner.description())
+    }
+    /// The port number.
+    ///
+    /// Executes the query and returns its result.
+    pub fn port(&self) -> eyre::Result<isize> {
+        self.runtime.block_on(self.inner.port())
+    }
+    /// The transport layer network protocol.
+    ///
+    /// Executes the query and returns its result.
+    pub fn protocol(&self) -> eyre::Result<NetworkProtocol> {
+        self.runtime.block_on(self.inner.protocol())
+    }
+}
+/// Blocking version of [`super::Project`]
+#[cfg(feature = "project")]
+#[derive(Debug, Clone)]
+pub struct Project {
+    pub inner: super::Project,
+    pub runtime: Arc<Runtime>,
+}
+
+#[cfg(feature = "project")]
+impl Project {
+    /// extensions in this project
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Project`] is called.
+    pub fn extensions(&self) -> Vec<Project> {
+        self.inner
+            .extensions()
+            .into_iter()
+            .map(|inner| Project {
+                inner,
+                runtime: self.runtime.clone(),
+            })
+            .collect()
+    }
+    /// Code files generated by the SDKs in the project
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    pub fn generated_code(&self) -> Directory {
+        Directory {
+            inner: self.inner.generated_code(),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// install the project's schema
+    ///
+    /// Executes the query and returns its result.
+    pub fn install(&self) -> eyre::Result<bool> {
+        self.runtime.block_on(self.inner.install())
+    }
+    /// name of the project
+    ///
+    /// Executes the query and returns its result.
+    pub fn name(&self) -> eyre::Result<String> {
+        self.runtime.block_on(self.inner.name())
+    }
+    /// schema provided by the project
+    ///
+    /// Executes the query and returns its result.
+    pub fn schema(&self) -> eyre::Result<String> {
+        self.runtime.block_on(self.inner.schema())
+    }
+    /// sdk used to generate code for and/or execute this project
+    ///
+    /// Executes the query and returns its result.
+    pub fn sdk(&self) -> eyre::Result<String> {
+        self.runtime.block_on(self.inner.sdk())
+    }
+}
+/// Blocking version of [`super::Query`]
+#[derive(Debug, Clone)]
+pub struct Query {
+    pub inner: super::Query,
+    pub runtime: Arc<Runtime>,
+}
+
+impl Query {
+    /// Constructs a cache volume for a given cache key.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`CacheVolume`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A string identifier to target this cache volume (e.g., "modules-cache").
+    pub fn cache_volume(&self, key: impl Into<String>) -> CacheVolume {
+        CacheVolume {
+            inner: self.inner.cache_volume(key),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Loads a container from ID.
+    /// Null ID returns an empty container (scratch).
+    /// Optional platform argument initializes new containers to execute and publish as that platform.
+    /// Platform defaults to that of the builder's host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// Optional arguments, see [`QueryContainerOpts`], are taken by [`Self::container_opts`] and [`Self::container_with`].
+    pub fn container(&self) -> Container {
+        Container {
+            inner: self.inner.container(),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Loads a container from ID.
+    /// Null ID returns an empty container (scratch).
+    /// Optional platform argument initializes new containers to execute and publish as that platform.
+    /// Platform defaults to that of the builder's host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`QueryContainerOpts`].
+    pub fn container_opts(&self, opts: QueryContainerOpts) -> Container {
+        Container {
+            inner: self.inner.container_opts(opts),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Loads a container from ID.
+    /// Null ID returns an empty container (scratch).
+    /// Optional platform argument initializes new containers to execute and publish as that platform.
+    /// Platform defaults to that of the builder's host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`QueryContainerOpts`].
+    pub fn container_with(
+        &self,
+        opts: impl FnOnce(QueryContainerOpts) -> QueryContainerOpts,
+    ) -> Container {
+        self.container_opts(opts(Default::default()))
+    }
+    /// The default platform of the builder.
+    ///
+    /// Executes the query and returns its result.
+    pub fn default_platform(&self) -> eyre::Result<Platform> {
+        self.runtime.block_on(self.inner.default_platform())
+    }
+    /// Load a directory by ID. No argument produces an empty directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// Optional arguments, see [`QueryDirectoryOpts`], are taken by [`Self::directory_opts`] and [`Self::directory_with`].
+    pub fn directory(&self) -> Directory {
+        Directory {
+            inner: self.inner.directory(),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Load a directory by ID. No argument produces an empty directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`QueryDirectoryOpts`].
+    pub fn directory_opts(&self, opts: QueryDirectoryOpts) -> Directory {
+        Directory {
+            inner: self.inner.directory_opts(opts),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Load a directory by ID. No argument produces an empty directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`QueryDirectoryOpts`].
+    pub fn directory_with(
+        &self,
+        opts: impl FnOnce(QueryDirectoryOpts) -> QueryDirectoryOpts,
+    ) -> Directory {
+        self.directory_opts(opts(Default::default()))
+    }
+    /// Loads a file by ID.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Identifies a [`File`].
+    pub fn file(&self, id: FileId) -> File {
+        File {
+            inner: self.inner.file(id),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Queries a git repository.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`GitRepository`] is called.
+    ///
+    /// Optional arguments, see [`QueryGitOpts`], are taken by [`Self::git_opts`] and [`Self::git_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Url of the git repository.
+    ///   Can be formatted as <https://{host}/{owner}/{repo}>, git@{host}/{owner}/{repo}
+    ///   Suffix ".git" is optional.
+    #[cfg(feature = "git")]
+    pub fn git(&self, url: impl Into<String>) -> GitRepository {
+        GitRepository {
+            inner: self.inner.git(url),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Queries a git repository.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`GitRepository`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Url of the git repository.
+    ///   Can be formatted as <https://{host}/{owner}/{repo}>, git@{host}/{owner}/{repo}
+    ///   Suffix ".git" is optional.
+    /// * `opts` - Optional arguments, see [`QueryGitOpts`].
+    #[cfg(feature = "git")]
+    pub fn git_opts(&self, url: impl Into<String>, opts: QueryGitOpts) -> GitRepository {
+        GitRepository {
+            inner: self.inner.git_opts(url, opts),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Queries a git repository.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`GitRepository`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Url of the git repository.
+    ///   Can be formatted as <https://{host}/{owner}/{repo}>, git@{host}/{owner}/{repo}
+    ///   Suffix ".git" is optional.
+    /// * `opts` - Optional arguments, see [`QueryGitOpts`].
+    #[cfg(feature = "git")]
+    pub fn git_with(
+        &self,
+        url: impl Into<String>,
+        opts: impl FnOnce(QueryGitOpts) -> QueryGitOpts,
+    ) -> GitRepository {
+        self.git_opts(url, opts(Default::default()))
+    }
+    /// Queries the host environment.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Host`] is called.
+    pub fn host(&self) -> Host {
+        Host {
+            inner: self.inner.host(),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Returns a file containing an http remote url content.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is called.
+    ///
+    /// Optional arguments, see [`QueryHttpOpts`], are taken by [`Self::http_opts`] and [`Self::http_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - HTTP url to get the content from (e.g., "<https://docs.dagger.io>").
+    pub fn http(&self, url: impl Into<String>) -> File {
+        File {
+            inner: self.inner.http(url),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Returns a file containing an http remote url content.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - HTTP url to get the content from (e.g., "<https://docs.dagger.io>").
+    /// * `opts` - Optional arguments, see [`QueryHttpOpts`].
+    pub fn http_opts(&self, url: impl Into<String>, opts: QueryHttpOpts) -> File {
+        File {
+            inner: self.inner.http_opts(url, opts),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Returns a file containing an http remote url content.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - HTTP url to get the content from (e.g., "<https://docs.dagger.io>").
+    /// * `opts` - Optional arguments, see [`QueryHttpOpts`].
+    pub fn http_with(
+        &self,
+        url: impl Into<String>,
+        opts: impl FnOnce(QueryHttpOpts) -> QueryHttpOpts,
+    ) -> File {
+        self.http_opts(url, opts(Default::default()))
+    }
+    /// Creates a named sub-pipeline.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Query`] is called.
+    ///
+    /// Optional arguments, see [`QueryPipelineOpts`], are taken by [`Self::pipeline_opts`] and [`Self::pipeline_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    pub fn pipeline(&self, name: impl Into<String>) -> Query {
+        Query {
+            inner: self.inner.pipeline(name),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Creates a named sub-pipeline.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Query`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    /// * `opts` - Optional arguments, see [`QueryPipelineOpts`].
+    pub fn pipeline_opts<'a>(&self, name: impl Into<String>, opts: QueryPipelineOpts<'a>) -> Query {
+        Query {
+            inner: self.inner.pipeline_opts(name, opts),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Creates a named sub-pipeline.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Query`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    /// * `opts` - Optional arguments, see [`QueryPipelineOpts`].
+    pub fn pipeline_with<'a>(
+        &self,
+        name: impl Into<String>,
+        opts: impl FnOnce(QueryPipelineOpts<'a>) -> QueryPipelineOpts<'a>,
+    ) -> Query {
+        self.pipeline_opts(name, opts(Default::default()))
+    }
+    /// Look up a project by name
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Project`] is called.
+    #[cfg(feature = "project")]
+    pub fn project(&self, name: impl Into<String>) -> Project {
+        Project {
+            inner: self.inner.project(name),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Loads a secret from its ID.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Secret`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Identifies a [`Secret`].
+    pub fn secret(&self, id: SecretId) -> Secret {
+        Secret {
+            inner: self.inner.secret(id),
+            runtime: self.runtime.clone(),
+        }
+    }
+    /// Loads a socket by its ID.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Socket`] is called.
+    ///
+    /// Optional arguments, see [`QuerySocketOpts`], are taken by [`Self::socket_opts`] and [`Self::socket_with`].
+    #[cfg(feature = "socket")]
+    pub fn socket(&self) -> Socket {
+        Socket {
+            inner: self.inner.socket(),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Loads a socket by its ID.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Socket`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`QuerySocketOpts`].
+    #[cfg(feature = "socket")]
+    pub fn socket_opts(&self, opts: QuerySocketOpts) -> Socket {
+        Socket {
+            inner: self.inner.socket_opts(opts),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Loads a socket by its ID.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Socket`] is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`QuerySocketOpts`].
+    #[cfg(feature = "socket")]
+    pub fn socket_with(&self, opts: impl FnOnce(QuerySocketOpts) -> QuerySocketOpts) -> Socket {
+        self.socket_opts(opts(Default::default()))
+    }
+}
+/// Blocking version of [`super::Secret`]
+#[derive(Debug, Clone)]
+pub struct Secret {
+    pub inner: super::Secret,
+    pub runtime: Arc<Runtime>,
+}
+
+impl Secret {
+    /// The identifier for this secret.
+    ///
+    /// Executes the query and returns its result.
+    pub fn id(&self) -> eyre::Result<SecretId> {
+        self.runtime.block_on(self.inner.id())
+    }
+    /// The value of this secret.
+    ///
+    /// Executes the query and returns its result.
+    pub fn plaintext(&self) -> eyre::Result<String> {
+        self.runtime.block_on(self.inner.plaintext())
+    }
+}
+/// Blocking version of [`super::Socket`]
+#[cfg(feature = "socket")]
+#[derive(Debug, Clone)]
+pub struct Socket {
+    pub inner: super::Socket,
+    pub runtime: Arc<Runtime>,
+}
+
+#[cfg(feature = "socket")]
+impl Socket {
+    /// The content-addressed identifier of the socket.
+    ///
+    /// Executes the query and returns its result.
+    pub fn id(&self) -> eyre::Result<SocketId> {
+        self.runtime.block_on(self.inner.id())
+    }
+}
@@ -0,0 +1,23 @@
+use crate::client::graphql_client;
+use crate::querybuilder::Selection;
+use dagger_core::connect_params::ConnectParams;
+use std::sync::Arc;
+use tokio::process::Child;
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct CacheVolume {
+    pub proc: Arc<Child>,
+    pub selection: Selection,
+    pub conn: ConnectParams,
+}
+
+impl CacheVolume {
+    /// Executes the query and returns its result.
+    pub async fn id(&self) -> eyre::Result<CacheId> {
+        let query = self.selection.select("id");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+}
@@ -0,0 +1,1997 @@
+use crate::client::graphql_client;
+use crate::querybuilder::Selection;
+use dagger_core::connect_params::ConnectParams;
+use derive_builder::Builder;
+use std::sync::Arc;
+use tokio::process::Child;
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct Container {
+    pub proc: Arc<Child>,
+    pub selection: Selection,
+    pub conn: ConnectParams,
+}
+
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ContainerBuildOpts<'a> {
+    /// Path to the Dockerfile to use.
+    /// Default: './Dockerfile'.
+    #[builder(setter(into, strip_option), default)]
+    pub dockerfile: Option<&'a str>,
+    /// Additional build arguments.
+    #[builder(setter(into, strip_option), default)]
+    pub build_args: Option<Vec<BuildArg>>,
+    /// Target build stage to build.
+    #[builder(setter(into, strip_option), default)]
+    pub target: Option<&'a str>,
+}
+
+impl<'a> ContainerBuildOpts<'a> {
+    /// Path to the Dockerfile to use.
+    /// Default: './Dockerfile'.
+    pub fn dockerfile(mut self, dockerfile: &'a str) -> Self {
+        self.dockerfile = Some(dockerfile);
+        self
+    }
+    /// Additional build arguments.
+    pub fn build_args(mut self, build_args: Vec<BuildArg>) -> Self {
+        self.build_args = Some(build_args);
+        self
+    }
+    /// Target build stage to build.
+    pub fn target(mut self, target: &'a str) -> Self {
+        self.target = Some(target);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ContainerEndpointOpts<'a> {
+    /// The exposed port number for the endpoint
+    #[builder(setter(into, strip_option), default)]
+    pub port: Option<isize>,
+    /// Return a URL with the given scheme, eg. http for <http://>
+    #[builder(setter(into, strip_option), default)]
+    pub scheme: Option<&'a str>,
+}
+
+impl<'a> ContainerEndpointOpts<'a> {
+    /// The exposed port number for the endpoint
+    pub fn port(mut self, port: isize) -> Self {
+        self.port = Some(port);
+        self
+    }
+    /// Return a URL with the given scheme, eg. http for <http://>
+    pub fn scheme(mut self, scheme: &'a str) -> Self {
+        self.scheme = Some(scheme);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ContainerExecOpts<'a> {
+    /// Command to run instead of the container's default command (e.g., \["run", "main.go"\]).
+    #[builder(setter(into, strip_option), default)]
+    pub args: Option<Vec<&'a str>>,
+    /// Content to write to the command's standard input before closing (e.g., "Hello world").
+    #[builder(setter(into, strip_option), default)]
+    pub stdin: Option<&'a str>,
+    /// Redirect the command's standard output to a file in the container (e.g., "/tmp/stdout").
+    #[builder(setter(into, strip_option), default)]
+    pub redirect_stdout: Option<&'a str>,
+    /// Redirect the command's standard error to a file in the container (e.g., "/tmp/stderr").
+    #[builder(setter(into, strip_option), default)]
+    pub redirect_stderr: Option<&'a str>,
+    /// Provide dagger access to the executed command.
+    /// Do not use this option unless you trust the command being executed.
+    /// The command being executed WILL BE GRANTED FULL ACCESS TO YOUR HOST FILESYSTEM.
+    #[builder(setter(into, strip_option), default)]
+    pub experimental_privileged_nesting: Option<bool>,
+}
+
+impl<'a> ContainerExecOpts<'a> {
+    /// Command to run instead of the container's default command (e.g., \["run", "main.go"\]).
+    pub fn args(mut self, args: Vec<&'a str>) -> Self {
+        self.args = Some(args);
+        self
+    }
+    /// Content to write to the command's standard input before closing (e.g., "Hello world").
+    pub fn stdin(mut self, stdin: &'a str) -> Self {
+        self.stdin = Some(stdin);
+        self
+    }
+    /// Redirect the command's standard output to a file in the container (e.g., "/tmp/stdout").
+    pub fn redirect_stdout(mut self, redirect_stdout: &'a str) -> Self {
+        self.redirect_stdout = Some(redirect_stdout);
+        self
+    }
+    /// Redirect the command's standard error to a file in the container (e.g., "/tmp/stderr").
+    pub fn redirect_stderr(mut self, redirect_stderr: &'a str) -> Self {
+        self.redirect_stderr = Some(redirect_stderr);
+        self
+    }
+    /// Provide dagger access to the executed command.
+    /// Do not use this option unless you trust the command being executed.
+    /// The command being executed WILL BE GRANTED FULL ACCESS TO YOUR HOST FILESYSTEM.
+    pub fn experimental_privileged_nesting(
+        mut self,
+        experimental_privileged_nesting: bool,
+    ) -> Self {
+        self.experimental_privileged_nesting = Some(experimental_privileged_nesting);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ContainerExportOpts {
+    /// Identifiers for other platform specific containers.
+    /// Used for multi-platform image.
+    /// Identifies a [`Container`].
+    #[builder(setter(into, strip_option), default)]
+    pub platform_variants: Option<Vec<ContainerId>>,
+}
+
+impl ContainerExportOpts {
+    /// Identifiers for other platform specific containers.
+    /// Used for multi-platform image.
+    /// Identifies a [`Container`].
+    pub fn platform_variants(mut self, platform_variants: Vec<ContainerId>) -> Self {
+        self.platform_variants = Some(platform_variants);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ContainerPipelineOpts<'a> {
+    /// Pipeline description.
+    #[builder(setter(into, strip_option), default)]
+    pub description: Option<&'a str>,
+    /// Pipeline labels.
+    #[builder(setter(into, strip_option), default)]
+    pub labels: Option<Vec<PipelineLabel>>,
+}
+
+impl<'a> ContainerPipelineOpts<'a> {
+    /// Pipeline description.
+    pub fn description(mut self, description: &'a str) -> Self {
+        self.description = Some(description);
+        self
+    }
+    /// Pipeline labels.
+    pub fn labels(mut self, labels: Vec<PipelineLabel>) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ContainerPublishOpts {
+    /// Identifiers for other platform specific containers.
+    /// Used for multi-platform image.
+    /// Identifies a [`Container`].
+    #[builder(setter(into, strip_option), default)]
+    pub platform_variants: Option<Vec<ContainerId>>,
+}
+
+impl ContainerPublishOpts {
+    /// Identifiers for other platform specific containers.
+    /// Used for multi-platform image.
+    /// Identifies a [`Container`].
+    pub fn platform_variants(mut self, platform_variants: Vec<ContainerId>) -> Self {
+        self.platform_variants = Some(platform_variants);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ContainerWithDefaultArgsOpts<'a> {
+    /// Arguments to prepend to future executions (e.g., \["-v", "--no-cache"\]).
+    #[builder(setter(into, strip_option), default)]
+    pub args: Option<Vec<&'a str>>,
+}
+
+impl<'a> ContainerWithDefaultArgsOpts<'a> {
+    /// Arguments to prepend to future executions (e.g., \["-v", "--no-cache"\]).
+    pub fn args(mut self, args: Vec<&'a str>) -> Self {
+        self.args = Some(args);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ContainerWithDirectoryOpts<'a> {
+    /// Patterns to exclude in the written directory (e.g., \["node_modules/**", ".gitignore", ".git/"\]).
+    #[builder(setter(into, strip_option), default)]
+    pub exclude: Option<Vec<&'a str>>,
+    /// Patterns to include in the written directory (e.g., \["*.go", "go.mod", "go.sum"\]).
+    #[builder(setter(into, strip_option), default)]
+    pub include: Option<Vec<&'a str>>,
+}
+
+impl<'a> ContainerWithDirectoryOpts<'a> {
+    /// Patterns to exclude in the written directory (e.g., \["node_modules/**", ".gitignore", ".git/"\]).
+    pub fn exclude(mut self, exclude: Vec<&'a str>) -> Self {
+        self.exclude = Some(exclude);
+        self
+    }
+    /// Patterns to include in the written directory (e.g., \["*.go", "go.mod", "go.sum"\]).
+    pub fn include(mut self, include: Vec<&'a str>) -> Self {
+        self.include = Some(include);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ContainerWithExecOpts<'a> {
+    /// Content to write to the command's standard input before closing (e.g., "Hello world").
+    #[builder(setter(into, strip_option), default)]
+    pub stdin: Option<&'a str>,
+    /// Redirect the command's standard output to a file in the container (e.g., "/tmp/stdout").
+    #[builder(setter(into, strip_option), default)]
+    pub redirect_stdout: Option<&'a str>,
+    /// Redirect the command's standard error to a file in the container (e.g., "/tmp/stderr").
+    #[builder(setter(into, strip_option), default)]
+    pub redirect_stderr: Option<&'a str>,
+    /// Provides dagger access to the executed command.
+    /// Do not use this option unless you trust the command being executed.
+    /// The command being executed WILL BE GRANTED FULL ACCESS TO YOUR HOST FILESYSTEM.
+    #[builder(setter(into, strip_option), default)]
+    pub experimental_privileged_nesting: Option<bool>,
+    /// Execute the command with all root capabilities. This is similar to running a command
+    /// with "sudo" or executing `docker run` with the `--privileged` flag. Containerization
+    /// does not provide any security guarantees when using this option. It should only be used
+    /// when absolutely necessary and only with trusted commands.
+    #[builder(setter(into, strip_option), default)]
+    pub insecure_root_capabilities: Option<bool>,
+}
+
+impl<'a> ContainerWithExecOpts<'a> {
+    /// Content to write to the command's standard input before closing (e.g., "Hello world").
+    pub fn stdin(mut self, stdin: &'a str) -> Self {
+        self.stdin = Some(stdin);
+        self
+    }
+    /// Redirect the command's standard output to a file in the container (e.g., "/tmp/stdout").
+    pub fn redirect_stdout(mut self, redirect_stdout: &'a str) -> Self {
+        self.redirect_stdout = Some(redirect_stdout);
+        self
+    }
+    /// Redirect the command's standard error to a file in the container (e.g., "/tmp/stderr").
+    pub fn redirect_stderr(mut self, redirect_stderr: &'a str) -> Self {
+        self.redirect_stderr = Some(redirect_stderr);
+        self
+    }
+    /// Provides dagger access to the executed command.
+    /// Do not use this option unless you trust the command being executed.
+    /// The command being executed WILL BE GRANTED FULL ACCESS TO YOUR HOST FILESYSTEM.
+    pub fn experimental_privileged_nesting(
+        mut self,
+        experimental_privileged_nesting: bool,
+    ) -> Self {
+        self.experimental_privileged_nesting = Some(experimental_privileged_nesting);
+        self
+    }
+    /// Execute the command with all root capabilities. This is similar to running a command
+    /// with "sudo" or executing `docker run` with the `--privileged` flag. Containerization
+    /// does not provide any security guarantees when using this option. It should only be used
+    /// when absolutely necessary and only with trusted commands.
+    pub fn insecure_root_capabilities(mut self, insecure_root_capabilities: bool) -> Self {
+        self.insecure_root_capabilities = Some(insecure_root_capabilities);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ContainerWithExposedPortOpts<'a> {
+    /// Transport layer network protocol
+    #[builder(setter(into, strip_option), default)]
+    pub protocol: Option<NetworkProtocol>,
+    /// Optional port description
+    #[builder(setter(into, strip_option), default)]
+    pub description: Option<&'a str>,
+}
+
+impl<'a> ContainerWithExposedPortOpts<'a> {
+    /// Transport layer network protocol
+    pub fn protocol(mut self, protocol: NetworkProtocol) -> Self {
+        self.protocol = Some(protocol);
+        self
+    }
+    /// Optional port description
+    pub fn description(mut self, description: &'a str) -> Self {
+        self.description = Some(description);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ContainerWithFileOpts {
+    /// Permission given to the copied file (e.g., 0600).
+    /// Default: 0644.
+    #[builder(setter(into, strip_option), default)]
+    pub permissions: Option<isize>,
+}
+
+impl ContainerWithFileOpts {
+    /// Permission given to the copied file (e.g., 0600).
+    /// Default: 0644.
+    pub fn permissions(mut self, permissions: isize) -> Self {
+        self.permissions = Some(permissions);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ContainerWithMountedCacheOpts {
+    /// Identifier of the directory to use as the cache volume's root.
+    /// Identifies a [`Directory`].
+    #[builder(setter(into, strip_option), default)]
+    pub source: Option<DirectoryId>,
+    /// Sharing mode of the cache volume.
+    #[builder(setter(into, strip_option), default)]
+    pub sharing: Option<CacheSharingMode>,
+}
+
+impl ContainerWithMountedCacheOpts {
+    /// Identifier of the directory to use as the cache volume's root.
+    /// Identifies a [`Directory`].
+    pub fn source(mut self, source: impl Into<DirectoryId>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+    /// Sharing mode of the cache volume.
+    pub fn sharing(mut self, sharing: CacheSharingMode) -> Self {
+        self.sharing = Some(sharing);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ContainerWithNewFileOpts<'a> {
+    /// Content of the file to write (e.g., "Hello world!").
+    #[builder(setter(into, strip_option), default)]
+    pub contents: Option<&'a str>,
+    /// Permission given to the written file (e.g., 0600).
+    /// Default: 0644.
+    #[builder(setter(into, strip_option), default)]
+    pub permissions: Option<isize>,
+}
+
+impl<'a> ContainerWithNewFileOpts<'a> {
+    /// Content of the file to write (e.g., "Hello world!").
+    pub fn contents(mut self, contents: &'a str) -> Self {
+        self.contents = Some(contents);
+        self
+    }
+    /// Permission given to the written file (e.g., 0600).
+    /// Default: 0644.
+    pub fn permissions(mut self, permissions: isize) -> Self {
+        self.permissions = Some(permissions);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ContainerWithoutExposedPortOpts {
+    /// Port protocol to unexpose
+    #[builder(setter(into, strip_option), default)]
+    pub protocol: Option<NetworkProtocol>,
+}
+
+impl ContainerWithoutExposedPortOpts {
+    /// Port protocol to unexpose
+    pub fn protocol(mut self, protocol: NetworkProtocol) -> Self {
+        self.protocol = Some(protocol);
+        self
+    }
+}
+
+impl Container {
+    /// Initializes this container from a Dockerfile build.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerBuildOpts`], are taken by [`Self::build_opts`] and [`Self::build_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - Directory context used by the Dockerfile.
+    ///   Identifies a [`Directory`].
+    pub fn build(&self, context: DirectoryId) -> Container {
+        let mut query = self.selection.select("build");
+
+        query = query.arg("context", context);
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Initializes this container from a Dockerfile build.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - Directory context used by the Dockerfile.
+    ///   Identifies a [`Directory`].
+    /// * `opts` - Optional arguments, see [`ContainerBuildOpts`].
+    pub fn build_opts<'a>(&self, context: DirectoryId, opts: ContainerBuildOpts<'a>) -> Container {
+        let mut query = self.selection.select("build");
+
+        query = query.arg("context", context);
+        if let Some(dockerfile) = opts.dockerfile {
+            query = query.arg("dockerfile", dockerfile);
+        }
+        if let Some(build_args) = opts.build_args {
+            query = query.arg("buildArgs", build_args);
+        }
+        if let Some(target) = opts.target {
+            query = query.arg("target", target);
+        }
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Initializes this container from a Dockerfile build.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - Directory context used by the Dockerfile.
+    ///   Identifies a [`Directory`].
+    /// * `opts` - Optional arguments, see [`ContainerBuildOpts`].
+    pub fn build_with<'a>(
+        &self,
+        context: DirectoryId,
+        opts: impl FnOnce(ContainerBuildOpts<'a>) -> ContainerBuildOpts<'a>,
+    ) -> Container {
+        self.build_opts(context, opts(Default::default()))
+    }
+    /// Retrieves default arguments for future commands.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn default_args(&self) -> eyre::Result<Vec<String>> {
+        let query = self.selection.select("defaultArgs");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// Retrieves a directory at the given path.
+    /// Mounts are included.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the directory to retrieve (e.g., "./src").
+    pub fn directory(&self, path: impl Into<String>) -> Directory {
+        let mut query = self.selection.select("directory");
+
+        query = query.arg("path", path.into());
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Retrieves an endpoint that clients can use to reach this container.
+    /// If no port is specified, the first exposed port is used. If none exist an error is returned.
+    /// If a scheme is specified, a URL is returned. Otherwise, a host:port pair is returned.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`ContainerEndpointOpts`], are taken by [`Self::endpoint_opts`] and [`Self::endpoint_with`].
+    pub async fn endpoint(&self) -> eyre::Result<String> {
+        let query = self.selection.select("endpoint");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+
+    /// Retrieves an endpoint that clients can use to reach this container.
+    /// If no port is specified, the first exposed port is used. If none exist an error is returned.
+    /// If a scheme is specified, a URL is returned. Otherwise, a host:port pair is returned.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerEndpointOpts`].
+    pub async fn endpoint_opts<'a>(&self, opts: ContainerEndpointOpts<'a>) -> eyre::Result<String> {
+        let mut query = self.selection.select("endpoint");
+
+        if let Some(port) = opts.port {
+            query = query.arg("port", port);
+        }
+        if let Some(scheme) = opts.scheme {
+            query = query.arg("scheme", scheme);
+        }
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+
+    /// Retrieves an endpoint that clients can use to reach this container.
+    /// If no port is specified, the first exposed port is used. If none exist an error is returned.
+    /// If a scheme is specified, a URL is returned. Otherwise, a host:port pair is returned.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerEndpointOpts`].
+    pub async fn endpoint_with<'a>(
+        &self,
+        opts: impl FnOnce(ContainerEndpointOpts<'a>) -> ContainerEndpointOpts<'a>,
+    ) -> eyre::Result<String> {
+        self.endpoint_opts(opts(Default::default())).await
+    }
+    /// Retrieves entrypoint to be prepended to the arguments of all commands.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn entrypoint(&self) -> eyre::Result<Vec<String>> {
+        let query = self.selection.select("entrypoint");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// Retrieves the value of the specified environment variable.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the environment variable to retrieve (e.g., "PATH").
+    pub async fn env_variable(&self, name: impl Into<String>) -> eyre::Result<String> {
+        let mut query = self.selection.select("envVariable");
+
+        query = query.arg("name", name.into());
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// Retrieves the list of environment variables passed to commands.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`EnvVariable`] is awaited.
+    pub fn env_variables(&self) -> Vec<EnvVariable> {
+        let query = self.selection.select("envVariables");
+
+        return vec![EnvVariable {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }];
+    }
+    /// Retrieves this container after executing the specified command inside it.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerExecOpts`], are taken by [`Self::exec_opts`] and [`Self::exec_with`].
+    pub fn exec(&self) -> Container {
+        let query = self.selection.select("exec");
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Retrieves this container after executing the specified command inside it.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerExecOpts`].
+    pub fn exec_opts<'a>(&self, opts: ContainerExecOpts<'a>) -> Container {
+        let mut query = self.selection.select("exec");
+
+        if let Some(args) = opts.args {
+            query = query.arg("args", args);
+        }
+        if let Some(stdin) = opts.stdin {
+            query = query.arg("stdin", stdin);
+        }
+        if let Some(redirect_stdout) = opts.redirect_stdout {
+            query = query.arg("redirectStdout", redirect_stdout);
+        }
+        if let Some(redirect_stderr) = opts.redirect_stderr {
+            query = query.arg("redirectStderr", redirect_stderr);
+        }
+        if let Some(experimental_privileged_nesting) = opts.experimental_privileged_nesting {
+            query = query.arg(
+                "experimentalPrivilegedNesting",
+                experimental_privileged_nesting,
+            );
+        }
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Retrieves this container after executing the specified command inside it.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerExecOpts`].
+    pub fn exec_with<'a>(
+        &self,
+        opts: impl FnOnce(ContainerExecOpts<'a>) -> ContainerExecOpts<'a>,
+    ) -> Container {
+        self.exec_opts(opts(Default::default()))
+    }
+    /// Exit code of the last executed command. Zero means success.
+    /// Errors if no command has been executed.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn exit_code(&self) -> eyre::Result<isize> {
+        let query = self.selection.select("exitCode");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// Writes the container as an OCI tarball to the destination file path on the host for the specified platform variants.
+    /// Return true on success.
+    /// It can also publishes platform variants.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`ContainerExportOpts`], are taken by [`Self::export_opts`] and [`Self::export_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Host's destination path (e.g., "./tarball").
+    ///   Path can be relative to the engine's workdir or absolute.
+    pub async fn export(&self, path: impl Into<String>) -> eyre::Result<bool> {
+        let mut query = self.selection.select("export");
+
+        query = query.arg("path", path.into());
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+
+    /// Writes the container as an OCI tarball to the destination file path on the host for the specified platform variants.
+    /// Return true on success.
+    /// It can also publishes platform variants.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Host's destination path (e.g., "./tarball").
+    ///   Path can be relative to the engine's workdir or absolute.
+    /// * `opts` - Optional arguments, see [`ContainerExportOpts`].
+    pub async fn export_opts(
+        &self,
+        path: impl Into<String>,
+        opts: ContainerExportOpts,
+    ) -> eyre::Result<bool> {
+        let mut query = self.selection.select("export");
+
+        query = query.arg("path", path.into());
+        if let Some(platform_variants) = opts.platform_variants {
+            query = query.arg("platformVariants", platform_variants);
+        }
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+
+    /// Writes the container as an OCI tarball to the destination file path on the host for the specified platform variants.
+    /// Return true on success.
+    /// It can also publishes platform variants.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Host's destination path (e.g., "./tarball").
+    ///   Path can be relative to the engine's workdir or absolute.
+    /// * `opts` - Optional arguments, see [`ContainerExportOpts`].
+    pub async fn export_with(
+        &self,
+        path: impl Into<String>,
+        opts: impl FnOnce(ContainerExportOpts) -> ContainerExportOpts,
+    ) -> eyre::Result<bool> {
+        self.export_opts(path, opts(Default::default())).await
+    }
+    /// Retrieves the list of exposed ports.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Port`] is awaited.
+    pub fn exposed_ports(&self) -> Vec<Port> {
+        let query = self.selection.select("exposedPorts");
+
+        return vec![Port {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }];
+    }
+    /// Retrieves a file at the given path.
+    /// Mounts are included.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file to retrieve (e.g., "./README.md").
+    pub fn file(&self, path: impl Into<String>) -> File {
+        let mut query = self.selection.select("file");
+
+        query = query.arg("path", path.into());
+
+        return File {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Initializes this container from a pulled base image.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Image's address from its registry.
+    ///
+    ///   Formatted as \[host\]/\[user\]/\[repo\]:\[tag\] (e.g., "docker.io/dagger/dagger:main").
+    pub fn from(&self, address: impl Into<String>) -> Container {
+        let mut query = self.selection.select("from");
+
+        query = query.arg("address", address.into());
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Retrieves this container's root filesystem. Mounts are not included.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    pub fn fs(&self) -> Directory {
+        let query = self.selection.select("fs");
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Retrieves a hostname which can be used by clients to reach this container.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn hostname(&self) -> eyre::Result<String> {
+        let query = self.selection.select("hostname");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// A unique identifier for this container.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn id(&self) -> eyre::Result<ContainerId> {
+        let query = self.selection.select("id");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// The unique image reference which can only be retrieved immediately after the 'Container.From' call.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn image_ref(&self) -> eyre::Result<String> {
+        let query = self.selection.select("imageRef");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// Retrieves the value of the specified label.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn label(&self, name: impl Into<String>) -> eyre::Result<String> {
+        let mut query = self.selection.select("label");
+
+        query = query.arg("name", name.into());
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// Retrieves the list of labels passed to container.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Label`] is awaited.
+    pub fn labels(&self) -> Vec<Label> {
+        let query = self.selection.select("labels");
+
+        return vec![Label {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }];
+    }
+    /// Retrieves the list of paths where a directory is mounted.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn mounts(&self) -> eyre::Result<Vec<String>> {
+        let query = self.selection.select("mounts");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// Creates a named sub-pipeline
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerPipelineOpts`], are taken by [`Self::pipeline_opts`] and [`Self::pipeline_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    pub fn pipeline(&self, name: impl Into<String>) -> Container {
+        let mut query = self.selection.select("pipeline");
+
+        query = query.arg("name", name.into());
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Creates a named sub-pipeline
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    /// * `opts` - Optional arguments, see [`ContainerPipelineOpts`].
+    pub fn pipeline_opts<'a>(
+        &self,
+        name: impl Into<String>,
+        opts: ContainerPipelineOpts<'a>,
+    ) -> Container {
+        let mut query = self.selection.select("pipeline");
+
+        query = query.arg("name", name.into());
+        if let Some(description) = opts.description {
+            query = query.arg("description", description);
+        }
+        if let Some(labels) = opts.labels {
+            query = query.arg("labels", labels);
+        }
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Creates a named sub-pipeline
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    /// * `opts` - Optional arguments, see [`ContainerPipelineOpts`].
+    pub fn pipeline_with<'a>(
+        &self,
+        name: impl Into<String>,
+        opts: impl FnOnce(ContainerPipelineOpts<'a>) -> ContainerPipelineOpts<'a>,
+    ) -> Container {
+        self.pipeline_opts(name, opts(Default::default()))
+    }
+    /// The platform this container executes and publishes as.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn platform(&self) -> eyre::Result<Platform> {
+        let query = self.selection.select("platform");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// Publishes this container as a new image to the specified address.
+    /// Publish returns a fully qualified ref.
+    /// It can also publish platform variants.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`ContainerPublishOpts`], are taken by [`Self::publish_opts`] and [`Self::publish_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Registry's address to publish the image to.
+    ///
+    ///   Formatted as \[host\]/\[user\]/\[repo\]:\[tag\] (e.g. "docker.io/dagger/dagger:main").
+    pub async fn publish(&self, address: impl Into<String>) -> eyre::Result<String> {
+        let mut query = self.selection.select("publish");
+
+        query = query.arg("address", address.into());
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+
+    /// Publishes this container as a new image to the specified address.
+    /// Publish returns a fully qualified ref.
+    /// It can also publish platform variants.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Registry's address to publish the image to.
+    ///
+    ///   Formatted as \[host\]/\[user\]/\[repo\]:\[tag\] (e.g. "docker.io/dagger/dagger:main").
+    /// * `opts` - Optional arguments, see [`ContainerPublishOpts`].
+    pub async fn publish_opts(
+        &self,
+        address: impl Into<String>,
+        opts: ContainerPublishOpts,
+    ) -> eyre::Result<String> {
+        let mut query = self.selection.select("publish");
+
+        query = query.arg("address", address.into());
+        if let Some(platform_variants) = opts.platform_variants {
+            query = query.arg("platformVariants", platform_variants);
+        }
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+
+    /// Publishes this container as a new image to the specified address.
+    /// Publish returns a fully qualified ref.
+    /// It can also publish platform variants.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Registry's address to publish the image to.
+    ///
+    ///   Formatted as \[host\]/\[user\]/\[repo\]:\[tag\] (e.g. "docker.io/dagger/dagger:main").
+    /// * `opts` - Optional arguments, see [`ContainerPublishOpts`].
+    pub async fn publish_with(
+        &self,
+        address: impl Into<String>,
+        opts: impl FnOnce(ContainerPublishOpts) -> ContainerPublishOpts,
+    ) -> eyre::Result<String> {
+        self.publish_opts(address, opts(Default::default())).await
+    }
+    /// Retrieves this container's root filesystem. Mounts are not included.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    pub fn rootfs(&self) -> Directory {
+        let query = self.selection.select("rootfs");
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// The error stream of the last executed command.
+    /// Errors if no command has been executed.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn stderr(&self) -> eyre::Result<String> {
+        let query = self.selection.select("stderr");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// The output stream of the last executed command.
+    /// Errors if no command has been executed.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn stdout(&self) -> eyre::Result<String> {
+        let query = self.selection.select("stdout");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// Retrieves the user to be set for all commands.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn user(&self) -> eyre::Result<String> {
+        let query = self.selection.select("user");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// Configures default arguments for future commands.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithDefaultArgsOpts`], are taken by [`Self::with_default_args_opts`] and [`Self::with_default_args_with`].
+    pub fn with_default_args(&self) -> Container {
+        let query = self.selection.select("withDefaultArgs");
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Configures default arguments for future commands.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerWithDefaultArgsOpts`].
+    pub fn with_default_args_opts<'a>(&self, opts: ContainerWithDefaultArgsOpts<'a>) -> Container {
+        let mut query = self.selection.select("withDefaultArgs");
+
+        if let Some(args) = opts.args {
+            query = query.arg("args", args);
+        }
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Configures default arguments for future commands.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`ContainerWithDefaultArgsOpts`].
+    pub fn with_default_args_with<'a>(
+        &self,
+        opts: impl FnOnce(ContainerWithDefaultArgsOpts<'a>) -> ContainerWithDefaultArgsOpts<'a>,
+    ) -> Container {
+        self.with_default_args_opts(opts(Default::default()))
+    }
+    /// Retrieves this container plus a directory written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithDirectoryOpts`], are taken by [`Self::with_directory_opts`] and [`Self::with_directory_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written directory (e.g., "/tmp/directory").
+    /// * `directory` - Identifier of the directory to write
+    ///   Identifies a [`Directory`].
+    pub fn with_directory(&self, path: impl Into<String>, directory: DirectoryId) -> Container {
+        let mut query = self.selection.select("withDirectory");
+
+        query = query.arg("path", path.into());
+        query = query.arg("directory", directory);
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Retrieves this container plus a directory written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written directory (e.g., "/tmp/directory").
+    /// * `directory` - Identifier of the directory to write
+    ///   Identifies a [`Directory`].
+    /// * `opts` - Optional arguments, see [`ContainerWithDirectoryOpts`].
+    pub fn with_directory_opts<'a>(
+        &self,
+        path: impl Into<String>,
+        directory: DirectoryId,
+        opts: ContainerWithDirectoryOpts<'a>,
+    ) -> Container {
+        let mut query = self.selection.select("withDirectory");
+
+        query = query.arg("path", path.into());
+        query = query.arg("directory", directory);
+        if let Some(exclude) = opts.exclude {
+            query = query.arg("exclude", exclude);
+        }
+        if let Some(include) = opts.include {
+            query = query.arg("include", include);
+        }
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Retrieves this container plus a directory written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written directory (e.g., "/tmp/directory").
+    /// * `directory` - Identifier of the directory to write
+    ///   Identifies a [`Directory`].
+    /// * `opts` - Optional arguments, see [`ContainerWithDirectoryOpts`].
+    pub fn with_directory_with<'a>(
+        &self,
+        path: impl Into<String>,
+        directory: DirectoryId,
+        opts: impl FnOnce(ContainerWithDirectoryOpts<'a>) -> ContainerWithDirectoryOpts<'a>,
+    ) -> Container {
+        self.with_directory_opts(path, directory, opts(Default::default()))
+    }
+    /// Retrieves this container but with a different command entrypoint.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Entrypoint to use for future executions (e.g., \["go", "run"\]).
+    pub fn with_entrypoint(&self, args: Vec<impl Into<String>>) -> Container {
+        let mut query = self.selection.select("withEntrypoint");
+
+        query = query.arg(
+            "args",
+            args.into_iter().map(|i| i.into()).collect::<Vec<String>>(),
+        );
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Retrieves this container plus the given environment variable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the environment variable (e.g., "HOST").
+    /// * `value` - The value of the environment variable. (e.g., "localhost").
+    pub fn with_env_variable(
+        &self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Container {
+        let mut query = self.selection.select("withEnvVariable");
+
+        query = query.arg("name", name.into());
+        query = query.arg("value", value.into());
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Retrieves this container after executing the specified command inside it.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithExecOpts`], are taken by [`Self::with_exec_opts`] and [`Self::with_exec_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Command to run instead of the container's default command (e.g., \["run", "main.go"\]).
+    pub fn with_exec(&self, args: Vec<impl Into<String>>) -> Container {
+        let mut query = self.selection.select("withExec");
+
+        query = query.arg(
+            "args",
+            args.into_iter().map(|i| i.into()).collect::<Vec<String>>(),
+        );
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Retrieves this container after executing the specified command inside it.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Command to run instead of the container's default command (e.g., \["run", "main.go"\]).
+    /// * `opts` - Optional arguments, see [`ContainerWithExecOpts`].
+    pub fn with_exec_opts<'a>(
+        &self,
+        args: Vec<impl Into<String>>,
+        opts: ContainerWithExecOpts<'a>,
+    ) -> Container {
+        let mut query = self.selection.select("withExec");
+
+        query = query.arg(
+            "args",
+            args.into_iter().map(|i| i.into()).collect::<Vec<String>>(),
+        );
+        if let Some(stdin) = opts.stdin {
+            query = query.arg("stdin", stdin);
+        }
+        if let Some(redirect_stdout) = opts.redirect_stdout {
+            query = query.arg("redirectStdout", redirect_stdout);
+        }
+        if let Some(redirect_stderr) = opts.redirect_stderr {
+            query = query.arg("redirectStderr", redirect_stderr);
+        }
+        if let Some(experimental_privileged_nesting) = opts.experimental_privileged_nesting {
+            query = query.arg(
+                "experimentalPrivilegedNesting",
+                experimental_privileged_nesting,
+            );
+        }
+        if let Some(insecure_root_capabilities) = opts.insecure_root_capabilities {
+            query = query.arg("insecureRootCapabilities", insecure_root_capabilities);
+        }
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Retrieves this container after executing the specified command inside it.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Command to run instead of the container's default command (e.g., \["run", "main.go"\]).
+    /// * `opts` - Optional arguments, see [`ContainerWithExecOpts`].
+    pub fn with_exec_with<'a>(
+        &self,
+        args: Vec<impl Into<String>>,
+        opts: impl FnOnce(ContainerWithExecOpts<'a>) -> ContainerWithExecOpts<'a>,
+    ) -> Container {
+        self.with_exec_opts(args, opts(Default::default()))
+    }
+    /// Expose a network port.
+    /// Exposed ports serve two purposes:
+    /// - For health checks and introspection, when running services
+    /// - For setting the EXPOSE OCI field when publishing the container
+    ///
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithExposedPortOpts`], are taken by [`Self::with_exposed_port_opts`] and [`Self::with_exposed_port_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Port number to expose
+    pub fn with_exposed_port(&self, port: isize) -> Container {
+        let mut query = self.selection.select("withExposedPort");
+
+        query = query.arg("port", port);
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Expose a network port.
+    /// Exposed ports serve two purposes:
+    /// - For health checks and introspection, when running services
+    /// - For setting the EXPOSE OCI field when publishing the container
+    ///
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Port number to expose
+    /// * `opts` - Optional arguments, see [`ContainerWithExposedPortOpts`].
+    pub fn with_exposed_port_opts<'a>(
+        &self,
+        port: isize,
+        opts: ContainerWithExposedPortOpts<'a>,
+    ) -> Container {
+        let mut query = self.selection.select("withExposedPort");
+
+        query = query.arg("port", port);
+        if let Some(protocol) = opts.protocol {
+            query = query.arg_enum("protocol", protocol);
+        }
+        if let Some(description) = opts.description {
+            query = query.arg("description", description);
+        }
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Expose a network port.
+    /// Exposed ports serve two purposes:
+    /// - For health checks and introspection, when running services
+    /// - For setting the EXPOSE OCI field when publishing the container
+    ///
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Port number to expose
+    /// * `opts` - Optional arguments, see [`ContainerWithExposedPortOpts`].
+    pub fn with_exposed_port_with<'a>(
+        &self,
+        port: isize,
+        opts: impl FnOnce(ContainerWithExposedPortOpts<'a>) -> ContainerWithExposedPortOpts<'a>,
+    ) -> Container {
+        self.with_exposed_port_opts(port, opts(Default::default()))
+    }
+    /// Initializes this container from this DirectoryID.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Identifies a [`Directory`].
+    pub fn with_fs(&self, id: DirectoryId) -> Container {
+        let mut query = self.selection.select("withFS");
+
+        query = query.arg("id", id);
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Retrieves this container plus the contents of the given file copied to the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithFileOpts`], are taken by [`Self::with_file_opts`] and [`Self::with_file_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the copied file (e.g., "/tmp/file.txt").
+    /// * `source` - Identifier of the file to copy.
+    ///   Identifies a [`File`].
+    pub fn with_file(&self, path: impl Into<String>, source: FileId) -> Container {
+        let mut query = self.selection.select("withFile");
+
+        query = query.arg("path", path.into());
+        query = query.arg("source", source);
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Retrieves this container plus the contents of the given file copied to the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the copied file (e.g., "/tmp/file.txt").
+    /// * `source` - Identifier of the file to copy.
+    ///   Identifies a [`File`].
+    /// * `opts` - Optional arguments, see [`ContainerWithFileOpts`].
+    pub fn with_file_opts(
+        &self,
+        path: impl Into<String>,
+        source: FileId,
+        opts: ContainerWithFileOpts,
+    ) -> Container {
+        let mut query = self.selection.select("withFile");
+
+        query = query.arg("path", path.into());
+        query = query.arg("source", source);
+        if let Some(permissions) = opts.permissions {
+            query = query.arg("permissions", permissions);
+        }
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Retrieves this container plus the contents of the given file copied to the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the copied file (e.g., "/tmp/file.txt").
+    /// * `source` - Identifier of the file to copy.
+    ///   Identifies a [`File`].
+    /// * `opts` - Optional arguments, see [`ContainerWithFileOpts`].
+    pub fn with_file_with(
+        &self,
+        path: impl Into<String>,
+        source: FileId,
+        opts: impl FnOnce(ContainerWithFileOpts) -> ContainerWithFileOpts,
+    ) -> Container {
+        self.with_file_opts(path, source, opts(Default::default()))
+    }
+    /// Retrieves this container plus the given label.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the label (e.g., "org.opencontainers.artifact.created").
+    /// * `value` - The value of the label (e.g., "2023-01-01T00:00:00Z").
+    pub fn with_label(&self, name: impl Into<String>, value: impl Into<String>) -> Container {
+        let mut query = self.selection.select("withLabel");
+
+        query = query.arg("name", name.into());
+        query = query.arg("value", value.into());
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Retrieves this container plus a cache volume mounted at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithMountedCacheOpts`], are taken by [`Self::with_mounted_cache_opts`] and [`Self::with_mounted_cache_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the cache directory (e.g., "/cache/node_modules").
+    /// * `cache` - Identifier of the cache volume to mount.
+    pub fn with_mounted_cache(&self, path: impl Into<String>, cache: CacheId) -> Container {
+        let mut query = self.selection.select("withMountedCache");
+
+        query = query.arg("path", path.into());
+        query = query.arg("cache", cache);
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Retrieves this container plus a cache volume mounted at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the cache directory (e.g., "/cache/node_modules").
+    /// * `cache` - Identifier of the cache volume to mount.
+    /// * `opts` - Optional arguments, see [`ContainerWithMountedCacheOpts`].
+    pub fn with_mounted_cache_opts(
+        &self,
+        path: impl Into<String>,
+        cache: CacheId,
+        opts: ContainerWithMountedCacheOpts,
+    ) -> Container {
+        let mut query = self.selection.select("withMountedCache");
+
+        query = query.arg("path", path.into());
+        query = query.arg("cache", cache);
+        if let Some(source) = opts.source {
+            query = query.arg("source", source);
+        }
+        if let Some(sharing) = opts.sharing {
+            query = query.arg_enum("sharing", sharing);
+        }
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Retrieves this container plus a cache volume mounted at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the cache directory (e.g., "/cache/node_modules").
+    /// * `cache` - Identifier of the cache volume to mount.
+    /// * `opts` - Optional arguments, see [`ContainerWithMountedCacheOpts`].
+    pub fn with_mounted_cache_with(
+        &self,
+        path: impl Into<String>,
+        cache: CacheId,
+        opts: impl FnOnce(ContainerWithMountedCacheOpts) -> ContainerWithMountedCacheOpts,
+    ) -> Container {
+        self.with_mounted_cache_opts(path, cache, opts(Default::default()))
+    }
+    /// Retrieves this container plus a directory mounted at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the mounted directory (e.g., "/mnt/directory").
+    /// * `source` - Identifier of the mounted directory.
+    ///   Identifies a [`Directory`].
+    pub fn with_mounted_directory(
+        &self,
+        path: impl Into<String>,
+        source: DirectoryId,
+    ) -> Container {
+        let mut query = self.selection.select("withMountedDirectory");
+
+        query = query.arg("path", path.into());
+        query = query.arg("source", source);
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Retrieves this container plus a file mounted at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the mounted file (e.g., "/tmp/file.txt").
+    /// * `source` - Identifier of the mounted file.
+    ///   Identifies a [`File`].
+    pub fn with_mounted_file(&self, path: impl Into<String>, source: FileId) -> Container {
+        let mut query = self.selection.select("withMountedFile");
+
+        query = query.arg("path", path.into());
+        query = query.arg("source", source);
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Retrieves this container plus a secret mounted into a file at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the secret file (e.g., "/tmp/secret.txt").
+    /// * `source` - Identifier of the secret to mount.
+    ///   Identifies a [`Secret`].
+    pub fn with_mounted_secret(&self, path: impl Into<String>, source: SecretId) -> Container {
+        let mut query = self.selection.select("withMountedSecret");
+
+        query = query.arg("path", path.into());
+        query = query.arg("source", source);
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Retrieves this container plus a temporary directory mounted at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the temporary directory (e.g., "/tmp/temp_dir").
+    pub fn with_mounted_temp(&self, path: impl Into<String>) -> Container {
+        let mut query = self.selection.select("withMountedTemp");
+
+        query = query.arg("path", path.into());
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Retrieves this container plus a new file written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithNewFileOpts`], are taken by [`Self::with_new_file_opts`] and [`Self::with_new_file_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written file (e.g., "/tmp/file.txt").
+    pub fn with_new_file(&self, path: impl Into<String>) -> Container {
+        let mut query = self.selection.select("withNewFile");
+
+        query = query.arg("path", path.into());
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Retrieves this container plus a new file written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written file (e.g., "/tmp/file.txt").
+    /// * `opts` - Optional arguments, see [`ContainerWithNewFileOpts`].
+    pub fn with_new_file_opts<'a>(
+        &self,
+        path: impl Into<String>,
+        opts: ContainerWithNewFileOpts<'a>,
+    ) -> Container {
+        let mut query = self.selection.select("withNewFile");
+
+        query = query.arg("path", path.into());
+        if let Some(contents) = opts.contents {
+            query = query.arg("contents", contents);
+        }
+        if let Some(permissions) = opts.permissions {
+            query = query.arg("permissions", permissions);
+        }
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Retrieves this container plus a new file written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written file (e.g., "/tmp/file.txt").
+    /// * `opts` - Optional arguments, see [`ContainerWithNewFileOpts`].
+    pub fn with_new_file_with<'a>(
+        &self,
+        path: impl Into<String>,
+        opts: impl FnOnce(ContainerWithNewFileOpts<'a>) -> ContainerWithNewFileOpts<'a>,
+    ) -> Container {
+        self.with_new_file_opts(path, opts(Default::default()))
+    }
+    /// Retrieves this container with a registry authentication for a given address.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Registry's address to bind the authentication to.
+    ///   Formatted as \[host\]/\[user\]/\[repo\]:\[tag\] (e.g. docker.io/dagger/dagger:main).
+    /// * `username` - The username of the registry's account (e.g., "Dagger").
+    /// * `secret` - The API key, password or token to authenticate to this registry.
+    ///   Identifies a [`Secret`].
+    pub fn with_registry_auth(
+        &self,
+        address: impl Into<String>,
+        username: impl Into<String>,
+        secret: SecretId,
+    ) -> Container {
+        let mut query = self.selection.select("withRegistryAuth");
+
+        query = query.arg("address", address.into());
+        query = query.arg("username", username.into());
+        query = query.arg("secret", secret);
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Initializes this container from this DirectoryID.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Identifies a [`Directory`].
+    pub fn with_rootfs(&self, id: DirectoryId) -> Container {
+        let mut query = self.selection.select("withRootfs");
+
+        query = query.arg("id", id);
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Retrieves this container plus an env variable containing the given secret.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the secret variable (e.g., "API_SECRET").
+    /// * `secret` - The identifier of the secret value.
+    ///   Identifies a [`Secret`].
+    pub fn with_secret_variable(&self, name: impl Into<String>, secret: SecretId) -> Container {
+        let mut query = self.selection.select("withSecretVariable");
+
+        query = query.arg("name", name.into());
+        query = query.arg("secret", secret);
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Establish a runtime dependency on a service. The service will be started automatically when needed and detached when it is no longer needed.
+    /// The service will be reachable from the container via the provided hostname alias.
+    /// The service dependency will also convey to any files or directories produced by the container.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `alias` - A name that can be used to reach the service from the container
+    /// * `service` - Identifier of the service container
+    ///   Identifies a [`Container`].
+    pub fn with_service_binding(
+        &self,
+        alias: impl Into<String>,
+        service: ContainerId,
+    ) -> Container {
+        let mut query = self.selection.select("withServiceBinding");
+
+        query = query.arg("alias", alias.into());
+        query = query.arg("service", service);
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Retrieves this container plus a socket forwarded to the given Unix socket path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the forwarded Unix socket (e.g., "/tmp/socket").
+    /// * `source` - Identifier of the socket to forward.
+    pub fn with_unix_socket(&self, path: impl Into<String>, source: SocketId) -> Container {
+        let mut query = self.selection.select("withUnixSocket");
+
+        query = query.arg("path", path.into());
+        query = query.arg("source", source);
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Retrieves this container with a different command user.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The user to set (e.g., "root").
+    pub fn with_user(&self, name: impl Into<String>) -> Container {
+        let mut query = self.selection.select("withUser");
+
+        query = query.arg("name", name.into());
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Retrieves this container with a different working directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to set as the working directory (e.g., "/app").
+    pub fn with_workdir(&self, path: impl Into<String>) -> Container {
+        let mut query = self.selection.select("withWorkdir");
+
+        query = query.arg("path", path.into());
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Retrieves this container minus the given environment variable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the environment variable (e.g., "HOST").
+    pub fn without_env_variable(&self, name: impl Into<String>) -> Container {
+        let mut query = self.selection.select("withoutEnvVariable");
+
+        query = query.arg("name", name.into());
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Unexpose a previously exposed port.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`ContainerWithoutExposedPortOpts`], are taken by [`Self::without_exposed_port_opts`] and [`Self::without_exposed_port_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Port number to unexpose
+    pub fn without_exposed_port(&self, port: isize) -> Container {
+        let mut query = self.selection.select("withoutExposedPort");
+
+        query = query.arg("port", port);
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Unexpose a previously exposed port.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Port number to unexpose
+    /// * `opts` - Optional arguments, see [`ContainerWithoutExposedPortOpts`].
+    pub fn without_exposed_port_opts(
+        &self,
+        port: isize,
+        opts: ContainerWithoutExposedPortOpts,
+    ) -> Container {
+        let mut query = self.selection.select("withoutExposedPort");
+
+        query = query.arg("port", port);
+        if let Some(protocol) = opts.protocol {
+            query = query.arg_enum("protocol", protocol);
+        }
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Unexpose a previously exposed port.
+    /// Currently experimental; set _EXPERIMENTAL_DAGGER_SERVICES_DNS=0 to disable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `port` - Port number to unexpose
+    /// * `opts` - Optional arguments, see [`ContainerWithoutExposedPortOpts`].
+    pub fn without_exposed_port_with(
+        &self,
+        port: isize,
+        opts: impl FnOnce(ContainerWithoutExposedPortOpts) -> ContainerWithoutExposedPortOpts,
+    ) -> Container {
+        self.without_exposed_port_opts(port, opts(Default::default()))
+    }
+    /// Retrieves this container minus the given environment label.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the label to remove (e.g., "org.opencontainers.artifact.created").
+    pub fn without_label(&self, name: impl Into<String>) -> Container {
+        let mut query = self.selection.select("withoutLabel");
+
+        query = query.arg("name", name.into());
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Retrieves this container after unmounting everything at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the cache directory (e.g., "/cache/node_modules").
+    pub fn without_mount(&self, path: impl Into<String>) -> Container {
+        let mut query = self.selection.select("withoutMount");
+
+        query = query.arg("path", path.into());
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Retrieves this container without the registry authentication of a given address.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Registry's address to remove the authentication from.
+    ///   Formatted as \[host\]/\[user\]/\[repo\]:\[tag\] (e.g. docker.io/dagger/dagger:main).
+    pub fn without_registry_auth(&self, address: impl Into<String>) -> Container {
+        let mut query = self.selection.select("withoutRegistryAuth");
+
+        query = query.arg("address", address.into());
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Retrieves this container with a previously added Unix socket removed.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the socket to remove (e.g., "/tmp/socket").
+    pub fn without_unix_socket(&self, path: impl Into<String>) -> Container {
+        let mut query = self.selection.select("withoutUnixSocket");
+
+        query = query.arg("path", path.into());
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Retrieves the working directory for all commands.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn workdir(&self) -> eyre::Result<String> {
+        let query = self.selection.select("workdir");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+}
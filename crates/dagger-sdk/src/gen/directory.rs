@@ -0,0 +1,770 @@
+use crate::client::graphql_client;
+use crate::querybuilder::Selection;
+use dagger_core::connect_params::ConnectParams;
+use derive_builder::Builder;
+use std::sync::Arc;
+use tokio::process::Child;
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct Directory {
+    pub proc: Arc<Child>,
+    pub selection: Selection,
+    pub conn: ConnectParams,
+}
+
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct DirectoryDockerBuildOpts<'a> {
+    /// Path to the Dockerfile to use (e.g., "frontend.Dockerfile").
+    /// Defaults: './Dockerfile'.
+    #[builder(setter(into, strip_option), default)]
+    pub dockerfile: Option<&'a str>,
+    /// The platform to build.
+    #[builder(setter(into, strip_option), default)]
+    pub platform: Option<Platform>,
+    /// Build arguments to use in the build.
+    #[builder(setter(into, strip_option), default)]
+    pub build_args: Option<Vec<BuildArg>>,
+    /// Target build stage to build.
+    #[builder(setter(into, strip_option), default)]
+    pub target: Option<&'a str>,
+}
+
+impl<'a> DirectoryDockerBuildOpts<'a> {
+    /// Path to the Dockerfile to use (e.g., "frontend.Dockerfile").
+    /// Defaults: './Dockerfile'.
+    pub fn dockerfile(mut self, dockerfile: &'a str) -> Self {
+        self.dockerfile = Some(dockerfile);
+        self
+    }
+    /// The platform to build.
+    pub fn platform(mut self, platform: impl Into<Platform>) -> Self {
+        self.platform = Some(platform.into());
+        self
+    }
+    /// Build arguments to use in the build.
+    pub fn build_args(mut self, build_args: Vec<BuildArg>) -> Self {
+        self.build_args = Some(build_args);
+        self
+    }
+    /// Target build stage to build.
+    pub fn target(mut self, target: &'a str) -> Self {
+        self.target = Some(target);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct DirectoryEntriesOpts<'a> {
+    /// Location of the directory to look at (e.g., "/src").
+    #[builder(setter(into, strip_option), default)]
+    pub path: Option<&'a str>,
+}
+
+impl<'a> DirectoryEntriesOpts<'a> {
+    /// Location of the directory to look at (e.g., "/src").
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.path = Some(path);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct DirectoryPipelineOpts<'a> {
+    /// Pipeline description.
+    #[builder(setter(into, strip_option), default)]
+    pub description: Option<&'a str>,
+    /// Pipeline labels.
+    #[builder(setter(into, strip_option), default)]
+    pub labels: Option<Vec<PipelineLabel>>,
+}
+
+impl<'a> DirectoryPipelineOpts<'a> {
+    /// Pipeline description.
+    pub fn description(mut self, description: &'a str) -> Self {
+        self.description = Some(description);
+        self
+    }
+    /// Pipeline labels.
+    pub fn labels(mut self, labels: Vec<PipelineLabel>) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct DirectoryWithDirectoryOpts<'a> {
+    /// Exclude artifacts that match the given pattern (e.g., \["node_modules/", ".git*"\]).
+    #[builder(setter(into, strip_option), default)]
+    pub exclude: Option<Vec<&'a str>>,
+    /// Include only artifacts that match the given pattern (e.g., \["app/", "package.*"\]).
+    #[builder(setter(into, strip_option), default)]
+    pub include: Option<Vec<&'a str>>,
+}
+
+impl<'a> DirectoryWithDirectoryOpts<'a> {
+    /// Exclude artifacts that match the given pattern (e.g., \["node_modules/", ".git*"\]).
+    pub fn exclude(mut self, exclude: Vec<&'a str>) -> Self {
+        self.exclude = Some(exclude);
+        self
+    }
+    /// Include only artifacts that match the given pattern (e.g., \["app/", "package.*"\]).
+    pub fn include(mut self, include: Vec<&'a str>) -> Self {
+        self.include = Some(include);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct DirectoryWithFileOpts {
+    /// Permission given to the copied file (e.g., 0600).
+    /// Default: 0644.
+    #[builder(setter(into, strip_option), default)]
+    pub permissions: Option<isize>,
+}
+
+impl DirectoryWithFileOpts {
+    /// Permission given to the copied file (e.g., 0600).
+    /// Default: 0644.
+    pub fn permissions(mut self, permissions: isize) -> Self {
+        self.permissions = Some(permissions);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct DirectoryWithNewDirectoryOpts {
+    /// Permission granted to the created directory (e.g., 0777).
+    /// Default: 0755.
+    #[builder(setter(into, strip_option), default)]
+    pub permissions: Option<isize>,
+}
+
+impl DirectoryWithNewDirectoryOpts {
+    /// Permission granted to the created directory (e.g., 0777).
+    /// Default: 0755.
+    pub fn permissions(mut self, permissions: isize) -> Self {
+        self.permissions = Some(permissions);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct DirectoryWithNewFileOpts {
+    /// Permission given to the copied file (e.g., 0600).
+    /// Default: 0644.
+    #[builder(setter(into, strip_option), default)]
+    pub permissions: Option<isize>,
+}
+
+impl DirectoryWithNewFileOpts {
+    /// Permission given to the copied file (e.g., 0600).
+    /// Default: 0644.
+    pub fn permissions(mut self, permissions: isize) -> Self {
+        self.permissions = Some(permissions);
+        self
+    }
+}
+
+impl Directory {
+    /// Gets the difference between this directory and an another directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Identifier of the directory to compare.
+    ///   Identifies a [`Directory`].
+    pub fn diff(&self, other: DirectoryId) -> Directory {
+        let mut query = self.selection.select("diff");
+
+        query = query.arg("other", other);
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Retrieves a directory at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the directory to retrieve (e.g., "/src").
+    pub fn directory(&self, path: impl Into<String>) -> Directory {
+        let mut query = self.selection.select("directory");
+
+        query = query.arg("path", path.into());
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Builds a new Docker container from this directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`DirectoryDockerBuildOpts`], are taken by [`Self::docker_build_opts`] and [`Self::docker_build_with`].
+    pub fn docker_build(&self) -> Container {
+        let query = self.selection.select("dockerBuild");
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Builds a new Docker container from this directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`DirectoryDockerBuildOpts`].
+    pub fn docker_build_opts<'a>(&self, opts: DirectoryDockerBuildOpts<'a>) -> Container {
+        let mut query = self.selection.select("dockerBuild");
+
+        if let Some(dockerfile) = opts.dockerfile {
+            query = query.arg("dockerfile", dockerfile);
+        }
+        if let Some(platform) = opts.platform {
+            query = query.arg("platform", platform);
+        }
+        if let Some(build_args) = opts.build_args {
+            query = query.arg("buildArgs", build_args);
+        }
+        if let Some(target) = opts.target {
+            query = query.arg("target", target);
+        }
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Builds a new Docker container from this directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`DirectoryDockerBuildOpts`].
+    pub fn docker_build_with<'a>(
+        &self,
+        opts: impl FnOnce(DirectoryDockerBuildOpts<'a>) -> DirectoryDockerBuildOpts<'a>,
+    ) -> Container {
+        self.docker_build_opts(opts(Default::default()))
+    }
+    /// Returns a list of files and directories at the given path.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// Optional arguments, see [`DirectoryEntriesOpts`], are taken by [`Self::entries_opts`] and [`Self::entries_with`].
+    pub async fn entries(&self) -> eyre::Result<Vec<String>> {
+        let query = self.selection.select("entries");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+
+    /// Returns a list of files and directories at the given path.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`DirectoryEntriesOpts`].
+    pub async fn entries_opts<'a>(
+        &self,
+        opts: DirectoryEntriesOpts<'a>,
+    ) -> eyre::Result<Vec<String>> {
+        let mut query = self.selection.select("entries");
+
+        if let Some(path) = opts.path {
+            query = query.arg("path", path);
+        }
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+
+    /// Returns a list of files and directories at the given path.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`DirectoryEntriesOpts`].
+    pub async fn entries_with<'a>(
+        &self,
+        opts: impl FnOnce(DirectoryEntriesOpts<'a>) -> DirectoryEntriesOpts<'a>,
+    ) -> eyre::Result<Vec<String>> {
+        self.entries_opts(opts(Default::default())).await
+    }
+    /// Writes the contents of the directory to a path on the host.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the copied directory (e.g., "logs/").
+    pub async fn export(&self, path: impl Into<String>) -> eyre::Result<bool> {
+        let mut query = self.selection.select("export");
+
+        query = query.arg("path", path.into());
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// Retrieves a file at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the file to retrieve (e.g., "README.md").
+    pub fn file(&self, path: impl Into<String>) -> File {
+        let mut query = self.selection.select("file");
+
+        query = query.arg("path", path.into());
+
+        return File {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// The content-addressed identifier of the directory.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn id(&self) -> eyre::Result<DirectoryId> {
+        let query = self.selection.select("id");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// load a project's metadata
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Project`] is awaited.
+    #[cfg(feature = "project")]
+    pub fn load_project(&self, config_path: impl Into<String>) -> Project {
+        let mut query = self.selection.select("loadProject");
+
+        query = query.arg("configPath", config_path.into());
+
+        return Project {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Creates a named sub-pipeline
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`DirectoryPipelineOpts`], are taken by [`Self::pipeline_opts`] and [`Self::pipeline_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    pub fn pipeline(&self, name: impl Into<String>) -> Directory {
+        let mut query = self.selection.select("pipeline");
+
+        query = query.arg("name", name.into());
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Creates a named sub-pipeline
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    /// * `opts` - Optional arguments, see [`DirectoryPipelineOpts`].
+    pub fn pipeline_opts<'a>(
+        &self,
+        name: impl Into<String>,
+        opts: DirectoryPipelineOpts<'a>,
+    ) -> Directory {
+        let mut query = self.selection.select("pipeline");
+
+        query = query.arg("name", name.into());
+        if let Some(description) = opts.description {
+            query = query.arg("description", description);
+        }
+        if let Some(labels) = opts.labels {
+            query = query.arg("labels", labels);
+        }
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Creates a named sub-pipeline
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    /// * `opts` - Optional arguments, see [`DirectoryPipelineOpts`].
+    pub fn pipeline_with<'a>(
+        &self,
+        name: impl Into<String>,
+        opts: impl FnOnce(DirectoryPipelineOpts<'a>) -> DirectoryPipelineOpts<'a>,
+    ) -> Directory {
+        self.pipeline_opts(name, opts(Default::default()))
+    }
+    /// Retrieves this directory plus a directory written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`DirectoryWithDirectoryOpts`], are taken by [`Self::with_directory_opts`] and [`Self::with_directory_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written directory (e.g., "/src/").
+    /// * `directory` - Identifier of the directory to copy.
+    ///   Identifies a [`Directory`].
+    pub fn with_directory(&self, path: impl Into<String>, directory: DirectoryId) -> Directory {
+        let mut query = self.selection.select("withDirectory");
+
+        query = query.arg("path", path.into());
+        query = query.arg("directory", directory);
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Retrieves this directory plus a directory written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written directory (e.g., "/src/").
+    /// * `directory` - Identifier of the directory to copy.
+    ///   Identifies a [`Directory`].
+    /// * `opts` - Optional arguments, see [`DirectoryWithDirectoryOpts`].
+    pub fn with_directory_opts<'a>(
+        &self,
+        path: impl Into<String>,
+        directory: DirectoryId,
+        opts: DirectoryWithDirectoryOpts<'a>,
+    ) -> Directory {
+        let mut query = self.selection.select("withDirectory");
+
+        query = query.arg("path", path.into());
+        query = query.arg("directory", directory);
+        if let Some(exclude) = opts.exclude {
+            query = query.arg("exclude", exclude);
+        }
+        if let Some(include) = opts.include {
+            query = query.arg("include", include);
+        }
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Retrieves this directory plus a directory written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written directory (e.g., "/src/").
+    /// * `directory` - Identifier of the directory to copy.
+    ///   Identifies a [`Directory`].
+    /// * `opts` - Optional arguments, see [`DirectoryWithDirectoryOpts`].
+    pub fn with_directory_with<'a>(
+        &self,
+        path: impl Into<String>,
+        directory: DirectoryId,
+        opts: impl FnOnce(DirectoryWithDirectoryOpts<'a>) -> DirectoryWithDirectoryOpts<'a>,
+    ) -> Directory {
+        self.with_directory_opts(path, directory, opts(Default::default()))
+    }
+    /// Retrieves this directory plus the contents of the given file copied to the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`DirectoryWithFileOpts`], are taken by [`Self::with_file_opts`] and [`Self::with_file_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the copied file (e.g., "/file.txt").
+    /// * `source` - Identifier of the file to copy.
+    ///   Identifies a [`File`].
+    pub fn with_file(&self, path: impl Into<String>, source: FileId) -> Directory {
+        let mut query = self.selection.select("withFile");
+
+        query = query.arg("path", path.into());
+        query = query.arg("source", source);
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Retrieves this directory plus the contents of the given file copied to the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the copied file (e.g., "/file.txt").
+    /// * `source` - Identifier of the file to copy.
+    ///   Identifies a [`File`].
+    /// * `opts` - Optional arguments, see [`DirectoryWithFileOpts`].
+    pub fn with_file_opts(
+        &self,
+        path: impl Into<String>,
+        source: FileId,
+        opts: DirectoryWithFileOpts,
+    ) -> Directory {
+        let mut query = self.selection.select("withFile");
+
+        query = query.arg("path", path.into());
+        query = query.arg("source", source);
+        if let Some(permissions) = opts.permissions {
+            query = query.arg("permissions", permissions);
+        }
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Retrieves this directory plus the contents of the given file copied to the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the copied file (e.g., "/file.txt").
+    /// * `source` - Identifier of the file to copy.
+    ///   Identifies a [`File`].
+    /// * `opts` - Optional arguments, see [`DirectoryWithFileOpts`].
+    pub fn with_file_with(
+        &self,
+        path: impl Into<String>,
+        source: FileId,
+        opts: impl FnOnce(DirectoryWithFileOpts) -> DirectoryWithFileOpts,
+    ) -> Directory {
+        self.with_file_opts(path, source, opts(Default::default()))
+    }
+    /// Retrieves this directory plus a new directory created at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`DirectoryWithNewDirectoryOpts`], are taken by [`Self::with_new_directory_opts`] and [`Self::with_new_directory_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the directory created (e.g., "/logs").
+    pub fn with_new_directory(&self, path: impl Into<String>) -> Directory {
+        let mut query = self.selection.select("withNewDirectory");
+
+        query = query.arg("path", path.into());
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Retrieves this directory plus a new directory created at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the directory created (e.g., "/logs").
+    /// * `opts` - Optional arguments, see [`DirectoryWithNewDirectoryOpts`].
+    pub fn with_new_directory_opts(
+        &self,
+        path: impl Into<String>,
+        opts: DirectoryWithNewDirectoryOpts,
+    ) -> Directory {
+        let mut query = self.selection.select("withNewDirectory");
+
+        query = query.arg("path", path.into());
+        if let Some(permissions) = opts.permissions {
+            query = query.arg("permissions", permissions);
+        }
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Retrieves this directory plus a new directory created at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the directory created (e.g., "/logs").
+    /// * `opts` - Optional arguments, see [`DirectoryWithNewDirectoryOpts`].
+    pub fn with_new_directory_with(
+        &self,
+        path: impl Into<String>,
+        opts: impl FnOnce(DirectoryWithNewDirectoryOpts) -> DirectoryWithNewDirectoryOpts,
+    ) -> Directory {
+        self.with_new_directory_opts(path, opts(Default::default()))
+    }
+    /// Retrieves this directory plus a new file written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`DirectoryWithNewFileOpts`], are taken by [`Self::with_new_file_opts`] and [`Self::with_new_file_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written file (e.g., "/file.txt").
+    /// * `contents` - Content of the written file (e.g., "Hello world!").
+    pub fn with_new_file(&self, path: impl Into<String>, contents: impl Into<String>) -> Directory {
+        let mut query = self.selection.select("withNewFile");
+
+        query = query.arg("path", path.into());
+        query = query.arg("contents", contents.into());
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Retrieves this directory plus a new file written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written file (e.g., "/file.txt").
+    /// * `contents` - Content of the written file (e.g., "Hello world!").
+    /// * `opts` - Optional arguments, see [`DirectoryWithNewFileOpts`].
+    pub fn with_new_file_opts(
+        &self,
+        path: impl Into<String>,
+        contents: impl Into<String>,
+        opts: DirectoryWithNewFileOpts,
+    ) -> Directory {
+        let mut query = self.selection.select("withNewFile");
+
+        query = query.arg("path", path.into());
+        query = query.arg("contents", contents.into());
+        if let Some(permissions) = opts.permissions {
+            query = query.arg("permissions", permissions);
+        }
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Retrieves this directory plus a new file written at the given path.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written file (e.g., "/file.txt").
+    /// * `contents` - Content of the written file (e.g., "Hello world!").
+    /// * `opts` - Optional arguments, see [`DirectoryWithNewFileOpts`].
+    pub fn with_new_file_with(
+        &self,
+        path: impl Into<String>,
+        contents: impl Into<String>,
+        opts: impl FnOnce(DirectoryWithNewFileOpts) -> DirectoryWithNewFileOpts,
+    ) -> Directory {
+        self.with_new_file_opts(path, contents, opts(Default::default()))
+    }
+    /// Retrieves this directory with all file/dir timestamps set to the given time.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - Timestamp to set dir/files in.
+    ///
+    ///   Formatted in seconds following Unix epoch (e.g., 1672531199).
+    pub fn with_timestamps(&self, timestamp: isize) -> Directory {
+        let mut query = self.selection.select("withTimestamps");
+
+        query = query.arg("timestamp", timestamp);
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Retrieves this directory with the directory at the given path removed.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the directory to remove (e.g., ".github/").
+    pub fn without_directory(&self, path: impl Into<String>) -> Directory {
+        let mut query = self.selection.select("withoutDirectory");
+
+        query = query.arg("path", path.into());
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Retrieves this directory with the file at the given path removed.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the file to remove (e.g., "/file.txt").
+    pub fn without_file(&self, path: impl Into<String>) -> Directory {
+        let mut query = self.selection.select("withoutFile");
+
+        query = query.arg("path", path.into());
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+}
@@ -0,0 +1,126 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Clone, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum CacheSharingMode {
+    LOCKED,
+    SHARED,
+    PRIVATE,
+    /// A value added to the schema after this was generated
+    Unknown(String),
+}
+
+impl CacheSharingMode {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::LOCKED => "LOCKED",
+            Self::SHARED => "SHARED",
+            Self::PRIVATE => "PRIVATE",
+            Self::Unknown(value) => value,
+        }
+    }
+}
+
+impl AsRef<str> for CacheSharingMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for CacheSharingMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for CacheSharingMode {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "LOCKED" => Self::LOCKED,
+            "SHARED" => Self::SHARED,
+            "PRIVATE" => Self::PRIVATE,
+            _ => Self::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for CacheSharingMode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for CacheSharingMode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+
+        Ok(match value.parse() {
+            Ok(value) => value,
+            Err(never) => match never {},
+        })
+    }
+}
+#[derive(Clone, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum NetworkProtocol {
+    TCP,
+    UDP,
+    /// A value added to the schema after this was generated
+    Unknown(String),
+}
+
+impl NetworkProtocol {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::TCP => "TCP",
+            Self::UDP => "UDP",
+            Self::Unknown(value) => value,
+        }
+    }
+}
+
+impl AsRef<str> for NetworkProtocol {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for NetworkProtocol {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for NetworkProtocol {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "TCP" => Self::TCP,
+            "UDP" => Self::UDP,
+            _ => Self::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl Serialize for NetworkProtocol {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for NetworkProtocol {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+
+        Ok(match value.parse() {
+            Ok(value) => value,
+            Err(never) => match never {},
+        })
+    }
+}
@@ -0,0 +1,87 @@
+use crate::client::graphql_client;
+use crate::querybuilder::Selection;
+use dagger_core::connect_params::ConnectParams;
+use std::sync::Arc;
+use tokio::process::Child;
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct File {
+    pub proc: Arc<Child>,
+    pub selection: Selection,
+    pub conn: ConnectParams,
+}
+
+impl File {
+    /// Retrieves the contents of the file.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn contents(&self) -> eyre::Result<String> {
+        let query = self.selection.select("contents");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// Writes the file to a file path on the host.
+    ///
+    /// Executes the query and returns its result.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the written directory (e.g., "output.txt").
+    pub async fn export(&self, path: impl Into<String>) -> eyre::Result<bool> {
+        let mut query = self.selection.select("export");
+
+        query = query.arg("path", path.into());
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// Retrieves the content-addressed identifier of the file.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn id(&self) -> eyre::Result<FileId> {
+        let query = self.selection.select("id");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// Retrieves a secret referencing the contents of this file.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Secret`] is awaited.
+    pub fn secret(&self) -> Secret {
+        let query = self.selection.select("secret");
+
+        return Secret {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Gets the size of the file, in bytes.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn size(&self) -> eyre::Result<isize> {
+        let query = self.selection.select("size");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// Retrieves this file with its created/modified timestamps set to the given time.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - Timestamp to set dir/files in.
+    ///
+    ///   Formatted in seconds following Unix epoch (e.g., 1672531199).
+    pub fn with_timestamps(&self, timestamp: isize) -> File {
+        let mut query = self.selection.select("withTimestamps");
+
+        query = query.arg("timestamp", timestamp);
+
+        return File {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+}
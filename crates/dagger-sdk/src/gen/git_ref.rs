@@ -0,0 +1,98 @@
+use crate::client::graphql_client;
+use crate::querybuilder::Selection;
+use dagger_core::connect_params::ConnectParams;
+use derive_builder::Builder;
+use std::sync::Arc;
+use tokio::process::Child;
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct GitRef {
+    pub proc: Arc<Child>,
+    pub selection: Selection,
+    pub conn: ConnectParams,
+}
+
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct GitRefTreeOpts<'a> {
+    #[builder(setter(into, strip_option), default)]
+    pub ssh_known_hosts: Option<&'a str>,
+    #[builder(setter(into, strip_option), default)]
+    pub ssh_auth_socket: Option<SocketId>,
+}
+
+impl<'a> GitRefTreeOpts<'a> {
+    pub fn ssh_known_hosts(mut self, ssh_known_hosts: &'a str) -> Self {
+        self.ssh_known_hosts = Some(ssh_known_hosts);
+        self
+    }
+    pub fn ssh_auth_socket(mut self, ssh_auth_socket: impl Into<SocketId>) -> Self {
+        self.ssh_auth_socket = Some(ssh_auth_socket.into());
+        self
+    }
+}
+
+impl GitRef {
+    /// The digest of the current value of this ref.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn digest(&self) -> eyre::Result<String> {
+        let query = self.selection.select("digest");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// The filesystem tree at this ref.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`GitRefTreeOpts`], are taken by [`Self::tree_opts`] and [`Self::tree_with`].
+    pub fn tree(&self) -> Directory {
+        let query = self.selection.select("tree");
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// The filesystem tree at this ref.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`GitRefTreeOpts`].
+    pub fn tree_opts<'a>(&self, opts: GitRefTreeOpts<'a>) -> Directory {
+        let mut query = self.selection.select("tree");
+
+        if let Some(ssh_known_hosts) = opts.ssh_known_hosts {
+            query = query.arg("sshKnownHosts", ssh_known_hosts);
+        }
+        if let Some(ssh_auth_socket) = opts.ssh_auth_socket {
+            query = query.arg("sshAuthSocket", ssh_auth_socket);
+        }
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// The filesystem tree at this ref.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`GitRefTreeOpts`].
+    pub fn tree_with<'a>(
+        &self,
+        opts: impl FnOnce(GitRefTreeOpts<'a>) -> GitRefTreeOpts<'a>,
+    ) -> Directory {
+        self.tree_opts(opts(Default::default()))
+    }
+}
@@ -0,0 +1,87 @@
+use crate::client::graphql_client;
+use crate::querybuilder::Selection;
+use dagger_core::connect_params::ConnectParams;
+use std::sync::Arc;
+use tokio::process::Child;
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct GitRepository {
+    pub proc: Arc<Child>,
+    pub selection: Selection,
+    pub conn: ConnectParams,
+}
+
+impl GitRepository {
+    /// Returns details on one branch.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`GitRef`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Branch's name (e.g., "main").
+    pub fn branch(&self, name: impl Into<String>) -> GitRef {
+        let mut query = self.selection.select("branch");
+
+        query = query.arg("name", name.into());
+
+        return GitRef {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Lists of branches on the repository.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn branches(&self) -> eyre::Result<Vec<String>> {
+        let query = self.selection.select("branches");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// Returns details on one commit.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`GitRef`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Identifier of the commit (e.g., "b6315d8f2810962c601af73f86831f6866ea798b").
+    pub fn commit(&self, id: impl Into<String>) -> GitRef {
+        let mut query = self.selection.select("commit");
+
+        query = query.arg("id", id.into());
+
+        return GitRef {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Returns details on one tag.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`GitRef`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Tag's name (e.g., "v0.3.9").
+    pub fn tag(&self, name: impl Into<String>) -> GitRef {
+        let mut query = self.selection.select("tag");
+
+        query = query.arg("name", name.into());
+
+        return GitRef {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Lists of tags on the repository.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn tags(&self) -> eyre::Result<Vec<String>> {
+        let query = self.selection.select("tags");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+}
@@ -0,0 +1,219 @@
+use crate::querybuilder::Selection;
+use dagger_core::connect_params::ConnectParams;
+use derive_builder::Builder;
+use std::sync::Arc;
+use tokio::process::Child;
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct Host {
+    pub proc: Arc<Child>,
+    pub selection: Selection,
+    pub conn: ConnectParams,
+}
+
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct HostDirectoryOpts<'a> {
+    /// Exclude artifacts that match the given pattern (e.g., \["node_modules/", ".git*"\]).
+    #[builder(setter(into, strip_option), default)]
+    pub exclude: Option<Vec<&'a str>>,
+    /// Include only artifacts that match the given pattern (e.g., \["app/", "package.*"\]).
+    #[builder(setter(into, strip_option), default)]
+    pub include: Option<Vec<&'a str>>,
+}
+
+impl<'a> HostDirectoryOpts<'a> {
+    /// Exclude artifacts that match the given pattern (e.g., \["node_modules/", ".git*"\]).
+    pub fn exclude(mut self, exclude: Vec<&'a str>) -> Self {
+        self.exclude = Some(exclude);
+        self
+    }
+    /// Include only artifacts that match the given pattern (e.g., \["app/", "package.*"\]).
+    pub fn include(mut self, include: Vec<&'a str>) -> Self {
+        self.include = Some(include);
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct HostWorkdirOpts<'a> {
+    /// Exclude artifacts that match the given pattern (e.g., \["node_modules/", ".git*"\]).
+    #[builder(setter(into, strip_option), default)]
+    pub exclude: Option<Vec<&'a str>>,
+    /// Include only artifacts that match the given pattern (e.g., \["app/", "package.*"\]).
+    #[builder(setter(into, strip_option), default)]
+    pub include: Option<Vec<&'a str>>,
+}
+
+impl<'a> HostWorkdirOpts<'a> {
+    /// Exclude artifacts that match the given pattern (e.g., \["node_modules/", ".git*"\]).
+    pub fn exclude(mut self, exclude: Vec<&'a str>) -> Self {
+        self.exclude = Some(exclude);
+        self
+    }
+    /// Include only artifacts that match the given pattern (e.g., \["app/", "package.*"\]).
+    pub fn include(mut self, include: Vec<&'a str>) -> Self {
+        self.include = Some(include);
+        self
+    }
+}
+
+impl Host {
+    /// Accesses a directory on the host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`HostDirectoryOpts`], are taken by [`Self::directory_opts`] and [`Self::directory_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the directory to access (e.g., ".").
+    pub fn directory(&self, path: impl Into<String>) -> Directory {
+        let mut query = self.selection.select("directory");
+
+        query = query.arg("path", path.into());
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Accesses a directory on the host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the directory to access (e.g., ".").
+    /// * `opts` - Optional arguments, see [`HostDirectoryOpts`].
+    pub fn directory_opts<'a>(
+        &self,
+        path: impl Into<String>,
+        opts: HostDirectoryOpts<'a>,
+    ) -> Directory {
+        let mut query = self.selection.select("directory");
+
+        query = query.arg("path", path.into());
+        if let Some(exclude) = opts.exclude {
+            query = query.arg("exclude", exclude);
+        }
+        if let Some(include) = opts.include {
+            query = query.arg("include", include);
+        }
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Accesses a directory on the host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the directory to access (e.g., ".").
+    /// * `opts` - Optional arguments, see [`HostDirectoryOpts`].
+    pub fn directory_with<'a>(
+        &self,
+        path: impl Into<String>,
+        opts: impl FnOnce(HostDirectoryOpts<'a>) -> HostDirectoryOpts<'a>,
+    ) -> Directory {
+        self.directory_opts(path, opts(Default::default()))
+    }
+    /// Accesses an environment variable on the host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`HostVariable`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the environment variable (e.g., "PATH").
+    pub fn env_variable(&self, name: impl Into<String>) -> HostVariable {
+        let mut query = self.selection.select("envVariable");
+
+        query = query.arg("name", name.into());
+
+        return HostVariable {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Accesses a Unix socket on the host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Socket`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Location of the Unix socket (e.g., "/var/run/docker.sock").
+    #[cfg(feature = "socket")]
+    pub fn unix_socket(&self, path: impl Into<String>) -> Socket {
+        let mut query = self.selection.select("unixSocket");
+
+        query = query.arg("path", path.into());
+
+        return Socket {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Retrieves the current working directory on the host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`HostWorkdirOpts`], are taken by [`Self::workdir_opts`] and [`Self::workdir_with`].
+    pub fn workdir(&self) -> Directory {
+        let query = self.selection.select("workdir");
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Retrieves the current working directory on the host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`HostWorkdirOpts`].
+    pub fn workdir_opts<'a>(&self, opts: HostWorkdirOpts<'a>) -> Directory {
+        let mut query = self.selection.select("workdir");
+
+        if let Some(exclude) = opts.exclude {
+            query = query.arg("exclude", exclude);
+        }
+        if let Some(include) = opts.include {
+            query = query.arg("include", include);
+        }
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Retrieves the current working directory on the host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`HostWorkdirOpts`].
+    pub fn workdir_with<'a>(
+        &self,
+        opts: impl FnOnce(HostWorkdirOpts<'a>) -> HostWorkdirOpts<'a>,
+    ) -> Directory {
+        self.workdir_opts(opts(Default::default()))
+    }
+}
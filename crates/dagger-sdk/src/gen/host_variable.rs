@@ -0,0 +1,37 @@
+use crate::client::graphql_client;
+use crate::querybuilder::Selection;
+use dagger_core::connect_params::ConnectParams;
+use std::sync::Arc;
+use tokio::process::Child;
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct HostVariable {
+    pub proc: Arc<Child>,
+    pub selection: Selection,
+    pub conn: ConnectParams,
+}
+
+impl HostVariable {
+    /// A secret referencing the value of this variable.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Secret`] is awaited.
+    pub fn secret(&self) -> Secret {
+        let query = self.selection.select("secret");
+
+        return Secret {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// The value of this variable.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn value(&self) -> eyre::Result<String> {
+        let query = self.selection.select("value");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+}
@@ -0,0 +1,31 @@
+use crate::client::graphql_client;
+use crate::querybuilder::Selection;
+use dagger_core::connect_params::ConnectParams;
+use std::sync::Arc;
+use tokio::process::Child;
+
+#[derive(Debug, Clone)]
+pub struct Label {
+    pub proc: Arc<Child>,
+    pub selection: Selection,
+    pub conn: ConnectParams,
+}
+
+impl Label {
+    /// The label name.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn name(&self) -> eyre::Result<String> {
+        let query = self.selection.select("name");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// The label value.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn value(&self) -> eyre::Result<String> {
+        let query = self.selection.select("value");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+}
@@ -0,0 +1,46 @@
+pub mod api;
+pub mod blocking;
+mod cache_volume;
+pub use cache_volume::*;
+mod container;
+pub use container::*;
+mod directory;
+pub use directory::*;
+mod enums;
+pub use enums::*;
+mod env_variable;
+pub use env_variable::*;
+mod file;
+pub use file::*;
+#[cfg(feature = "git")]
+mod git_ref;
+#[cfg(feature = "git")]
+pub use git_ref::*;
+#[cfg(feature = "git")]
+mod git_repository;
+#[cfg(feature = "git")]
+pub use git_repository::*;
+mod host;
+pub use host::*;
+mod host_variable;
+pub use host_variable::*;
+mod inputs;
+pub use inputs::*;
+mod label;
+pub use label::*;
+mod port;
+pub use port::*;
+#[cfg(feature = "project")]
+mod project;
+#[cfg(feature = "project")]
+pub use project::*;
+mod query;
+pub use query::*;
+mod scalars;
+pub use scalars::*;
+mod secret;
+pub use secret::*;
+#[cfg(feature = "socket")]
+mod socket;
+#[cfg(feature = "socket")]
+pub use socket::*;
@@ -0,0 +1,41 @@
+use crate::client::graphql_client;
+use crate::querybuilder::Selection;
+use dagger_core::connect_params::ConnectParams;
+use std::sync::Arc;
+use tokio::process::Child;
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct Port {
+    pub proc: Arc<Child>,
+    pub selection: Selection,
+    pub conn: ConnectParams,
+}
+
+impl Port {
+    /// The port description.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn description(&self) -> eyre::Result<String> {
+        let query = self.selection.select("description");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// The port number.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn port(&self) -> eyre::Result<isize> {
+        let query = self.selection.select("port");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// The transport layer network protocol.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn protocol(&self) -> eyre::Result<NetworkProtocol> {
+        let query = self.selection.select("protocol");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+}
@@ -0,0 +1,73 @@
+use crate::client::graphql_client;
+use crate::querybuilder::Selection;
+use dagger_core::connect_params::ConnectParams;
+use std::sync::Arc;
+use tokio::process::Child;
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct Project {
+    pub proc: Arc<Child>,
+    pub selection: Selection,
+    pub conn: ConnectParams,
+}
+
+impl Project {
+    /// extensions in this project
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Project`] is awaited.
+    pub fn extensions(&self) -> Vec<Project> {
+        let query = self.selection.select("extensions");
+
+        return vec![Project {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        }];
+    }
+    /// Code files generated by the SDKs in the project
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    pub fn generated_code(&self) -> Directory {
+        let query = self.selection.select("generatedCode");
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// install the project's schema
+    ///
+    /// Executes the query and returns its result.
+    pub async fn install(&self) -> eyre::Result<bool> {
+        let query = self.selection.select("install");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// name of the project
+    ///
+    /// Executes the query and returns its result.
+    pub async fn name(&self) -> eyre::Result<String> {
+        let query = self.selection.select("name");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// schema provided by the project
+    ///
+    /// Executes the query and returns its result.
+    pub async fn schema(&self) -> eyre::Result<String> {
+        let query = self.selection.select("schema");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// sdk used to generate code for and/or execute this project
+    ///
+    /// Executes the query and returns its result.
+    pub async fn sdk(&self) -> eyre::Result<String> {
+        let query = self.selection.select("sdk");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+}
@@ -0,0 +1,583 @@
+use crate::client::graphql_client;
+use crate::querybuilder::Selection;
+use dagger_core::connect_params::ConnectParams;
+use derive_builder::Builder;
+use std::sync::Arc;
+use tokio::process::Child;
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct Query {
+    pub proc: Arc<Child>,
+    pub selection: Selection,
+    pub conn: ConnectParams,
+}
+
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct QueryContainerOpts {
+    /// Identifies a [`Container`].
+    #[builder(setter(into, strip_option), default)]
+    pub id: Option<ContainerId>,
+    #[builder(setter(into, strip_option), default)]
+    pub platform: Option<Platform>,
+}
+
+impl QueryContainerOpts {
+    /// Identifies a [`Container`].
+    pub fn id(mut self, id: impl Into<ContainerId>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+    pub fn platform(mut self, platform: impl Into<Platform>) -> Self {
+        self.platform = Some(platform.into());
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct QueryDirectoryOpts {
+    /// Identifies a [`Directory`].
+    #[builder(setter(into, strip_option), default)]
+    pub id: Option<DirectoryId>,
+}
+
+impl QueryDirectoryOpts {
+    /// Identifies a [`Directory`].
+    pub fn id(mut self, id: impl Into<DirectoryId>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+}
+#[cfg(feature = "git")]
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct QueryGitOpts {
+    /// Set to true to keep .git directory.
+    #[builder(setter(into, strip_option), default)]
+    pub keep_git_dir: Option<bool>,
+    /// A service which must be started before the repo is fetched.
+    /// Identifies a [`Container`].
+    #[builder(setter(into, strip_option), default)]
+    pub experimental_service_host: Option<ContainerId>,
+}
+
+#[cfg(feature = "git")]
+impl QueryGitOpts {
+    /// Set to true to keep .git directory.
+    pub fn keep_git_dir(mut self, keep_git_dir: bool) -> Self {
+        self.keep_git_dir = Some(keep_git_dir);
+        self
+    }
+    /// A service which must be started before the repo is fetched.
+    /// Identifies a [`Container`].
+    pub fn experimental_service_host(
+        mut self,
+        experimental_service_host: impl Into<ContainerId>,
+    ) -> Self {
+        self.experimental_service_host = Some(experimental_service_host.into());
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct QueryHttpOpts {
+    /// A service which must be started before the URL is fetched.
+    /// Identifies a [`Container`].
+    #[builder(setter(into, strip_option), default)]
+    pub experimental_service_host: Option<ContainerId>,
+}
+
+impl QueryHttpOpts {
+    /// A service which must be started before the URL is fetched.
+    /// Identifies a [`Container`].
+    pub fn experimental_service_host(
+        mut self,
+        experimental_service_host: impl Into<ContainerId>,
+    ) -> Self {
+        self.experimental_service_host = Some(experimental_service_host.into());
+        self
+    }
+}
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct QueryPipelineOpts<'a> {
+    /// Pipeline description.
+    #[builder(setter(into, strip_option), default)]
+    pub description: Option<&'a str>,
+    /// Pipeline labels.
+    #[builder(setter(into, strip_option), default)]
+    pub labels: Option<Vec<PipelineLabel>>,
+}
+
+impl<'a> QueryPipelineOpts<'a> {
+    /// Pipeline description.
+    pub fn description(mut self, description: &'a str) -> Self {
+        self.description = Some(description);
+        self
+    }
+    /// Pipeline labels.
+    pub fn labels(mut self, labels: Vec<PipelineLabel>) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+}
+#[cfg(feature = "socket")]
+#[derive(Builder, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct QuerySocketOpts {
+    #[builder(setter(into, strip_option), default)]
+    pub id: Option<SocketId>,
+}
+
+#[cfg(feature = "socket")]
+impl QuerySocketOpts {
+    pub fn id(mut self, id: impl Into<SocketId>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+}
+
+impl Query {
+    /// Constructs a cache volume for a given cache key.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`CacheVolume`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A string identifier to target this cache volume (e.g., "modules-cache").
+    pub fn cache_volume(&self, key: impl Into<String>) -> CacheVolume {
+        let mut query = self.selection.select("cacheVolume");
+
+        query = query.arg("key", key.into());
+
+        return CacheVolume {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Loads a container from ID.
+    /// Null ID returns an empty container (scratch).
+    /// Optional platform argument initializes new containers to execute and publish as that platform.
+    /// Platform defaults to that of the builder's host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// Optional arguments, see [`QueryContainerOpts`], are taken by [`Self::container_opts`] and [`Self::container_with`].
+    pub fn container(&self) -> Container {
+        let query = self.selection.select("container");
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Loads a container from ID.
+    /// Null ID returns an empty container (scratch).
+    /// Optional platform argument initializes new containers to execute and publish as that platform.
+    /// Platform defaults to that of the builder's host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`QueryContainerOpts`].
+    pub fn container_opts(&self, opts: QueryContainerOpts) -> Container {
+        let mut query = self.selection.select("container");
+
+        if let Some(id) = opts.id {
+            query = query.arg("id", id);
+        }
+        if let Some(platform) = opts.platform {
+            query = query.arg("platform", platform);
+        }
+
+        return Container {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Loads a container from ID.
+    /// Null ID returns an empty container (scratch).
+    /// Optional platform argument initializes new containers to execute and publish as that platform.
+    /// Platform defaults to that of the builder's host.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Container`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`QueryContainerOpts`].
+    pub fn container_with(
+        &self,
+        opts: impl FnOnce(QueryContainerOpts) -> QueryContainerOpts,
+    ) -> Container {
+        self.container_opts(opts(Default::default()))
+    }
+    /// The default platform of the builder.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn default_platform(&self) -> eyre::Result<Platform> {
+        let query = self.selection.select("defaultPlatform");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// Load a directory by ID. No argument produces an empty directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// Optional arguments, see [`QueryDirectoryOpts`], are taken by [`Self::directory_opts`] and [`Self::directory_with`].
+    pub fn directory(&self) -> Directory {
+        let query = self.selection.select("directory");
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Load a directory by ID. No argument produces an empty directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`QueryDirectoryOpts`].
+    pub fn directory_opts(&self, opts: QueryDirectoryOpts) -> Directory {
+        let mut query = self.selection.select("directory");
+
+        if let Some(id) = opts.id {
+            query = query.arg("id", id);
+        }
+
+        return Directory {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Load a directory by ID. No argument produces an empty directory.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Directory`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`QueryDirectoryOpts`].
+    pub fn directory_with(
+        &self,
+        opts: impl FnOnce(QueryDirectoryOpts) -> QueryDirectoryOpts,
+    ) -> Directory {
+        self.directory_opts(opts(Default::default()))
+    }
+    /// Loads a file by ID.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Identifies a [`File`].
+    pub fn file(&self, id: FileId) -> File {
+        let mut query = self.selection.select("file");
+
+        query = query.arg("id", id);
+
+        return File {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Queries a git repository.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`GitRepository`] is awaited.
+    ///
+    /// Optional arguments, see [`QueryGitOpts`], are taken by [`Self::git_opts`] and [`Self::git_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Url of the git repository.
+    ///   Can be formatted as <https://{host}/{owner}/{repo}>, git@{host}/{owner}/{repo}
+    ///   Suffix ".git" is optional.
+    #[cfg(feature = "git")]
+    pub fn git(&self, url: impl Into<String>) -> GitRepository {
+        let mut query = self.selection.select("git");
+
+        query = query.arg("url", url.into());
+
+        return GitRepository {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Queries a git repository.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`GitRepository`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Url of the git repository.
+    ///   Can be formatted as <https://{host}/{owner}/{repo}>, git@{host}/{owner}/{repo}
+    ///   Suffix ".git" is optional.
+    /// * `opts` - Optional arguments, see [`QueryGitOpts`].
+    #[cfg(feature = "git")]
+    pub fn git_opts(&self, url: impl Into<String>, opts: QueryGitOpts) -> GitRepository {
+        let mut query = self.selection.select("git");
+
+        query = query.arg("url", url.into());
+        if let Some(keep_git_dir) = opts.keep_git_dir {
+            query = query.arg("keepGitDir", keep_git_dir);
+        }
+        if let Some(experimental_service_host) = opts.experimental_service_host {
+            query = query.arg("experimentalServiceHost", experimental_service_host);
+        }
+
+        return GitRepository {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Queries a git repository.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`GitRepository`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Url of the git repository.
+    ///   Can be formatted as <https://{host}/{owner}/{repo}>, git@{host}/{owner}/{repo}
+    ///   Suffix ".git" is optional.
+    /// * `opts` - Optional arguments, see [`QueryGitOpts`].
+    #[cfg(feature = "git")]
+    pub fn git_with(
+        &self,
+        url: impl Into<String>,
+        opts: impl FnOnce(QueryGitOpts) -> QueryGitOpts,
+    ) -> GitRepository {
+        self.git_opts(url, opts(Default::default()))
+    }
+    /// Queries the host environment.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Host`] is awaited.
+    pub fn host(&self) -> Host {
+        let query = self.selection.select("host");
+
+        return Host {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Returns a file containing an http remote url content.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is awaited.
+    ///
+    /// Optional arguments, see [`QueryHttpOpts`], are taken by [`Self::http_opts`] and [`Self::http_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - HTTP url to get the content from (e.g., "<https://docs.dagger.io>").
+    pub fn http(&self, url: impl Into<String>) -> File {
+        let mut query = self.selection.select("http");
+
+        query = query.arg("url", url.into());
+
+        return File {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Returns a file containing an http remote url content.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - HTTP url to get the content from (e.g., "<https://docs.dagger.io>").
+    /// * `opts` - Optional arguments, see [`QueryHttpOpts`].
+    pub fn http_opts(&self, url: impl Into<String>, opts: QueryHttpOpts) -> File {
+        let mut query = self.selection.select("http");
+
+        query = query.arg("url", url.into());
+        if let Some(experimental_service_host) = opts.experimental_service_host {
+            query = query.arg("experimentalServiceHost", experimental_service_host);
+        }
+
+        return File {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Returns a file containing an http remote url content.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`File`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - HTTP url to get the content from (e.g., "<https://docs.dagger.io>").
+    /// * `opts` - Optional arguments, see [`QueryHttpOpts`].
+    pub fn http_with(
+        &self,
+        url: impl Into<String>,
+        opts: impl FnOnce(QueryHttpOpts) -> QueryHttpOpts,
+    ) -> File {
+        self.http_opts(url, opts(Default::default()))
+    }
+    /// Creates a named sub-pipeline.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Query`] is awaited.
+    ///
+    /// Optional arguments, see [`QueryPipelineOpts`], are taken by [`Self::pipeline_opts`] and [`Self::pipeline_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    pub fn pipeline(&self, name: impl Into<String>) -> Query {
+        let mut query = self.selection.select("pipeline");
+
+        query = query.arg("name", name.into());
+
+        return Query {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Creates a named sub-pipeline.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Query`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    /// * `opts` - Optional arguments, see [`QueryPipelineOpts`].
+    pub fn pipeline_opts<'a>(&self, name: impl Into<String>, opts: QueryPipelineOpts<'a>) -> Query {
+        let mut query = self.selection.select("pipeline");
+
+        query = query.arg("name", name.into());
+        if let Some(description) = opts.description {
+            query = query.arg("description", description);
+        }
+        if let Some(labels) = opts.labels {
+            query = query.arg("labels", labels);
+        }
+
+        return Query {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Creates a named sub-pipeline.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Query`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Pipeline name.
+    /// * `opts` - Optional arguments, see [`QueryPipelineOpts`].
+    pub fn pipeline_with<'a>(
+        &self,
+        name: impl Into<String>,
+        opts: impl FnOnce(QueryPipelineOpts<'a>) -> QueryPipelineOpts<'a>,
+    ) -> Query {
+        self.pipeline_opts(name, opts(Default::default()))
+    }
+    /// Look up a project by name
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Project`] is awaited.
+    #[cfg(feature = "project")]
+    pub fn project(&self, name: impl Into<String>) -> Project {
+        let mut query = self.selection.select("project");
+
+        query = query.arg("name", name.into());
+
+        return Project {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Loads a secret from its ID.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Secret`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Identifies a [`Secret`].
+    pub fn secret(&self, id: SecretId) -> Secret {
+        let mut query = self.selection.select("secret");
+
+        query = query.arg("id", id);
+
+        return Secret {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+    /// Loads a socket by its ID.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Socket`] is awaited.
+    ///
+    /// Optional arguments, see [`QuerySocketOpts`], are taken by [`Self::socket_opts`] and [`Self::socket_with`].
+    #[cfg(feature = "socket")]
+    pub fn socket(&self) -> Socket {
+        let query = self.selection.select("socket");
+
+        return Socket {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Loads a socket by its ID.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Socket`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`QuerySocketOpts`].
+    #[cfg(feature = "socket")]
+    pub fn socket_opts(&self, opts: QuerySocketOpts) -> Socket {
+        let mut query = self.selection.select("socket");
+
+        if let Some(id) = opts.id {
+            query = query.arg("id", id);
+        }
+
+        return Socket {
+            proc: self.proc.clone(),
+            selection: query,
+            conn: self.conn.clone(),
+        };
+    }
+
+    /// Loads a socket by its ID.
+    ///
+    /// Lazy, the query only runs once a field of the returned [`Socket`] is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `opts` - Optional arguments, see [`QuerySocketOpts`].
+    #[cfg(feature = "socket")]
+    pub fn socket_with(&self, opts: impl FnOnce(QuerySocketOpts) -> QuerySocketOpts) -> Socket {
+        self.socket_opts(opts(Default::default()))
+    }
+}
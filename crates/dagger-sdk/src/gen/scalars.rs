@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct CacheId(pub String);
+
+impl Into<CacheId> for &str {
+    fn into(self) -> CacheId {
+        CacheId(self.to_string())
+    }
+}
+
+impl Into<CacheId> for String {
+    fn into(self) -> CacheId {
+        CacheId(self.clone())
+    }
+}
+/// Identifies a [`Container`].
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct ContainerId(pub String);
+
+impl Into<ContainerId> for &str {
+    fn into(self) -> ContainerId {
+        ContainerId(self.to_string())
+    }
+}
+
+impl Into<ContainerId> for String {
+    fn into(self) -> ContainerId {
+        ContainerId(self.clone())
+    }
+}
+/// Identifies a [`Directory`].
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct DirectoryId(pub String);
+
+impl Into<DirectoryId> for &str {
+    fn into(self) -> DirectoryId {
+        DirectoryId(self.to_string())
+    }
+}
+
+impl Into<DirectoryId> for String {
+    fn into(self) -> DirectoryId {
+        DirectoryId(self.clone())
+    }
+}
+/// Identifies a [`File`].
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct FileId(pub String);
+
+impl Into<FileId> for &str {
+    fn into(self) -> FileId {
+        FileId(self.to_string())
+    }
+}
+
+impl Into<FileId> for String {
+    fn into(self) -> FileId {
+        FileId(self.clone())
+    }
+}
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Platform(pub String);
+
+impl Into<Platform> for &str {
+    fn into(self) -> Platform {
+        Platform(self.to_string())
+    }
+}
+
+impl Into<Platform> for String {
+    fn into(self) -> Platform {
+        Platform(self.clone())
+    }
+}
+/// Identifies a [`Secret`].
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct SecretId(pub String);
+
+impl Into<SecretId> for &str {
+    fn into(self) -> SecretId {
+        SecretId(self.to_string())
+    }
+}
+
+impl Into<SecretId> for String {
+    fn into(self) -> SecretId {
+        SecretId(self.clone())
+    }
+}
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct SocketId(pub String);
+
+impl Into<SocketId> for &str {
+    fn into(self) -> SocketId {
+        SocketId(self.to_string())
+    }
+}
+
+impl Into<SocketId> for String {
+    fn into(self) -> SocketId {
+        SocketId(self.clone())
+    }
+}
@@ -0,0 +1,33 @@
+use crate::client::graphql_client;
+use crate::querybuilder::Selection;
+use dagger_core::connect_params::ConnectParams;
+use std::sync::Arc;
+use tokio::process::Child;
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct Secret {
+    pub proc: Arc<Child>,
+    pub selection: Selection,
+    pub conn: ConnectParams,
+}
+
+impl Secret {
+    /// The identifier for this secret.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn id(&self) -> eyre::Result<SecretId> {
+        let query = self.selection.select("id");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+    /// The value of this secret.
+    ///
+    /// Executes the query and returns its result.
+    pub async fn plaintext(&self) -> eyre::Result<String> {
+        let query = self.selection.select("plaintext");
+
+        query.execute(&graphql_client(&self.conn)).await
+    }
+}
@@ -0,0 +1,392 @@
+//! Excludes of host directories from the ignore files in them.
+//!
+//! The ignore files are evaluated on this side, with the semantics of their tools, into the exact
+//! paths to exclude. Of a `.gitignore`, a negated pattern can't bring back files of an ignored
+//! directory, while the exceptions of a `.dockerignore` can.
+
+use std::ops::BitOr;
+use std::path::{Component, Path};
+
+use eyre::Context;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+
+use crate::gen::{Directory, Host, HostDirectoryOpts};
+
+/// The kinds of ignore files to honor, combined with `|`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IgnoreFiles(u8);
+
+impl IgnoreFiles {
+    /// The `.gitignore` files of the directory and its subdirectories
+    pub const GIT: Self = Self(1);
+    /// The `.dockerignore` file at the root of the directory
+    pub const DOCKER: Self = Self(1 << 1);
+
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for IgnoreFiles {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl Host {
+    /// Like [`Self::directory`], without what the `ignore_files` of the directory ignore.
+    ///
+    /// `path` is read on this side, relative to the working directory of the process.
+    pub fn directory_ignoring(
+        &self,
+        path: impl Into<String>,
+        ignore_files: IgnoreFiles,
+    ) -> eyre::Result<Directory> {
+        let path = path.into();
+        let excluded = ignored_paths(&path, ignore_files)?;
+        let opts =
+            HostDirectoryOpts::default().exclude(excluded.iter().map(|e| e.as_str()).collect());
+
+        Ok(self.directory_opts(path, opts))
+    }
+}
+
+/// Exclude patterns matching exactly what `ignore_files` ignore under `path`, relative to it
+pub fn ignored_paths(
+    path: impl AsRef<Path>,
+    ignore_files: IgnoreFiles,
+) -> eyre::Result<Vec<String>> {
+    let root = path.as_ref();
+    let docker = match ignore_files.contains(IgnoreFiles::DOCKER) {
+        true => DockerIgnore::read(root)?,
+        false => DockerIgnore::default(),
+    };
+
+    let mut walk = Walk {
+        root,
+        git: ignore_files.contains(IgnoreFiles::GIT),
+        docker,
+        excluded: Vec::new(),
+    };
+    walk.dir(root, &mut Vec::new())?;
+
+    Ok(walk.excluded)
+}
+
+struct Walk<'a> {
+    root: &'a Path,
+    git: bool,
+    docker: DockerIgnore,
+    excluded: Vec<String>,
+}
+
+impl Walk<'_> {
+    /// `gitignores` are the ones of the parents of `dir`, the deepest last
+    fn dir(&mut self, dir: &Path, gitignores: &mut Vec<Gitignore>) -> eyre::Result<()> {
+        let gitignore = dir.join(".gitignore");
+        let has_gitignore = self.git && gitignore.is_file();
+        if has_gitignore {
+            let (gitignore, err) = Gitignore::new(&gitignore);
+            if let Some(err) = err {
+                return Err(err).context(format!(
+                    "could not parse {}",
+                    dir.join(".gitignore").display()
+                ));
+            }
+            gitignores.push(gitignore);
+        }
+
+        let mut entries = std::fs::read_dir(dir)
+            .with_context(|| format!("could not read {}", dir.display()))?
+            .collect::<Result<Vec<_>, _>>()?;
+        entries.sort_by_key(|e| e.file_name());
+
+        for entry in entries {
+            let path = entry.path();
+            let is_dir = entry.file_type()?.is_dir();
+            let relative = path.strip_prefix(self.root)?;
+            let is_git_dir = is_dir && entry.file_name() == ".git";
+
+            // Git never tracks its own directory, otherwise the deepest .gitignore with a
+            // matching pattern decides
+            let git_ignored = (self.git && is_git_dir)
+                || gitignores
+                    .iter()
+                    .rev()
+                    .map(|g| g.matched(&path, is_dir))
+                    .find(|m| !m.is_none())
+                    .map(|m| m.is_ignore())
+                    .unwrap_or(false);
+            let docker_ignored = self.docker.is_ignored(relative, is_dir);
+
+            if is_dir
+                && !is_git_dir
+                && !git_ignored
+                && (!docker_ignored || self.docker.may_except_under(relative))
+            {
+                self.dir(&path, gitignores)?;
+            } else if git_ignored || docker_ignored {
+                self.excluded.push(exclude_pattern(relative));
+            }
+        }
+
+        if has_gitignore {
+            gitignores.pop();
+        }
+
+        Ok(())
+    }
+}
+
+/// The patterns of a `.dockerignore`, each matching a path when it matches the path or one of its
+/// parents, where the last matching one decides
+#[derive(Default)]
+struct DockerIgnore {
+    patterns: Vec<(Gitignore, bool)>,
+    /// The patterns of the exceptions, split into their components
+    exceptions: Vec<Vec<String>>,
+}
+
+impl DockerIgnore {
+    fn read(root: &Path) -> eyre::Result<Self> {
+        let path = root.join(".dockerignore");
+        if !path.is_file() {
+            return Ok(Self::default());
+        }
+
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("could not read {}", path.display()))?;
+
+        let mut ignore = Self::default();
+        for line in content.lines().map(|l| l.trim()) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (pattern, exception) = match line.strip_prefix('!') {
+                Some(pattern) => (pattern.trim(), true),
+                None => (line, false),
+            };
+            // Patterns are relative to the root, with or without a leading or trailing slash
+            let pattern = pattern.trim_matches('/');
+
+            let mut builder = GitignoreBuilder::new(root);
+            builder
+                .add_line(None, &format!("/{}", pattern))
+                .with_context(|| format!("invalid pattern {} in {}", line, path.display()))?;
+
+            ignore.patterns.push((builder.build()?, exception));
+            if exception {
+                ignore
+                    .exceptions
+                    .push(pattern.split('/').map(|c| c.to_string()).collect());
+            }
+        }
+
+        Ok(ignore)
+    }
+
+    fn is_ignored(&self, relative: &Path, is_dir: bool) -> bool {
+        self.patterns
+            .iter()
+            .rev()
+            .find(|(pattern, _)| {
+                pattern
+                    .matched_path_or_any_parents(relative, is_dir)
+                    .is_ignore()
+            })
+            .map(|(_, exception)| !exception)
+            .unwrap_or(false)
+    }
+
+    /// Whether an exception may match a path under the ignored directory `relative`, as one
+    /// matching the directory itself would have made it not ignored
+    fn may_except_under(&self, relative: &Path) -> bool {
+        let dir = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>();
+
+        self.exceptions.iter().any(|exception| {
+            exception.iter().any(|c| c.contains("**"))
+                || (exception.len() > dir.len()
+                    && dir.iter().zip(exception).all(|(name, pattern)| {
+                        name == pattern || pattern.contains(['*', '?', '['])
+                    }))
+        })
+    }
+}
+
+/// Matches `relative` literally, also on the engine's side which reads it as a glob
+fn exclude_pattern(relative: &Path) -> String {
+    relative
+        .components()
+        .filter_map(|c| match c {
+            Component::Normal(name) => Some(name.to_string_lossy()),
+            _ => None,
+        })
+        .map(|name| {
+            name.chars()
+                .flat_map(|c| match c {
+                    '*' | '?' | '[' | ']' | '\\' => vec!['\\', c],
+                    _ => vec![c],
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use pretty_assertions::assert_eq;
+
+    use super::{ignored_paths, IgnoreFiles};
+
+    fn write(root: &Path, path: &str, content: &str) {
+        let path = root.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    fn fixture() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+
+        write(
+            root,
+            ".gitignore",
+            "target/\n*.log\n!keep.log\n/build\nvendor/\n!vendor/keep.txt\n",
+        );
+        write(
+            root,
+            ".dockerignore",
+            "# docs are built separately\ndocs\n!docs/README.md\n**/*.tmp\n",
+        );
+        write(
+            root,
+            "src/.gitignore",
+            "*.bak\n!important.bak\n!debug.log\n",
+        );
+        for path in [
+            "app.log",
+            "keep.log",
+            "weird[1].log",
+            "build/out",
+            "docs/README.md",
+            "docs/guide.md",
+            "src/main.rs",
+            "src/build/generated.rs",
+            "src/cache.tmp",
+            "src/debug.log",
+            "src/important.bak",
+            "src/old.bak",
+            "target/debug/app",
+            "vendor/keep.txt",
+        ] {
+            write(root, path, "");
+        }
+
+        dir
+    }
+
+    #[test]
+    fn ignored_by_git_and_docker() {
+        let dir = fixture();
+
+        let ignored = ignored_paths(dir.path(), IgnoreFiles::GIT | IgnoreFiles::DOCKER).unwrap();
+
+        assert_eq!(
+            ignored,
+            vec![
+                "app.log",
+                "build",
+                "docs/guide.md",
+                "src/cache.tmp",
+                "src/old.bak",
+                "target",
+                "vendor",
+                r"weird\[1\].log",
+            ]
+        );
+    }
+
+    #[test]
+    fn ignored_by_git() {
+        let dir = fixture();
+
+        let ignored = ignored_paths(dir.path(), IgnoreFiles::GIT).unwrap();
+
+        assert_eq!(
+            ignored,
+            vec![
+                "app.log",
+                "build",
+                "src/old.bak",
+                "target",
+                "vendor",
+                r"weird\[1\].log"
+            ]
+        );
+    }
+
+    #[test]
+    fn ignored_by_docker() {
+        let dir = fixture();
+
+        let ignored = ignored_paths(dir.path(), IgnoreFiles::DOCKER).unwrap();
+
+        assert_eq!(ignored, vec!["docs/guide.md", "src/cache.tmp"]);
+    }
+
+    #[test]
+    fn docker_exceptions_in_order() {
+        let dir = fixture();
+        write(dir.path(), ".dockerignore", "!docs/README.md\ndocs\n");
+
+        let ignored = ignored_paths(dir.path(), IgnoreFiles::DOCKER).unwrap();
+
+        assert_eq!(ignored, vec!["docs/README.md", "docs/guide.md"]);
+    }
+
+    #[test]
+    fn git_directory_is_not_walked() {
+        let dir = fixture();
+        write(dir.path(), ".git/objects/ab/cdef", "");
+        write(dir.path(), ".dockerignore", ".git\n!src/main.rs\n");
+
+        let git = ignored_paths(dir.path(), IgnoreFiles::GIT).unwrap();
+        let docker = ignored_paths(dir.path(), IgnoreFiles::DOCKER).unwrap();
+
+        assert_eq!(git[0], ".git");
+        assert_eq!(docker, vec![".git"]);
+    }
+
+    #[test]
+    fn docker_exceptions_only_reach_their_directories() {
+        let dir = fixture();
+        write(dir.path(), ".dockerignore", "*\n!docs/README.md\n");
+
+        let ignored = ignored_paths(dir.path(), IgnoreFiles::DOCKER).unwrap();
+
+        assert_eq!(
+            ignored,
+            vec![
+                ".dockerignore",
+                ".gitignore",
+                "app.log",
+                "build",
+                "docs/guide.md",
+                "keep.log",
+                "src",
+                "target",
+                "vendor",
+                r"weird\[1\].log",
+            ]
+        );
+    }
+}
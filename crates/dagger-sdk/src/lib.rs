@@ -1,8 +1,43 @@
 #![deny(warnings)]
 
+pub mod api;
+pub mod blocking;
+mod cassette;
 mod client;
+#[cfg(test)]
+mod codegen_fixtures;
+mod exec;
+mod fake;
 mod gen;
+mod ignore_files;
+mod matrix;
+mod multi_platform;
 mod querybuilder;
+pub mod secrets;
+mod service;
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
 
+pub use cassette::{Cassette, CassetteMode, CASSETTE_ENV};
 pub use client::*;
+pub use exec::{ExecError, ExecResult};
+pub use fake::Fake;
 pub use gen::*;
+pub use ignore_files::{ignored_paths, IgnoreFiles};
+pub use matrix::{current_cell, Matrix, MatrixError};
+pub use multi_platform::MultiPlatformImage;
+pub use querybuilder::Selection;
+pub use service::{Readiness, Service};
+
+/// Includes the bindings `dagger_codegen::build::Builder` generated for a schema in a build.rs,
+/// by the file stem of the schema
+///
+/// ```ignore
+/// dagger_sdk::include_schema!("schema");
+/// ```
+#[macro_export]
+macro_rules! include_schema {
+    ($name:literal) => {
+        include!(concat!(env!("OUT_DIR"), "/", $name, ".rs"));
+    };
+}
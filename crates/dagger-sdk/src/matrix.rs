@@ -0,0 +1,229 @@
+//! Runs a pipeline per cell of a matrix, like a platform or a toolchain version, concurrently.
+//!
+//! The cells run on the task awaiting [`Matrix::run`], at most [`Matrix::concurrency`] at once.
+//! Queries a cell executes are logged at debug level with its name as the `cell` field.
+
+use std::fmt::{Display, Formatter};
+use std::future::Future;
+
+use futures::future::BoxFuture;
+use futures::{FutureExt, StreamExt};
+
+tokio::task_local! {
+    static CELL: String;
+}
+
+/// The name of the matrix cell running the current task, if any
+pub fn current_cell() -> Option<String> {
+    CELL.try_with(|cell| cell.clone()).ok()
+}
+
+pub struct Matrix<'a, T> {
+    cells: Vec<(String, BoxFuture<'a, eyre::Result<T>>)>,
+    concurrency: Option<usize>,
+}
+
+impl<'a, T> Default for Matrix<'a, T> {
+    fn default() -> Self {
+        Self {
+            cells: Vec::new(),
+            concurrency: None,
+        }
+    }
+}
+
+impl<'a, T: Send + 'a> Matrix<'a, T> {
+    /// A matrix without cells, running all of them at once
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A cell running `pipeline` for each of `items`, named by the item
+    pub fn over<I, F, Fut>(items: impl IntoIterator<Item = I>, pipeline: F) -> Self
+    where
+        I: Display,
+        F: Fn(I) -> Fut,
+        Fut: Future<Output = eyre::Result<T>> + Send + 'a,
+    {
+        items.into_iter().fold(Self::new(), |matrix, item| {
+            let name = item.to_string();
+            let cell = pipeline(item);
+            matrix.cell(name, || cell)
+        })
+    }
+
+    /// Runs at most `limit` cells at once, at least one
+    pub fn concurrency(mut self, limit: usize) -> Self {
+        self.concurrency = Some(limit.max(1));
+        self
+    }
+
+    /// Adds the cell `name`, running the pipeline `pipeline` returns
+    pub fn cell<F, Fut>(mut self, name: impl Into<String>, pipeline: F) -> Self
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = eyre::Result<T>> + Send + 'a,
+    {
+        self.cells.push((name.into(), pipeline().boxed()));
+        self
+    }
+
+    /// The result of every cell by name, in the order they were added, or the errors of the cells
+    /// which failed once all of them are done
+    pub async fn run(self) -> Result<Vec<(String, T)>, MatrixError> {
+        let cells = self.cells.len();
+        let limit = self.concurrency.unwrap_or(cells).max(1);
+
+        let results = futures::stream::iter(self.cells)
+            .map(|(name, pipeline)| async move {
+                let result = CELL.scope(name.clone(), pipeline).await;
+                (name, result)
+            })
+            .buffered(limit)
+            .collect::<Vec<_>>()
+            .await;
+
+        let mut succeeded = Vec::new();
+        let mut failed = Vec::new();
+        for (name, result) in results {
+            match result {
+                Ok(value) => succeeded.push((name, value)),
+                Err(e) => failed.push((name, e)),
+            }
+        }
+
+        match failed.is_empty() {
+            true => Ok(succeeded),
+            false => Err(MatrixError { failed, cells }),
+        }
+    }
+}
+
+/// The cells of a [`Matrix`] which failed
+#[derive(Debug)]
+pub struct MatrixError {
+    /// Name and error of each failed cell, in the order they were added
+    pub failed: Vec<(String, eyre::Report)>,
+    /// How many cells the matrix had
+    pub cells: usize,
+}
+
+impl Display for MatrixError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} of {} matrix cells failed",
+            self.failed.len(),
+            self.cells
+        )?;
+        for (name, error) in &self.failed {
+            write!(f, "\n\n{}: {:#}", name, error)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for MatrixError {}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::{current_cell, Matrix};
+    use crate::gen::QueryContainerOpts;
+    use crate::test_util::FakeEngine;
+
+    #[tokio::test]
+    async fn collects_results_in_order() {
+        let engine = FakeEngine::start().await.unwrap();
+        for (platform, exit_code) in [("linux/arm64", 0), ("linux/amd64", 1)] {
+            engine.respond(
+                format!(
+                    r#"query{{container(platform:"{}"){{from(address:"alpine"){{withExec(args:["uname"]){{exitCode}}}}}}}}"#,
+                    platform
+                ),
+                json!({"container": {"from": {"withExec": {"exitCode": exit_code}}}}),
+            );
+        }
+        let client = engine.client();
+
+        let results = Matrix::over(["linux/arm64", "linux/amd64"], |platform| {
+            let container = client
+                .container_opts(QueryContainerOpts::default().platform(platform))
+                .from("alpine")
+                .with_exec(vec!["uname"]);
+
+            async move { container.exit_code().await }
+        })
+        .run()
+        .await
+        .unwrap();
+
+        assert_eq!(
+            results,
+            vec![
+                ("linux/arm64".to_string(), 0),
+                ("linux/amd64".to_string(), 1)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn names_failed_cells() {
+        let err = Matrix::new()
+            .cell("1.68", || async { Ok(()) })
+            .cell("1.67", || async { eyre::bail!("cargo test failed") })
+            .cell("nightly", || async { eyre::bail!("cargo build failed") })
+            .run()
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "2 of 3 matrix cells failed\n\n1.67: cargo test failed\n\nnightly: cargo build failed"
+        );
+    }
+
+    #[tokio::test]
+    async fn limits_concurrency() {
+        let running = AtomicUsize::new(0);
+        let most = AtomicUsize::new(0);
+        let pipeline = |_: usize| async {
+            let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+            most.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            running.fetch_sub(1, Ordering::SeqCst);
+            Ok(())
+        };
+
+        Matrix::over(0..6, pipeline)
+            .concurrency(2)
+            .run()
+            .await
+            .unwrap();
+
+        assert_eq!(most.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn cells_know_their_name() {
+        let results = Matrix::over(["a", "b"], |_| async { Ok(current_cell()) })
+            .run()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            results,
+            vec![
+                ("a".to_string(), Some("a".to_string())),
+                ("b".to_string(), Some("b".to_string()))
+            ]
+        );
+        assert_eq!(current_cell(), None);
+    }
+}
@@ -0,0 +1,192 @@
+//! Builds an image for several platforms and publishes them as one manifest list.
+
+use std::future::Future;
+
+use crate::gen::{
+    Container, ContainerId, ContainerPublishOpts, Platform, Query, QueryContainerOpts,
+};
+use crate::matrix::Matrix;
+
+/// An image built by `build` for each of its platforms, from a container of that platform
+///
+/// ```ignore
+/// let reference = MultiPlatformImage::new(&client, |container, _platform| async move {
+///     Ok(container.from("alpine").with_exec(vec!["apk", "add", "git"]))
+/// })
+/// .platform("linux/amd64")
+/// .platform("linux/arm64")
+/// .publish("ttl.sh/example:1h")
+/// .await?;
+/// ```
+pub struct MultiPlatformImage<F> {
+    client: Query,
+    build: F,
+    platforms: Vec<Platform>,
+    concurrency: Option<usize>,
+}
+
+impl<F, Fut> MultiPlatformImage<F>
+where
+    F: Fn(Container, Platform) -> Fut,
+    Fut: Future<Output = eyre::Result<Container>> + Send,
+{
+    pub fn new(client: &Query, build: F) -> Self {
+        Self {
+            client: client.clone(),
+            build,
+            platforms: Vec::new(),
+            concurrency: None,
+        }
+    }
+
+    pub fn platform(mut self, platform: impl Into<Platform>) -> Self {
+        self.platforms.push(platform.into());
+        self
+    }
+
+    /// Builds at most `limit` platforms at once, see [`Matrix::concurrency`]
+    pub fn concurrency(mut self, limit: usize) -> Self {
+        self.concurrency = Some(limit);
+        self
+    }
+
+    /// Builds the variants in parallel and publishes them to `address` as a manifest list,
+    /// returning its reference pinned to the digest
+    pub async fn publish(self, address: impl Into<String>) -> eyre::Result<String> {
+        let address = address.into();
+        let variants = self.variants().await?;
+
+        let reference = self
+            .client
+            .container()
+            .publish_opts(
+                &address,
+                ContainerPublishOpts::default().platform_variants(variants),
+            )
+            .await?;
+        eyre::ensure!(
+            reference.contains("@sha256:"),
+            "publishing {} returned {}, which isn't pinned to a digest",
+            address,
+            reference
+        );
+
+        Ok(reference)
+    }
+
+    /// The id of the container `build` returns for each platform, in their order
+    async fn variants(&self) -> eyre::Result<Vec<ContainerId>> {
+        eyre::ensure!(!self.platforms.is_empty(), "no platforms to build for");
+        for (i, platform) in self.platforms.iter().enumerate() {
+            eyre::ensure!(
+                !self.platforms[..i].contains(platform),
+                "platform {} is built more than once",
+                platform.0
+            );
+        }
+
+        let mut matrix = Matrix::new();
+        if let Some(limit) = self.concurrency {
+            matrix = matrix.concurrency(limit);
+        }
+        for platform in &self.platforms {
+            let container = self
+                .client
+                .container_opts(QueryContainerOpts::default().platform(platform.clone()));
+            let variant = (self.build)(container, platform.clone());
+            matrix = matrix.cell(&platform.0, || async move { variant.await?.id().await });
+        }
+
+        Ok(matrix.run().await?.into_iter().map(|(_, id)| id).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::MultiPlatformImage;
+    use crate::test_util::FakeEngine;
+
+    const ADDRESS: &str = "ttl.sh/dagger-sdk:1h";
+
+    fn variant_query(platform: &str) -> String {
+        format!(
+            r#"query{{container(platform:"{}"){{from(address:"alpine"){{id}}}}}}"#,
+            platform
+        )
+    }
+
+    #[tokio::test]
+    async fn publishes_variants_as_manifest_list() {
+        let engine = FakeEngine::start().await.unwrap();
+        for platform in ["linux/amd64", "linux/arm64"] {
+            engine.respond(
+                variant_query(platform),
+                json!({"container": {"from": {"id": format!("id-{}", platform)}}}),
+            );
+        }
+        let publish = r#"query{container{publish(address:"ttl.sh/dagger-sdk:1h", platformVariants:["id-linux/amd64","id-linux/arm64"])}}"#;
+        engine.respond(
+            publish,
+            json!({"container": {"publish": "ttl.sh/dagger-sdk:1h@sha256:abc"}}),
+        );
+
+        let reference = MultiPlatformImage::new(&engine.client(), |container, _| async move {
+            Ok(container.from("alpine"))
+        })
+        .platform("linux/amd64")
+        .platform("linux/arm64")
+        .publish(ADDRESS)
+        .await
+        .unwrap();
+
+        assert_eq!(reference, "ttl.sh/dagger-sdk:1h@sha256:abc");
+        assert_eq!(engine.queries().last().unwrap(), publish);
+    }
+
+    #[tokio::test]
+    async fn names_failed_platforms() {
+        let engine = FakeEngine::start().await.unwrap();
+        engine.respond(
+            variant_query("linux/amd64"),
+            json!({"container": {"from": {"id": "id-linux/amd64"}}}),
+        );
+
+        let err = MultiPlatformImage::new(&engine.client(), |container, platform| async move {
+            eyre::ensure!(platform.0 != "linux/s390x", "no s390x toolchain");
+            Ok(container.from("alpine"))
+        })
+        .platform("linux/amd64")
+        .platform("linux/s390x")
+        .publish(ADDRESS)
+        .await
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "1 of 2 matrix cells failed\n\nlinux/s390x: no s390x toolchain"
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_duplicate_platforms() {
+        let engine = FakeEngine::start().await.unwrap();
+
+        let err = MultiPlatformImage::new(&engine.client(), |container, _| async move {
+            Ok(container.from("alpine"))
+        })
+        .platform("linux/amd64")
+        .platform("linux/amd64")
+        .publish(ADDRESS)
+        .await
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "platform linux/amd64 is built more than once"
+        );
+        assert_eq!(engine.queries(), Vec::<String>::new());
+    }
+}
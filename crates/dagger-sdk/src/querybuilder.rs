@@ -1,39 +1,52 @@
-use std::{collections::HashMap, ops::Add, sync::Arc};
+use std::{collections::BTreeMap, ops::Add, sync::Arc};
 
+use dagger_core::redaction::redact;
 use eyre::Context;
 use serde::{Deserialize, Serialize};
 
+use crate::cassette::Cassette;
+
 pub fn query() -> Selection {
     Selection::default()
 }
 
-impl Default for Selection {
-    fn default() -> Self {
-        Self {
-            name: Default::default(),
-            alias: Default::default(),
-            args: Default::default(),
-            prev: Default::default(),
-        }
-    }
-}
-
-#[derive(Debug, Clone)]
+#[derive(Clone, Default)]
 pub struct Selection {
     name: Option<String>,
     alias: Option<String>,
-    args: Option<HashMap<String, String>>,
+    /// Sorted by name, so the same selection always builds the same query
+    args: Option<BTreeMap<String, String>>,
 
     prev: Option<Arc<Selection>>,
+    /// Records or replays the queries of this selection and the ones selected from it
+    cassette: Option<Arc<Cassette>>,
+}
+
+impl std::fmt::Debug for Selection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // the query, as its arguments may hold secrets which are redacted from it
+        let query = self.build().map_err(|_| std::fmt::Error)?;
+        f.debug_struct("Selection")
+            .field("query", &redact(&query))
+            .finish()
+    }
 }
 
 impl Selection {
+    pub fn with_cassette(&self, cassette: Arc<Cassette>) -> Selection {
+        Self {
+            cassette: Some(cassette),
+            ..self.clone()
+        }
+    }
+
     pub fn select_with_alias(&self, alias: &str, name: &str) -> Selection {
         Self {
             name: Some(name.to_string()),
             alias: Some(alias.to_string()),
             args: None,
             prev: Some(Arc::new(self.clone())),
+            cassette: self.cassette.clone(),
         }
     }
 
@@ -43,9 +56,28 @@ impl Selection {
             alias: None,
             args: None,
             prev: Some(Arc::new(self.clone())),
+            cassette: self.cassette.clone(),
         }
     }
 
+    /// Selects several fields at once, their values are deserialized from a single object
+    pub fn select_fields(&self, names: &[&str]) -> Selection {
+        self.select(&names.join(" "))
+    }
+
+    /// Value of `arg` of the last selection of `name`, as serialized into the query
+    pub(crate) fn last_arg(&self, name: &str, arg: &str) -> Option<String> {
+        self.path()
+            .into_iter()
+            .rev()
+            .find(|s| s.name.as_deref() == Some(name))
+            .and_then(|s| s.args?.get(arg).cloned())
+    }
+
+    pub fn inline_fragment(&self, type_name: &str) -> Selection {
+        self.select(&format!("... on {}", type_name))
+    }
+
     pub fn arg<S>(&self, name: &str, value: S) -> Selection
     where
         S: Serialize,
@@ -59,7 +91,7 @@ impl Selection {
                 let _ = args.insert(name.to_string(), val);
             }
             None => {
-                let mut hm = HashMap::new();
+                let mut hm = BTreeMap::new();
                 let _ = hm.insert(name.to_string(), val);
                 s.args = Some(hm);
             }
@@ -68,24 +100,21 @@ impl Selection {
         s
     }
 
+    /// Enum values are written as is, without the quotes of a string
     pub fn arg_enum<S>(&self, name: &str, value: S) -> Selection
     where
-        S: Serialize,
+        S: std::fmt::Display,
     {
         let mut s = self.clone();
 
-        let val = serde_json::to_string(&value).unwrap();
-        let val = val[1..val.len() - 1].to_string();
-
-        println!("test");
-        println!("{}", val);
+        let val = value.to_string();
 
         match s.args.as_mut() {
             Some(args) => {
                 let _ = args.insert(name.to_string(), val);
             }
             None => {
-                let mut hm = HashMap::new();
+                let mut hm = BTreeMap::new();
                 let _ = hm.insert(name.to_string(), val);
                 s.args = Some(hm);
             }
@@ -125,12 +154,18 @@ impl Selection {
     {
         let query = self.build()?;
 
-        let qbs = query.as_str();
-        println!("{}", qbs);
-
-        let resp: Option<serde_json::Value> = match gql_client.query(&query).await {
-            Ok(r) => r,
-            Err(e) => eyre::bail!(e),
+        tracing::debug!(
+            cell = crate::matrix::current_cell(),
+            query = %redact(&query),
+            "executing query"
+        );
+
+        let resp: Option<serde_json::Value> = match self.cassette.as_ref() {
+            Some(cassette) => cassette.query(&query, gql_client).await?,
+            None => match gql_client.query(&query).await {
+                Ok(r) => r,
+                Err(e) => eyre::bail!(redact(&e.to_string())),
+            },
         };
 
         let resp: Option<D> = self.unpack_resp(resp)?;
@@ -168,14 +203,12 @@ impl Selection {
     where
         D: for<'de> Deserialize<'de>,
     {
+        // Objects of several fields are the values of `select_fields`
         if let Some(o) = r.as_object() {
-            let keys = o.keys();
-            if keys.len() != 1 {
-                eyre::bail!("too many nested objects inside graphql response")
+            if o.len() == 1 {
+                let first = o.keys().next().unwrap();
+                return self.unpack_resp_value(o.get(first).unwrap().clone());
             }
-
-            let first = keys.into_iter().next().unwrap();
-            return self.unpack_resp_value(o.get(first).unwrap().clone());
         }
 
         serde_json::from_value::<D>(r).context("could not deserialize response")
@@ -223,6 +256,22 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_inline_fragment() {
+        let root = query()
+            .select("search")
+            .arg("text", "alpine")
+            .inline_fragment("Container")
+            .select("id");
+
+        let query = root.build().unwrap();
+
+        assert_eq!(
+            query,
+            r#"query{search(text:"alpine"){... on Container{id}}}"#.to_string()
+        )
+    }
+
     #[test]
     fn test_arg_collision() {
         let root = query()
@@ -236,6 +285,47 @@ mod tests {
         assert_eq!(query, r#"query{a(arg:"one"){b(arg:"two")}}"#.to_string())
     }
 
+    #[test]
+    fn test_args_sorted() {
+        let root = query()
+            .select("a")
+            .arg("second", "two")
+            .arg("first", "one")
+            .arg("third", "three");
+
+        let query = root.build().unwrap();
+
+        assert_eq!(
+            query,
+            r#"query{a(first:"one", second:"two", third:"three")}"#.to_string()
+        )
+    }
+
+    #[test]
+    fn test_select_fields() {
+        let root = query()
+            .select("container")
+            .select_fields(&["exitCode", "stdout"]);
+
+        let query = root.build().unwrap();
+
+        assert_eq!(query, r#"query{container{exitCode stdout}}"#.to_string())
+    }
+
+    #[test]
+    fn test_last_arg() {
+        let root = query()
+            .select("a")
+            .arg("arg", "one")
+            .select("b")
+            .select("a")
+            .arg("arg", "two")
+            .select("c");
+
+        assert_eq!(root.last_arg("a", "arg"), Some(r#""two""#.to_string()));
+        assert_eq!(root.last_arg("b", "arg"), None);
+    }
+
     #[test]
     fn test_vec_arg() {
         let input = vec!["some-string"];
@@ -266,6 +356,14 @@ mod tests {
         assert_eq!(query, r#"query{a(arg:"some-string")}"#.to_string())
     }
 
+    #[test]
+    fn test_enum_arg() {
+        let root = query().select("a").arg_enum("protocol", "TCP");
+        let query = root.build().unwrap();
+
+        assert_eq!(query, r#"query{a(protocol:TCP)}"#.to_string())
+    }
+
     #[test]
     fn test_field_immutability() {
         let root = query().select("test");
@@ -0,0 +1,135 @@
+//! Secrets from the host, kept out of everything the SDK prints.
+//!
+//! Values registered with [`register_secret`] are replaced by `***` in the queries logged while
+//! executing, in errors, in the output of the engine and in recorded cassettes. The helpers on
+//! [`Host`] read the value on this side and register it before handing it to the engine.
+
+use std::path::Path;
+
+use eyre::Context;
+
+pub use dagger_core::redaction::{redact, register_secret, MIN_SECRET_LEN, REDACTED};
+
+use crate::gen::{Host, HostDirectoryOpts, Secret};
+
+impl Host {
+    /// The environment variable `name` as a secret, registered for redaction. Fails when the
+    /// variable isn't set for this process, which the engine session inherits.
+    pub fn secret_env_variable(&self, name: &str) -> eyre::Result<Secret> {
+        let value = std::env::var(name)
+            .with_context(|| format!("could not read environment variable {}", name))?;
+        register(&value);
+
+        Ok(self.env_variable(name).secret())
+    }
+
+    /// The file at `path` as a secret, registered for redaction. `path` is read on this side,
+    /// relative to the working directory of the process.
+    pub fn secret_file(&self, path: impl AsRef<Path>) -> eyre::Result<Secret> {
+        let path = path.as_ref();
+        let value = std::fs::read_to_string(path)
+            .with_context(|| format!("could not read secret file {}", path.display()))?;
+        register(&value);
+
+        let name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .ok_or_else(|| eyre::eyre!("{} is not a file", path.display()))?;
+        let dir = match path.parent().and_then(|p| p.to_str()) {
+            Some("") | None => ".",
+            Some(dir) => dir,
+        };
+        let opts = HostDirectoryOpts::default().include(vec![name]);
+
+        Ok(self.directory_opts(dir, opts).file(name).secret())
+    }
+}
+
+impl crate::blocking::Host {
+    /// Blocking version of [`Host::secret_env_variable`]
+    pub fn secret_env_variable(&self, name: &str) -> eyre::Result<crate::blocking::Secret> {
+        Ok(crate::blocking::Secret {
+            inner: self.inner.secret_env_variable(name)?,
+            runtime: self.runtime.clone(),
+        })
+    }
+
+    /// Blocking version of [`Host::secret_file`]
+    pub fn secret_file(&self, path: impl AsRef<Path>) -> eyre::Result<crate::blocking::Secret> {
+        Ok(crate::blocking::Secret {
+            inner: self.inner.secret_file(path)?,
+            runtime: self.runtime.clone(),
+        })
+    }
+}
+
+/// Registers `value`, and without the trailing newline files and variables tend to end with
+fn register(value: &str) {
+    register_secret(value);
+    register_secret(value.trim_end());
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::redact;
+    use crate::test_util::FakeEngine;
+
+    #[tokio::test]
+    async fn secret_env_variable_is_redacted() {
+        std::env::set_var("DAGGER_SDK_TEST_SECRET_ENV", "s3cr3t-from-env");
+        let engine = FakeEngine::start().await.unwrap();
+        let query = r#"query{host{envVariable(name:"DAGGER_SDK_TEST_SECRET_ENV"){secret{id}}}}"#;
+        engine.respond(
+            query,
+            json!({"host": {"envVariable": {"secret": {"id": "secret-id"}}}}),
+        );
+
+        let secret = engine
+            .client()
+            .host()
+            .secret_env_variable("DAGGER_SDK_TEST_SECRET_ENV")
+            .unwrap();
+        secret.id().await.unwrap();
+
+        assert_eq!(redact("token=s3cr3t-from-env"), "token=***");
+        assert_eq!(engine.queries(), vec![query.to_string()]);
+    }
+
+    #[tokio::test]
+    async fn secret_env_variable_fails_when_unset() {
+        let engine = FakeEngine::start().await.unwrap();
+
+        let err = engine
+            .client()
+            .host()
+            .secret_env_variable("DAGGER_SDK_TEST_UNSET_ENV")
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "could not read environment variable DAGGER_SDK_TEST_UNSET_ENV"
+        );
+    }
+
+    #[tokio::test]
+    async fn secret_file_is_redacted() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("token");
+        std::fs::write(&path, "s3cr3t-from-file\n").unwrap();
+        let engine = FakeEngine::start().await.unwrap();
+
+        engine.client().host().secret_file(&path).unwrap();
+        // passed in plain text by mistake
+        let container = engine
+            .client()
+            .container()
+            .with_env_variable("TOKEN", "s3cr3t-from-file");
+
+        assert_eq!(redact("s3cr3t-from-file\n"), "***");
+        assert_eq!(redact("s3cr3t-from-file."), "***.");
+        assert!(!format!("{:?}", container).contains("s3cr3t-from-file"));
+    }
+}
@@ -0,0 +1,242 @@
+//! Sidecar services, like a database or a cache, for the containers of integration tests.
+//!
+//! A [`Service`] is a container with exposed ports, bound to consumers by an alias with
+//! [`Container::with_service_binding`]. With a readiness probe, a client container bound to the
+//! service retries the probe until it passes, and consumers depend on that container, so their
+//! commands only run once the service is ready.
+
+use std::time::Duration;
+
+use crate::gen::{Container, ContainerEndpointOpts, Query};
+
+/// Where probe containers write that the service was ready, consumers mount it
+const READY_FILE: &str = "/ready";
+
+/// How a [`Service`] is probed for readiness, from a client container bound to it
+#[derive(Clone, Debug, PartialEq)]
+pub enum Readiness {
+    /// Ready once the command exits with 0. The alias of the service resolves to it, so the
+    /// command can reach it by its alias, like `pg_isready -h db`.
+    Exec { image: String, args: Vec<String> },
+    /// Ready once the port accepts TCP connections
+    Tcp { port: isize },
+}
+
+/// A container with exposed ports, started for its consumers and stopped once they are done
+///
+/// ```ignore
+/// let redis = Service::new(&client, client.container().from("redis:7"))
+///     .port(6379)
+///     .ready_when_tcp(6379);
+///
+/// let tests = redis
+///     .bind(&app, "cache")
+///     .await?
+///     .with_env_variable("REDIS_URL", redis.endpoint_with_scheme(6379, "redis").await?)
+///     .with_exec(vec!["cargo", "test"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Service {
+    client: Query,
+    container: Container,
+    readiness: Option<Readiness>,
+    timeout: Duration,
+}
+
+impl Service {
+    pub fn new(client: &Query, container: Container) -> Self {
+        Self {
+            client: client.clone(),
+            container,
+            readiness: None,
+            timeout: Duration::from_secs(60),
+        }
+    }
+
+    /// Exposes `port` of the service to its consumers
+    pub fn port(mut self, port: isize) -> Self {
+        self.container = self.container.with_exposed_port(port);
+        self
+    }
+
+    /// Probes readiness by running `args` in a container of `image`
+    pub fn ready_when_exec(mut self, image: impl Into<String>, args: Vec<&str>) -> Self {
+        self.readiness = Some(Readiness::Exec {
+            image: image.into(),
+            args: args.into_iter().map(|a| a.to_string()).collect(),
+        });
+        self
+    }
+
+    /// Probes readiness by connecting to `port`
+    pub fn ready_when_tcp(mut self, port: isize) -> Self {
+        self.readiness = Some(Readiness::Tcp { port });
+        self
+    }
+
+    /// How long the probe is retried before the service counts as failed, 60 seconds by default
+    pub fn ready_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn container(&self) -> &Container {
+        &self.container
+    }
+
+    /// The address of `port` of the service, reachable from its consumers
+    pub async fn endpoint(&self, port: isize) -> eyre::Result<String> {
+        self.container
+            .endpoint_opts(ContainerEndpointOpts::default().port(port))
+            .await
+    }
+
+    /// Like [`Self::endpoint`], as a URL with `scheme`, like `postgres://`
+    pub async fn endpoint_with_scheme(&self, port: isize, scheme: &str) -> eyre::Result<String> {
+        self.container
+            .endpoint_opts(ContainerEndpointOpts::default().port(port).scheme(scheme))
+            .await
+    }
+
+    /// `consumer` with the service bound as `alias`, running its commands once the service is
+    /// ready when it has a readiness probe
+    pub async fn bind(&self, consumer: &Container, alias: &str) -> eyre::Result<Container> {
+        let id = self.container.id().await?;
+        let consumer = consumer.with_service_binding(alias, id.clone());
+
+        let (image, check) = match &self.readiness {
+            Some(Readiness::Exec { image, args }) => (image.as_str(), shell_words(args)),
+            Some(Readiness::Tcp { port }) => ("alpine", format!("nc -z {} {}", alias, port)),
+            None => return Ok(consumer),
+        };
+        let ready = self
+            .client
+            .container()
+            .from(image)
+            .with_service_binding(alias, id)
+            .with_exec(vec!["sh", "-c", &probe_script(alias, &check, self.timeout)])
+            .file(READY_FILE);
+
+        Ok(consumer.with_mounted_file(
+            format!("/.dagger/services/{}/ready", alias),
+            ready.id().await?,
+        ))
+    }
+}
+
+/// Retries `check` every second until it passes or `timeout` is up
+fn probe_script(alias: &str, check: &str, timeout: Duration) -> String {
+    format!(
+        "for i in $(seq 1 {}); do if {}; then touch {}; exit 0; fi; sleep 1; done; echo 'service {} was not ready after {}s' >&2; exit 1",
+        timeout.as_secs().max(1),
+        check,
+        READY_FILE,
+        alias,
+        timeout.as_secs()
+    )
+}
+
+/// `args` quoted for `sh`
+fn shell_words(args: &[String]) -> String {
+    args.iter()
+        .map(|a| format!("'{}'", a.replace('\'', r"'\''")))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::{probe_script, shell_words, Service};
+    use crate::test_util::FakeEngine;
+
+    const SERVICE_ID: &str =
+        r#"query{container{from(address:"redis:7"){withExposedPort(port:6379){id}}}}"#;
+
+    #[test]
+    fn test_shell_words() {
+        assert_eq!(
+            shell_words(&["pg_isready".into(), "-h".into(), "it's".into()]),
+            r#"'pg_isready' '-h' 'it'\''s'"#
+        );
+    }
+
+    #[tokio::test]
+    async fn bind_without_readiness() {
+        let engine = FakeEngine::start().await.unwrap();
+        engine.respond(
+            SERVICE_ID,
+            json!({"container": {"from": {"withExposedPort": {"id": "redis-id"}}}}),
+        );
+        let client = engine.client();
+
+        let redis = Service::new(&client, client.container().from("redis:7")).port(6379);
+        let consumer = redis
+            .bind(&client.container().from("alpine"), "cache")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            consumer.selection.build().unwrap(),
+            r#"query{container{from(address:"alpine"){withServiceBinding(alias:"cache", service:"redis-id")}}}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn bind_waits_for_readiness() {
+        let engine = FakeEngine::start().await.unwrap();
+        engine.respond(
+            SERVICE_ID,
+            json!({"container": {"from": {"withExposedPort": {"id": "redis-id"}}}}),
+        );
+        let script = probe_script("cache", "nc -z cache 6379", Duration::from_secs(5));
+        let probe = format!(
+            r#"query{{container{{from(address:"alpine"){{withServiceBinding(alias:"cache", service:"redis-id"){{withExec(args:["sh","-c",{}]){{file(path:"/ready"){{id}}}}}}}}}}}}"#,
+            serde_json::to_string(&script).unwrap()
+        );
+        engine.respond(
+            &probe,
+            json!({"container": {"from": {"withServiceBinding": {"withExec": {"file": {"id": "ready-id"}}}}}}),
+        );
+        let client = engine.client();
+
+        let redis = Service::new(&client, client.container().from("redis:7"))
+            .port(6379)
+            .ready_when_tcp(6379)
+            .ready_timeout(Duration::from_secs(5));
+        let consumer = redis
+            .bind(&client.container().from("alpine"), "cache")
+            .await
+            .unwrap();
+
+        assert_eq!(engine.queries(), vec![SERVICE_ID.to_string(), probe]);
+        assert_eq!(
+            consumer.selection.build().unwrap(),
+            r#"query{container{from(address:"alpine"){withServiceBinding(alias:"cache", service:"redis-id"){withMountedFile(path:"/.dagger/services/cache/ready", source:"ready-id")}}}}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn endpoint_of_port() {
+        let engine = FakeEngine::start().await.unwrap();
+        engine.respond(
+            r#"query{container{from(address:"postgres:15"){withExposedPort(port:5432){endpoint(port:5432, scheme:"postgres")}}}}"#,
+            json!({"container": {"from": {"withExposedPort": {"endpoint": "postgres://abc:5432"}}}}),
+        );
+        let client = engine.client();
+
+        let postgres = Service::new(&client, client.container().from("postgres:15")).port(5432);
+
+        assert_eq!(
+            postgres
+                .endpoint_with_scheme(5432, "postgres")
+                .await
+                .unwrap(),
+            "postgres://abc:5432"
+        );
+    }
+}
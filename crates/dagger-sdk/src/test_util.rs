@@ -0,0 +1,383 @@
+//! Helpers for testing code using the SDK.
+//!
+//! [`test_client`] and [`blocking_test_client`] share a single `dagger session` between the tests
+//! of a test binary, instead of each test starting its own with [`connect`](crate::connect).
+//!
+//! [`FakeEngine`] replaces the engine altogether. It serves GraphQL over HTTP on a random local port like the engine does, so
+//! queries go through the whole client: the session token, the transport and the unpacking and
+//! errors of responses. It answers with the responses registered for the exact queries
+//! [`Selection::build`](crate::Selection::build) produces, and keeps the queries it received for
+//! tests to assert on.
+//!
+//! ```ignore
+//! let engine = FakeEngine::start().await?;
+//! engine.respond(
+//!     r#"query{container{from(address:"alpine"){stdout}}}"#,
+//!     json!({"container": {"from": {"stdout": "hello"}}}),
+//! );
+//!
+//! let stdout = engine.client().container().from("alpine").stdout().await?;
+//! ```
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use dagger_core::config::Config;
+use dagger_core::connect_params::ConnectParams;
+use dagger_core::engine::Engine as DaggerEngine;
+use eyre::Context;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::process::Child;
+use tokio::runtime::Runtime;
+use tokio::task::JoinHandle;
+
+use crate::client::{authorization, placeholder_process, DaggerConn};
+use crate::gen::Query;
+use crate::querybuilder::query;
+
+const SESSION_TOKEN: &str = "fake-engine";
+
+/// The session shared by the tests of a test binary
+struct Session {
+    conn: ConnectParams,
+    proc: Arc<Child>,
+    /// Forwards the output of the session, it outlives the runtimes of the tests
+    _runtime: Runtime,
+}
+
+static SESSION: OnceLock<eyre::Result<Session>> = OnceLock::new();
+
+/// A client of the session shared by all tests of the test binary, which the first call starts.
+///
+/// The session is killed when the test binary exits, which never drops the session.
+///
+/// ```ignore
+/// #[tokio::test]
+/// async fn test_container() -> eyre::Result<()> {
+///     let client = dagger_sdk::test_util::test_client().await?;
+///     ...
+/// }
+/// ```
+pub async fn test_client() -> eyre::Result<DaggerConn> {
+    // Starting the session blocks the tests waiting for it, away from their runtimes
+    let session = tokio::task::spawn_blocking(|| SESSION.get_or_init(start_session)).await?;
+
+    Ok(Arc::new(session_query(session)?))
+}
+
+/// Like [`test_client`], for the blocking API, see [`crate::blocking`]
+///
+/// ```ignore
+/// #[test]
+/// fn test_container() -> eyre::Result<()> {
+///     let client = dagger_sdk::test_util::blocking_test_client()?;
+///     ...
+/// }
+/// ```
+pub fn blocking_test_client() -> eyre::Result<crate::blocking::DaggerConn> {
+    let session = SESSION.get_or_init(start_session);
+
+    Ok(Arc::new(crate::blocking::Query {
+        inner: session_query(session)?,
+        runtime: Arc::new(Runtime::new()?),
+    }))
+}
+
+fn session_query(session: &eyre::Result<Session>) -> eyre::Result<Query> {
+    match session {
+        Ok(session) => Ok(Query {
+            conn: session.conn.clone(),
+            proc: session.proc.clone(),
+            selection: query(),
+        }),
+        Err(e) => eyre::bail!("could not start the shared session: {:#}", e),
+    }
+}
+
+fn start_session() -> eyre::Result<Session> {
+    let runtime = Runtime::new()?;
+    let (conn, proc) = runtime.block_on(DaggerEngine::new().start(&Config::default()))?;
+    // Statics are never dropped, so the session can't be killed on drop
+    // SAFETY: stop_session is a plain function, safe to call at any point of the exit
+    unsafe { libc::atexit(stop_session) };
+
+    Ok(Session {
+        conn,
+        proc: Arc::new(proc),
+        _runtime: runtime,
+    })
+}
+
+/// Kills the shared session, if it was started
+extern "C" fn stop_session() {
+    let Some(pid) = SESSION
+        .get()
+        .and_then(|session| session.as_ref().ok())
+        .and_then(|session| session.proc.id())
+    else {
+        return;
+    };
+
+    #[cfg(unix)]
+    // SAFETY: kill only sends a signal, the pid is of the session's child
+    unsafe {
+        libc::kill(pid as libc::pid_t, libc::SIGKILL);
+    }
+    #[cfg(windows)]
+    let _ = std::process::Command::new("taskkill")
+        .args(["/F", "/PID", &pid.to_string()])
+        .status();
+}
+
+#[derive(Default)]
+struct State {
+    /// Response bodies by query
+    responses: HashMap<String, Value>,
+    queries: Vec<String>,
+}
+
+/// An in-process GraphQL server standing in for the engine, which stops when dropped
+pub struct FakeEngine {
+    conn: ConnectParams,
+    proc: Arc<Child>,
+    state: Arc<Mutex<State>>,
+    server: JoinHandle<()>,
+}
+
+impl FakeEngine {
+    /// Starts listening on a random port of 127.0.0.1
+    pub async fn start() -> eyre::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .context("could not listen for the fake engine")?;
+        let conn = ConnectParams::new(listener.local_addr()?.port().into(), SESSION_TOKEN);
+        let state = Arc::new(Mutex::new(State::default()));
+
+        let server = tokio::spawn({
+            let state = state.clone();
+            let authorization = authorization(&conn);
+
+            async move {
+                while let Ok((stream, _)) = listener.accept().await {
+                    let state = state.clone();
+                    let authorization = authorization.clone();
+
+                    // The client reports requests which couldn't be served, as its connection breaks
+                    tokio::spawn(async move {
+                        let _ = serve(stream, &state, &authorization).await;
+                    });
+                }
+            }
+        });
+
+        Ok(Self {
+            conn,
+            proc: Arc::new(placeholder_process()?),
+            state,
+            server,
+        })
+    }
+
+    /// Parameters to connect to the fake engine with, like the ones of a `dagger session`
+    pub fn conn(&self) -> ConnectParams {
+        self.conn.clone()
+    }
+
+    /// The process objects hold in place of the one of an engine, which already exited
+    pub fn proc(&self) -> Arc<Child> {
+        self.proc.clone()
+    }
+
+    /// A client sending its queries to the fake engine
+    pub fn client(&self) -> DaggerConn {
+        Arc::new(Query {
+            proc: self.proc(),
+            selection: query(),
+            conn: self.conn(),
+        })
+    }
+
+    /// Answers `query` with `data`, the `data` field of the GraphQL response
+    pub fn respond(&self, query: impl Into<String>, data: Value) {
+        self.state
+            .lock()
+            .unwrap()
+            .responses
+            .insert(query.into(), json!({ "data": data }));
+    }
+
+    /// Answers `query` with a GraphQL error
+    pub fn respond_error(&self, query: impl Into<String>, message: impl Into<String>) {
+        self.state
+            .lock()
+            .unwrap()
+            .responses
+            .insert(query.into(), graphql_error(&message.into()));
+    }
+
+    /// The queries received so far, in order
+    pub fn queries(&self) -> Vec<String> {
+        self.state.lock().unwrap().queries.clone()
+    }
+}
+
+impl Drop for FakeEngine {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
+
+#[derive(Deserialize)]
+struct Request {
+    query: String,
+}
+
+/// Serves a single request, closing the connection after responding
+async fn serve(stream: TcpStream, state: &Mutex<State>, authorization: &str) -> eyre::Result<()> {
+    let mut reader = BufReader::new(stream);
+
+    let mut content_length = 0;
+    let mut authorized = false;
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).await?;
+
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            match name.to_ascii_lowercase().as_str() {
+                "content-length" => content_length = value.trim().parse()?,
+                "authorization" => authorized = value.trim() == authorization,
+                _ => {}
+            }
+        }
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).await?;
+
+    let (status, response) = if authorized {
+        let request: Request = serde_json::from_slice(&body)?;
+
+        let mut state = state.lock().unwrap();
+        state.queries.push(request.query.clone());
+        let response = match state.responses.get(&request.query) {
+            Some(response) => response.clone(),
+            None => graphql_error(&format!("no response for query {}", request.query)),
+        };
+
+        ("200 OK", response)
+    } else {
+        ("401 Unauthorized", graphql_error("invalid session token"))
+    };
+
+    let response = response.to_string();
+    let mut stream = reader.into_inner();
+    stream
+        .write_all(
+            format!(
+                "HTTP/1.1 {}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                status,
+                response.len(),
+                response
+            )
+            .as_bytes(),
+        )
+        .await?;
+
+    Ok(())
+}
+
+fn graphql_error(message: &str) -> Value {
+    json!({ "errors": [{ "message": message }] })
+}
+
+#[cfg(test)]
+mod test {
+    use dagger_core::connect_params::ConnectParams;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::FakeEngine;
+    use crate::gen::Query;
+    use crate::querybuilder::query;
+
+    const STDOUT: &str =
+        r#"query{container{from(address:"alpine"){withExec(args:["echo","hello"]){stdout}}}}"#;
+
+    #[tokio::test]
+    async fn responds_to_queries() {
+        let engine = FakeEngine::start().await.unwrap();
+        engine.respond(
+            STDOUT,
+            json!({"container": {"from": {"withExec": {"stdout": "hello\n"}}}}),
+        );
+
+        let stdout = engine
+            .client()
+            .container()
+            .from("alpine")
+            .with_exec(vec!["echo", "hello"])
+            .stdout()
+            .await
+            .unwrap();
+
+        assert_eq!(stdout, "hello\n");
+        assert_eq!(engine.queries(), vec![STDOUT.to_string()]);
+    }
+
+    #[tokio::test]
+    async fn responds_with_errors() {
+        let engine = FakeEngine::start().await.unwrap();
+        engine.respond_error(STDOUT, "exit code: 1");
+
+        let err = engine
+            .client()
+            .container()
+            .from("alpine")
+            .with_exec(vec!["echo", "hello"])
+            .stdout()
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("exit code: 1"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn fails_unknown_queries() {
+        let engine = FakeEngine::start().await.unwrap();
+
+        let err = engine.client().default_platform().await.unwrap_err();
+
+        assert!(
+            err.to_string()
+                .contains("no response for query query{defaultPlatform}"),
+            "{}",
+            err
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_other_sessions() {
+        let engine = FakeEngine::start().await.unwrap();
+        engine.respond(
+            "query{defaultPlatform}",
+            json!({"defaultPlatform": "linux/amd64"}),
+        );
+        let client = Query {
+            proc: engine.proc(),
+            selection: query(),
+            conn: ConnectParams::new(engine.conn().port, "other"),
+        };
+
+        let err = client.default_platform().await.unwrap_err();
+
+        assert!(err.to_string().contains("401"), "{}", err);
+        assert_eq!(engine.queries(), Vec::<String>::new());
+    }
+}
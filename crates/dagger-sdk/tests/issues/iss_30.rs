@@ -1,34 +1,33 @@
-use dagger_sdk::{QueryContainerOpts, QueryContainerOptsBuilder};
+use dagger_sdk::{Matrix, QueryContainerOpts, QueryContainerOptsBuilder};
 
 static PLATFORMS: [&str; 2] = ["linux/arm64", "linux/x86_64"];
 
 #[tokio::test]
 async fn test_issue_30_alt() -> eyre::Result<()> {
-    let client = dagger_sdk::connect().await?;
-
-    for platform in PLATFORMS {
-        let ref_ = client
-            .container_opts(QueryContainerOpts {
-                id: None,
-                platform: Some(platform.to_string().into()),
-            })
+    let client = crate::cassette_client("iss_30_alt").await?;
+
+    let exit_codes = Matrix::over(PLATFORMS, |platform| {
+        let container = client
+            .container_opts(QueryContainerOpts::default().platform(platform))
             .from("alpine")
-            .with_exec(vec!["echo", "'hello'"])
-            .exit_code()
-            .await?;
+            .with_exec(vec!["echo", "'hello'"]);
+
+        async move { container.exit_code().await }
+    })
+    .run()
+    .await?;
 
-        println!("published image to: {:#?}", ref_);
-    }
+    println!("exit codes: {:#?}", exit_codes);
 
     Ok(())
 }
 
 #[tokio::test]
 async fn test_issue_30() -> eyre::Result<()> {
-    let client = dagger_sdk::connect().await?;
+    let client = crate::cassette_client("iss_30").await?;
 
-    for platform in PLATFORMS {
-        let ref_ = client
+    let exit_codes = Matrix::over(PLATFORMS, |platform| {
+        let container = client
             .container_opts(
                 QueryContainerOptsBuilder::default()
                     .platform(platform)
@@ -36,12 +35,14 @@ async fn test_issue_30() -> eyre::Result<()> {
                     .unwrap(),
             )
             .from("alpine")
-            .with_exec(vec!["echo", "'hello'"])
-            .exit_code()
-            .await?;
+            .with_exec(vec!["echo", "'hello'"]);
+
+        async move { container.exit_code().await }
+    })
+    .run()
+    .await?;
 
-        println!("published image to: {:#?}", ref_);
-    }
+    println!("exit codes: {:#?}", exit_codes);
 
     Ok(())
 }
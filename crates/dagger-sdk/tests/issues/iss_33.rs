@@ -1,25 +1,23 @@
-use dagger_sdk::{ContainerWithExposedPortOpts, NetworkProtocol};
+use dagger_sdk::{ContainerWithExposedPortOptsBuilder, NetworkProtocol};
 
 #[tokio::test]
 async fn test_issue_30_alt() -> eyre::Result<()> {
-    let client = dagger_sdk::connect().await?;
+    let client = dagger_sdk::test_util::test_client().await?;
 
     client
         .container()
         .from("denoland/deno:debian-1.30.3")
         .with_exposed_port_opts(
             53,
-            ContainerWithExposedPortOpts {
-                protocol: Some(NetworkProtocol::TCP),
-                description: None,
-            },
+            ContainerWithExposedPortOptsBuilder::default()
+                .protocol(NetworkProtocol::TCP)
+                .build()?,
         )
         .with_exposed_port_opts(
             53,
-            ContainerWithExposedPortOpts {
-                protocol: Some(NetworkProtocol::UDP),
-                description: None,
-            },
+            ContainerWithExposedPortOptsBuilder::default()
+                .protocol(NetworkProtocol::UDP)
+                .build()?,
         )
         .with_exec(vec!["echo", "hello"])
         .exit_code()
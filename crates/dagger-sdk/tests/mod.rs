@@ -1,11 +1,26 @@
 mod issues;
 
-use dagger_sdk::{connect, ContainerExecOptsBuilder};
+use std::path::Path;
+
+use dagger_sdk::test_util::{blocking_test_client, test_client};
+use dagger_sdk::{connect_with_cassette, Cassette, ContainerExecOptsBuilder, DaggerConn};
 use pretty_assertions::assert_eq;
 
+/// A client answering from `tests/cassettes/<name>.json` without an engine, or recording it with
+/// `DAGGER_CASSETTE=record`. The cassettes checked in hold hand-written responses rather than
+/// recordings of an engine, so the tests using them cover the queries the SDK builds and how it
+/// unpacks responses, not the engine's protocol.
+async fn cassette_client(name: &str) -> eyre::Result<DaggerConn> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/cassettes")
+        .join(format!("{}.json", name));
+
+    connect_with_cassette(Cassette::from_env(path)?).await
+}
+
 #[tokio::test]
 async fn test_example_container() {
-    let client = connect().await.unwrap();
+    let client = test_client().await.unwrap();
 
     let alpine = client.container().from("alpine:3.16.2");
 
@@ -25,7 +40,7 @@ async fn test_example_container() {
 
 #[tokio::test]
 async fn test_directory() {
-    let c = connect().await.unwrap();
+    let c = test_client().await.unwrap();
 
     let contents = c
         .directory()
@@ -40,7 +55,7 @@ async fn test_directory() {
 
 #[tokio::test]
 async fn test_git() {
-    let c = connect().await.unwrap();
+    let c = cassette_client("test_git").await.unwrap();
 
     let tree = c.git("github.com/dagger/dagger").branch("main").tree();
 
@@ -65,7 +80,7 @@ async fn test_git() {
 
 #[tokio::test]
 async fn test_container() {
-    let client = connect().await.unwrap();
+    let client = test_client().await.unwrap();
 
     let alpine = client.container().from("alpine:3.16.2");
 
@@ -91,10 +106,12 @@ async fn test_container() {
 
     let id = alpine.id().await.unwrap();
     let contents = client
-        .container_opts(dagger_sdk::QueryContainerOpts {
-            id: Some(id),
-            platform: None,
-        })
+        .container_opts(
+            dagger_sdk::QueryContainerOptsBuilder::default()
+                .id(id)
+                .build()
+                .unwrap(),
+        )
         .fs()
         .file("/etc/alpine-release")
         .contents()
@@ -105,7 +122,7 @@ async fn test_container() {
 
 #[tokio::test]
 async fn test_err_message() {
-    let client = connect().await.unwrap();
+    let client = test_client().await.unwrap();
 
     let alpine = client.container().from("fake.invalid:latest").id().await;
     assert_eq!(alpine.is_err(), true);
@@ -118,3 +135,17 @@ Message: pull access denied, repository does not exist or may require authorizat
 
     assert_eq!(err.to_string().as_str(), error_msg);
 }
+
+#[test]
+fn test_blocking_directory() {
+    let client = blocking_test_client().unwrap();
+
+    let contents = client
+        .directory()
+        .with_new_file("/hello.txt", "world")
+        .file("/hello.txt")
+        .contents()
+        .unwrap();
+
+    assert_eq!("world", contents)
+}
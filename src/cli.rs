@@ -1,4 +1,4 @@
-use crate::cli_generate;
+use crate::{cli_export_schema, cli_generate, cli_schema_diff};
 
 pub struct Cli {
     cmd: clap::Command,
@@ -9,7 +9,9 @@ impl Cli {
         Ok(Self {
             cmd: clap::Command::new("dagger-rust")
                 .subcommand_required(true)
-                .subcommand(cli_generate::GenerateCommand::new_cmd()),
+                .subcommand(cli_generate::GenerateCommand::new_cmd())
+                .subcommand(cli_schema_diff::SchemaDiffCommand::new_cmd())
+                .subcommand(cli_export_schema::ExportSchemaCommand::new_cmd()),
         })
     }
 
@@ -18,6 +20,10 @@ impl Cli {
 
         match matches.subcommand() {
             Some(("generate", args)) => cli_generate::GenerateCommand::exec(args).await?,
+            Some(("schema-diff", args)) => cli_schema_diff::SchemaDiffCommand::exec(args).await?,
+            Some(("export-schema", args)) => {
+                cli_export_schema::ExportSchemaCommand::exec(args).await?
+            }
             _ => eyre::bail!("command missing"),
         }
 
@@ -0,0 +1,39 @@
+use std::io::Write;
+
+use clap::{Arg, ArgMatches};
+use dagger_core::config::Config;
+use dagger_core::engine::Engine;
+use dagger_core::session::Session;
+use eyre::Context;
+
+pub struct ExportSchemaCommand;
+
+impl ExportSchemaCommand {
+    pub fn new_cmd() -> clap::Command {
+        clap::Command::new("export-schema")
+            .about("write the introspection schema of the engine, for generate --schema")
+            .arg(Arg::new("output").long("output"))
+    }
+
+    pub async fn exec(arg_matches: &ArgMatches) -> eyre::Result<()> {
+        let cfg = Config::default();
+        let (conn, _proc) = Engine::new().start(&cfg).await?;
+        let session = Session::new();
+        let req = session.start(&cfg, &conn)?;
+        let json = session.schema_json(req).await?;
+
+        // pretty printed, so updates of the checked in schema can be reviewed
+        let json = serde_json::from_str::<serde_json::Value>(&json)
+            .context("the engine returned an invalid introspection schema")?;
+        let json = serde_json::to_string_pretty(&json)? + "\n";
+
+        match arg_matches.get_one::<String>("output") {
+            Some(output) => std::fs::File::create(output)
+                .with_context(|| format!("could not create {}", output))?
+                .write_all(json.as_bytes())?,
+            None => print!("{}", json),
+        }
+
+        Ok(())
+    }
+}
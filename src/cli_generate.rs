@@ -1,12 +1,23 @@
+use std::collections::BTreeMap;
 use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
 use std::sync::Arc;
 
-use clap::{Arg, ArgMatches};
-use dagger_codegen::generate;
+use clap::{Arg, ArgAction, ArgMatches};
+use dagger_codegen::config::Config as CodegenConfig;
+use dagger_codegen::generator::DynGenerator;
 use dagger_codegen::rust::RustGenerator;
+use dagger_codegen::sdl::SdlGenerator;
+use dagger_codegen::{generate_modules_with_diagnostics, generate_with_diagnostics};
 use dagger_core::config::Config;
 use dagger_core::engine::Engine;
+use dagger_core::introspection::Schema;
 use dagger_core::session::Session;
+use eyre::Context;
+use similar::TextDiff;
+
+use crate::cli_schema_diff::load_schema;
 
 #[allow(dead_code)]
 pub struct GenerateCommand;
@@ -14,23 +25,119 @@ pub struct GenerateCommand;
 #[allow(dead_code)]
 impl GenerateCommand {
     pub fn new_cmd() -> clap::Command {
-        clap::Command::new("generate").arg(Arg::new("output").long("output"))
+        clap::Command::new("generate")
+            .arg(Arg::new("output").long("output"))
+            .arg(
+                Arg::new("format")
+                    .long("format")
+                    .value_parser(["rust", "sdl"])
+                    .default_value("rust")
+                    .help("generate rust code, or the schema as GraphQL SDL"),
+            )
+            .arg(
+                Arg::new("config")
+                    .long("config")
+                    .help("customize the generated code with a dagger-codegen.toml"),
+            )
+            .arg(
+                Arg::new("schema")
+                    .long("schema")
+                    .help("generate from an introspection schema file instead of an engine"),
+            )
+            .arg(
+                Arg::new("check")
+                    .long("check")
+                    .action(ArgAction::SetTrue)
+                    .requires("output")
+                    .help("compare the generated code with --output instead of writing it"),
+            )
+            .arg(
+                Arg::new("modules")
+                    .long("modules")
+                    .action(ArgAction::SetTrue)
+                    .requires("output")
+                    .help("generate a module per type into the --output directory"),
+            )
+            .arg(
+                Arg::new("strict")
+                    .long("strict")
+                    .action(ArgAction::SetTrue)
+                    .help("fail on any codegen warning"),
+            )
     }
 
     pub async fn exec(arg_matches: &ArgMatches) -> eyre::Result<()> {
-        let cfg = Config::default();
-        let (conn, _proc) = Engine::new().start(&cfg).await?;
-        let session = Session::new();
-        let req = session.start(&cfg, &conn)?;
-        let schema = session.schema(req).await?;
-        let code = generate(
-            schema.into_schema().schema.unwrap(),
-            Arc::new(RustGenerator {}),
-        )?;
+        let sdl = arg_matches.get_one::<String>("format").map(|f| f.as_str()) == Some("sdl");
+        if sdl && arg_matches.get_flag("modules") {
+            eyre::bail!("--modules is only supported for --format rust");
+        }
+
+        let codegen_config = match arg_matches.get_one::<String>("config") {
+            Some(path) => CodegenConfig::from_toml(
+                &std::fs::read_to_string(path)
+                    .with_context(|| format!("could not read {}", path))?,
+            )
+            .with_context(|| format!("could not parse {}", path))?,
+            None => CodegenConfig::default(),
+        };
+
+        let schema = match arg_matches.get_one::<String>("schema") {
+            Some(path) => load_schema(path)?,
+            None => engine_schema().await?,
+        };
+        let generator: DynGenerator = if sdl {
+            Arc::new(SdlGenerator)
+        } else {
+            Arc::new(RustGenerator::new(codegen_config))
+        };
+
+        if arg_matches.get_flag("modules") {
+            let output = arg_matches
+                .get_one::<String>("output")
+                .ok_or(eyre::anyhow!("--modules requires --output"))?;
+
+            let generated = generate_modules_with_diagnostics(schema, generator)?;
+            for diagnostic in generated.diagnostics.iter() {
+                eprintln!("{}", diagnostic);
+            }
+            generated.check(arg_matches.get_flag("strict"))?;
+
+            let files = generated
+                .files
+                .iter()
+                .map(|(path, code)| Ok((path.clone(), format_code(code)?)))
+                .collect::<eyre::Result<BTreeMap<_, _>>>()?;
+
+            if arg_matches.get_flag("check") {
+                return check_modules(Path::new(output), &files);
+            }
+
+            return write_modules(Path::new(output), &files);
+        }
+
+        let generated = generate_with_diagnostics(schema, generator)?;
+
+        for diagnostic in generated.diagnostics.iter() {
+            eprintln!("{}", diagnostic);
+        }
+        generated.check(arg_matches.get_flag("strict"))?;
+        // formatted before writing as well, so --check passes on what --output wrote
+        let code = match sdl {
+            true => generated.code,
+            false => format_code(&generated.code)?,
+        };
+
+        if arg_matches.get_flag("check") {
+            let output = arg_matches
+                .get_one::<String>("output")
+                .ok_or(eyre::anyhow!("--check requires --output"))?;
+
+            return check(output, &code);
+        }
 
         if let Some(output) = arg_matches.get_one::<String>("output") {
             let mut file = std::fs::File::create(output)?;
-            file.write(code.as_bytes())?;
+            file.write_all(code.as_bytes())?;
         } else {
             println!("{}", code);
         }
@@ -38,3 +145,145 @@ impl GenerateCommand {
         Ok(())
     }
 }
+
+/// The schema of an engine started for this command
+async fn engine_schema() -> eyre::Result<Schema> {
+    let cfg = Config::default();
+    let (conn, _proc) = Engine::new().start(&cfg).await?;
+    let session = Session::new();
+    let req = session.start(&cfg, &conn)?;
+
+    session
+        .schema(req)
+        .await?
+        .into_schema()
+        .schema
+        .ok_or(eyre::anyhow!("the engine returned no __schema"))
+}
+
+/// Formats code the same way `cargo fmt` does in the ci `codegen` job.
+fn format_code(code: &str) -> eyre::Result<String> {
+    let mut rustfmt = Command::new("rustfmt")
+        .args(["--edition", "2021", "--emit", "stdout"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("could not start rustfmt")?;
+
+    rustfmt
+        .stdin
+        .take()
+        .ok_or(eyre::anyhow!("could not acquire stdin from rustfmt"))?
+        .write_all(code.as_bytes())?;
+
+    let output = rustfmt.wait_with_output()?;
+    if !output.status.success() {
+        eyre::bail!(
+            "rustfmt failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    String::from_utf8(output.stdout).context("rustfmt produced invalid utf-8")
+}
+
+/// Lists the rust files directly in `dir`, which the generator owns when generating modules
+fn existing_modules(dir: &Path) -> eyre::Result<Vec<String>> {
+    let mut modules = Vec::new();
+    for entry in
+        std::fs::read_dir(dir).with_context(|| format!("could not read {}", dir.display()))?
+    {
+        let name = entry?.file_name().to_string_lossy().to_string();
+        if name.ends_with(".rs") {
+            modules.push(name);
+        }
+    }
+
+    Ok(modules)
+}
+
+fn write_modules(dir: &Path, files: &BTreeMap<String, String>) -> eyre::Result<()> {
+    std::fs::create_dir_all(dir)?;
+
+    for stale in existing_modules(dir)?
+        .into_iter()
+        .filter(|m| !files.contains_key(m))
+    {
+        std::fs::remove_file(dir.join(stale))?;
+    }
+
+    for (path, code) in files {
+        std::fs::write(dir.join(path), code)?;
+    }
+
+    Ok(())
+}
+
+/// Prints the diff of every changed, added and removed module before failing
+fn check_modules(dir: &Path, files: &BTreeMap<String, String>) -> eyre::Result<()> {
+    let mut out_of_date = Vec::new();
+
+    for stale in existing_modules(dir)?
+        .into_iter()
+        .filter(|m| !files.contains_key(m))
+    {
+        let path = dir.join(&stale);
+        let existing = std::fs::read_to_string(&path)
+            .with_context(|| format!("could not read {}", path.display()))?;
+        if print_diff(&path.to_string_lossy(), &existing, "") {
+            out_of_date.push(stale);
+        }
+    }
+
+    for (module, code) in files {
+        let path = dir.join(module);
+        let existing = match path.exists() {
+            true => std::fs::read_to_string(&path)
+                .with_context(|| format!("could not read {}", path.display()))?,
+            false => String::new(),
+        };
+        if print_diff(&path.to_string_lossy(), &existing, code) {
+            out_of_date.push(module.clone());
+        }
+    }
+
+    if !out_of_date.is_empty() {
+        out_of_date.sort();
+        eyre::bail!(
+            "modules of {} are out of date: {}, run `cargo make codegen`",
+            dir.display(),
+            out_of_date.join(", ")
+        );
+    }
+
+    Ok(())
+}
+
+fn check(output: &str, code: &str) -> eyre::Result<()> {
+    let existing =
+        std::fs::read_to_string(output).with_context(|| format!("could not read {}", output))?;
+
+    if print_diff(output, &existing, code) {
+        eyre::bail!("{} is out of date, run `cargo make codegen`", output)
+    }
+
+    Ok(())
+}
+
+/// Prints the diff from `existing` to `code`, returns whether they differ
+fn print_diff(output: &str, existing: &str, code: &str) -> bool {
+    if existing == code {
+        return false;
+    }
+
+    let diff = TextDiff::from_lines(existing, code);
+    eprint!(
+        "{}",
+        diff.unified_diff()
+            .context_radius(3)
+            .header(output, "generated")
+    );
+
+    true
+}
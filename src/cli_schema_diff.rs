@@ -0,0 +1,56 @@
+use clap::{Arg, ArgAction, ArgMatches};
+use dagger_core::introspection::{IntrospectionResponse, Schema};
+use dagger_core::schema_diff::diff;
+use eyre::Context;
+
+pub struct SchemaDiffCommand;
+
+impl SchemaDiffCommand {
+    pub fn new_cmd() -> clap::Command {
+        clap::Command::new("schema-diff")
+            .about("report api changes between two introspection schemas")
+            .arg(Arg::new("old").required(true))
+            .arg(Arg::new("new").required(true))
+            .arg(
+                Arg::new("fail-on-breaking")
+                    .long("fail-on-breaking")
+                    .action(ArgAction::SetTrue)
+                    .help("exit non-zero when there are breaking changes"),
+            )
+    }
+
+    pub async fn exec(arg_matches: &ArgMatches) -> eyre::Result<()> {
+        let old = load_schema(arg_matches.get_one::<String>("old").unwrap())?;
+        let new = load_schema(arg_matches.get_one::<String>("new").unwrap())?;
+
+        let diff = diff(&old, &new);
+
+        for change in diff.changes.iter() {
+            println!("{}", change);
+        }
+
+        println!(
+            "{} breaking, {} non-breaking changes",
+            diff.breaking().count(),
+            diff.non_breaking().count()
+        );
+
+        if arg_matches.get_flag("fail-on-breaking") && diff.has_breaking_changes() {
+            eyre::bail!("the new schema has breaking changes");
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads an introspection schema, as written by `export-schema`
+pub(crate) fn load_schema(path: &str) -> eyre::Result<Schema> {
+    let content =
+        std::fs::read_to_string(path).with_context(|| format!("could not read {}", path))?;
+    let resp: IntrospectionResponse = serde_json::from_str(&content)
+        .with_context(|| format!("could not parse introspection schema {}", path))?;
+
+    resp.into_schema()
+        .schema
+        .ok_or(eyre::anyhow!("{} does not contain a __schema", path))
+}
@@ -1,7 +1,9 @@
 use cli::Cli;
 
 pub mod cli;
+mod cli_export_schema;
 mod cli_generate;
+mod cli_schema_diff;
 
 #[tokio::main]
 async fn main() -> eyre::Result<()> {